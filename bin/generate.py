@@ -6,6 +6,12 @@ import argparse
 import os
 import re
 
+integer = "i32"
+ffi_integer = "c_int"
+index = "usize"
+suffix = "_"
+complex = "complex"
+
 level_scalars = {
     1: ["alpha", "a", "b", "c", "s", "d1", "d2", "x1", "y1"],
     2: ["alpha", "beta"],
@@ -48,7 +54,7 @@ def translate_type_base(cty):
     if "c_char" in cty:
         return "u8"
     elif "c_int" in cty:
-        return "i32"
+        return integer
     elif "c_double_complex" in cty:
         return "c64"
     elif "c_float_complex" in cty:
@@ -64,7 +70,7 @@ def translate_body_argument(name, rty):
     if rty == "u8":
         return "&({} as c_char)".format(name)
 
-    elif rty == "i32":
+    elif rty == integer:
         return "&{}".format(name)
 
     elif rty.startswith("f"):
@@ -89,7 +95,7 @@ def translate_body_argument(name, rty):
 
 def translate_return_type(cty):
     if cty == "c_int":
-        return "usize"
+        return index
     elif cty == "c_float":
         return "f32"
     elif cty == "c_double":
@@ -97,9 +103,14 @@ def translate_return_type(cty):
 
     assert False, "cannot translate `{}`".format(cty)
 
+def returns_complex(f):
+    return len(f.args) > 0 and f.args[0][0] == "pres"
+
 def format_header(f):
     args = format_header_arguments(f)
-    if f.ret is None:
+    if returns_complex(f):
+        return "pub unsafe fn {}({}) -> {}".format(f.name, args, translate_type_base(f.args[0][1]))
+    elif f.ret is None:
         return "pub unsafe fn {}({})".format(f.name, args)
     else:
         return "pub unsafe fn {}({}) -> {}".format(f.name, args, translate_return_type(f.ret))
@@ -107,20 +118,35 @@ def format_header(f):
 def format_body(f):
     args = format_body_arguments(f)
     ret = format_body_return(f)
-    if ret is None:
-        return "ffi::{}_({})".format(f.name, args)
+    if returns_complex(f):
+        return "{}::{}{}({})".format(complex, f.name, suffix, args)
+    elif ret is None:
+        return "ffi::{}{}({})".format(f.name, suffix, args)
     else:
-        return "ffi::{}_({}) as {}".format(f.name, args, ret)
+        return "ffi::{}{}({}) as {}".format(f.name, suffix, args, ret)
+
+def format_arguments(f):
+    return f.args[1:] if returns_complex(f) else f.args
 
 def format_header_arguments(f):
     s = []
-    for arg in f.args:
-        s.append("{}: {}".format(arg[0], translate_argument(*arg, f=f)))
+    for arg in format_arguments(f):
+        rty = translate_argument(*arg, f=f)
+        if rty == "u8":
+            rty = "impl Into<u8>"
+        s.append("{}: {}".format(arg[0], rty))
     return ", ".join(s)
 
+def format_flags(f):
+    s = []
+    for arg in format_arguments(f):
+        if translate_argument(*arg, f=f) == "u8":
+            s.append("    let {0}: u8 = {0}.into();\n".format(arg[0]))
+    return "".join(s)
+
 def format_body_arguments(f):
     s = []
-    for arg in f.args:
+    for arg in format_arguments(f):
         rty = translate_argument(*arg, f=f)
         s.append(translate_body_argument(arg[0], rty))
     return ", ".join(s)
@@ -130,7 +156,7 @@ def format_body_return(f):
         return None
 
     rty = translate_return_type(f.ret)
-    if rty.startswith("f"):
+    if rty.startswith("f") or rty == integer:
         return None
 
     return rty
@@ -141,21 +167,147 @@ def prepare(level, code):
     lines = filter(lambda line: not re.match(r'^\s*$', line), lines)
     return [Function.parse(level, line) for line in lines]
 
+def format_declaration(name, args, ret, prefix="fn"):
+    line = "    {} {}({}){};".format(prefix, name, ", ".join(args), ret)
+    if len(line) <= 100:
+        return line
+    return "    {} {}(\n{}\n    ){};".format(
+        prefix,
+        name,
+        "\n".join("        {},".format(arg) for arg in args),
+        ret,
+    )
+
+def do_dynamic(functions):
+    for f in functions:
+        args = format_ffi_arguments(f)
+        if returns_complex(f):
+            print('    #[cfg(not(feature = "complex-by-value"))]')
+            print(format_declaration(f.name + suffix, args, ""))
+            print('    #[cfg(feature = "complex-by-value")]')
+            ret = " -> ::{}".format(translate_type_base(f.args[0][1]))
+            print(format_declaration(f.name + suffix, args[1:], ret))
+        else:
+            print(format_declaration(f.name + suffix, args, format_ffi_return(f)))
+
+def takes_characters(f):
+    return any("c_char" in arg[1] for arg in f.args)
+
+def format_ffi_arguments(f):
+    return ["{}: {}".format(arg[0], arg[1].replace("c_int", ffi_integer)) for arg in f.args]
+
+def format_ffi_return(f):
+    return "" if f.ret is None else " -> {}".format(f.ret.replace("c_int", ffi_integer))
+
+def do_ffi(functions):
+    for f in functions:
+        args = format_ffi_arguments(f)
+        ret = format_ffi_return(f)
+        if returns_complex(f):
+            print('    #[cfg(not(feature = "complex-by-value"))]')
+        elif takes_characters(f):
+            print('    #[cfg(not(feature = "hidden-length"))]')
+        print(format_declaration(f.name + suffix, args, ret, prefix="pub fn"))
+
+def do_hidden(functions):
+    functions = [f for f in functions if takes_characters(f)]
+    if len(functions) == 0:
+        return
+    print('\nextern "C" {')
+    for f in functions:
+        args = format_ffi_arguments(f)
+        args += ["{}_len: size_t".format(arg[0]) for arg in f.args if "c_char" in arg[1]]
+        print('    #[link_name = "{}{}"]'.format(f.name, suffix))
+        print(format_declaration(f.name, args, format_ffi_return(f)))
+    print("}")
+    for f in functions:
+        args = format_ffi_arguments(f)
+        names = [arg[0] for arg in f.args] + ["1" for arg in f.args if "c_char" in arg[1]]
+        print("\n#[inline]")
+        print(format_declaration(f.name + suffix, args, format_ffi_return(f), prefix="pub unsafe fn")[4:-1] + " {")
+        print("    {}({})\n}}".format(f.name, ", ".join(names)))
+
+def format_trace(f):
+    args = format_arguments(f)
+    flags = [arg[0] for arg in args if "c_char" in arg[1]]
+    dimensions = [arg[0] for arg in args if arg[0] in ["m", "n", "k", "kl", "ku"]]
+    leading = [arg[0] for arg in args if arg[0].startswith("ld")]
+    return "trace!({}, [{}], [{}], [{}], [{}], {})".format(
+        f.name,
+        ", ".join(flags),
+        ", ".join(dimensions),
+        ", ".join(leading),
+        ", ".join(arg[0] for arg in args),
+        format_body(f),
+    )
+
+def do_replay(functions):
+    for f in functions:
+        print('    "{}" => {{'.format(f.name))
+        args, outputs = [], []
+        for arg in format_arguments(f):
+            rty = translate_argument(*arg, f=f)
+            if rty.startswith("&mut"):
+                base = rty[len("&mut "):].replace("&[", "[")
+                outputs.append(arg[0])
+                prefix = "mut "
+                args.append("&mut " + arg[0])
+            elif rty.startswith("&["):
+                base = rty[1:]
+                prefix = ""
+                args.append("&" + arg[0])
+            else:
+                base = rty
+                prefix = ""
+                args.append(arg[0])
+            if base.startswith("["):
+                base = "Vec<{}>".format(base[1:-1])
+            print('        let {}{}: {} = arguments.get("{}")?;'.format(prefix, arg[0], base, arg[0]))
+        call = "::{}({})".format(f.name, ", ".join(args))
+        if returns_complex(f) or f.ret is not None:
+            print("        let result = {};".format(call))
+            result = "result.value(true)"
+        else:
+            print("        {};".format(call))
+            result = "Value::None"
+        outputs = ", ".join('("{}", {}.value(true))'.format(name, name) for name in outputs)
+        print("        Ok((vec![{}], {}))".format(outputs, result))
+        print("    }")
+
 def do(functions, reference):
     for f in functions:
         if reference is not None:
             print_documentation(f, reference)
         print("\n#[inline]")
         print(format_header(f) + " {")
-        print("    " + format_body(f) + "\n}")
+        print(format_flags(f) + "    " + format_trace(f) + "\n}")
 
 if __name__ == '__main__':
     parser = argparse.ArgumentParser()
     parser.add_argument('--sys', required=True)
     parser.add_argument('--doc')
+    parser.add_argument('--dynamic', action='store_true')
+    parser.add_argument('--ilp64', action='store_true')
+    parser.add_argument('--ffi', action='store_true')
+    parser.add_argument('--hidden', action='store_true')
+    parser.add_argument('--replay', action='store_true')
     arguments = parser.parse_args()
+    if arguments.ilp64:
+        integer = "i64"
+        ffi_integer = "i64"
+        index = "i64"
+        suffix = "_64_"
+        complex = "complex::ilp64"
     sections = read_functions(os.path.join(arguments.sys, 'src', 'lib.rs'))
     assert(len(sections) == 3)
-    do(prepare(1, sections[0]), arguments.doc)
-    do(prepare(2, sections[1]), arguments.doc)
-    do(prepare(3, sections[2]), arguments.doc)
+    for level, section in enumerate(sections, 1):
+        if arguments.dynamic:
+            do_dynamic(prepare(level, section))
+        elif arguments.ffi:
+            do_ffi(prepare(level, section))
+        elif arguments.hidden:
+            do_hidden(prepare(level, section))
+        elif arguments.replay:
+            do_replay(prepare(level, section))
+        else:
+            do(prepare(level, section), arguments.doc)
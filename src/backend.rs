@@ -0,0 +1,200 @@
+//! Introspection of the library in use.
+
+#[cfg(all(unix, not(all(feature = "reference", not(feature = "dynamic")))))]
+use std::ffi::CStr;
+
+#[cfg(all(unix, not(all(feature = "reference", not(feature = "dynamic")))))]
+use libc::{c_char, c_int};
+
+/// A description of the library in use.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Backend {
+    /// The name of the library, such as `OpenBLAS`, `BLIS`, `MKL`, or
+    /// `reference`.
+    pub name: &'static str,
+    /// The version and configuration reported by the library if any.
+    pub config: Option<String>,
+    /// The width of the integers taken by the library in bits if known.
+    pub integer: Option<u32>,
+}
+
+/// Return a description of the library in use if it can be identified.
+///
+/// The library is identified by looking for routines specific to OpenBLAS,
+/// BLIS, and MKL among the symbols of the process or, with the `dynamic`
+/// feature enabled, of the library loaded at run time.
+pub fn backend_info() -> Option<Backend> {
+    imp::info()
+}
+
+/// Set the number of threads used by the library.
+///
+/// The function returns `None` if the library does not provide a way of doing
+/// so.
+pub fn set_num_threads(threads: usize) -> Option<()> {
+    imp::set_num_threads(threads)
+}
+
+/// Return the number of threads used by the library.
+///
+/// The function returns `None` if the library does not provide a way of
+/// querying it.
+pub fn get_num_threads() -> Option<usize> {
+    imp::get_num_threads()
+}
+
+#[cfg(all(feature = "reference", not(feature = "dynamic")))]
+mod imp {
+    use super::Backend;
+
+    pub fn info() -> Option<Backend> {
+        Some(Backend {
+            name: "reference",
+            config: Some(concat!("blas ", env!("CARGO_PKG_VERSION")).into()),
+            integer: Some(32),
+        })
+    }
+
+    pub fn set_num_threads(_: usize) -> Option<()> {
+        None
+    }
+
+    pub fn get_num_threads() -> Option<usize> {
+        Some(1)
+    }
+}
+
+#[cfg(all(unix, not(all(feature = "reference", not(feature = "dynamic")))))]
+mod imp {
+    use super::*;
+
+    /// The capacity of the buffer receiving the version of MKL.
+    const VERSION_CAPACITY: usize = 256;
+
+    pub fn info() -> Option<Backend> {
+        unsafe {
+            type Config = unsafe extern "C" fn() -> *const c_char;
+            type Size = unsafe extern "C" fn() -> i64;
+            type Version = unsafe extern "C" fn(*mut c_char, c_int);
+            if let Some(function) =
+                symbol::<Config>(&["openblas_get_config\0", "openblas_get_config64_\0"])
+            {
+                let config = string(function());
+                let integer = if config.contains("USE64BITINT") {
+                    64
+                } else {
+                    32
+                };
+                return Some(Backend {
+                    name: "OpenBLAS",
+                    config: Some(config),
+                    integer: Some(integer),
+                });
+            }
+            if let Some(function) = symbol::<Config>(&["bli_info_get_version_str\0"]) {
+                let integer = symbol::<Size>(&["bli_info_get_blas_int_type_size\0"]);
+                return Some(Backend {
+                    name: "BLIS",
+                    config: Some(string(function())),
+                    integer: integer.map(|function| function() as u32),
+                });
+            }
+            if let Some(function) = symbol::<Version>(&["MKL_Get_Version_String\0"]) {
+                let mut buffer = vec![0 as c_char; VERSION_CAPACITY];
+                function(buffer.as_mut_ptr(), VERSION_CAPACITY as c_int - 1);
+                return Some(Backend {
+                    name: "MKL",
+                    config: Some(string(buffer.as_ptr())),
+                    integer: None,
+                });
+            }
+            None
+        }
+    }
+
+    pub fn set_num_threads(threads: usize) -> Option<()> {
+        unsafe {
+            type Set = unsafe extern "C" fn(c_int);
+            type SetBlis = unsafe extern "C" fn(i64);
+            let threads = threads.clamp(1, c_int::MAX as usize);
+            if let Some(function) = symbol::<Set>(&[
+                "openblas_set_num_threads\0",
+                "openblas_set_num_threads64_\0",
+            ]) {
+                function(threads as c_int);
+            } else if let Some(function) = symbol::<SetBlis>(&["bli_thread_set_num_threads\0"]) {
+                function(threads as i64);
+            } else if let Some(function) = symbol::<Set>(&["MKL_Set_Num_Threads\0"]) {
+                function(threads as c_int);
+            } else {
+                return None;
+            }
+            Some(())
+        }
+    }
+
+    pub fn get_num_threads() -> Option<usize> {
+        unsafe {
+            type Get = unsafe extern "C" fn() -> c_int;
+            type GetBlis = unsafe extern "C" fn() -> i64;
+            let threads = if let Some(function) = symbol::<Get>(&[
+                "openblas_get_num_threads\0",
+                "openblas_get_num_threads64_\0",
+            ]) {
+                function() as i64
+            } else if let Some(function) = symbol::<GetBlis>(&["bli_thread_get_num_threads\0"]) {
+                function()
+            } else if let Some(function) = symbol::<Get>(&["MKL_Get_Max_Threads\0"]) {
+                function() as i64
+            } else {
+                return None;
+            };
+            Some(threads.max(1) as usize)
+        }
+    }
+
+    /// Return the first of the symbols given as null-terminated strings that
+    /// the library provides.
+    unsafe fn symbol<F: Copy>(names: &[&str]) -> Option<F> {
+        names.iter().filter_map(|name| lookup(name)).next()
+    }
+
+    #[cfg(feature = "dynamic")]
+    unsafe fn lookup<F: Copy>(name: &str) -> Option<F> {
+        ::dynamic::current().and_then(|library| library.symbol(name))
+    }
+
+    #[cfg(not(feature = "dynamic"))]
+    unsafe fn lookup<F: Copy>(name: &str) -> Option<F> {
+        let pointer = libc::dlsym(libc::RTLD_DEFAULT, name.as_ptr() as *const c_char);
+        if pointer.is_null() {
+            None
+        } else {
+            Some(::std::mem::transmute_copy(&pointer))
+        }
+    }
+
+    unsafe fn string(pointer: *const c_char) -> String {
+        if pointer.is_null() {
+            return String::new();
+        }
+        CStr::from_ptr(pointer).to_string_lossy().trim().into()
+    }
+}
+
+#[cfg(all(not(unix), not(all(feature = "reference", not(feature = "dynamic")))))]
+mod imp {
+    use super::Backend;
+
+    pub fn info() -> Option<Backend> {
+        None
+    }
+
+    pub fn set_num_threads(_: usize) -> Option<()> {
+        None
+    }
+
+    pub fn get_num_threads() -> Option<usize> {
+        None
+    }
+}
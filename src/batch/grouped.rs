@@ -0,0 +1,411 @@
+use std::mem;
+
+use checked::Check;
+use matrix::{self, MatMut, MatRef};
+use parallel;
+use {Blas, Result, Transpose};
+
+/// A group of problems for [`gemm_batch`] sharing the operations and the
+/// scalars.
+///
+/// Problem `i` of the group computes `c[i] := alpha * op(a[i]) * op(b[i]) +
+/// beta * c[i]`. The dimensions are taken from the views, so the problems of
+/// a group usually, but not necessarily, have the same shape.
+///
+/// [`gemm_batch`]: fn.gemm_batch.html
+#[derive(Debug)]
+pub struct GemmGroup<'a, T: 'a> {
+    /// The operation applied to each `a[i]`.
+    pub transa: Transpose,
+    /// The operation applied to each `b[i]`.
+    pub transb: Transpose,
+    /// The scalar multiplying the products.
+    pub alpha: T,
+    /// The scalar multiplying the outputs.
+    pub beta: T,
+    /// The left operands.
+    pub a: Vec<MatRef<'a, T>>,
+    /// The right operands.
+    pub b: Vec<MatRef<'a, T>>,
+    /// The outputs.
+    pub c: Vec<MatMut<'a, T>>,
+}
+
+/// A problem ready to be computed.
+struct Problem<'a, 'b: 'a, T: 'b> {
+    transa: Transpose,
+    transb: Transpose,
+    alpha: T,
+    beta: T,
+    a: MatRef<'b, T>,
+    b: MatRef<'b, T>,
+    c: &'a mut MatMut<'b, T>,
+    result: Result<()>,
+}
+
+impl<'a, T> GemmGroup<'a, T> {
+    /// Create a group without problems.
+    #[inline]
+    pub fn new(transa: Transpose, transb: Transpose, alpha: T, beta: T) -> Self {
+        GemmGroup {
+            transa,
+            transb,
+            alpha,
+            beta,
+            a: vec![],
+            b: vec![],
+            c: vec![],
+        }
+    }
+
+    /// Add a problem.
+    #[inline]
+    pub fn push(&mut self, a: MatRef<'a, T>, b: MatRef<'a, T>, c: MatMut<'a, T>) {
+        self.a.push(a);
+        self.b.push(b);
+        self.c.push(c);
+    }
+
+    /// Return the number of problems.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.c.len()
+    }
+
+    /// Check if the group has no problems.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.c.is_empty()
+    }
+}
+
+/// Compute `c := alpha * op(a) * op(b) + beta * c` for each problem of each
+/// group using [`matrix::gemm`], in the style of `?gemm_batch` of MKL.
+///
+/// All problems are validated before any of them is computed, and the error
+/// refers to the arguments of [`matrix::gemm`]. The problems are independent
+/// of one another and are distributed over several threads if the total
+/// amount of work is large enough, which requires the outputs not to overlap,
+/// as guaranteed by the borrowing rules.
+///
+/// ## Example
+///
+/// ```no_run
+/// use blas::batch::{gemm_batch, GemmGroup};
+/// use blas::matrix::{Layout, MatMut, MatRef};
+/// use blas::Transpose;
+///
+/// let (a, b) = (vec![1.0; 6], vec![1.0; 12]);
+/// let (mut c, mut d) = (vec![0.0; 8], vec![0.0; 9]);
+///
+/// let mut first = GemmGroup::new(Transpose::No, Transpose::No, 1.0, 0.0);
+/// first.push(
+///     MatRef::new(&a, 2, 3, Layout::ColMajor).unwrap(),
+///     MatRef::new(&b, 3, 4, Layout::ColMajor).unwrap(),
+///     MatMut::new(&mut c, 2, 4, Layout::ColMajor).unwrap(),
+/// );
+/// let mut second = GemmGroup::new(Transpose::Trans, Transpose::No, 2.0, 0.0);
+/// second.push(
+///     MatRef::new(&a, 2, 3, Layout::ColMajor).unwrap(),
+///     MatRef::new(&a, 2, 3, Layout::ColMajor).unwrap(),
+///     MatMut::new(&mut d, 3, 3, Layout::ColMajor).unwrap(),
+/// );
+/// gemm_batch(&mut [first, second]).unwrap();
+/// ```
+///
+/// [`matrix::gemm`]: ../matrix/fn.gemm.html
+pub fn gemm_batch<T: Blas>(groups: &mut [GemmGroup<T>]) -> Result<()> {
+    let check = Check::new("gemm_batch");
+    let mut flops = 0.0;
+    for group in groups.iter() {
+        if group.a.len() != group.c.len() {
+            return Err(check.argument(4, "a", "must have as many matrices as c"));
+        }
+        if group.b.len() != group.c.len() {
+            return Err(check.argument(5, "b", "must have as many matrices as c"));
+        }
+        for ((a, b), c) in group.a.iter().zip(&group.b).zip(&group.c) {
+            matrix::operations(&check, group.transa, group.transb, a, b, c)?;
+            let k = if group.transa == Transpose::No {
+                a.cols()
+            } else {
+                a.rows()
+            };
+            flops += 2.0 * c.rows() as f64 * c.cols() as f64 * k as f64;
+        }
+    }
+    if mem::size_of::<T>() != mem::size_of::<T::Real>() {
+        flops *= 4.0;
+    }
+    let mut problems = vec![];
+    for group in groups.iter_mut() {
+        for ((a, b), c) in group.a.iter().zip(&group.b).zip(&mut group.c) {
+            problems.push(Problem {
+                transa: group.transa,
+                transb: group.transb,
+                alpha: group.alpha,
+                beta: group.beta,
+                a: *a,
+                b: *b,
+                c,
+                result: Ok(()),
+            });
+        }
+    }
+    let threads = parallel::threads(problems.len(), flops);
+    parallel::each(&mut problems, threads, |problem| {
+        problem.result = matrix::gemm(
+            problem.transa,
+            problem.transb,
+            problem.alpha,
+            problem.a,
+            problem.b,
+            problem.beta,
+            problem.c,
+        );
+    });
+    problems.into_iter().try_for_each(|problem| problem.result)
+}
+
+#[cfg(all(test, feature = "reference", not(feature = "dynamic")))]
+mod tests {
+    use super::*;
+    use c64;
+    use matrix::Layout;
+
+    fn elements<T: From<f64>>(len: usize, seed: f64) -> Vec<T> {
+        (0..len)
+            .map(|i| T::from((seed + 0.37 * i as f64).sin()))
+            .collect()
+    }
+
+    /// A problem given by `(m, n, k, ldc)` for each group.
+    type Shapes = [(
+        Transpose,
+        Transpose,
+        f64,
+        f64,
+        &'static [(usize, usize, usize, usize)],
+    )];
+
+    fn compare(shapes: &Shapes) {
+        let mut operands = vec![];
+        let mut expected = vec![];
+        for (g, &(transa, transb, alpha, beta, problems)) in shapes.iter().enumerate() {
+            for (p, &(m, n, k, ldc)) in problems.iter().enumerate() {
+                let seed = (10 * g + p) as f64;
+                let a = elements::<f64>(m * k, seed);
+                let b = elements::<f64>(k * n, seed + 0.5);
+                let c = elements::<f64>(ldc * n, seed + 0.25);
+                let (lda, ldb) = (
+                    if transa == Transpose::No { m } else { k },
+                    if transb == Transpose::No { k } else { n },
+                );
+                let mut d = c.clone();
+                unsafe {
+                    ::dgemm(
+                        transa,
+                        transb,
+                        m as i32,
+                        n as i32,
+                        k as i32,
+                        alpha,
+                        &a,
+                        lda.max(1) as i32,
+                        &b,
+                        ldb.max(1) as i32,
+                        beta,
+                        &mut d,
+                        ldc as i32,
+                    )
+                };
+                expected.push(d);
+                operands.push((a, b, c));
+            }
+        }
+
+        let mut groups = vec![];
+        let mut operands = &mut operands[..];
+        for &(transa, transb, alpha, beta, problems) in shapes {
+            let mut group = GemmGroup::new(transa, transb, alpha, beta);
+            let (head, tail) = operands.split_at_mut(problems.len());
+            operands = tail;
+            for (&(m, n, k, ldc), &mut (ref a, ref b, ref mut c)) in problems.iter().zip(head) {
+                let (rows, cols) = if transa == Transpose::No {
+                    (m, k)
+                } else {
+                    (k, m)
+                };
+                let a = MatRef::new(a, rows, cols, Layout::ColMajor).unwrap();
+                let (rows, cols) = if transb == Transpose::No {
+                    (k, n)
+                } else {
+                    (n, k)
+                };
+                let b = MatRef::new(b, rows, cols, Layout::ColMajor).unwrap();
+                let c = MatMut::with_ld(c, m, n, ldc, Layout::ColMajor).unwrap();
+                group.push(a, b, c);
+            }
+            groups.push(group);
+        }
+        gemm_batch(&mut groups).unwrap();
+        let results = groups
+            .iter()
+            .flat_map(|group| group.c.iter().map(|c| c.as_slice().to_vec()))
+            .collect::<Vec<_>>();
+        for (result, expected) in results.iter().zip(&expected) {
+            assert_eq!(&result[..], &expected[..result.len()]);
+        }
+    }
+
+    #[test]
+    fn shapes() {
+        compare(&[
+            (
+                Transpose::No,
+                Transpose::No,
+                1.0,
+                0.0,
+                &[(2, 3, 4, 2), (5, 1, 2, 7), (1, 1, 1, 1)],
+            ),
+            (Transpose::Trans, Transpose::No, 0.5, 2.0, &[(3, 3, 1, 4)]),
+            (Transpose::No, Transpose::ConjTrans, -1.0, 1.0, &[]),
+            (
+                Transpose::Trans,
+                Transpose::Trans,
+                2.0,
+                -0.5,
+                &[(4, 2, 3, 4), (2, 6, 5, 3)],
+            ),
+            (
+                Transpose::No,
+                Transpose::No,
+                1.0,
+                1.0,
+                &[(3, 2, 0, 3), (0, 2, 3, 1)],
+            ),
+        ]);
+    }
+
+    #[test]
+    fn parallel() {
+        // The problems amount to enough work to be spread across threads.
+        compare(&[
+            (
+                Transpose::No,
+                Transpose::Trans,
+                1.0,
+                0.5,
+                &[(64, 48, 80, 64), (8, 8, 8, 9)],
+            ),
+            (
+                Transpose::Trans,
+                Transpose::No,
+                -1.0,
+                0.0,
+                &[(40, 72, 56, 41), (72, 40, 56, 72)],
+            ),
+            (
+                Transpose::No,
+                Transpose::No,
+                2.0,
+                1.0,
+                &[(96, 64, 32, 96), (1, 1, 1, 1)],
+            ),
+        ]);
+    }
+
+    #[test]
+    fn complex() {
+        let a = elements::<f64>(12, 1.0)
+            .into_iter()
+            .zip(elements::<f64>(12, 2.0))
+            .map(|(re, im)| c64::new(re, im))
+            .collect::<Vec<_>>();
+        let (alpha, beta) = (c64::new(0.5, 1.0), c64::new(0.0, -1.0));
+        let mut c = vec![c64::new(1.0, 1.0); 9];
+        let mut d = vec![c64::new(1.0, 1.0); 4];
+        let mut expected = (c.clone(), d.clone());
+        unsafe {
+            ::zgemm(
+                Transpose::ConjTrans,
+                Transpose::No,
+                3,
+                3,
+                4,
+                alpha,
+                &a,
+                4,
+                &a,
+                4,
+                beta,
+                &mut expected.0,
+                3,
+            );
+            ::zgemm(
+                Transpose::No,
+                Transpose::Trans,
+                2,
+                2,
+                6,
+                alpha,
+                &a,
+                2,
+                &a,
+                2,
+                beta,
+                &mut expected.1,
+                2,
+            );
+        }
+        let mut first = GemmGroup::new(Transpose::ConjTrans, Transpose::No, alpha, beta);
+        let view = MatRef::new(&a, 4, 3, Layout::ColMajor).unwrap();
+        first.push(
+            view,
+            view,
+            MatMut::new(&mut c, 3, 3, Layout::ColMajor).unwrap(),
+        );
+        let mut second = GemmGroup::new(Transpose::No, Transpose::Trans, alpha, beta);
+        let view = MatRef::new(&a, 2, 6, Layout::ColMajor).unwrap();
+        second.push(
+            view,
+            view,
+            MatMut::new(&mut d, 2, 2, Layout::ColMajor).unwrap(),
+        );
+        gemm_batch(&mut [first, second]).unwrap();
+        assert_eq!((c, d), expected);
+    }
+
+    #[test]
+    fn validate() {
+        let a = vec![1.0; 6];
+        let (mut c, mut d) = (vec![0.0; 4], vec![0.0; 4]);
+        {
+            let mut first = GemmGroup::new(Transpose::No, Transpose::No, 1.0, 0.0);
+            let view = MatRef::new(&a, 2, 2, Layout::ColMajor).unwrap();
+            first.push(
+                view,
+                view,
+                MatMut::new(&mut c, 2, 2, Layout::ColMajor).unwrap(),
+            );
+            let mut second = GemmGroup::new(Transpose::No, Transpose::No, 1.0, 0.0);
+            let view = MatRef::new(&a, 2, 3, Layout::ColMajor).unwrap();
+            second.push(
+                view,
+                view,
+                MatMut::new(&mut d, 2, 2, Layout::ColMajor).unwrap(),
+            );
+            let error = gemm_batch(&mut [first, second]).unwrap_err();
+            assert_eq!(error.position(), 5);
+        }
+        // No problem is computed unless all are valid.
+        assert_eq!(c, [0.0; 4]);
+
+        let mut group = GemmGroup::new(Transpose::No, Transpose::No, 1.0, 0.0);
+        group
+            .c
+            .push(MatMut::new(&mut c, 2, 2, Layout::ColMajor).unwrap());
+        assert_eq!(gemm_batch(&mut [group]).unwrap_err().position(), 4);
+        gemm_batch::<f64>(&mut []).unwrap();
+    }
+}
@@ -0,0 +1,52 @@
+//! Batched routines.
+//!
+//! A batched routine performs the same operation on many independent sets of
+//! operands in one call. The arguments are validated once for the whole
+//! batch as in [`checked`](../checked/index.html). If the library in use
+//! provides a batched routine, the batch is passed on to it; otherwise, the
+//! operation is performed for each set of operands, in parallel if the batch
+//! is large enough.
+//!
+//! Batches with operands of different shapes are expressed as groups of
+//! matrix views and passed to [`gemm_batch`](fn.gemm_batch.html).
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use blas::batch::dgemm_batch_strided;
+//! use blas::Transpose;
+//!
+//! let (m, n, k, batch) = (2, 2, 2, 1000);
+//! let a = vec![1.0; 4 * batch];
+//! let b = vec![1.0; 4 * batch];
+//! let mut c = vec![0.0; 4 * batch];
+//! dgemm_batch_strided(
+//!     Transpose::No, Transpose::No, m, n, k,
+//!     1.0, &a, 2, 4, &b, 2, 4, 0.0, &mut c, 2, 4,
+//!     batch,
+//! ).unwrap();
+//! ```
+
+mod grouped;
+mod strided;
+
+pub use self::grouped::*;
+pub use self::strided::*;
+
+/// Return the number of elements spanned by `batch` operands with `len`
+/// elements each and `stride` elements apart.
+#[inline]
+fn batch_len(batch: usize, stride: usize, len: usize) -> usize {
+    if batch == 0 || len == 0 {
+        0
+    } else {
+        (batch - 1) * stride + len
+    }
+}
+
+/// Return the part of `data` starting at `offset`, which is empty if
+/// `offset` is out of range.
+#[inline]
+fn part<T>(data: &[T], offset: usize) -> &[T] {
+    data.get(offset..).unwrap_or(&[])
+}
@@ -0,0 +1,295 @@
+#[cfg(any(feature = "dynamic", all(unix, not(feature = "reference"))))]
+use std::convert::TryFrom;
+
+#[cfg(any(feature = "dynamic", all(unix, not(feature = "reference"))))]
+use libc::c_char;
+
+use super::{batch_len, part};
+use checked::{matrix_len, Check};
+use parallel;
+use {c32, c64, Result, Transpose};
+
+macro_rules! gemm_batch_strided {
+    ($name:ident, $gemm:ident, $extension:ident, $ty:ty, $flops:expr) => {
+        #[doc = concat!(
+            "Compute `c_i := alpha * op(a_i) * op(b_i) + beta * c_i` for `i < batch` using [`",
+            stringify!($gemm), "`](../fn.", stringify!($gemm), ".html), where the matrices ",
+            "`a_i`, `b_i`, and `c_i` start at `i * stride_a`, `i * stride_b`, and ",
+            "`i * stride_c`, respectively.\n\n",
+            "The positions of the arguments follow the Fortran interface of MKL. Unless the ",
+            "batch has at most one element, `stride_c` has to be at least the number of ",
+            "elements spanned by `c_i`, so that the results do not overlap.",
+        )]
+        pub fn $name(
+            transa: Transpose,
+            transb: Transpose,
+            m: i32,
+            n: i32,
+            k: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            stride_a: usize,
+            b: &[$ty],
+            ldb: i32,
+            stride_b: usize,
+            beta: $ty,
+            c: &mut [$ty],
+            ldc: i32,
+            stride_c: usize,
+            batch: usize,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(3, "m", m)?;
+            check.dimension(4, "n", n)?;
+            check.dimension(5, "k", k)?;
+            let (rowsa, colsa) = if transa == Transpose::No {
+                (m, k)
+            } else {
+                (k, m)
+            };
+            let (rowsb, colsb) = if transb == Transpose::No {
+                (k, n)
+            } else {
+                (n, k)
+            };
+            check.leading(
+                8,
+                "lda",
+                lda,
+                rowsa.max(1),
+                "must be at least max(1, rows of a)",
+            )?;
+            check.leading(
+                11,
+                "ldb",
+                ldb,
+                rowsb.max(1),
+                "must be at least max(1, rows of b)",
+            )?;
+            check.leading(15, "ldc", ldc, m.max(1), "must be at least max(1, m)")?;
+            let (lena, lenb, lenc) = (
+                matrix_len(rowsa, colsa, lda),
+                matrix_len(rowsb, colsb, ldb),
+                matrix_len(m, n, ldc),
+            );
+            if batch > 1 && stride_c < lenc {
+                return Err(check.argument(
+                    16,
+                    "stride_c",
+                    "must be at least the number of elements of c",
+                ));
+            }
+            check.length(7, "a", a.len(), batch_len(batch, stride_a, lena))?;
+            check.length(10, "b", b.len(), batch_len(batch, stride_b, lenb))?;
+            check.length(14, "c", c.len(), batch_len(batch, stride_c, lenc))?;
+            if lenc == 0 || batch == 0 {
+                return Ok(());
+            }
+            #[cfg(any(feature = "dynamic", all(unix, not(feature = "reference"))))]
+            {
+                let function = ::native::$extension();
+                let strides = (
+                    i32::try_from(stride_a),
+                    i32::try_from(stride_b),
+                    i32::try_from(stride_c),
+                    i32::try_from(batch),
+                );
+                if let (Some(function), (Ok(stride_a), Ok(stride_b), Ok(stride_c), Ok(batch))) =
+                    (function, strides)
+                {
+                    unsafe {
+                        function(
+                            &(u8::from(transa) as c_char),
+                            &(u8::from(transb) as c_char),
+                            &m,
+                            &n,
+                            &k,
+                            &alpha as *const _ as *const _,
+                            a.as_ptr() as *const _,
+                            &lda,
+                            &stride_a,
+                            b.as_ptr() as *const _,
+                            &ldb,
+                            &stride_b,
+                            &beta as *const _ as *const _,
+                            c.as_mut_ptr() as *mut _,
+                            &ldc,
+                            &stride_c,
+                            &batch,
+                        );
+                    }
+                    return check.finish();
+                }
+            }
+            let flops = $flops * batch as f64 * m as f64 * n as f64 * k as f64;
+            let threads = parallel::threads(batch, flops);
+            parallel::chunks(c, stride_c, batch, threads, |i, c| unsafe {
+                ::$gemm(
+                    transa,
+                    transb,
+                    m,
+                    n,
+                    k,
+                    alpha,
+                    part(a, i * stride_a),
+                    lda,
+                    part(b, i * stride_b),
+                    ldb,
+                    beta,
+                    c,
+                    ldc,
+                )
+            });
+            check.finish()
+        }
+    };
+}
+
+gemm_batch_strided!(sgemm_batch_strided, sgemm, sgemm_batch_strided_, f32, 2.0);
+gemm_batch_strided!(dgemm_batch_strided, dgemm, dgemm_batch_strided_, f64, 2.0);
+gemm_batch_strided!(cgemm_batch_strided, cgemm, cgemm_batch_strided_, c32, 8.0);
+gemm_batch_strided!(zgemm_batch_strided, zgemm, zgemm_batch_strided_, c64, 8.0);
+
+#[cfg(all(test, feature = "reference", not(feature = "dynamic")))]
+mod tests {
+    use super::*;
+
+    fn elements(len: usize, seed: f64) -> Vec<f64> {
+        (0..len).map(|i| (seed + 0.37 * i as f64).sin()).collect()
+    }
+
+    #[test]
+    fn fallback() {
+        // The batch is large enough to be split across threads.
+        let (m, n, k, batch) = (32, 24, 40, 64);
+        let (stride_a, stride_b, stride_c) = (m * k + 3, 0, m * n + 5);
+        for &transa in &[Transpose::No, Transpose::Trans] {
+            for &transb in &[Transpose::No, Transpose::ConjTrans] {
+                let lda = if transa == Transpose::No { m } else { k };
+                let ldb = if transb == Transpose::No { k } else { n };
+                let a = elements(batch * stride_a, 1.0);
+                let b = elements(k * n, 2.0);
+                let c = elements(batch * stride_c, 3.0);
+                let mut expected = c.clone();
+                for i in 0..batch {
+                    unsafe {
+                        ::dgemm(
+                            transa,
+                            transb,
+                            m as i32,
+                            n as i32,
+                            k as i32,
+                            0.5,
+                            &a[i * stride_a..],
+                            lda as i32,
+                            &b,
+                            ldb as i32,
+                            -1.0,
+                            &mut expected[i * stride_c..],
+                            m as i32,
+                        )
+                    };
+                }
+                let mut c = c;
+                dgemm_batch_strided(
+                    transa, transb, m as i32, n as i32, k as i32, 0.5, &a, lda as i32, stride_a,
+                    &b, ldb as i32, stride_b, -1.0, &mut c, m as i32, stride_c, batch,
+                )
+                .unwrap();
+                assert_eq!(c, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn complex() {
+        let (m, n, k, batch) = (3, 2, 4, 5);
+        let a = (0..batch * k * m)
+            .map(|i| c64::new(i as f64, 1.0 - i as f64))
+            .collect::<Vec<_>>();
+        let b = (0..batch * k * n)
+            .map(|i| c64::new(0.5 * i as f64, 2.0))
+            .collect::<Vec<_>>();
+        let mut c = vec![c64::new(1.0, -1.0); batch * m * n];
+        let mut expected = c.clone();
+        let (alpha, beta) = (c64::new(0.5, 1.0), c64::new(0.0, 2.0));
+        for i in 0..batch {
+            unsafe {
+                ::zgemm(
+                    Transpose::ConjTrans,
+                    Transpose::No,
+                    3,
+                    2,
+                    4,
+                    alpha,
+                    &a[i * k * m..],
+                    4,
+                    &b[i * k * n..],
+                    4,
+                    beta,
+                    &mut expected[i * m * n..],
+                    3,
+                )
+            };
+        }
+        zgemm_batch_strided(
+            Transpose::ConjTrans,
+            Transpose::No,
+            3,
+            2,
+            4,
+            alpha,
+            &a,
+            4,
+            k * m,
+            &b,
+            4,
+            k * n,
+            beta,
+            &mut c,
+            3,
+            m * n,
+            batch,
+        )
+        .unwrap();
+        assert_eq!(c, expected);
+    }
+
+    #[test]
+    fn validate() {
+        let (a, b) = (vec![1.0; 8], vec![1.0; 8]);
+        let mut c = vec![0.0; 8];
+        let gemm = |c: &mut [f64], stride_c, batch| {
+            dgemm_batch_strided(
+                Transpose::No,
+                Transpose::No,
+                2,
+                2,
+                2,
+                1.0,
+                &a,
+                2,
+                4,
+                &b,
+                2,
+                4,
+                0.0,
+                c,
+                2,
+                stride_c,
+                batch,
+            )
+        };
+
+        // The results would overlap.
+        assert_eq!(gemm(&mut c, 3, 2).unwrap_err().position(), 16);
+        assert_eq!(c, [0.0; 8]);
+        // A single result may have any stride.
+        gemm(&mut c, 0, 1).unwrap();
+        assert_eq!(c, [2.0, 2.0, 2.0, 2.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(gemm(&mut c[..7], 4, 2).unwrap_err().position(), 14);
+        assert_eq!(gemm(&mut c, 4, 3).unwrap_err().position(), 7);
+        gemm(&mut [], 4, 0).unwrap();
+    }
+}
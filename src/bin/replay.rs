@@ -0,0 +1,65 @@
+//! Replay a recording of calls using the library given by the environment
+//! variable `BLAS_LIBRARY` and report the outputs that differ. The library
+//! needs to provide only the routines called in the recording.
+//!
+//! ```text
+//! blas-replay <recording> [tolerance]
+//! ```
+//!
+//! The process exits with status 1 if any output differs by more than the
+//! tolerance, which is zero by default.
+
+extern crate blas;
+
+use std::env;
+use std::fs::File;
+use std::io::BufReader;
+use std::process;
+
+use blas::{dynamic, record};
+
+fn main() {
+    let arguments = env::args().skip(1).collect::<Vec<_>>();
+    if arguments.is_empty() || arguments.len() > 2 {
+        fail("usage: blas-replay <recording> [tolerance]");
+    }
+    let tolerance = match arguments.get(1) {
+        Some(tolerance) => match tolerance.parse() {
+            Ok(tolerance) => tolerance,
+            _ => fail(&format!("invalid tolerance `{}`", tolerance)),
+        },
+        _ => 0.0,
+    };
+    let library = match env::var_os(dynamic::VARIABLE) {
+        Some(ref path) if !path.is_empty() => dynamic::Library::open(path),
+        _ => fail(&format!("{} is not set", dynamic::VARIABLE)),
+    };
+    match library {
+        Ok(library) => dynamic::install(library),
+        Err(error) => fail(&error.to_string()),
+    }
+    let file = match File::open(&arguments[0]) {
+        Ok(file) => BufReader::new(file),
+        Err(error) => fail(&format!("{}: {}", arguments[0], error)),
+    };
+    let report = match record::replay(file, tolerance) {
+        Ok(report) => report,
+        Err(error) => fail(&format!("{}: {}", arguments[0], error)),
+    };
+    for difference in &report.differences {
+        println!("{}", difference);
+    }
+    println!(
+        "{} calls replayed, {} outputs differ",
+        report.calls,
+        report.differences.len()
+    );
+    if !report.differences.is_empty() {
+        process::exit(1);
+    }
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("blas-replay: {}", message);
+    process::exit(2);
+}
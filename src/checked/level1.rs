@@ -0,0 +1,294 @@
+use super::Check;
+use {c32, c64, Result};
+
+macro_rules! rotg {
+    ($name:ident, $ty:ty, $real:ty, $b:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(a: &mut $ty, b: $b, c: &mut $real, s: &mut $ty) {
+            unsafe { ::$name(a, b, c, s) }
+        }
+    };
+}
+
+rotg!(srotg, f32, f32, &mut f32);
+rotg!(drotg, f64, f64, &mut f64);
+rotg!(crotg, c32, f32, c32);
+rotg!(zrotg, c64, f64, c64);
+
+macro_rules! rotmg {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            d1: &mut $ty,
+            d2: &mut $ty,
+            x1: &mut $ty,
+            y1: $ty,
+            param: &mut [$ty],
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.length(5, "param", param.len(), 5)?;
+            unsafe { ::$name(d1, d2, x1, y1, param) };
+            Ok(())
+        }
+    };
+}
+
+rotmg!(srotmg, f32);
+rotmg!(drotmg, f64);
+
+macro_rules! rot {
+    ($name:ident, $ty:ty, $real:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            n: i32,
+            x: &mut [$ty],
+            incx: i32,
+            y: &mut [$ty],
+            incy: i32,
+            c: $real,
+            s: $real,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.vector(2, "x", x.len(), n, incx)?;
+            check.vector(4, "y", y.len(), n, incy)?;
+            unsafe { ::$name(n, x, incx, y, incy, c, s) };
+            Ok(())
+        }
+    };
+}
+
+rot!(srot, f32, f32);
+rot!(drot, f64, f64);
+rot!(csrot, c32, f32);
+rot!(zdrot, c64, f64);
+
+macro_rules! rotm {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            n: i32,
+            x: &mut [$ty],
+            incx: i32,
+            y: &mut [$ty],
+            incy: i32,
+            param: &[$ty],
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.vector(2, "x", x.len(), n, incx)?;
+            check.vector(4, "y", y.len(), n, incy)?;
+            check.length(6, "param", param.len(), 5)?;
+            unsafe { ::$name(n, x, incx, y, incy, param) };
+            Ok(())
+        }
+    };
+}
+
+rotm!(srotm, f32);
+rotm!(drotm, f64);
+
+macro_rules! swap {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(n: i32, x: &mut [$ty], incx: i32, y: &mut [$ty], incy: i32) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.vector(2, "x", x.len(), n, incx)?;
+            check.vector(4, "y", y.len(), n, incy)?;
+            unsafe { ::$name(n, x, incx, y, incy) };
+            Ok(())
+        }
+    };
+}
+
+swap!(sswap, f32);
+swap!(dswap, f64);
+swap!(cswap, c32);
+swap!(zswap, c64);
+
+macro_rules! scal {
+    ($name:ident, $ty:ty, $alpha:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(n: i32, a: $alpha, x: &mut [$ty], incx: i32) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.vector(3, "x", x.len(), n, incx)?;
+            unsafe { ::$name(n, a, x, incx) };
+            Ok(())
+        }
+    };
+}
+
+scal!(sscal, f32, f32);
+scal!(dscal, f64, f64);
+scal!(cscal, c32, c32);
+scal!(zscal, c64, c64);
+scal!(csscal, c32, f32);
+scal!(zdscal, c64, f64);
+
+macro_rules! copy {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(n: i32, x: &[$ty], incx: i32, y: &mut [$ty], incy: i32) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.vector(2, "x", x.len(), n, incx)?;
+            check.vector(4, "y", y.len(), n, incy)?;
+            unsafe { ::$name(n, x, incx, y, incy) };
+            Ok(())
+        }
+    };
+}
+
+copy!(scopy, f32);
+copy!(dcopy, f64);
+copy!(ccopy, c32);
+copy!(zcopy, c64);
+
+macro_rules! axpy {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            n: i32,
+            alpha: $ty,
+            x: &[$ty],
+            incx: i32,
+            y: &mut [$ty],
+            incy: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.vector(3, "x", x.len(), n, incx)?;
+            check.vector(5, "y", y.len(), n, incy)?;
+            unsafe { ::$name(n, alpha, x, incx, y, incy) };
+            Ok(())
+        }
+    };
+}
+
+axpy!(saxpy, f32);
+axpy!(daxpy, f64);
+axpy!(caxpy, c32);
+axpy!(zaxpy, c64);
+
+macro_rules! dot {
+    ($name:ident, $ty:ty, $result:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(n: i32, x: &[$ty], incx: i32, y: &[$ty], incy: i32) -> Result<$result> {
+            let check = Check::new(stringify!($name));
+            check.vector(2, "x", x.len(), n, incx)?;
+            check.vector(4, "y", y.len(), n, incy)?;
+            Ok(unsafe { ::$name(n, x, incx, y, incy) })
+        }
+    };
+}
+
+dot!(sdot, f32, f32);
+dot!(ddot, f64, f64);
+dot!(dsdot, f32, f64);
+
+/// Checked [`sdsdot`](../fn.sdsdot.html).
+#[inline]
+pub fn sdsdot(n: i32, sb: &[f32], x: &[f32], incx: i32, y: &[f32], incy: i32) -> Result<f32> {
+    let check = Check::new("sdsdot");
+    check.length(2, "sb", sb.len(), 1)?;
+    check.vector(3, "x", x.len(), n, incx)?;
+    check.vector(5, "y", y.len(), n, incy)?;
+    Ok(unsafe { ::sdsdot(n, sb, x, incx, y, incy) })
+}
+
+dot!(cdotu, c32, c32);
+dot!(cdotc, c32, c32);
+dot!(zdotu, c64, c64);
+dot!(zdotc, c64, c64);
+
+macro_rules! reduce {
+    ($name:ident, $ty:ty, $result:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(n: i32, x: &[$ty], incx: i32) -> Result<$result> {
+            let check = Check::new(stringify!($name));
+            check.vector(2, "x", x.len(), n, incx)?;
+            Ok(unsafe { ::$name(n, x, incx) })
+        }
+    };
+}
+
+reduce!(snrm2, f32, f32);
+reduce!(dnrm2, f64, f64);
+reduce!(scnrm2, c32, f32);
+reduce!(dznrm2, c64, f64);
+
+reduce!(sasum, f32, f32);
+reduce!(dasum, f64, f64);
+reduce!(scasum, c32, f32);
+reduce!(dzasum, c64, f64);
+
+reduce!(isamax, f32, usize);
+reduce!(idamax, f64, usize);
+reduce!(icamax, c32, usize);
+reduce!(izamax, c64, usize);
+
+#[cfg(all(test, feature = "reference", not(feature = "dynamic")))]
+mod tests {
+    use super::*;
+    use Error;
+
+    #[test]
+    fn quick_return() {
+        let mut y = vec![1.0, 2.0];
+        daxpy(-1, 2.0, &[], 1, &mut y, 1).unwrap();
+        daxpy(0, 2.0, &[], 1, &mut y, 1).unwrap();
+        assert_eq!(y, vec![1.0, 2.0]);
+        dscal(-3, 0.0, &mut y, 1).unwrap();
+        assert_eq!(y, vec![1.0, 2.0]);
+        assert_eq!(ddot(-2, &[], 1, &[], 1).unwrap(), 0.0);
+        assert_eq!(dnrm2(0, &[], 1).unwrap(), 0.0);
+        assert_eq!(zdotc(-1, &[], 1, &[], 1).unwrap(), c64::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn length() {
+        let x = vec![1.0, 2.0, 3.0];
+        let mut y = vec![0.0; 2];
+        assert_eq!(
+            daxpy(3, 1.0, &x, 1, &mut y, 1),
+            Err(Error::Length {
+                routine: "daxpy",
+                position: 5,
+                name: "y",
+                required: 3,
+                provided: 2,
+            }),
+        );
+        assert_eq!(
+            dcopy(2, &x, -3, &mut y, 1),
+            Err(Error::Length {
+                routine: "dcopy",
+                position: 2,
+                name: "x",
+                required: 4,
+                provided: 3,
+            }),
+        );
+        let error = drotmg(&mut 1.0, &mut 1.0, &mut 1.0, 1.0, &mut [0.0; 4]).unwrap_err();
+        assert_eq!((error.routine(), error.position()), ("drotmg", 5));
+        let error = sdsdot(1, &[], &[1.0], 1, &[1.0], 1).unwrap_err();
+        assert_eq!((error.routine(), error.position()), ("sdsdot", 2));
+    }
+
+    #[test]
+    fn increment() {
+        let x = vec![1.0, 2.0, 3.0];
+        let mut y = vec![0.0; 3];
+        dcopy(3, &x, -1, &mut y, 1).unwrap();
+        assert_eq!(y, vec![3.0, 2.0, 1.0]);
+        daxpy(2, 1.0, &x, 2, &mut y, -1).unwrap();
+        assert_eq!(y, vec![6.0, 3.0, 1.0]);
+        assert_eq!(idamax(3, &[1.0, -4.0, 4.0], 1).unwrap(), 2);
+    }
+}
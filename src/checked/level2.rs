@@ -0,0 +1,724 @@
+use super::{matrix_len, packed_len, Check};
+use {c32, c64, Diag, Result, Transpose, Uplo};
+
+macro_rules! gemv {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            trans: Transpose,
+            m: i32,
+            n: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            x: &[$ty],
+            incx: i32,
+            beta: $ty,
+            y: &mut [$ty],
+            incy: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(2, "m", m)?;
+            check.dimension(3, "n", n)?;
+            check.leading(6, "lda", lda, m.max(1), "must be at least max(1, m)")?;
+            check.increment(8, "incx", incx)?;
+            check.increment(11, "incy", incy)?;
+            let (lenx, leny) = if trans == Transpose::No {
+                (n, m)
+            } else {
+                (m, n)
+            };
+            check.length(5, "a", a.len(), matrix_len(m, n, lda))?;
+            check.vector(7, "x", x.len(), lenx, incx)?;
+            check.vector(10, "y", y.len(), leny, incy)?;
+            unsafe { ::$name(trans, m, n, alpha, a, lda, x, incx, beta, y, incy) };
+            check.finish()
+        }
+    };
+}
+
+gemv!(sgemv, f32);
+gemv!(dgemv, f64);
+gemv!(cgemv, c32);
+gemv!(zgemv, c64);
+
+macro_rules! gbmv {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            trans: Transpose,
+            m: i32,
+            n: i32,
+            kl: i32,
+            ku: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            x: &[$ty],
+            incx: i32,
+            beta: $ty,
+            y: &mut [$ty],
+            incy: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(2, "m", m)?;
+            check.dimension(3, "n", n)?;
+            check.dimension(4, "kl", kl)?;
+            check.dimension(5, "ku", ku)?;
+            let band = i64::from(kl) + i64::from(ku) + 1;
+            let band = check.band(8, "lda", lda, band, "must be at least kl + ku + 1")?;
+            check.increment(10, "incx", incx)?;
+            check.increment(13, "incy", incy)?;
+            let (lenx, leny) = if trans == Transpose::No {
+                (n, m)
+            } else {
+                (m, n)
+            };
+            check.length(7, "a", a.len(), matrix_len(band, n, lda))?;
+            check.vector(9, "x", x.len(), lenx, incx)?;
+            check.vector(12, "y", y.len(), leny, incy)?;
+            unsafe { ::$name(trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy) };
+            check.finish()
+        }
+    };
+}
+
+gbmv!(sgbmv, f32);
+gbmv!(dgbmv, f64);
+gbmv!(cgbmv, c32);
+gbmv!(zgbmv, c64);
+
+macro_rules! symv {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            n: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            x: &[$ty],
+            incx: i32,
+            beta: $ty,
+            y: &mut [$ty],
+            incy: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(2, "n", n)?;
+            check.leading(5, "lda", lda, n.max(1), "must be at least max(1, n)")?;
+            check.increment(7, "incx", incx)?;
+            check.increment(10, "incy", incy)?;
+            check.length(4, "a", a.len(), matrix_len(n, n, lda))?;
+            check.vector(6, "x", x.len(), n, incx)?;
+            check.vector(9, "y", y.len(), n, incy)?;
+            unsafe { ::$name(uplo, n, alpha, a, lda, x, incx, beta, y, incy) };
+            check.finish()
+        }
+    };
+}
+
+symv!(ssymv, f32);
+symv!(dsymv, f64);
+symv!(chemv, c32);
+symv!(zhemv, c64);
+
+macro_rules! sbmv {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            n: i32,
+            k: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            x: &[$ty],
+            incx: i32,
+            beta: $ty,
+            y: &mut [$ty],
+            incy: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(2, "n", n)?;
+            check.dimension(3, "k", k)?;
+            let band = check.band(6, "lda", lda, i64::from(k) + 1, "must be at least k + 1")?;
+            check.increment(8, "incx", incx)?;
+            check.increment(11, "incy", incy)?;
+            check.length(5, "a", a.len(), matrix_len(band, n, lda))?;
+            check.vector(7, "x", x.len(), n, incx)?;
+            check.vector(10, "y", y.len(), n, incy)?;
+            unsafe { ::$name(uplo, n, k, alpha, a, lda, x, incx, beta, y, incy) };
+            check.finish()
+        }
+    };
+}
+
+sbmv!(ssbmv, f32);
+sbmv!(dsbmv, f64);
+sbmv!(chbmv, c32);
+sbmv!(zhbmv, c64);
+
+macro_rules! spmv {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            n: i32,
+            alpha: $ty,
+            ap: &[$ty],
+            x: &[$ty],
+            incx: i32,
+            beta: $ty,
+            y: &mut [$ty],
+            incy: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(2, "n", n)?;
+            check.increment(6, "incx", incx)?;
+            check.increment(9, "incy", incy)?;
+            check.length(4, "ap", ap.len(), packed_len(n))?;
+            check.vector(5, "x", x.len(), n, incx)?;
+            check.vector(8, "y", y.len(), n, incy)?;
+            unsafe { ::$name(uplo, n, alpha, ap, x, incx, beta, y, incy) };
+            check.finish()
+        }
+    };
+}
+
+spmv!(sspmv, f32);
+spmv!(dspmv, f64);
+spmv!(chpmv, c32);
+spmv!(zhpmv, c64);
+
+macro_rules! trmv {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            trans: Transpose,
+            diag: Diag,
+            n: i32,
+            a: &[$ty],
+            lda: i32,
+            x: &mut [$ty],
+            incx: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(4, "n", n)?;
+            check.leading(6, "lda", lda, n.max(1), "must be at least max(1, n)")?;
+            check.increment(8, "incx", incx)?;
+            check.length(5, "a", a.len(), matrix_len(n, n, lda))?;
+            check.vector(7, "x", x.len(), n, incx)?;
+            unsafe { ::$name(uplo, trans, diag, n, a, lda, x, incx) };
+            check.finish()
+        }
+    };
+}
+
+trmv!(strmv, f32);
+trmv!(dtrmv, f64);
+trmv!(ctrmv, c32);
+trmv!(ztrmv, c64);
+
+trmv!(strsv, f32);
+trmv!(dtrsv, f64);
+trmv!(ctrsv, c32);
+trmv!(ztrsv, c64);
+
+macro_rules! tbmv {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            trans: Transpose,
+            diag: Diag,
+            n: i32,
+            k: i32,
+            a: &[$ty],
+            lda: i32,
+            x: &mut [$ty],
+            incx: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(4, "n", n)?;
+            check.dimension(5, "k", k)?;
+            let band = check.band(7, "lda", lda, i64::from(k) + 1, "must be at least k + 1")?;
+            check.increment(9, "incx", incx)?;
+            check.length(6, "a", a.len(), matrix_len(band, n, lda))?;
+            check.vector(8, "x", x.len(), n, incx)?;
+            unsafe { ::$name(uplo, trans, diag, n, k, a, lda, x, incx) };
+            check.finish()
+        }
+    };
+}
+
+tbmv!(stbmv, f32);
+tbmv!(dtbmv, f64);
+tbmv!(ctbmv, c32);
+tbmv!(ztbmv, c64);
+
+tbmv!(stbsv, f32);
+tbmv!(dtbsv, f64);
+tbmv!(ctbsv, c32);
+tbmv!(ztbsv, c64);
+
+macro_rules! tpmv {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            trans: Transpose,
+            diag: Diag,
+            n: i32,
+            ap: &[$ty],
+            x: &mut [$ty],
+            incx: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(4, "n", n)?;
+            check.increment(7, "incx", incx)?;
+            check.length(5, "ap", ap.len(), packed_len(n))?;
+            check.vector(6, "x", x.len(), n, incx)?;
+            unsafe { ::$name(uplo, trans, diag, n, ap, x, incx) };
+            check.finish()
+        }
+    };
+}
+
+tpmv!(stpmv, f32);
+tpmv!(dtpmv, f64);
+tpmv!(ctpmv, c32);
+tpmv!(ztpmv, c64);
+
+tpmv!(stpsv, f32);
+tpmv!(dtpsv, f64);
+tpmv!(ctpsv, c32);
+tpmv!(ztpsv, c64);
+
+macro_rules! ger {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            m: i32,
+            n: i32,
+            alpha: $ty,
+            x: &[$ty],
+            incx: i32,
+            y: &[$ty],
+            incy: i32,
+            a: &mut [$ty],
+            lda: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(1, "m", m)?;
+            check.dimension(2, "n", n)?;
+            check.increment(5, "incx", incx)?;
+            check.increment(7, "incy", incy)?;
+            check.leading(9, "lda", lda, m.max(1), "must be at least max(1, m)")?;
+            check.vector(4, "x", x.len(), m, incx)?;
+            check.vector(6, "y", y.len(), n, incy)?;
+            check.length(8, "a", a.len(), matrix_len(m, n, lda))?;
+            unsafe { ::$name(m, n, alpha, x, incx, y, incy, a, lda) };
+            check.finish()
+        }
+    };
+}
+
+ger!(sger, f32);
+ger!(dger, f64);
+ger!(cgeru, c32);
+ger!(cgerc, c32);
+ger!(zgeru, c64);
+ger!(zgerc, c64);
+
+macro_rules! syr {
+    ($name:ident, $ty:ty, $alpha:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            n: i32,
+            alpha: $alpha,
+            x: &[$ty],
+            incx: i32,
+            a: &mut [$ty],
+            lda: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(2, "n", n)?;
+            check.increment(5, "incx", incx)?;
+            check.leading(7, "lda", lda, n.max(1), "must be at least max(1, n)")?;
+            check.vector(4, "x", x.len(), n, incx)?;
+            check.length(6, "a", a.len(), matrix_len(n, n, lda))?;
+            unsafe { ::$name(uplo, n, alpha, x, incx, a, lda) };
+            check.finish()
+        }
+    };
+}
+
+syr!(ssyr, f32, f32);
+syr!(dsyr, f64, f64);
+syr!(cher, c32, f32);
+syr!(zher, c64, f64);
+
+macro_rules! spr {
+    ($name:ident, $ty:ty, $alpha:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            n: i32,
+            alpha: $alpha,
+            x: &[$ty],
+            incx: i32,
+            ap: &mut [$ty],
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(2, "n", n)?;
+            check.increment(5, "incx", incx)?;
+            check.vector(4, "x", x.len(), n, incx)?;
+            check.length(6, "ap", ap.len(), packed_len(n))?;
+            unsafe { ::$name(uplo, n, alpha, x, incx, ap) };
+            check.finish()
+        }
+    };
+}
+
+spr!(sspr, f32, f32);
+spr!(dspr, f64, f64);
+spr!(chpr, c32, f32);
+spr!(zhpr, c64, f64);
+
+macro_rules! syr2 {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            n: i32,
+            alpha: $ty,
+            x: &[$ty],
+            incx: i32,
+            y: &[$ty],
+            incy: i32,
+            a: &mut [$ty],
+            lda: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(2, "n", n)?;
+            check.increment(5, "incx", incx)?;
+            check.increment(7, "incy", incy)?;
+            check.leading(9, "lda", lda, n.max(1), "must be at least max(1, n)")?;
+            check.vector(4, "x", x.len(), n, incx)?;
+            check.vector(6, "y", y.len(), n, incy)?;
+            check.length(8, "a", a.len(), matrix_len(n, n, lda))?;
+            unsafe { ::$name(uplo, n, alpha, x, incx, y, incy, a, lda) };
+            check.finish()
+        }
+    };
+}
+
+syr2!(ssyr2, f32);
+syr2!(dsyr2, f64);
+syr2!(cher2, c32);
+syr2!(zher2, c64);
+
+macro_rules! spr2 {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            n: i32,
+            alpha: $ty,
+            x: &[$ty],
+            incx: i32,
+            y: &[$ty],
+            incy: i32,
+            ap: &mut [$ty],
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(2, "n", n)?;
+            check.increment(5, "incx", incx)?;
+            check.increment(7, "incy", incy)?;
+            check.vector(4, "x", x.len(), n, incx)?;
+            check.vector(6, "y", y.len(), n, incy)?;
+            check.length(8, "ap", ap.len(), packed_len(n))?;
+            unsafe { ::$name(uplo, n, alpha, x, incx, y, incy, ap) };
+            check.finish()
+        }
+    };
+}
+
+spr2!(sspr2, f32);
+spr2!(dspr2, f64);
+spr2!(chpr2, c32);
+spr2!(zhpr2, c64);
+
+#[cfg(all(test, feature = "reference", not(feature = "dynamic")))]
+mod tests {
+    use super::*;
+    use Error;
+
+    #[test]
+    fn position() {
+        let a = vec![1.0; 4];
+        let mut y = vec![0.0; 2];
+        let error = dgemv(
+            Transpose::No,
+            -1,
+            2,
+            1.0,
+            &a,
+            2,
+            &y.clone(),
+            1,
+            0.0,
+            &mut y,
+            1,
+        );
+        assert_eq!(error.unwrap_err().position(), 2);
+        let error = dgemv(
+            Transpose::No,
+            2,
+            2,
+            1.0,
+            &a,
+            1,
+            &[1.0; 2],
+            1,
+            0.0,
+            &mut y,
+            1,
+        );
+        assert_eq!(error.unwrap_err().position(), 6);
+        let error = dgemv(
+            Transpose::No,
+            2,
+            2,
+            1.0,
+            &a,
+            2,
+            &[1.0; 2],
+            0,
+            0.0,
+            &mut y,
+            1,
+        );
+        assert_eq!(error.unwrap_err().position(), 8);
+        let error = dtrsv(
+            Uplo::Upper,
+            Transpose::No,
+            Diag::NonUnit,
+            2,
+            &a,
+            2,
+            &mut y,
+            0,
+        );
+        assert_eq!(error.unwrap_err().position(), 8);
+        let error = dspr(Uplo::Lower, -1, 1.0, &[], 1, &mut []);
+        assert_eq!(error.unwrap_err().position(), 2);
+    }
+
+    #[test]
+    fn band() {
+        let a = vec![1.0; 4];
+        let mut y = vec![0.0; 2];
+        let error = dgbmv(
+            Transpose::No,
+            2,
+            2,
+            i32::MAX,
+            i32::MAX,
+            1.0,
+            &a,
+            2,
+            &[1.0; 2],
+            1,
+            0.0,
+            &mut y,
+            1,
+        );
+        assert_eq!(
+            error,
+            Err(Error::Argument {
+                routine: "dgbmv",
+                position: 8,
+                name: "lda",
+                reason: "must be at least kl + ku + 1",
+            }),
+        );
+        let error = dsbmv(
+            Uplo::Upper,
+            2,
+            i32::MAX,
+            1.0,
+            &a,
+            2,
+            &[1.0; 2],
+            1,
+            0.0,
+            &mut y,
+            1,
+        );
+        assert_eq!(error.unwrap_err().position(), 6);
+        let error = dtbsv(
+            Uplo::Upper,
+            Transpose::No,
+            Diag::Unit,
+            2,
+            i32::MAX,
+            &a,
+            i32::MAX,
+            &mut y,
+            1,
+        );
+        assert_eq!(error.unwrap_err().position(), 7);
+        let error = dtbmv(
+            Uplo::Upper,
+            Transpose::No,
+            Diag::Unit,
+            2,
+            1,
+            &a[..3],
+            2,
+            &mut y,
+            1,
+        );
+        assert_eq!(
+            error,
+            Err(Error::Length {
+                routine: "dtbmv",
+                position: 6,
+                name: "a",
+                required: 4,
+                provided: 3,
+            }),
+        );
+    }
+
+    #[test]
+    fn length() {
+        let a = vec![1.0; 5];
+        let mut y = vec![0.0; 2];
+        assert_eq!(
+            dgemv(
+                Transpose::Trans,
+                2,
+                3,
+                1.0,
+                &a,
+                2,
+                &[1.0; 2],
+                1,
+                0.0,
+                &mut y,
+                1
+            ),
+            Err(Error::Length {
+                routine: "dgemv",
+                position: 5,
+                name: "a",
+                required: 6,
+                provided: 5,
+            }),
+        );
+        assert_eq!(
+            dgemv(
+                Transpose::Trans,
+                2,
+                2,
+                1.0,
+                &a,
+                2,
+                &[1.0; 2],
+                1,
+                0.0,
+                &mut y,
+                -2
+            ),
+            Err(Error::Length {
+                routine: "dgemv",
+                position: 10,
+                name: "y",
+                required: 3,
+                provided: 2,
+            }),
+        );
+        assert_eq!(
+            dspmv(Uplo::Upper, 3, 1.0, &a, &[1.0; 3], 1, 0.0, &mut [0.0; 3], 1),
+            Err(Error::Length {
+                routine: "dspmv",
+                position: 4,
+                name: "ap",
+                required: 6,
+                provided: 5,
+            }),
+        );
+    }
+
+    #[test]
+    fn quick_return() {
+        let mut y = vec![1.0, 2.0];
+        dgemv(
+            Transpose::No,
+            0,
+            2,
+            1.0,
+            &[],
+            1,
+            &[1.0; 2],
+            1,
+            0.0,
+            &mut [],
+            1,
+        )
+        .unwrap();
+        dgemv(Transpose::No, 2, 0, 1.0, &[], 2, &[], 1, 1.0, &mut y, 1).unwrap();
+        assert_eq!(y, vec![1.0, 2.0]);
+        dtrsv(
+            Uplo::Lower,
+            Transpose::No,
+            Diag::Unit,
+            0,
+            &[],
+            1,
+            &mut [],
+            1,
+        )
+        .unwrap();
+        dger(0, 0, 1.0, &[], 1, &[], 1, &mut [], 1).unwrap();
+        dspr2(Uplo::Upper, 0, 1.0, &[], 1, &[], 1, &mut []).unwrap();
+    }
+
+    #[test]
+    fn compute() {
+        let a = vec![1.0, 3.0, 2.0, 4.0];
+        let mut y = vec![1.0, 1.0];
+        dgemv(
+            Transpose::No,
+            2,
+            2,
+            1.0,
+            &a,
+            2,
+            &[1.0, 1.0],
+            1,
+            2.0,
+            &mut y,
+            1,
+        )
+        .unwrap();
+        assert_eq!(y, vec![5.0, 9.0]);
+    }
+}
@@ -0,0 +1,468 @@
+use super::{matrix_len, Check};
+use {c32, c64, Diag, Result, Side, Transpose, Uplo};
+
+macro_rules! gemm {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            transa: Transpose,
+            transb: Transpose,
+            m: i32,
+            n: i32,
+            k: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            b: &[$ty],
+            ldb: i32,
+            beta: $ty,
+            c: &mut [$ty],
+            ldc: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(3, "m", m)?;
+            check.dimension(4, "n", n)?;
+            check.dimension(5, "k", k)?;
+            let (rowsa, colsa) = if transa == Transpose::No {
+                (m, k)
+            } else {
+                (k, m)
+            };
+            let (rowsb, colsb) = if transb == Transpose::No {
+                (k, n)
+            } else {
+                (n, k)
+            };
+            check.leading(
+                8,
+                "lda",
+                lda,
+                rowsa.max(1),
+                "must be at least max(1, rows of a)",
+            )?;
+            check.leading(
+                10,
+                "ldb",
+                ldb,
+                rowsb.max(1),
+                "must be at least max(1, rows of b)",
+            )?;
+            check.leading(13, "ldc", ldc, m.max(1), "must be at least max(1, m)")?;
+            check.length(7, "a", a.len(), matrix_len(rowsa, colsa, lda))?;
+            check.length(9, "b", b.len(), matrix_len(rowsb, colsb, ldb))?;
+            check.length(12, "c", c.len(), matrix_len(m, n, ldc))?;
+            unsafe { ::$name(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc) };
+            check.finish()
+        }
+    };
+}
+
+gemm!(sgemm, f32);
+gemm!(dgemm, f64);
+gemm!(cgemm, c32);
+gemm!(zgemm, c64);
+
+macro_rules! symm {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            side: Side,
+            uplo: Uplo,
+            m: i32,
+            n: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            b: &[$ty],
+            ldb: i32,
+            beta: $ty,
+            c: &mut [$ty],
+            ldc: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(3, "m", m)?;
+            check.dimension(4, "n", n)?;
+            let order = if side == Side::Left { m } else { n };
+            check.leading(
+                7,
+                "lda",
+                lda,
+                order.max(1),
+                "must be at least max(1, rows of a)",
+            )?;
+            check.leading(9, "ldb", ldb, m.max(1), "must be at least max(1, m)")?;
+            check.leading(12, "ldc", ldc, m.max(1), "must be at least max(1, m)")?;
+            check.length(6, "a", a.len(), matrix_len(order, order, lda))?;
+            check.length(8, "b", b.len(), matrix_len(m, n, ldb))?;
+            check.length(11, "c", c.len(), matrix_len(m, n, ldc))?;
+            unsafe { ::$name(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc) };
+            check.finish()
+        }
+    };
+}
+
+symm!(ssymm, f32);
+symm!(dsymm, f64);
+symm!(csymm, c32);
+symm!(zsymm, c64);
+symm!(chemm, c32);
+symm!(zhemm, c64);
+
+macro_rules! syrk {
+    ($name:ident, $ty:ty, $scalar:ty $(, $allowed:expr, $reason:expr)*) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            trans: Transpose,
+            n: i32,
+            k: i32,
+            alpha: $scalar,
+            a: &[$ty],
+            lda: i32,
+            beta: $scalar,
+            c: &mut [$ty],
+            ldc: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            $(check.transpose(2, "trans", trans, $allowed, $reason)?;)*
+            check.dimension(3, "n", n)?;
+            check.dimension(4, "k", k)?;
+            let (rowsa, colsa) = if trans == Transpose::No { (n, k) } else { (k, n) };
+            check.leading(
+                7,
+                "lda",
+                lda,
+                rowsa.max(1),
+                "must be at least max(1, rows of a)",
+            )?;
+            check.leading(10, "ldc", ldc, n.max(1), "must be at least max(1, n)")?;
+            check.length(6, "a", a.len(), matrix_len(rowsa, colsa, lda))?;
+            check.length(9, "c", c.len(), matrix_len(n, n, ldc))?;
+            unsafe { ::$name(uplo, trans, n, k, alpha, a, lda, beta, c, ldc) };
+            check.finish()
+        }
+    };
+}
+
+syrk!(ssyrk, f32, f32);
+syrk!(dsyrk, f64, f64);
+syrk!(
+    csyrk,
+    c32,
+    c32,
+    &[Transpose::No, Transpose::Trans],
+    "must not be ConjTrans"
+);
+syrk!(
+    zsyrk,
+    c64,
+    c64,
+    &[Transpose::No, Transpose::Trans],
+    "must not be ConjTrans"
+);
+syrk!(
+    cherk,
+    c32,
+    f32,
+    &[Transpose::No, Transpose::ConjTrans],
+    "must not be Trans"
+);
+syrk!(
+    zherk,
+    c64,
+    f64,
+    &[Transpose::No, Transpose::ConjTrans],
+    "must not be Trans"
+);
+
+macro_rules! syr2k {
+    ($name:ident, $ty:ty, $beta:ty $(, $allowed:expr, $reason:expr)*) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            trans: Transpose,
+            n: i32,
+            k: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            b: &[$ty],
+            ldb: i32,
+            beta: $beta,
+            c: &mut [$ty],
+            ldc: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            $(check.transpose(2, "trans", trans, $allowed, $reason)?;)*
+            check.dimension(3, "n", n)?;
+            check.dimension(4, "k", k)?;
+            let (rows, cols) = if trans == Transpose::No { (n, k) } else { (k, n) };
+            check.leading(
+                7,
+                "lda",
+                lda,
+                rows.max(1),
+                "must be at least max(1, rows of a)",
+            )?;
+            check.leading(
+                9,
+                "ldb",
+                ldb,
+                rows.max(1),
+                "must be at least max(1, rows of b)",
+            )?;
+            check.leading(12, "ldc", ldc, n.max(1), "must be at least max(1, n)")?;
+            check.length(6, "a", a.len(), matrix_len(rows, cols, lda))?;
+            check.length(8, "b", b.len(), matrix_len(rows, cols, ldb))?;
+            check.length(11, "c", c.len(), matrix_len(n, n, ldc))?;
+            unsafe { ::$name(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc) };
+            check.finish()
+        }
+    };
+}
+
+syr2k!(ssyr2k, f32, f32);
+syr2k!(dsyr2k, f64, f64);
+syr2k!(
+    csyr2k,
+    c32,
+    c32,
+    &[Transpose::No, Transpose::Trans],
+    "must not be ConjTrans"
+);
+syr2k!(
+    zsyr2k,
+    c64,
+    c64,
+    &[Transpose::No, Transpose::Trans],
+    "must not be ConjTrans"
+);
+syr2k!(
+    cher2k,
+    c32,
+    f32,
+    &[Transpose::No, Transpose::ConjTrans],
+    "must not be Trans"
+);
+syr2k!(
+    zher2k,
+    c64,
+    f64,
+    &[Transpose::No, Transpose::ConjTrans],
+    "must not be Trans"
+);
+
+macro_rules! trmm {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Checked [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            side: Side,
+            uplo: Uplo,
+            transa: Transpose,
+            diag: Diag,
+            m: i32,
+            n: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            b: &mut [$ty],
+            ldb: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(5, "m", m)?;
+            check.dimension(6, "n", n)?;
+            let order = if side == Side::Left { m } else { n };
+            check.leading(
+                9,
+                "lda",
+                lda,
+                order.max(1),
+                "must be at least max(1, rows of a)",
+            )?;
+            check.leading(11, "ldb", ldb, m.max(1), "must be at least max(1, m)")?;
+            check.length(8, "a", a.len(), matrix_len(order, order, lda))?;
+            check.length(10, "b", b.len(), matrix_len(m, n, ldb))?;
+            unsafe { ::$name(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb) };
+            check.finish()
+        }
+    };
+}
+
+trmm!(strmm, f32);
+trmm!(dtrmm, f64);
+trmm!(ctrmm, c32);
+trmm!(ztrmm, c64);
+
+trmm!(strsm, f32);
+trmm!(dtrsm, f64);
+trmm!(ctrsm, c32);
+trmm!(ztrsm, c64);
+
+#[cfg(all(test, feature = "reference", not(feature = "dynamic")))]
+mod tests {
+    use super::*;
+    use Error;
+
+    #[test]
+    fn position() {
+        use self::Transpose::{No, Trans};
+        let a = vec![1.0; 6];
+        let mut c = vec![0.0; 4];
+        let error = dgemm(No, No, 2, -1, 2, 1.0, &a, 2, &a, 2, 0.0, &mut c, 2);
+        assert_eq!(error.unwrap_err().position(), 4);
+        let error = dgemm(Trans, No, 2, 2, 3, 1.0, &a, 2, &a, 3, 0.0, &mut c, 2);
+        assert_eq!(error.unwrap_err().position(), 8);
+        let error = dgemm(No, Trans, 2, 2, 3, 1.0, &a, 2, &a, 1, 0.0, &mut c, 2);
+        assert_eq!(error.unwrap_err().position(), 10);
+        let error = dgemm(No, No, 2, 2, 2, 1.0, &a, 2, &a, 2, 0.0, &mut c, 1);
+        assert_eq!(error.unwrap_err().position(), 13);
+        let error = dtrsm(
+            Side::Right,
+            Uplo::Lower,
+            No,
+            Diag::Unit,
+            2,
+            2,
+            1.0,
+            &a,
+            2,
+            &mut c,
+            1,
+        );
+        assert_eq!(error.unwrap_err().position(), 11);
+        let a = vec![c64::new(1.0, 0.0); 4];
+        let mut c = vec![c64::new(0.0, 0.0); 4];
+        assert_eq!(
+            zherk(Uplo::Upper, Trans, 2, 2, 1.0, &a, 2, 0.0, &mut c, 2),
+            Err(Error::Argument {
+                routine: "zherk",
+                position: 2,
+                name: "trans",
+                reason: "must not be Trans",
+            }),
+        );
+    }
+
+    #[test]
+    fn length() {
+        let a = vec![1.0; 6];
+        let mut c = vec![0.0; 5];
+        assert_eq!(
+            dgemm(
+                Transpose::No,
+                Transpose::No,
+                2,
+                3,
+                2,
+                1.0,
+                &a,
+                2,
+                &a,
+                2,
+                0.0,
+                &mut c,
+                2,
+            ),
+            Err(Error::Length {
+                routine: "dgemm",
+                position: 12,
+                name: "c",
+                required: 6,
+                provided: 5,
+            }),
+        );
+        assert_eq!(
+            dsymm(
+                Side::Right,
+                Uplo::Upper,
+                1,
+                3,
+                1.0,
+                &a,
+                3,
+                &a,
+                1,
+                0.0,
+                &mut c,
+                1
+            ),
+            Err(Error::Length {
+                routine: "dsymm",
+                position: 6,
+                name: "a",
+                required: 9,
+                provided: 6,
+            }),
+        );
+    }
+
+    #[test]
+    fn quick_return() {
+        let mut c = vec![1.0, 2.0, 3.0, 4.0];
+        dgemm(
+            Transpose::No,
+            Transpose::No,
+            0,
+            0,
+            2,
+            1.0,
+            &[],
+            1,
+            &[],
+            2,
+            0.0,
+            &mut [],
+            1,
+        )
+        .unwrap();
+        dgemm(
+            Transpose::No,
+            Transpose::No,
+            2,
+            2,
+            0,
+            1.0,
+            &[],
+            2,
+            &[],
+            1,
+            2.0,
+            &mut c,
+            2,
+        )
+        .unwrap();
+        assert_eq!(c, vec![2.0, 4.0, 6.0, 8.0]);
+        dsyrk(
+            Uplo::Upper,
+            Transpose::No,
+            0,
+            3,
+            1.0,
+            &[],
+            1,
+            0.0,
+            &mut [],
+            1,
+        )
+        .unwrap();
+        dtrmm(
+            Side::Left,
+            Uplo::Upper,
+            Transpose::No,
+            Diag::NonUnit,
+            0,
+            2,
+            1.0,
+            &[],
+            1,
+            &mut [],
+            1,
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,224 @@
+//! Wrappers that validate their arguments.
+//!
+//! Each function mirrors the corresponding `unsafe` wrapper at the root of
+//! the crate. Before crossing the FFI boundary, the dimensions and increments
+//! are validated, and the number of elements that the routine will touch is
+//! compared with the lengths of the slices. A violation is reported as an
+//! [`Error`](../enum.Error.html) naming the routine and the position of the
+//! offending argument. With the `xerbla` feature enabled, errors detected by
+//! BLAS itself are reported likewise. Flags are taken as enums such as
+//! [`Transpose`](../enum.Transpose.html) instead of bytes.
+//!
+//! As in BLAS, a Level 1 routine given a nonpositive number of elements does
+//! nothing, whereas a negative dimension of a Level 2 or Level 3 routine is
+//! an error.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use blas::checked::daxpy;
+//!
+//! let x = vec![1.0, 2.0, 3.0];
+//! let mut y = vec![0.0; 2];
+//! let error = daxpy(3, 1.0, &x, 1, &mut y, 1).unwrap_err();
+//! assert_eq!(error.position(), 5);
+//! ```
+
+use std::convert::TryFrom;
+
+use {Error, Result, Transpose};
+
+mod level1;
+mod level2;
+mod level3;
+
+pub use self::level1::*;
+pub use self::level2::*;
+pub use self::level3::*;
+
+/// Return the number of elements spanned by a vector with `n` elements and
+/// increment `inc`, saturating instead of overflowing.
+#[inline]
+pub(crate) fn vector_len(n: impl Into<i64>, inc: impl Into<i64>) -> usize {
+    let n = n.into();
+    if n <= 0 {
+        0
+    } else {
+        size(inc.into().unsigned_abs())
+            .saturating_mul(size(n as u64) - 1)
+            .saturating_add(1)
+    }
+}
+
+/// Return the number of elements spanned by a matrix with `rows` rows,
+/// `cols` columns, and leading dimension `ld`, saturating instead of
+/// overflowing.
+#[inline]
+pub(crate) fn matrix_len(rows: impl Into<i64>, cols: impl Into<i64>, ld: impl Into<i64>) -> usize {
+    let (rows, cols) = (rows.into(), cols.into());
+    if rows <= 0 || cols <= 0 {
+        0
+    } else {
+        size(ld.into().max(0) as u64)
+            .saturating_mul(size(cols as u64) - 1)
+            .saturating_add(size(rows as u64))
+    }
+}
+
+/// Return the number of elements in a packed triangular matrix of order `n`,
+/// saturating instead of overflowing.
+#[inline]
+pub(crate) fn packed_len(n: impl Into<i64>) -> usize {
+    let n = n.into();
+    if n <= 0 {
+        0
+    } else {
+        let n = n as u64;
+        size(n.checked_mul(n + 1).map_or(u64::MAX, |product| product / 2))
+    }
+}
+
+/// Convert a count into `usize`, saturating on targets where it does not fit.
+#[inline]
+fn size(value: u64) -> usize {
+    usize::try_from(value).unwrap_or(usize::MAX)
+}
+
+pub(crate) struct Check {
+    routine: &'static str,
+}
+
+impl Check {
+    #[inline]
+    pub fn new(routine: &'static str) -> Self {
+        #[cfg(feature = "xerbla")]
+        ::xerbla::clear();
+        Check { routine }
+    }
+
+    #[inline]
+    pub fn finish(&self) -> Result<()> {
+        #[cfg(feature = "xerbla")]
+        {
+            if let Some(error) = ::xerbla::take() {
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn argument(&self, position: usize, name: &'static str, reason: &'static str) -> Error {
+        Error::Argument {
+            routine: self.routine,
+            position,
+            name,
+            reason,
+        }
+    }
+
+    #[inline]
+    pub fn dimension(
+        &self,
+        position: usize,
+        name: &'static str,
+        value: impl Into<i64>,
+    ) -> Result<()> {
+        if value.into() < 0 {
+            return Err(self.argument(position, name, "must be nonnegative"));
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn transpose(
+        &self,
+        position: usize,
+        name: &'static str,
+        value: Transpose,
+        allowed: &[Transpose],
+        reason: &'static str,
+    ) -> Result<()> {
+        if !allowed.contains(&value) {
+            return Err(self.argument(position, name, reason));
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn increment(
+        &self,
+        position: usize,
+        name: &'static str,
+        value: impl Into<i64>,
+    ) -> Result<()> {
+        if value.into() == 0 {
+            return Err(self.argument(position, name, "must be nonzero"));
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn leading(
+        &self,
+        position: usize,
+        name: &'static str,
+        value: impl Into<i64>,
+        required: impl Into<i64>,
+        reason: &'static str,
+    ) -> Result<()> {
+        if value.into() < required.into() {
+            return Err(self.argument(position, name, reason));
+        }
+        Ok(())
+    }
+
+    /// Validate the leading dimension of a band matrix with `band` rows,
+    /// which is computed in `i64` so as not to overflow, and return `band`.
+    #[inline]
+    pub fn band(
+        &self,
+        position: usize,
+        name: &'static str,
+        value: i32,
+        band: i64,
+        reason: &'static str,
+    ) -> Result<i32> {
+        if i64::from(value) < band {
+            return Err(self.argument(position, name, reason));
+        }
+        Ok(band as i32)
+    }
+
+    #[inline]
+    pub fn length(
+        &self,
+        position: usize,
+        name: &'static str,
+        provided: usize,
+        required: usize,
+    ) -> Result<()> {
+        if provided < required {
+            return Err(Error::Length {
+                routine: self.routine,
+                position,
+                name,
+                required,
+                provided,
+            });
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn vector(
+        &self,
+        position: usize,
+        name: &'static str,
+        provided: usize,
+        n: impl Into<i64>,
+        inc: impl Into<i64>,
+    ) -> Result<()> {
+        self.length(position, name, provided, vector_len(n, inc))
+    }
+}
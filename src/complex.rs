@@ -0,0 +1,175 @@
+//! Functions returning a complex value.
+//!
+//! Fortran compilers disagree on how a complex value is returned. gfortran
+//! returns it in registers like a C `_Complex`, which is also what OpenBLAS
+//! and BLIS do, whereas f2c and g77 pass a pointer to the result as a hidden
+//! first argument, which is the signature assumed by `blas-sys`. The latter
+//! is the default; the `complex-by-value` feature selects the former. Neither
+//! applies to the `reference` feature, whereas the `dynamic` feature and the
+//! ILP64 interface abide by the choice.
+
+#[cfg(not(all(feature = "complex-by-value", feature = "dynamic")))]
+use blas_sys::{c_double_complex, c_float_complex};
+#[cfg(not(all(feature = "complex-by-value", feature = "dynamic")))]
+use libc::c_int;
+
+#[cfg(not(all(feature = "complex-by-value", feature = "dynamic")))]
+use {c32, c64};
+
+#[cfg(any(
+    not(feature = "complex-by-value"),
+    all(feature = "reference", not(feature = "dynamic")),
+))]
+macro_rules! function {
+    ($name:ident, $function:path, $ty:ident, $complex:ident, $int:ty) => {
+        #[inline]
+        pub unsafe fn $name(
+            n: *const $int,
+            x: *const $complex,
+            incx: *const $int,
+            y: *const $complex,
+            incy: *const $int,
+        ) -> $ty {
+            let mut pres = $ty::new(0.0, 0.0);
+            $function(&mut pres as *mut _ as *mut _, n, x, incx, y, incy);
+            pres
+        }
+    };
+}
+
+#[cfg(any(
+    not(feature = "complex-by-value"),
+    all(feature = "reference", not(feature = "dynamic")),
+))]
+function!(cdotu_, ::ffi::cdotu_, c32, c_float_complex, c_int);
+#[cfg(any(
+    not(feature = "complex-by-value"),
+    all(feature = "reference", not(feature = "dynamic")),
+))]
+function!(cdotc_, ::ffi::cdotc_, c32, c_float_complex, c_int);
+#[cfg(any(
+    not(feature = "complex-by-value"),
+    all(feature = "reference", not(feature = "dynamic")),
+))]
+function!(zdotu_, ::ffi::zdotu_, c64, c_double_complex, c_int);
+#[cfg(any(
+    not(feature = "complex-by-value"),
+    all(feature = "reference", not(feature = "dynamic")),
+))]
+function!(zdotc_, ::ffi::zdotc_, c64, c_double_complex, c_int);
+
+#[cfg(all(feature = "complex-by-value", feature = "dynamic"))]
+pub(crate) use ffi::{cdotc_, cdotu_, zdotc_, zdotu_};
+
+#[cfg(all(
+    feature = "complex-by-value",
+    not(any(feature = "dynamic", feature = "reference")),
+))]
+extern "C" {
+    pub fn cdotu_(
+        n: *const c_int,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        y: *const c_float_complex,
+        incy: *const c_int,
+    ) -> c32;
+    pub fn cdotc_(
+        n: *const c_int,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        y: *const c_float_complex,
+        incy: *const c_int,
+    ) -> c32;
+    pub fn zdotu_(
+        n: *const c_int,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        y: *const c_double_complex,
+        incy: *const c_int,
+    ) -> c64;
+    pub fn zdotc_(
+        n: *const c_int,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        y: *const c_double_complex,
+        incy: *const c_int,
+    ) -> c64;
+}
+
+/// Functions of the ILP64 interface.
+#[cfg(feature = "ilp64")]
+pub mod ilp64 {
+    #[cfg(not(all(feature = "complex-by-value", feature = "dynamic")))]
+    use blas_sys::{c_double_complex, c_float_complex};
+
+    #[cfg(not(all(feature = "complex-by-value", feature = "dynamic")))]
+    use {c32, c64};
+
+    #[cfg(not(feature = "complex-by-value"))]
+    function!(
+        cdotu_64_,
+        ::ilp64::ffi::cdotu_64_,
+        c32,
+        c_float_complex,
+        i64
+    );
+    #[cfg(not(feature = "complex-by-value"))]
+    function!(
+        cdotc_64_,
+        ::ilp64::ffi::cdotc_64_,
+        c32,
+        c_float_complex,
+        i64
+    );
+    #[cfg(not(feature = "complex-by-value"))]
+    function!(
+        zdotu_64_,
+        ::ilp64::ffi::zdotu_64_,
+        c64,
+        c_double_complex,
+        i64
+    );
+    #[cfg(not(feature = "complex-by-value"))]
+    function!(
+        zdotc_64_,
+        ::ilp64::ffi::zdotc_64_,
+        c64,
+        c_double_complex,
+        i64
+    );
+
+    #[cfg(all(feature = "complex-by-value", feature = "dynamic"))]
+    pub(crate) use ilp64::ffi::{cdotc_64_, cdotu_64_, zdotc_64_, zdotu_64_};
+
+    #[cfg(all(feature = "complex-by-value", not(feature = "dynamic")))]
+    extern "C" {
+        pub fn cdotu_64_(
+            n: *const i64,
+            x: *const c_float_complex,
+            incx: *const i64,
+            y: *const c_float_complex,
+            incy: *const i64,
+        ) -> c32;
+        pub fn cdotc_64_(
+            n: *const i64,
+            x: *const c_float_complex,
+            incx: *const i64,
+            y: *const c_float_complex,
+            incy: *const i64,
+        ) -> c32;
+        pub fn zdotu_64_(
+            n: *const i64,
+            x: *const c_double_complex,
+            incx: *const i64,
+            y: *const c_double_complex,
+            incy: *const i64,
+        ) -> c64;
+        pub fn zdotc_64_(
+            n: *const i64,
+            x: *const c_double_complex,
+            incx: *const i64,
+            y: *const c_double_complex,
+            incy: *const i64,
+        ) -> c64;
+    }
+}
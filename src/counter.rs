@@ -0,0 +1,170 @@
+//! Accounting of floating-point operations and memory traffic.
+//!
+//! With the `counter` feature enabled, which implies `trace`, and counting
+//! turned on via [`enable`], each call to a wrapper at the root of the crate
+//! or in [`ilp64`] adds the theoretical number of floating-point operations
+//! and bytes touched to counters kept per routine, both for the calling thread
+//! and aggregated over all threads. The counters are independent of the sink
+//! of [`trace`], if any.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use std::time::Instant;
+//!
+//! use blas::counter;
+//!
+//! counter::enable();
+//!
+//! let start = Instant::now();
+//! let x = vec![1.0; 1000];
+//! let mut y = vec![0.0; 1000];
+//! unsafe { blas::daxpy(1000, 2.0, &x, 1, &mut y, 1) };
+//! let elapsed = start.elapsed();
+//!
+//! let count = counter::thread().sum();
+//! println!("{} GFLOP/s", count.flops / elapsed.as_secs_f64() / 1e9);
+//! println!("{} FLOP/byte", count.intensity());
+//! counter::reset();
+//! ```
+//!
+//! [`enable`]: fn.enable.html
+//! [`ilp64`]: ../ilp64/index.html
+//! [`trace`]: ../trace/index.html
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::ops::AddAssign;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use trace::Call;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static EPOCH: AtomicU64 = AtomicU64::new(0);
+static TOTAL: Mutex<BTreeMap<&'static str, Count>> = Mutex::new(BTreeMap::new());
+
+thread_local! {
+    static THREAD: RefCell<(u64, Counters)> = RefCell::new((0, Counters::default()));
+}
+
+/// The counts of a routine or of a set of routines.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Count {
+    /// The number of calls.
+    pub calls: u64,
+    /// The theoretical number of floating-point operations.
+    pub flops: f64,
+    /// The theoretical number of bytes read and written.
+    pub bytes: f64,
+}
+
+/// The counts of the routines called.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Counters {
+    routines: BTreeMap<&'static str, Count>,
+}
+
+impl Count {
+    /// Return the number of floating-point operations per byte.
+    #[inline]
+    pub fn intensity(&self) -> f64 {
+        self.flops / self.bytes
+    }
+}
+
+impl AddAssign for Count {
+    #[inline]
+    fn add_assign(&mut self, other: Count) {
+        self.calls += other.calls;
+        self.flops += other.flops;
+        self.bytes += other.bytes;
+    }
+}
+
+impl Counters {
+    /// Return the counts of a routine, which are zero if it was not called.
+    pub fn get(&self, routine: &str) -> Count {
+        self.routines.get(routine).cloned().unwrap_or_default()
+    }
+
+    /// Return the counts of all routines together.
+    pub fn sum(&self) -> Count {
+        let mut sum = Count::default();
+        for &count in self.routines.values() {
+            sum += count;
+        }
+        sum
+    }
+
+    /// Return the counts of each routine called, ordered by name.
+    #[inline]
+    pub fn routines(&self) -> &BTreeMap<&'static str, Count> {
+        &self.routines
+    }
+}
+
+/// Start counting calls.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Release);
+}
+
+/// Stop counting calls, keeping the counts.
+pub fn disable() {
+    ENABLED.store(false, Ordering::Release);
+}
+
+/// Return the counts of the calls made by the calling thread since the last
+/// reset.
+pub fn thread() -> Counters {
+    let epoch = EPOCH.load(Ordering::Acquire);
+    THREAD.with(|counters| {
+        let counters = counters.borrow();
+        if counters.0 == epoch {
+            counters.1.clone()
+        } else {
+            Counters::default()
+        }
+    })
+}
+
+/// Return the counts of the calls made by all threads since the last reset.
+pub fn total() -> Counters {
+    Counters {
+        routines: TOTAL.lock().unwrap().clone(),
+    }
+}
+
+/// Set the counts of all threads to zero.
+pub fn reset() {
+    let mut total = TOTAL.lock().unwrap();
+    total.clear();
+    EPOCH.fetch_add(1, Ordering::AcqRel);
+}
+
+/// Check if calls are being counted.
+#[inline]
+pub(crate) fn enabled() -> bool {
+    ENABLED.load(Ordering::Acquire)
+}
+
+/// Add a call to the counts of the calling thread and to the total.
+pub(crate) fn add(call: &Call) {
+    let count = Count {
+        calls: 1,
+        flops: call.flops,
+        bytes: call.bytes,
+    };
+    let epoch = {
+        let mut total = TOTAL.lock().unwrap();
+        *total.entry(call.routine).or_default() += count;
+        EPOCH.load(Ordering::Acquire)
+    };
+    THREAD.with(|counters| {
+        let counters = &mut *counters.borrow_mut();
+        if counters.0 != epoch {
+            *counters = (epoch, Counters::default());
+        }
+        *counters.1.routines.entry(call.routine).or_default() += count;
+    });
+}
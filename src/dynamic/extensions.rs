@@ -0,0 +1,274 @@
+//! Routines beyond the reference interface.
+//!
+//! A library might provide these routines, in which case they are preferred
+//! over their counterparts implemented in terms of the reference interface.
+
+use blas_sys::{c_double_complex, c_float_complex};
+use libc::{c_char, c_double, c_float, c_int};
+
+extensions! {
+    fn sgemm_batch_strided_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        stridea: *const c_int,
+        b: *const c_float,
+        ldb: *const c_int,
+        strideb: *const c_int,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const c_int,
+        stridec: *const c_int,
+        batch_size: *const c_int,
+    );
+    fn dgemm_batch_strided_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        stridea: *const c_int,
+        b: *const c_double,
+        ldb: *const c_int,
+        strideb: *const c_int,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const c_int,
+        stridec: *const c_int,
+        batch_size: *const c_int,
+    );
+    fn cgemm_batch_strided_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        stridea: *const c_int,
+        b: *const c_float_complex,
+        ldb: *const c_int,
+        strideb: *const c_int,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const c_int,
+        stridec: *const c_int,
+        batch_size: *const c_int,
+    );
+    fn zgemm_batch_strided_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        stridea: *const c_int,
+        b: *const c_double_complex,
+        ldb: *const c_int,
+        strideb: *const c_int,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const c_int,
+        stridec: *const c_int,
+        batch_size: *const c_int,
+    );
+    fn saxpby_(
+        n: *const c_int,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const c_int,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const c_int,
+    );
+    fn daxpby_(
+        n: *const c_int,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const c_int,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const c_int,
+    );
+    fn caxpby_(
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const c_int,
+    );
+    fn zaxpby_(
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const c_int,
+    );
+    #[alias(mkl_somatcopy_)]
+    fn somatcopy_(
+        order: *const c_char,
+        trans: *const c_char,
+        rows: *const c_int,
+        cols: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        b: *mut c_float,
+        ldb: *const c_int,
+    );
+    #[alias(mkl_domatcopy_)]
+    fn domatcopy_(
+        order: *const c_char,
+        trans: *const c_char,
+        rows: *const c_int,
+        cols: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        b: *mut c_double,
+        ldb: *const c_int,
+    );
+    #[alias(mkl_comatcopy_)]
+    fn comatcopy_(
+        order: *const c_char,
+        trans: *const c_char,
+        rows: *const c_int,
+        cols: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        b: *mut c_float_complex,
+        ldb: *const c_int,
+    );
+    #[alias(mkl_zomatcopy_)]
+    fn zomatcopy_(
+        order: *const c_char,
+        trans: *const c_char,
+        rows: *const c_int,
+        cols: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        b: *mut c_double_complex,
+        ldb: *const c_int,
+    );
+    #[alias(mkl_simatcopy_)]
+    fn simatcopy_(
+        order: *const c_char,
+        trans: *const c_char,
+        rows: *const c_int,
+        cols: *const c_int,
+        alpha: *const c_float,
+        ab: *mut c_float,
+        lda: *const c_int,
+        ldb: *const c_int,
+    );
+    #[alias(mkl_dimatcopy_)]
+    fn dimatcopy_(
+        order: *const c_char,
+        trans: *const c_char,
+        rows: *const c_int,
+        cols: *const c_int,
+        alpha: *const c_double,
+        ab: *mut c_double,
+        lda: *const c_int,
+        ldb: *const c_int,
+    );
+    #[alias(mkl_cimatcopy_)]
+    fn cimatcopy_(
+        order: *const c_char,
+        trans: *const c_char,
+        rows: *const c_int,
+        cols: *const c_int,
+        alpha: *const c_float_complex,
+        ab: *mut c_float_complex,
+        lda: *const c_int,
+        ldb: *const c_int,
+    );
+    #[alias(mkl_zimatcopy_)]
+    fn zimatcopy_(
+        order: *const c_char,
+        trans: *const c_char,
+        rows: *const c_int,
+        cols: *const c_int,
+        alpha: *const c_double_complex,
+        ab: *mut c_double_complex,
+        lda: *const c_int,
+        ldb: *const c_int,
+    );
+    fn sgemmt_(
+        uplo: *const c_char,
+        transa: *const c_char,
+        transb: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        b: *const c_float,
+        ldb: *const c_int,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const c_int,
+    );
+    fn dgemmt_(
+        uplo: *const c_char,
+        transa: *const c_char,
+        transb: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        b: *const c_double,
+        ldb: *const c_int,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const c_int,
+    );
+    fn cgemmt_(
+        uplo: *const c_char,
+        transa: *const c_char,
+        transb: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        b: *const c_float_complex,
+        ldb: *const c_int,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const c_int,
+    );
+    fn zgemmt_(
+        uplo: *const c_char,
+        transa: *const c_char,
+        transb: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        b: *const c_double_complex,
+        ldb: *const c_int,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const c_int,
+    );
+}
@@ -0,0 +1,1422 @@
+//! The routines of a library.
+//!
+//! The declarations are generated by `bin/generate.py --dynamic` from those of
+//! `blas-sys`.
+
+use blas_sys::{c_double_complex, c_float_complex};
+use libc::{c_char, c_double, c_float, c_int, c_void};
+
+functions! {
+    functions,
+    fn srotg_(a: *mut c_float, b: *mut c_float, c: *mut c_float, s: *mut c_float);
+    fn srotmg_(
+        d1: *mut c_float,
+        d2: *mut c_float,
+        x1: *mut c_float,
+        y1: *const c_float,
+        param: *mut c_float,
+    );
+    fn srot_(
+        n: *const c_int,
+        x: *mut c_float,
+        incx: *const c_int,
+        y: *mut c_float,
+        incy: *const c_int,
+        c: *const c_float,
+        s: *const c_float,
+    );
+    fn srotm_(
+        n: *const c_int,
+        x: *mut c_float,
+        incx: *const c_int,
+        y: *mut c_float,
+        incy: *const c_int,
+        param: *const c_float,
+    );
+    fn sswap_(
+        n: *const c_int,
+        x: *mut c_float,
+        incx: *const c_int,
+        y: *mut c_float,
+        incy: *const c_int,
+    );
+    fn sscal_(n: *const c_int, a: *const c_float, x: *mut c_float, incx: *const c_int);
+    fn scopy_(
+        n: *const c_int,
+        x: *const c_float,
+        incx: *const c_int,
+        y: *mut c_float,
+        incy: *const c_int,
+    );
+    fn saxpy_(
+        n: *const c_int,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const c_int,
+        y: *mut c_float,
+        incy: *const c_int,
+    );
+    fn sdot_(
+        n: *const c_int,
+        x: *const c_float,
+        incx: *const c_int,
+        y: *const c_float,
+        incy: *const c_int,
+    ) -> c_float;
+    fn sdsdot_(
+        n: *const c_int,
+        sb: *const c_float,
+        x: *const c_float,
+        incx: *const c_int,
+        y: *const c_float,
+        incy: *const c_int,
+    ) -> c_float;
+    fn snrm2_(n: *const c_int, x: *const c_float, incx: *const c_int) -> c_float;
+    fn scnrm2_(n: *const c_int, x: *const c_float_complex, incx: *const c_int) -> c_float;
+    fn sasum_(n: *const c_int, x: *const c_float, incx: *const c_int) -> c_float;
+    fn isamax_(n: *const c_int, x: *const c_float, incx: *const c_int) -> c_int;
+    fn drotg_(a: *mut c_double, b: *mut c_double, c: *mut c_double, s: *mut c_double);
+    fn drotmg_(
+        d1: *mut c_double,
+        d2: *mut c_double,
+        x1: *mut c_double,
+        y1: *const c_double,
+        param: *mut c_double,
+    );
+    fn drot_(
+        n: *const c_int,
+        x: *mut c_double,
+        incx: *const c_int,
+        y: *mut c_double,
+        incy: *const c_int,
+        c: *const c_double,
+        s: *const c_double,
+    );
+    fn drotm_(
+        n: *const c_int,
+        x: *mut c_double,
+        incx: *const c_int,
+        y: *mut c_double,
+        incy: *const c_int,
+        param: *const c_double,
+    );
+    fn dswap_(
+        n: *const c_int,
+        x: *mut c_double,
+        incx: *const c_int,
+        y: *mut c_double,
+        incy: *const c_int,
+    );
+    fn dscal_(n: *const c_int, a: *const c_double, x: *mut c_double, incx: *const c_int);
+    fn dcopy_(
+        n: *const c_int,
+        x: *const c_double,
+        incx: *const c_int,
+        y: *mut c_double,
+        incy: *const c_int,
+    );
+    fn daxpy_(
+        n: *const c_int,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const c_int,
+        y: *mut c_double,
+        incy: *const c_int,
+    );
+    fn ddot_(
+        n: *const c_int,
+        x: *const c_double,
+        incx: *const c_int,
+        y: *const c_double,
+        incy: *const c_int,
+    ) -> c_double;
+    fn dsdot_(
+        n: *const c_int,
+        x: *const c_float,
+        incx: *const c_int,
+        y: *const c_float,
+        incy: *const c_int,
+    ) -> c_double;
+    fn dnrm2_(n: *const c_int, x: *const c_double, incx: *const c_int) -> c_double;
+    fn dznrm2_(n: *const c_int, x: *const c_double_complex, incx: *const c_int) -> c_double;
+    fn dasum_(n: *const c_int, x: *const c_double, incx: *const c_int) -> c_double;
+    fn idamax_(n: *const c_int, x: *const c_double, incx: *const c_int) -> c_int;
+    fn crotg_(
+        a: *mut c_float_complex,
+        b: *const c_float_complex,
+        c: *mut c_float,
+        s: *mut c_float_complex,
+    );
+    fn csrot_(
+        n: *const c_int,
+        x: *mut c_float_complex,
+        incx: *const c_int,
+        y: *mut c_float_complex,
+        incy: *const c_int,
+        c: *const c_float,
+        s: *const c_float,
+    );
+    fn cswap_(
+        n: *const c_int,
+        x: *mut c_float_complex,
+        incx: *const c_int,
+        y: *mut c_float_complex,
+        incy: *const c_int,
+    );
+    fn cscal_(
+        n: *const c_int,
+        a: *const c_float_complex,
+        x: *mut c_float_complex,
+        incx: *const c_int,
+    );
+    fn csscal_(n: *const c_int, a: *const c_float, x: *mut c_float_complex, incx: *const c_int);
+    fn ccopy_(
+        n: *const c_int,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        y: *mut c_float_complex,
+        incy: *const c_int,
+    );
+    fn caxpy_(
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        y: *mut c_float_complex,
+        incy: *const c_int,
+    );
+    #[cfg(not(feature = "complex-by-value"))]
+    fn cdotu_(
+        pres: *mut c_float_complex,
+        n: *const c_int,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        y: *const c_float_complex,
+        incy: *const c_int,
+    );
+    #[cfg(feature = "complex-by-value")]
+    fn cdotu_(
+        n: *const c_int,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        y: *const c_float_complex,
+        incy: *const c_int,
+    ) -> ::c32;
+    #[cfg(not(feature = "complex-by-value"))]
+    fn cdotc_(
+        pres: *mut c_float_complex,
+        n: *const c_int,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        y: *const c_float_complex,
+        incy: *const c_int,
+    );
+    #[cfg(feature = "complex-by-value")]
+    fn cdotc_(
+        n: *const c_int,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        y: *const c_float_complex,
+        incy: *const c_int,
+    ) -> ::c32;
+    fn scasum_(n: *const c_int, x: *const c_float_complex, incx: *const c_int) -> c_float;
+    fn icamax_(n: *const c_int, x: *const c_float_complex, incx: *const c_int) -> c_int;
+    fn zrotg_(
+        a: *mut c_double_complex,
+        b: *const c_double_complex,
+        c: *mut c_double,
+        s: *mut c_double_complex,
+    );
+    fn zdrot_(
+        n: *const c_int,
+        x: *mut c_double_complex,
+        incx: *const c_int,
+        y: *mut c_double_complex,
+        incy: *const c_int,
+        c: *const c_double,
+        s: *const c_double,
+    );
+    fn zswap_(
+        n: *const c_int,
+        x: *mut c_double_complex,
+        incx: *const c_int,
+        y: *mut c_double_complex,
+        incy: *const c_int,
+    );
+    fn zscal_(
+        n: *const c_int,
+        a: *const c_double_complex,
+        x: *mut c_double_complex,
+        incx: *const c_int,
+    );
+    fn zdscal_(n: *const c_int, a: *const c_double, x: *mut c_double_complex, incx: *const c_int);
+    fn zcopy_(
+        n: *const c_int,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        y: *mut c_double_complex,
+        incy: *const c_int,
+    );
+    fn zaxpy_(
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        y: *mut c_double_complex,
+        incy: *const c_int,
+    );
+    #[cfg(not(feature = "complex-by-value"))]
+    fn zdotu_(
+        pres: *mut c_double_complex,
+        n: *const c_int,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        y: *const c_double_complex,
+        incy: *const c_int,
+    );
+    #[cfg(feature = "complex-by-value")]
+    fn zdotu_(
+        n: *const c_int,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        y: *const c_double_complex,
+        incy: *const c_int,
+    ) -> ::c64;
+    #[cfg(not(feature = "complex-by-value"))]
+    fn zdotc_(
+        pres: *mut c_double_complex,
+        n: *const c_int,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        y: *const c_double_complex,
+        incy: *const c_int,
+    );
+    #[cfg(feature = "complex-by-value")]
+    fn zdotc_(
+        n: *const c_int,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        y: *const c_double_complex,
+        incy: *const c_int,
+    ) -> ::c64;
+    fn dzasum_(n: *const c_int, x: *const c_double_complex, incx: *const c_int) -> c_double;
+    fn izamax_(n: *const c_int, x: *const c_double_complex, incx: *const c_int) -> c_int;
+    fn sgemv_(
+        trans: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        x: *const c_float,
+        incx: *const c_int,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const c_int,
+    );
+    fn sgbmv_(
+        trans: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        kl: *const c_int,
+        ku: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        x: *const c_float,
+        incx: *const c_int,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const c_int,
+    );
+    fn ssymv_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        x: *const c_float,
+        incx: *const c_int,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const c_int,
+    );
+    fn ssbmv_(
+        uplo: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        x: *const c_float,
+        incx: *const c_int,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const c_int,
+    );
+    fn sspmv_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float,
+        ap: *const c_float,
+        x: *const c_float,
+        incx: *const c_int,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const c_int,
+    );
+    fn strmv_(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        a: *const c_float,
+        lda: *const c_int,
+        b: *mut c_float,
+        incx: *const c_int,
+    );
+    fn stbmv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        a: *const c_float,
+        lda: *const c_int,
+        x: *mut c_float,
+        incx: *const c_int,
+    );
+    fn stpmv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        ap: *const c_float,
+        x: *mut c_float,
+        incx: *const c_int,
+    );
+    fn strsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        a: *const c_float,
+        lda: *const c_int,
+        x: *mut c_float,
+        incx: *const c_int,
+    );
+    fn stbsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        a: *const c_float,
+        lda: *const c_int,
+        x: *mut c_float,
+        incx: *const c_int,
+    );
+    fn stpsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        ap: *const c_float,
+        x: *mut c_float,
+        incx: *const c_int,
+    );
+    fn sger_(
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const c_int,
+        y: *const c_float,
+        incy: *const c_int,
+        a: *mut c_float,
+        lda: *const c_int,
+    );
+    fn ssyr_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const c_int,
+        a: *mut c_float,
+        lda: *const c_int,
+    );
+    fn sspr_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const c_int,
+        ap: *mut c_float,
+    );
+    fn ssyr2_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const c_int,
+        y: *const c_float,
+        incy: *const c_int,
+        a: *mut c_float,
+        lda: *const c_int,
+    );
+    fn sspr2_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const c_int,
+        y: *const c_float,
+        incy: *const c_int,
+        ap: *mut c_float,
+    );
+    fn dgemv_(
+        trans: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        x: *const c_double,
+        incx: *const c_int,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const c_int,
+    );
+    fn dgbmv_(
+        trans: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        kl: *const c_int,
+        ku: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        x: *const c_double,
+        incx: *const c_int,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const c_int,
+    );
+    fn dsymv_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        x: *const c_double,
+        incx: *const c_int,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const c_int,
+    );
+    fn dsbmv_(
+        uplo: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        x: *const c_double,
+        incx: *const c_int,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const c_int,
+    );
+    fn dspmv_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double,
+        ap: *const c_double,
+        x: *const c_double,
+        incx: *const c_int,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const c_int,
+    );
+    fn dtrmv_(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        a: *const c_double,
+        lda: *const c_int,
+        b: *mut c_double,
+        incx: *const c_int,
+    );
+    fn dtbmv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        a: *const c_double,
+        lda: *const c_int,
+        x: *mut c_double,
+        incx: *const c_int,
+    );
+    fn dtpmv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        ap: *const c_double,
+        x: *mut c_double,
+        incx: *const c_int,
+    );
+    fn dtrsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        a: *const c_double,
+        lda: *const c_int,
+        x: *mut c_double,
+        incx: *const c_int,
+    );
+    fn dtbsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        a: *const c_double,
+        lda: *const c_int,
+        x: *mut c_double,
+        incx: *const c_int,
+    );
+    fn dtpsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        ap: *const c_double,
+        x: *mut c_double,
+        incx: *const c_int,
+    );
+    fn dger_(
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const c_int,
+        y: *const c_double,
+        incy: *const c_int,
+        a: *mut c_double,
+        lda: *const c_int,
+    );
+    fn dsyr_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const c_int,
+        a: *mut c_double,
+        lda: *const c_int,
+    );
+    fn dspr_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const c_int,
+        ap: *mut c_double,
+    );
+    fn dsyr2_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const c_int,
+        y: *const c_double,
+        incy: *const c_int,
+        a: *mut c_double,
+        lda: *const c_int,
+    );
+    fn dspr2_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const c_int,
+        y: *const c_double,
+        incy: *const c_int,
+        ap: *mut c_double,
+    );
+    fn cgemv_(
+        trans: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const c_int,
+    );
+    fn cgbmv_(
+        trans: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        kl: *const c_int,
+        ku: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const c_int,
+    );
+    fn chemv_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const c_int,
+    );
+    fn chbmv_(
+        uplo: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const c_int,
+    );
+    fn chpmv_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        ap: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const c_int,
+    );
+    fn ctrmv_(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        b: *mut c_float_complex,
+        incx: *const c_int,
+    );
+    fn ctbmv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        x: *mut c_float_complex,
+        incx: *const c_int,
+    );
+    fn ctpmv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        ap: *const c_float_complex,
+        x: *mut c_float_complex,
+        incx: *const c_int,
+    );
+    fn ctrsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        x: *mut c_float_complex,
+        incx: *const c_int,
+    );
+    fn ctbsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        x: *mut c_float_complex,
+        incx: *const c_int,
+    );
+    fn ctpsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        ap: *const c_float_complex,
+        x: *mut c_float_complex,
+        incx: *const c_int,
+    );
+    fn cgeru_(
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        y: *const c_float_complex,
+        incy: *const c_int,
+        a: *mut c_float_complex,
+        lda: *const c_int,
+    );
+    fn cgerc_(
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        y: *const c_float_complex,
+        incy: *const c_int,
+        a: *mut c_float_complex,
+        lda: *const c_int,
+    );
+    fn cher_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        a: *mut c_float_complex,
+        lda: *const c_int,
+    );
+    fn chpr_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        ap: *mut c_float_complex,
+    );
+    fn chpr2_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        y: *const c_float_complex,
+        incy: *const c_int,
+        ap: *mut c_float_complex,
+    );
+    fn cher2_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        y: *const c_float_complex,
+        incy: *const c_int,
+        a: *mut c_float_complex,
+        lda: *const c_int,
+    );
+    fn zgemv_(
+        trans: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const c_int,
+    );
+    fn zgbmv_(
+        trans: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        kl: *const c_int,
+        ku: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const c_int,
+    );
+    fn zhemv_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const c_int,
+    );
+    fn zhbmv_(
+        uplo: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const c_int,
+    );
+    fn zhpmv_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        ap: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const c_int,
+    );
+    fn ztrmv_(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        b: *mut c_double_complex,
+        incx: *const c_int,
+    );
+    fn ztbmv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        x: *mut c_double_complex,
+        incx: *const c_int,
+    );
+    fn ztpmv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        ap: *const c_double_complex,
+        x: *mut c_double_complex,
+        incx: *const c_int,
+    );
+    fn ztrsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        x: *mut c_double_complex,
+        incx: *const c_int,
+    );
+    fn ztbsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        x: *mut c_double_complex,
+        incx: *const c_int,
+    );
+    fn ztpsv_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        ap: *const c_double_complex,
+        x: *mut c_double_complex,
+        incx: *const c_int,
+    );
+    fn zgeru_(
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        y: *const c_double_complex,
+        incy: *const c_int,
+        a: *mut c_double_complex,
+        lda: *const c_int,
+    );
+    fn zgerc_(
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        y: *const c_double_complex,
+        incy: *const c_int,
+        a: *mut c_double_complex,
+        lda: *const c_int,
+    );
+    fn zher_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        a: *mut c_double_complex,
+        lda: *const c_int,
+    );
+    fn zhpr_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        ap: *mut c_double_complex,
+    );
+    fn zher2_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        y: *const c_double_complex,
+        incy: *const c_int,
+        a: *mut c_double_complex,
+        lda: *const c_int,
+    );
+    fn zhpr2_(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        y: *const c_double_complex,
+        incy: *const c_int,
+        ap: *mut c_double_complex,
+    );
+    fn sgemm_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        b: *const c_float,
+        ldb: *const c_int,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const c_int,
+    );
+    fn ssymm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        b: *const c_float,
+        ldb: *const c_int,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const c_int,
+    );
+    fn ssyrk_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const c_int,
+    );
+    fn ssyr2k_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        b: *const c_float,
+        ldb: *const c_int,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const c_int,
+    );
+    fn strmm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        b: *mut c_float,
+        ldb: *const c_int,
+    );
+    fn strsm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        b: *mut c_float,
+        ldb: *const c_int,
+    );
+    fn dgemm_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        b: *const c_double,
+        ldb: *const c_int,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const c_int,
+    );
+    fn dsymm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        b: *const c_double,
+        ldb: *const c_int,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const c_int,
+    );
+    fn dsyrk_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const c_int,
+    );
+    fn dsyr2k_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        b: *const c_double,
+        ldb: *const c_int,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const c_int,
+    );
+    fn dtrmm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        b: *mut c_double,
+        ldb: *const c_int,
+    );
+    fn dtrsm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        b: *mut c_double,
+        ldb: *const c_int,
+    );
+    fn cgemm_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        b: *const c_float_complex,
+        ldb: *const c_int,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const c_int,
+    );
+    fn csymm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        b: *const c_float_complex,
+        ldb: *const c_int,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const c_int,
+    );
+    fn chemm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        b: *const c_float_complex,
+        ldb: *const c_int,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const c_int,
+    );
+    fn csyrk_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const c_int,
+    );
+    fn cherk_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        beta: *const c_float,
+        c: *mut c_float_complex,
+        ldc: *const c_int,
+    );
+    fn csyr2k_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        b: *const c_float_complex,
+        ldb: *const c_int,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const c_int,
+    );
+    fn cher2k_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        b: *const c_float_complex,
+        ldb: *const c_int,
+        beta: *const c_float,
+        c: *mut c_float_complex,
+        ldc: *const c_int,
+    );
+    fn ctrmm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        b: *mut c_float_complex,
+        ldb: *const c_int,
+    );
+    fn ctrsm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        b: *mut c_float_complex,
+        ldb: *const c_int,
+    );
+    fn zgemm_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        b: *const c_double_complex,
+        ldb: *const c_int,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const c_int,
+    );
+    fn zsymm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        b: *const c_double_complex,
+        ldb: *const c_int,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const c_int,
+    );
+    fn zhemm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        b: *const c_double_complex,
+        ldb: *const c_int,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const c_int,
+    );
+    fn zsyrk_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const c_int,
+    );
+    fn zherk_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        beta: *const c_double,
+        c: *mut c_double_complex,
+        ldc: *const c_int,
+    );
+    fn zsyr2k_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        b: *const c_double_complex,
+        ldb: *const c_int,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const c_int,
+    );
+    fn zher2k_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        b: *const c_double_complex,
+        ldb: *const c_int,
+        beta: *const c_double,
+        c: *mut c_double_complex,
+        ldc: *const c_int,
+    );
+    fn ztrmm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        b: *mut c_double_complex,
+        ldb: *const c_int,
+    );
+    fn ztrsm_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        b: *mut c_double_complex,
+        ldb: *const c_int,
+    );
+}
@@ -0,0 +1,1422 @@
+//! The routines of the ILP64 interface of a library.
+//!
+//! The declarations are generated by `bin/generate.py --ilp64 --dynamic` from
+//! those of `blas-sys`.
+
+use blas_sys::{c_double_complex, c_float_complex};
+use libc::{c_char, c_double, c_float, c_void};
+
+functions! {
+    ilp64,
+    fn srotg_64_(a: *mut c_float, b: *mut c_float, c: *mut c_float, s: *mut c_float);
+    fn srotmg_64_(
+        d1: *mut c_float,
+        d2: *mut c_float,
+        x1: *mut c_float,
+        y1: *const c_float,
+        param: *mut c_float,
+    );
+    fn srot_64_(
+        n: *const i64,
+        x: *mut c_float,
+        incx: *const i64,
+        y: *mut c_float,
+        incy: *const i64,
+        c: *const c_float,
+        s: *const c_float,
+    );
+    fn srotm_64_(
+        n: *const i64,
+        x: *mut c_float,
+        incx: *const i64,
+        y: *mut c_float,
+        incy: *const i64,
+        param: *const c_float,
+    );
+    fn sswap_64_(
+        n: *const i64,
+        x: *mut c_float,
+        incx: *const i64,
+        y: *mut c_float,
+        incy: *const i64,
+    );
+    fn sscal_64_(n: *const i64, a: *const c_float, x: *mut c_float, incx: *const i64);
+    fn scopy_64_(
+        n: *const i64,
+        x: *const c_float,
+        incx: *const i64,
+        y: *mut c_float,
+        incy: *const i64,
+    );
+    fn saxpy_64_(
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const i64,
+        y: *mut c_float,
+        incy: *const i64,
+    );
+    fn sdot_64_(
+        n: *const i64,
+        x: *const c_float,
+        incx: *const i64,
+        y: *const c_float,
+        incy: *const i64,
+    ) -> c_float;
+    fn sdsdot_64_(
+        n: *const i64,
+        sb: *const c_float,
+        x: *const c_float,
+        incx: *const i64,
+        y: *const c_float,
+        incy: *const i64,
+    ) -> c_float;
+    fn snrm2_64_(n: *const i64, x: *const c_float, incx: *const i64) -> c_float;
+    fn scnrm2_64_(n: *const i64, x: *const c_float_complex, incx: *const i64) -> c_float;
+    fn sasum_64_(n: *const i64, x: *const c_float, incx: *const i64) -> c_float;
+    fn isamax_64_(n: *const i64, x: *const c_float, incx: *const i64) -> i64;
+    fn drotg_64_(a: *mut c_double, b: *mut c_double, c: *mut c_double, s: *mut c_double);
+    fn drotmg_64_(
+        d1: *mut c_double,
+        d2: *mut c_double,
+        x1: *mut c_double,
+        y1: *const c_double,
+        param: *mut c_double,
+    );
+    fn drot_64_(
+        n: *const i64,
+        x: *mut c_double,
+        incx: *const i64,
+        y: *mut c_double,
+        incy: *const i64,
+        c: *const c_double,
+        s: *const c_double,
+    );
+    fn drotm_64_(
+        n: *const i64,
+        x: *mut c_double,
+        incx: *const i64,
+        y: *mut c_double,
+        incy: *const i64,
+        param: *const c_double,
+    );
+    fn dswap_64_(
+        n: *const i64,
+        x: *mut c_double,
+        incx: *const i64,
+        y: *mut c_double,
+        incy: *const i64,
+    );
+    fn dscal_64_(n: *const i64, a: *const c_double, x: *mut c_double, incx: *const i64);
+    fn dcopy_64_(
+        n: *const i64,
+        x: *const c_double,
+        incx: *const i64,
+        y: *mut c_double,
+        incy: *const i64,
+    );
+    fn daxpy_64_(
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const i64,
+        y: *mut c_double,
+        incy: *const i64,
+    );
+    fn ddot_64_(
+        n: *const i64,
+        x: *const c_double,
+        incx: *const i64,
+        y: *const c_double,
+        incy: *const i64,
+    ) -> c_double;
+    fn dsdot_64_(
+        n: *const i64,
+        x: *const c_float,
+        incx: *const i64,
+        y: *const c_float,
+        incy: *const i64,
+    ) -> c_double;
+    fn dnrm2_64_(n: *const i64, x: *const c_double, incx: *const i64) -> c_double;
+    fn dznrm2_64_(n: *const i64, x: *const c_double_complex, incx: *const i64) -> c_double;
+    fn dasum_64_(n: *const i64, x: *const c_double, incx: *const i64) -> c_double;
+    fn idamax_64_(n: *const i64, x: *const c_double, incx: *const i64) -> i64;
+    fn crotg_64_(
+        a: *mut c_float_complex,
+        b: *const c_float_complex,
+        c: *mut c_float,
+        s: *mut c_float_complex,
+    );
+    fn csrot_64_(
+        n: *const i64,
+        x: *mut c_float_complex,
+        incx: *const i64,
+        y: *mut c_float_complex,
+        incy: *const i64,
+        c: *const c_float,
+        s: *const c_float,
+    );
+    fn cswap_64_(
+        n: *const i64,
+        x: *mut c_float_complex,
+        incx: *const i64,
+        y: *mut c_float_complex,
+        incy: *const i64,
+    );
+    fn cscal_64_(
+        n: *const i64,
+        a: *const c_float_complex,
+        x: *mut c_float_complex,
+        incx: *const i64,
+    );
+    fn csscal_64_(n: *const i64, a: *const c_float, x: *mut c_float_complex, incx: *const i64);
+    fn ccopy_64_(
+        n: *const i64,
+        x: *const c_float_complex,
+        incx: *const i64,
+        y: *mut c_float_complex,
+        incy: *const i64,
+    );
+    fn caxpy_64_(
+        n: *const i64,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const i64,
+        y: *mut c_float_complex,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "complex-by-value"))]
+    fn cdotu_64_(
+        pres: *mut c_float_complex,
+        n: *const i64,
+        x: *const c_float_complex,
+        incx: *const i64,
+        y: *const c_float_complex,
+        incy: *const i64,
+    );
+    #[cfg(feature = "complex-by-value")]
+    fn cdotu_64_(
+        n: *const i64,
+        x: *const c_float_complex,
+        incx: *const i64,
+        y: *const c_float_complex,
+        incy: *const i64,
+    ) -> ::c32;
+    #[cfg(not(feature = "complex-by-value"))]
+    fn cdotc_64_(
+        pres: *mut c_float_complex,
+        n: *const i64,
+        x: *const c_float_complex,
+        incx: *const i64,
+        y: *const c_float_complex,
+        incy: *const i64,
+    );
+    #[cfg(feature = "complex-by-value")]
+    fn cdotc_64_(
+        n: *const i64,
+        x: *const c_float_complex,
+        incx: *const i64,
+        y: *const c_float_complex,
+        incy: *const i64,
+    ) -> ::c32;
+    fn scasum_64_(n: *const i64, x: *const c_float_complex, incx: *const i64) -> c_float;
+    fn icamax_64_(n: *const i64, x: *const c_float_complex, incx: *const i64) -> i64;
+    fn zrotg_64_(
+        a: *mut c_double_complex,
+        b: *const c_double_complex,
+        c: *mut c_double,
+        s: *mut c_double_complex,
+    );
+    fn zdrot_64_(
+        n: *const i64,
+        x: *mut c_double_complex,
+        incx: *const i64,
+        y: *mut c_double_complex,
+        incy: *const i64,
+        c: *const c_double,
+        s: *const c_double,
+    );
+    fn zswap_64_(
+        n: *const i64,
+        x: *mut c_double_complex,
+        incx: *const i64,
+        y: *mut c_double_complex,
+        incy: *const i64,
+    );
+    fn zscal_64_(
+        n: *const i64,
+        a: *const c_double_complex,
+        x: *mut c_double_complex,
+        incx: *const i64,
+    );
+    fn zdscal_64_(n: *const i64, a: *const c_double, x: *mut c_double_complex, incx: *const i64);
+    fn zcopy_64_(
+        n: *const i64,
+        x: *const c_double_complex,
+        incx: *const i64,
+        y: *mut c_double_complex,
+        incy: *const i64,
+    );
+    fn zaxpy_64_(
+        n: *const i64,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const i64,
+        y: *mut c_double_complex,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "complex-by-value"))]
+    fn zdotu_64_(
+        pres: *mut c_double_complex,
+        n: *const i64,
+        x: *const c_double_complex,
+        incx: *const i64,
+        y: *const c_double_complex,
+        incy: *const i64,
+    );
+    #[cfg(feature = "complex-by-value")]
+    fn zdotu_64_(
+        n: *const i64,
+        x: *const c_double_complex,
+        incx: *const i64,
+        y: *const c_double_complex,
+        incy: *const i64,
+    ) -> ::c64;
+    #[cfg(not(feature = "complex-by-value"))]
+    fn zdotc_64_(
+        pres: *mut c_double_complex,
+        n: *const i64,
+        x: *const c_double_complex,
+        incx: *const i64,
+        y: *const c_double_complex,
+        incy: *const i64,
+    );
+    #[cfg(feature = "complex-by-value")]
+    fn zdotc_64_(
+        n: *const i64,
+        x: *const c_double_complex,
+        incx: *const i64,
+        y: *const c_double_complex,
+        incy: *const i64,
+    ) -> ::c64;
+    fn dzasum_64_(n: *const i64, x: *const c_double_complex, incx: *const i64) -> c_double;
+    fn izamax_64_(n: *const i64, x: *const c_double_complex, incx: *const i64) -> i64;
+    fn sgemv_64_(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        x: *const c_float,
+        incx: *const i64,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const i64,
+    );
+    fn sgbmv_64_(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        kl: *const i64,
+        ku: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        x: *const c_float,
+        incx: *const i64,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const i64,
+    );
+    fn ssymv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        x: *const c_float,
+        incx: *const i64,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const i64,
+    );
+    fn ssbmv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        x: *const c_float,
+        incx: *const i64,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const i64,
+    );
+    fn sspmv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        ap: *const c_float,
+        x: *const c_float,
+        incx: *const i64,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const i64,
+    );
+    fn strmv_64_(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_float,
+        lda: *const i64,
+        b: *mut c_float,
+        incx: *const i64,
+    );
+    fn stbmv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_float,
+        lda: *const i64,
+        x: *mut c_float,
+        incx: *const i64,
+    );
+    fn stpmv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_float,
+        x: *mut c_float,
+        incx: *const i64,
+    );
+    fn strsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_float,
+        lda: *const i64,
+        x: *mut c_float,
+        incx: *const i64,
+    );
+    fn stbsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_float,
+        lda: *const i64,
+        x: *mut c_float,
+        incx: *const i64,
+    );
+    fn stpsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_float,
+        x: *mut c_float,
+        incx: *const i64,
+    );
+    fn sger_64_(
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const i64,
+        y: *const c_float,
+        incy: *const i64,
+        a: *mut c_float,
+        lda: *const i64,
+    );
+    fn ssyr_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const i64,
+        a: *mut c_float,
+        lda: *const i64,
+    );
+    fn sspr_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const i64,
+        ap: *mut c_float,
+    );
+    fn ssyr2_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const i64,
+        y: *const c_float,
+        incy: *const i64,
+        a: *mut c_float,
+        lda: *const i64,
+    );
+    fn sspr2_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const i64,
+        y: *const c_float,
+        incy: *const i64,
+        ap: *mut c_float,
+    );
+    fn dgemv_64_(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        x: *const c_double,
+        incx: *const i64,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const i64,
+    );
+    fn dgbmv_64_(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        kl: *const i64,
+        ku: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        x: *const c_double,
+        incx: *const i64,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const i64,
+    );
+    fn dsymv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        x: *const c_double,
+        incx: *const i64,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const i64,
+    );
+    fn dsbmv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        x: *const c_double,
+        incx: *const i64,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const i64,
+    );
+    fn dspmv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        ap: *const c_double,
+        x: *const c_double,
+        incx: *const i64,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const i64,
+    );
+    fn dtrmv_64_(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_double,
+        lda: *const i64,
+        b: *mut c_double,
+        incx: *const i64,
+    );
+    fn dtbmv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_double,
+        lda: *const i64,
+        x: *mut c_double,
+        incx: *const i64,
+    );
+    fn dtpmv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_double,
+        x: *mut c_double,
+        incx: *const i64,
+    );
+    fn dtrsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_double,
+        lda: *const i64,
+        x: *mut c_double,
+        incx: *const i64,
+    );
+    fn dtbsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_double,
+        lda: *const i64,
+        x: *mut c_double,
+        incx: *const i64,
+    );
+    fn dtpsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_double,
+        x: *mut c_double,
+        incx: *const i64,
+    );
+    fn dger_64_(
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const i64,
+        y: *const c_double,
+        incy: *const i64,
+        a: *mut c_double,
+        lda: *const i64,
+    );
+    fn dsyr_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const i64,
+        a: *mut c_double,
+        lda: *const i64,
+    );
+    fn dspr_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const i64,
+        ap: *mut c_double,
+    );
+    fn dsyr2_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const i64,
+        y: *const c_double,
+        incy: *const i64,
+        a: *mut c_double,
+        lda: *const i64,
+    );
+    fn dspr2_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const i64,
+        y: *const c_double,
+        incy: *const i64,
+        ap: *mut c_double,
+    );
+    fn cgemv_64_(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        x: *const c_float_complex,
+        incx: *const i64,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const i64,
+    );
+    fn cgbmv_64_(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        kl: *const i64,
+        ku: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        x: *const c_float_complex,
+        incx: *const i64,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const i64,
+    );
+    fn chemv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        x: *const c_float_complex,
+        incx: *const i64,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const i64,
+    );
+    fn chbmv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        x: *const c_float_complex,
+        incx: *const i64,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const i64,
+    );
+    fn chpmv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        ap: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const i64,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const i64,
+    );
+    fn ctrmv_64_(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *mut c_float_complex,
+        incx: *const i64,
+    );
+    fn ctbmv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_float_complex,
+        lda: *const i64,
+        x: *mut c_float_complex,
+        incx: *const i64,
+    );
+    fn ctpmv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_float_complex,
+        x: *mut c_float_complex,
+        incx: *const i64,
+    );
+    fn ctrsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_float_complex,
+        lda: *const i64,
+        x: *mut c_float_complex,
+        incx: *const i64,
+    );
+    fn ctbsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_float_complex,
+        lda: *const i64,
+        x: *mut c_float_complex,
+        incx: *const i64,
+    );
+    fn ctpsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_float_complex,
+        x: *mut c_float_complex,
+        incx: *const i64,
+    );
+    fn cgeru_64_(
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const i64,
+        y: *const c_float_complex,
+        incy: *const i64,
+        a: *mut c_float_complex,
+        lda: *const i64,
+    );
+    fn cgerc_64_(
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const i64,
+        y: *const c_float_complex,
+        incy: *const i64,
+        a: *mut c_float_complex,
+        lda: *const i64,
+    );
+    fn cher_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float_complex,
+        incx: *const i64,
+        a: *mut c_float_complex,
+        lda: *const i64,
+    );
+    fn chpr_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float_complex,
+        incx: *const i64,
+        ap: *mut c_float_complex,
+    );
+    fn chpr2_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const i64,
+        y: *const c_float_complex,
+        incy: *const i64,
+        ap: *mut c_float_complex,
+    );
+    fn cher2_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const i64,
+        y: *const c_float_complex,
+        incy: *const i64,
+        a: *mut c_float_complex,
+        lda: *const i64,
+    );
+    fn zgemv_64_(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        x: *const c_double_complex,
+        incx: *const i64,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const i64,
+    );
+    fn zgbmv_64_(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        kl: *const i64,
+        ku: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        x: *const c_double_complex,
+        incx: *const i64,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const i64,
+    );
+    fn zhemv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        x: *const c_double_complex,
+        incx: *const i64,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const i64,
+    );
+    fn zhbmv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        x: *const c_double_complex,
+        incx: *const i64,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const i64,
+    );
+    fn zhpmv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        ap: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const i64,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const i64,
+    );
+    fn ztrmv_64_(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *mut c_double_complex,
+        incx: *const i64,
+    );
+    fn ztbmv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_double_complex,
+        lda: *const i64,
+        x: *mut c_double_complex,
+        incx: *const i64,
+    );
+    fn ztpmv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_double_complex,
+        x: *mut c_double_complex,
+        incx: *const i64,
+    );
+    fn ztrsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_double_complex,
+        lda: *const i64,
+        x: *mut c_double_complex,
+        incx: *const i64,
+    );
+    fn ztbsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_double_complex,
+        lda: *const i64,
+        x: *mut c_double_complex,
+        incx: *const i64,
+    );
+    fn ztpsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_double_complex,
+        x: *mut c_double_complex,
+        incx: *const i64,
+    );
+    fn zgeru_64_(
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const i64,
+        y: *const c_double_complex,
+        incy: *const i64,
+        a: *mut c_double_complex,
+        lda: *const i64,
+    );
+    fn zgerc_64_(
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const i64,
+        y: *const c_double_complex,
+        incy: *const i64,
+        a: *mut c_double_complex,
+        lda: *const i64,
+    );
+    fn zher_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double_complex,
+        incx: *const i64,
+        a: *mut c_double_complex,
+        lda: *const i64,
+    );
+    fn zhpr_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double_complex,
+        incx: *const i64,
+        ap: *mut c_double_complex,
+    );
+    fn zher2_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const i64,
+        y: *const c_double_complex,
+        incy: *const i64,
+        a: *mut c_double_complex,
+        lda: *const i64,
+    );
+    fn zhpr2_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const i64,
+        y: *const c_double_complex,
+        incy: *const i64,
+        ap: *mut c_double_complex,
+    );
+    fn sgemm_64_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        b: *const c_float,
+        ldb: *const i64,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const i64,
+    );
+    fn ssymm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        b: *const c_float,
+        ldb: *const i64,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const i64,
+    );
+    fn ssyrk_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const i64,
+    );
+    fn ssyr2k_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        b: *const c_float,
+        ldb: *const i64,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const i64,
+    );
+    fn strmm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        b: *mut c_float,
+        ldb: *const i64,
+    );
+    fn strsm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        b: *mut c_float,
+        ldb: *const i64,
+    );
+    fn dgemm_64_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        b: *const c_double,
+        ldb: *const i64,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const i64,
+    );
+    fn dsymm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        b: *const c_double,
+        ldb: *const i64,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const i64,
+    );
+    fn dsyrk_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const i64,
+    );
+    fn dsyr2k_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        b: *const c_double,
+        ldb: *const i64,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const i64,
+    );
+    fn dtrmm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        b: *mut c_double,
+        ldb: *const i64,
+    );
+    fn dtrsm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        b: *mut c_double,
+        ldb: *const i64,
+    );
+    fn cgemm_64_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *const c_float_complex,
+        ldb: *const i64,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const i64,
+    );
+    fn csymm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *const c_float_complex,
+        ldb: *const i64,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const i64,
+    );
+    fn chemm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *const c_float_complex,
+        ldb: *const i64,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const i64,
+    );
+    fn csyrk_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const i64,
+    );
+    fn cherk_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float,
+        a: *const c_float_complex,
+        lda: *const i64,
+        beta: *const c_float,
+        c: *mut c_float_complex,
+        ldc: *const i64,
+    );
+    fn csyr2k_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *const c_float_complex,
+        ldb: *const i64,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const i64,
+    );
+    fn cher2k_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *const c_float_complex,
+        ldb: *const i64,
+        beta: *const c_float,
+        c: *mut c_float_complex,
+        ldc: *const i64,
+    );
+    fn ctrmm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *mut c_float_complex,
+        ldb: *const i64,
+    );
+    fn ctrsm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *mut c_float_complex,
+        ldb: *const i64,
+    );
+    fn zgemm_64_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *const c_double_complex,
+        ldb: *const i64,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const i64,
+    );
+    fn zsymm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *const c_double_complex,
+        ldb: *const i64,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const i64,
+    );
+    fn zhemm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *const c_double_complex,
+        ldb: *const i64,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const i64,
+    );
+    fn zsyrk_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const i64,
+    );
+    fn zherk_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double,
+        a: *const c_double_complex,
+        lda: *const i64,
+        beta: *const c_double,
+        c: *mut c_double_complex,
+        ldc: *const i64,
+    );
+    fn zsyr2k_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *const c_double_complex,
+        ldb: *const i64,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const i64,
+    );
+    fn zher2k_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *const c_double_complex,
+        ldb: *const i64,
+        beta: *const c_double,
+        c: *mut c_double_complex,
+        ldc: *const i64,
+    );
+    fn ztrmm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *mut c_double_complex,
+        ldb: *const i64,
+    );
+    fn ztrsm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *mut c_double_complex,
+        ldb: *const i64,
+    );
+}
@@ -0,0 +1,362 @@
+//! Library loaded at run time.
+//!
+//! With the `dynamic` feature enabled, the wrappers at the root of the crate
+//! call the routines of a shared library opened at run time instead of one
+//! linked at build time, which allows for choosing between, for instance,
+//! OpenBLAS, BLIS, and the reference implementation upon deployment. The
+//! library is given either explicitly via [`load`] or by the environment
+//! variable named by [`VARIABLE`], which is consulted on the first call if
+//! no library has been loaded by then. The feature is supported on Unix and
+//! takes precedence over the `reference` feature.
+//!
+//! With the `ilp64` feature, the wrappers of [`ilp64`] call the routines of
+//! the library suffixed with `64_` likewise. They are optional: a library
+//! without them can be loaded, and calling one that it lacks causes a panic.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use blas::dynamic;
+//!
+//! dynamic::load("/usr/lib/x86_64-linux-gnu/libopenblas.so.0").unwrap();
+//!
+//! let x = vec![1.0, 2.0, 3.0];
+//! let norm = unsafe { blas::dnrm2(3, &x, 1) };
+//! ```
+//!
+//! [`ilp64`]: ../ilp64/index.html
+//! [`load`]: fn.load.html
+//! [`VARIABLE`]: constant.VARIABLE.html
+
+use std::ffi::{CStr, CString, OsStr, OsString};
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicPtr, Ordering};
+use std::{error, fmt, mem, ptr, result};
+
+use libc::{c_char, c_void};
+
+macro_rules! functions {
+    ($field:ident, $($(#[$attribute:meta])* fn $name:ident($($argument:ident: $ty:ty),* $(,)*) $(-> $output:ty)*;)*) => {
+        /// The routines of a library.
+        pub struct Functions {
+            $($(#[$attribute])* $name: Option<unsafe extern "C" fn($($ty),*) $(-> $output)*>,)*
+        }
+
+        impl Functions {
+            /// Resolve the routines and record the names of the missing ones.
+            pub unsafe fn resolve(handle: *mut c_void, missing: &mut Vec<&'static str>) -> Self {
+                Functions {
+                    $($(#[$attribute])* $name: {
+                        let function = super::function(handle, concat!(stringify!($name), "\0"));
+                        if function.is_none() {
+                            missing.push(stringify!($name));
+                        }
+                        function
+                    },)*
+                }
+            }
+        }
+
+        $(
+            $(#[$attribute])*
+            #[inline]
+            pub(crate) unsafe fn $name($($argument: $ty),*) $(-> $output)* {
+                match super::library().$field.$name {
+                    Some(function) => function($($argument),*),
+                    None => super::missing(stringify!($name)),
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! extensions {
+    ($($(#[alias($($alias:ident),*)])* fn $name:ident($($argument:ident: $ty:ty),* $(,)*) $(-> $output:ty)*;)*) => {
+        /// The optional routines of a library.
+        pub struct Extensions {
+            $($name: Option<unsafe extern "C" fn($($ty),*) $(-> $output)*>,)*
+        }
+
+        impl Extensions {
+            /// Resolve the routines that the library provides under their
+            /// names or aliases.
+            pub unsafe fn resolve(handle: *mut ::libc::c_void) -> Self {
+                Extensions {
+                    $($name: super::function(handle, concat!(stringify!($name), "\0"))
+                        $($(.or_else(|| super::function(handle, concat!(stringify!($alias), "\0"))))*)*,)*
+                }
+            }
+        }
+
+        $(
+            /// Return the routine if the library in use provides it.
+            #[inline]
+            pub(crate) fn $name() -> Option<unsafe extern "C" fn($($ty),*) $(-> $output)*> {
+                super::library().extensions.$name
+            }
+        )*
+    };
+}
+
+mod extensions;
+mod functions;
+#[cfg(feature = "ilp64")]
+pub(crate) mod ilp64;
+
+pub(crate) use self::extensions::*;
+pub(crate) use self::functions::*;
+
+use self::extensions::Extensions;
+use self::functions::Functions;
+
+/// The name of the environment variable with the path to the library.
+pub const VARIABLE: &str = "BLAS_LIBRARY";
+
+static LIBRARY: AtomicPtr<Library> = AtomicPtr::new(ptr::null_mut());
+
+/// An error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The environment variable is not set.
+    Unset,
+    /// The library cannot be opened.
+    Open {
+        /// The path to the library.
+        path: PathBuf,
+        /// The message given by the dynamic linker.
+        message: String,
+    },
+    /// The library does not provide some of the routines.
+    Missing {
+        /// The path to the library.
+        path: PathBuf,
+        /// The names of the missing symbols.
+        symbols: Vec<&'static str>,
+    },
+}
+
+/// A result.
+pub type Result<T> = result::Result<T, Error>;
+
+/// A shared library.
+pub struct Library {
+    path: PathBuf,
+    handle: *mut c_void,
+    functions: Functions,
+    extensions: Extensions,
+    #[cfg(feature = "ilp64")]
+    ilp64: ilp64::Functions,
+    missing: Vec<&'static str>,
+}
+
+unsafe impl Send for Library {}
+unsafe impl Sync for Library {}
+
+impl Library {
+    /// Open a library and resolve its routines.
+    ///
+    /// Unlike [`load`], a library missing some of the routines is accepted.
+    ///
+    /// [`load`]: fn.load.html
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Library> {
+        let path = path.as_ref().to_path_buf();
+        let name = match CString::new(path.as_os_str().as_bytes()) {
+            Ok(name) => name,
+            Err(_) => {
+                return Err(Error::Open {
+                    path,
+                    message: "the path contains a null byte".into(),
+                })
+            }
+        };
+        unsafe {
+            let handle = libc::dlopen(name.as_ptr(), libc::RTLD_NOW | libc::RTLD_LOCAL);
+            if handle.is_null() {
+                return Err(Error::Open {
+                    path,
+                    message: message(),
+                });
+            }
+            let mut missing = vec![];
+            let functions = Functions::resolve(handle, &mut missing);
+            let extensions = Extensions::resolve(handle);
+            // The ILP64 interface is optional and not reported as missing.
+            #[cfg(feature = "ilp64")]
+            let ilp64 = ilp64::Functions::resolve(handle, &mut vec![]);
+            Ok(Library {
+                path,
+                handle,
+                functions,
+                extensions,
+                #[cfg(feature = "ilp64")]
+                ilp64,
+                missing,
+            })
+        }
+    }
+
+    /// Return the path to the library.
+    #[inline]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Return the names of the symbols that the library does not provide.
+    ///
+    /// Calling a missing routine causes a panic. The routines of the ILP64
+    /// interface are not listed.
+    #[inline]
+    pub fn missing(&self) -> &[&'static str] {
+        &self.missing
+    }
+
+    /// Return a function given its name as a null-terminated string if the
+    /// library provides it.
+    #[inline]
+    pub(crate) unsafe fn symbol<F: Copy>(&self, name: &str) -> Option<F> {
+        function(self.handle, name)
+    }
+}
+
+impl Drop for Library {
+    fn drop(&mut self) {
+        unsafe {
+            libc::dlclose(self.handle);
+        }
+    }
+}
+
+impl fmt::Debug for Library {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("Library")
+            .field("path", &self.path)
+            .field("missing", &self.missing)
+            .finish()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Unset => write!(formatter, "{} is not set", VARIABLE),
+            Error::Open {
+                ref path,
+                ref message,
+            } => write!(formatter, "cannot open {}: {}", path.display(), message),
+            Error::Missing {
+                ref path,
+                ref symbols,
+            } => write!(
+                formatter,
+                "{} does not provide {} routines: {}",
+                path.display(),
+                symbols.len(),
+                symbols.join(", "),
+            ),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+/// Load a library providing all routines and use it for subsequent calls.
+pub fn load<P: AsRef<Path>>(path: P) -> Result<()> {
+    let library = Library::open(path)?;
+    if !library.missing.is_empty() {
+        return Err(Error::Missing {
+            path: library.path.clone(),
+            symbols: library.missing.clone(),
+        });
+    }
+    install(library);
+    Ok(())
+}
+
+/// Load the library given by the environment variable named by
+/// [`VARIABLE`].
+///
+/// [`VARIABLE`]: constant.VARIABLE.html
+pub fn load_from_env() -> Result<()> {
+    load_from(::std::env::var_os(VARIABLE))
+}
+
+/// Load the library given by the value of the environment variable.
+fn load_from(value: Option<OsString>) -> Result<()> {
+    match value {
+        Some(ref path) if !path.is_empty() => load(path),
+        _ => Err(Error::Unset),
+    }
+}
+
+/// Use a library for subsequent calls.
+///
+/// The library previously in use, if any, stays open, as other threads might
+/// still be calling it.
+pub fn install(library: Library) {
+    LIBRARY.swap(Box::into_raw(Box::new(library)), Ordering::AcqRel);
+}
+
+/// Return the library in use if any.
+pub fn current() -> Option<&'static Library> {
+    unsafe { LIBRARY.load(Ordering::Acquire).as_ref() }
+}
+
+/// Return the library in use, loading it from the environment if needed.
+#[inline]
+fn library() -> &'static Library {
+    match current() {
+        Some(library) => library,
+        None => initialize(),
+    }
+}
+
+#[cold]
+fn initialize() -> &'static Library {
+    if let Err(error) = load_from_env() {
+        panic!("{}", error);
+    }
+    current().unwrap()
+}
+
+#[cold]
+fn missing(name: &str) -> ! {
+    panic!("{} does not provide {}", library().path.display(), name);
+}
+
+/// Return a function given its name as a null-terminated string if the
+/// library provides it.
+unsafe fn function<F: Copy>(handle: *mut c_void, name: &str) -> Option<F> {
+    let pointer = libc::dlsym(handle, name.as_ptr() as *const c_char);
+    if pointer.is_null() {
+        None
+    } else {
+        Some(mem::transmute_copy(&pointer))
+    }
+}
+
+/// Return the message of the last error of the dynamic linker.
+unsafe fn message() -> String {
+    let message: *const c_char = libc::dlerror();
+    if message.is_null() {
+        return "unknown error".into();
+    }
+    let message = CStr::from_ptr(message).to_bytes();
+    OsStr::from_bytes(message).to_string_lossy().into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_from() {
+        assert_eq!(super::load_from(None), Err(Error::Unset));
+        assert_eq!(super::load_from(Some("".into())), Err(Error::Unset));
+        match super::load_from(Some("nonexistent".into())) {
+            Err(Error::Open { path, .. }) => assert_eq!(path, PathBuf::from("nonexistent")),
+            result => panic!("{:?}", result),
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use std::{error, fmt, result};
+
+/// An error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// An argument has an illegal value.
+    Argument {
+        /// The name of the routine.
+        routine: &'static str,
+        /// The position of the argument counting from one.
+        position: usize,
+        /// The name of the argument.
+        name: &'static str,
+        /// The condition that the argument violates.
+        reason: &'static str,
+    },
+    /// A buffer is shorter than required by the other arguments.
+    Length {
+        /// The name of the routine.
+        routine: &'static str,
+        /// The position of the argument counting from one.
+        position: usize,
+        /// The name of the argument.
+        name: &'static str,
+        /// The number of elements required.
+        required: usize,
+        /// The number of elements provided.
+        provided: usize,
+    },
+    /// An argument has been rejected by BLAS via `xerbla`.
+    Xerbla {
+        /// The name of the routine.
+        routine: String,
+        /// The position of the argument counting from one.
+        position: usize,
+    },
+}
+
+/// A result.
+pub type Result<T> = result::Result<T, Error>;
+
+impl Error {
+    /// Return the name of the routine that rejected the call.
+    pub fn routine(&self) -> &str {
+        match *self {
+            Error::Argument { routine, .. } | Error::Length { routine, .. } => routine,
+            Error::Xerbla { ref routine, .. } => routine,
+        }
+    }
+
+    /// Return the position of the offending argument counting from one.
+    ///
+    /// The position follows the Fortran argument list of the routine, which
+    /// is also what `xerbla` reports.
+    pub fn position(&self) -> usize {
+        match *self {
+            Error::Argument { position, .. }
+            | Error::Length { position, .. }
+            | Error::Xerbla { position, .. } => position,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Argument {
+                routine,
+                position,
+                name,
+                reason,
+            } => write!(
+                formatter,
+                "{}: parameter {} ({}) {}",
+                routine, position, name, reason,
+            ),
+            Error::Length {
+                routine,
+                position,
+                name,
+                required,
+                provided,
+            } => write!(
+                formatter,
+                "{}: parameter {} ({}) has {} elements but {} are required",
+                routine, position, name, provided, required,
+            ),
+            Error::Xerbla {
+                ref routine,
+                position,
+            } => write!(
+                formatter,
+                "{}: parameter {} has an illegal value",
+                routine, position,
+            ),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+/// Report an illegal argument to a routine implemented in Rust via
+/// `xerbla` if the `xerbla` feature is enabled or a panic otherwise.
+#[cold]
+pub(crate) fn xerbla(routine: &str, info: i32) {
+    let routine = routine.trim_end_matches('_');
+    #[cfg(feature = "xerbla")]
+    unsafe {
+        ::xerbla::xerbla_(routine.as_ptr() as *const _, &info, routine.len());
+    }
+    #[cfg(not(feature = "xerbla"))]
+    panic!(
+        "** On entry to {} parameter number {} had an illegal value",
+        routine.to_uppercase(),
+        info,
+    );
+}
+
+#[cfg(all(
+    test,
+    feature = "reference",
+    not(feature = "dynamic"),
+    not(feature = "xerbla"),
+))]
+mod tests {
+    #[test]
+    #[should_panic(expected = "On entry to DGEMV parameter number 1 had an illegal value")]
+    fn xerbla() {
+        let a = [1.0; 4];
+        let mut y = [0.0; 2];
+        unsafe { ::dgemv(b'X', 2, 2, 1.0, &a, 2, &a, 1, 0.0, &mut y, 1) };
+    }
+}
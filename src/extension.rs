@@ -0,0 +1,696 @@
+//! Common extensions to BLAS.
+
+#[cfg(any(feature = "dynamic", all(unix, not(feature = "reference"))))]
+use libc::c_char;
+use std::convert::TryFrom;
+use std::ops::{Add, Mul};
+
+use error::xerbla;
+use {c32, c64, Blas, Transpose, Uplo};
+
+/// The number of rows and columns of the blocks of a transpose.
+const BLOCK: usize = 32;
+
+/// The order of the diagonal blocks of a triangular product.
+const PANEL: usize = 64;
+
+macro_rules! axpby {
+    ($name:ident, $extension:ident, $ty:ty) => {
+        /// Compute `y := alpha * x + beta * y`.
+        #[inline]
+        pub unsafe fn $name(
+            n: i32,
+            alpha: $ty,
+            x: &[$ty],
+            incx: i32,
+            beta: $ty,
+            y: &mut [$ty],
+            incy: i32,
+        ) {
+            #[cfg(any(feature = "dynamic", all(unix, not(feature = "reference"))))]
+            {
+                if let Some(function) = ::native::$extension() {
+                    return function(
+                        &n,
+                        &alpha as *const _ as *const _,
+                        x.as_ptr() as *const _,
+                        &incx,
+                        &beta as *const _ as *const _,
+                        y.as_mut_ptr() as *mut _,
+                        &incy,
+                    );
+                }
+            }
+            axpby(n, alpha, x, incx, beta, y, incy);
+        }
+    };
+}
+
+axpby!(saxpby, saxpby_, f32);
+axpby!(daxpby, daxpby_, f64);
+axpby!(caxpby, caxpby_, c32);
+axpby!(zaxpby, zaxpby_, c64);
+
+macro_rules! omatcopy {
+    ($name:ident, $extension:ident, $ty:ty) => {
+        /// Compute `b := alpha * op(a)`, where `a` has `rows` rows and `cols`
+        /// columns.
+        ///
+        /// `order` is `b'C'` for column-major and `b'R'` for row-major
+        /// matrices, and `trans` is `b'N'`, `b'T'`, `b'R'`, or `b'C'` for no
+        /// operation, the transpose, the conjugate, or the conjugate
+        /// transpose, respectively.
+        #[inline]
+        pub unsafe fn $name(
+            order: impl Into<u8>,
+            trans: impl Into<u8>,
+            rows: i32,
+            cols: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            b: &mut [$ty],
+            ldb: i32,
+        ) {
+            let order: u8 = order.into();
+            let trans: u8 = trans.into();
+            #[cfg(any(feature = "dynamic", all(unix, not(feature = "reference"))))]
+            {
+                if let Some(function) = ::native::$extension() {
+                    return function(
+                        &(order as c_char),
+                        &(trans as c_char),
+                        &rows,
+                        &cols,
+                        &alpha as *const _ as *const _,
+                        a.as_ptr() as *const _,
+                        &lda,
+                        b.as_mut_ptr() as *mut _,
+                        &ldb,
+                    );
+                }
+            }
+            omatcopy(
+                stringify!($name),
+                order,
+                trans,
+                rows,
+                cols,
+                alpha,
+                a,
+                lda,
+                b,
+                ldb,
+            );
+        }
+    };
+}
+
+omatcopy!(somatcopy, somatcopy_, f32);
+omatcopy!(domatcopy, domatcopy_, f64);
+omatcopy!(comatcopy, comatcopy_, c32);
+omatcopy!(zomatcopy, zomatcopy_, c64);
+
+macro_rules! imatcopy {
+    ($name:ident, $extension:ident, $ty:ty) => {
+        /// Compute `ab := alpha * op(ab)` in place, where `ab` has `rows` rows
+        /// and `cols` columns on entry and is stored with the leading
+        /// dimension `lda` on entry and `ldb` on exit.
+        ///
+        /// `order` and `trans` are as for the out-of-place variant.
+        #[inline]
+        pub unsafe fn $name(
+            order: impl Into<u8>,
+            trans: impl Into<u8>,
+            rows: i32,
+            cols: i32,
+            alpha: $ty,
+            ab: &mut [$ty],
+            lda: i32,
+            ldb: i32,
+        ) {
+            let order: u8 = order.into();
+            let trans: u8 = trans.into();
+            #[cfg(any(feature = "dynamic", all(unix, not(feature = "reference"))))]
+            {
+                if let Some(function) = ::native::$extension() {
+                    return function(
+                        &(order as c_char),
+                        &(trans as c_char),
+                        &rows,
+                        &cols,
+                        &alpha as *const _ as *const _,
+                        ab.as_mut_ptr() as *mut _,
+                        &lda,
+                        &ldb,
+                    );
+                }
+            }
+            imatcopy(
+                stringify!($name),
+                order,
+                trans,
+                rows,
+                cols,
+                alpha,
+                ab,
+                lda,
+                ldb,
+            );
+        }
+    };
+}
+
+imatcopy!(simatcopy, simatcopy_, f32);
+imatcopy!(dimatcopy, dimatcopy_, f64);
+imatcopy!(cimatcopy, cimatcopy_, c32);
+imatcopy!(zimatcopy, zimatcopy_, c64);
+
+macro_rules! gemmt {
+    ($name:ident, $extension:ident, $ty:ty) => {
+        /// Compute the `uplo` triangle of `c := alpha * op(a) * op(b) + beta *
+        /// c`, where `c` is of order `n`, leaving the other triangle untouched.
+        #[inline]
+        pub unsafe fn $name(
+            uplo: impl Into<u8>,
+            transa: impl Into<u8>,
+            transb: impl Into<u8>,
+            n: i32,
+            k: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            b: &[$ty],
+            ldb: i32,
+            beta: $ty,
+            c: &mut [$ty],
+            ldc: i32,
+        ) {
+            let uplo: u8 = uplo.into();
+            let transa: u8 = transa.into();
+            let transb: u8 = transb.into();
+            #[cfg(any(feature = "dynamic", all(unix, not(feature = "reference"))))]
+            {
+                if let Some(function) = ::native::$extension() {
+                    return function(
+                        &(uplo as c_char),
+                        &(transa as c_char),
+                        &(transb as c_char),
+                        &n,
+                        &k,
+                        &alpha as *const _ as *const _,
+                        a.as_ptr() as *const _,
+                        &lda,
+                        b.as_ptr() as *const _,
+                        &ldb,
+                        &beta as *const _ as *const _,
+                        c.as_mut_ptr() as *mut _,
+                        &ldc,
+                    );
+                }
+            }
+            gemmt(
+                stringify!($name),
+                uplo,
+                transa,
+                transb,
+                n,
+                k,
+                alpha,
+                a,
+                lda,
+                b,
+                ldb,
+                beta,
+                c,
+                ldc,
+            );
+        }
+    };
+}
+
+gemmt!(sgemmt, sgemmt_, f32);
+gemmt!(dgemmt, dgemmt_, f64);
+gemmt!(cgemmt, cgemmt_, c32);
+gemmt!(zgemmt, zgemmt_, c64);
+
+/// Compute `y := alpha * x + beta * y` in terms of `scal` and `axpy`, where
+/// `y` is overwritten with zeros first if `beta` is zero so that neither NaN
+/// nor infinity in `y` survive.
+unsafe fn axpby<T: Blas + PartialEq>(
+    n: i32,
+    alpha: T,
+    x: &[T],
+    incx: i32,
+    beta: T,
+    y: &mut [T],
+    incy: i32,
+) {
+    if n <= 0 {
+        return;
+    }
+    // The elements of `y` are the same whatever the sign of the increment,
+    // which `scal` does not accept if negative.
+    let step = incy.unsigned_abs() as usize;
+    if beta == T::ZERO {
+        let count = if step == 0 { 1 } else { n as usize };
+        for value in y.iter_mut().step_by(step.max(1)).take(count) {
+            *value = T::ZERO;
+        }
+    } else if step > 0 {
+        T::scal(n, beta, y, step as i32);
+    }
+    T::axpy(n, alpha, x, incx, y, incy);
+}
+
+/// The validated arguments of a copy.
+struct Operation {
+    /// The number of rows of the column-major storage of the source.
+    m: usize,
+    /// The number of columns of the column-major storage of the source.
+    n: usize,
+    transpose: bool,
+    conjugate: bool,
+}
+
+/// Validate the arguments shared by the out-of-place and in-place copies,
+/// where `ldb` is at position `position`.
+fn validate(
+    routine: &str,
+    order: u8,
+    trans: u8,
+    rows: i32,
+    cols: i32,
+    lda: i32,
+    ldb: i32,
+    position: i32,
+) -> Option<Operation> {
+    let (m, n) = match order.to_ascii_uppercase() {
+        b'C' => (rows, cols),
+        b'R' => (cols, rows),
+        _ => return illegal(routine, 1),
+    };
+    let (transpose, conjugate) = match trans.to_ascii_uppercase() {
+        b'N' => (false, false),
+        b'T' => (true, false),
+        b'R' => (false, true),
+        b'C' => (true, true),
+        _ => return illegal(routine, 2),
+    };
+    if rows < 0 {
+        return illegal(routine, 3);
+    }
+    if cols < 0 {
+        return illegal(routine, 4);
+    }
+    if lda < m.max(1) {
+        return illegal(routine, 7);
+    }
+    if ldb < (if transpose { n } else { m }).max(1) {
+        return illegal(routine, position);
+    }
+    Some(Operation {
+        m: m as usize,
+        n: n as usize,
+        transpose,
+        conjugate,
+    })
+}
+
+#[cold]
+fn illegal(routine: &str, position: i32) -> Option<Operation> {
+    xerbla(routine, position);
+    None
+}
+
+fn omatcopy<T: Blas + Mul<Output = T>>(
+    routine: &str,
+    order: u8,
+    trans: u8,
+    rows: i32,
+    cols: i32,
+    alpha: T,
+    a: &[T],
+    lda: i32,
+    b: &mut [T],
+    ldb: i32,
+) {
+    if let Some(copy) = validate(routine, order, trans, rows, cols, lda, ldb, 9) {
+        transpose(copy, alpha, a, lda as usize, b, ldb as usize);
+    }
+}
+
+fn imatcopy<T: Blas + Mul<Output = T>>(
+    routine: &str,
+    order: u8,
+    trans: u8,
+    rows: i32,
+    cols: i32,
+    alpha: T,
+    ab: &mut [T],
+    lda: i32,
+    ldb: i32,
+) {
+    let copy = match validate(routine, order, trans, rows, cols, lda, ldb, 8) {
+        Some(copy) => copy,
+        _ => return,
+    };
+    let (m, n, lda, ldb) = (copy.m, copy.n, lda as usize, ldb as usize);
+    if !copy.transpose && lda == ldb {
+        for j in 0..n {
+            for value in &mut ab[j * lda..j * lda + m] {
+                *value = scale(alpha, *value, copy.conjugate);
+            }
+        }
+        return;
+    }
+    let mut a = Vec::with_capacity(m * n);
+    for j in 0..n {
+        a.extend_from_slice(&ab[j * lda..j * lda + m]);
+    }
+    transpose(copy, alpha, &a, m.max(1), ab, ldb);
+}
+
+/// Copy the column-major `a` scaled by `alpha` to `b` block by block, so
+/// that both matrices are traversed in a cache-friendly order.
+fn transpose<T: Blas + Mul<Output = T>>(
+    copy: Operation,
+    alpha: T,
+    a: &[T],
+    lda: usize,
+    b: &mut [T],
+    ldb: usize,
+) {
+    let (m, n) = (copy.m, copy.n);
+    if !copy.transpose {
+        for j in 0..n {
+            let (a, b) = (&a[j * lda..j * lda + m], &mut b[j * ldb..j * ldb + m]);
+            for (b, &a) in b.iter_mut().zip(a) {
+                *b = scale(alpha, a, copy.conjugate);
+            }
+        }
+        return;
+    }
+    for j in (0..n).step_by(BLOCK) {
+        for i in (0..m).step_by(BLOCK) {
+            for jj in j..n.min(j + BLOCK) {
+                for ii in i..m.min(i + BLOCK) {
+                    b[jj + ii * ldb] = scale(alpha, a[ii + jj * lda], copy.conjugate);
+                }
+            }
+        }
+    }
+}
+
+#[inline(always)]
+fn scale<T: Blas + Mul<Output = T>>(alpha: T, value: T, conjugate: bool) -> T {
+    alpha * if conjugate { value.conj() } else { value }
+}
+
+/// Compute a triangle of a product panel by panel, where each panel consists
+/// of a diagonal block, which is computed in full into a temporary buffer and
+/// then merged, and the part of the block column strictly inside the triangle,
+/// which is updated in place.
+unsafe fn gemmt<T: Blas + Add<Output = T> + Mul<Output = T> + PartialEq>(
+    routine: &str,
+    uplo: u8,
+    transa: u8,
+    transb: u8,
+    n: i32,
+    k: i32,
+    alpha: T,
+    a: &[T],
+    lda: i32,
+    b: &[T],
+    ldb: i32,
+    beta: T,
+    c: &mut [T],
+    ldc: i32,
+) {
+    let uplo = match Uplo::try_from(uplo) {
+        Ok(uplo) => uplo,
+        _ => return xerbla(routine, 1),
+    };
+    let transa = match Transpose::try_from(transa) {
+        Ok(transa) => transa,
+        _ => return xerbla(routine, 2),
+    };
+    let transb = match Transpose::try_from(transb) {
+        Ok(transb) => transb,
+        _ => return xerbla(routine, 3),
+    };
+    if n < 0 {
+        return xerbla(routine, 4);
+    }
+    if k < 0 {
+        return xerbla(routine, 5);
+    }
+    if lda < (if transa == Transpose::No { n } else { k }).max(1) {
+        return xerbla(routine, 8);
+    }
+    if ldb < (if transb == Transpose::No { k } else { n }).max(1) {
+        return xerbla(routine, 10);
+    }
+    if ldc < n.max(1) {
+        return xerbla(routine, 13);
+    }
+    let (order, lda, ldb, ldc) = (n as usize, lda as usize, ldb as usize, ldc as usize);
+    if k == 0 || alpha == T::ZERO {
+        for j in 0..order {
+            let (top, bottom) = match uplo {
+                Uplo::Upper => (0, j + 1),
+                Uplo::Lower => (j, order),
+            };
+            for c in &mut c[top + j * ldc..bottom + j * ldc] {
+                *c = if beta == T::ZERO { T::ZERO } else { beta * *c };
+            }
+        }
+        return;
+    }
+    // The rows of op(a) and the columns of op(b) starting at `i`, which exist
+    // since `k` is positive.
+    let rows = |i: usize| &a[if transa == Transpose::No { i } else { i * lda }..];
+    let cols = |j: usize| &b[if transb == Transpose::No { j * ldb } else { j }..];
+    let mut block = vec![T::ZERO; PANEL.min(order).pow(2)];
+    for start in (0..order).step_by(PANEL) {
+        let end = order.min(start + PANEL);
+        let size = end - start;
+        let (first, count) = match uplo {
+            Uplo::Upper => (0, start),
+            Uplo::Lower => (end, order - end),
+        };
+        if count > 0 {
+            T::gemm(
+                transa,
+                transb,
+                count as i32,
+                size as i32,
+                k,
+                alpha,
+                rows(first),
+                lda as i32,
+                cols(start),
+                ldb as i32,
+                beta,
+                &mut c[first + start * ldc..],
+                ldc as i32,
+            );
+        }
+        T::gemm(
+            transa,
+            transb,
+            size as i32,
+            size as i32,
+            k,
+            alpha,
+            rows(start),
+            lda as i32,
+            cols(start),
+            ldb as i32,
+            T::ZERO,
+            &mut block,
+            size as i32,
+        );
+        for j in 0..size {
+            let (top, bottom) = match uplo {
+                Uplo::Upper => (0, j + 1),
+                Uplo::Lower => (j, size),
+            };
+            for i in top..bottom {
+                let c = &mut c[start + i + (start + j) * ldc];
+                let value = block[i + j * size];
+                *c = if beta == T::ZERO {
+                    value
+                } else {
+                    value + beta * *c
+                };
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "reference", not(feature = "dynamic")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn axpby() {
+        let x = vec![1.0, 2.0, 3.0];
+        for &incy in &[1, 2, -2] {
+            let mut y = vec![f64::NAN, f64::INFINITY, -f64::INFINITY, 5.0, f64::NAN];
+            unsafe { daxpby(3, 2.0, &x, 1, 0.0, &mut y, incy) };
+            match incy {
+                1 => assert_eq!(y[..3], [2.0, 4.0, 6.0]),
+                2 => assert_eq!([y[0], y[2], y[4]], [2.0, 4.0, 6.0]),
+                _ => assert_eq!([y[4], y[2], y[0]], [2.0, 4.0, 6.0]),
+            }
+        }
+        let mut y = vec![1.0, 1.0, 1.0];
+        unsafe { daxpby(3, 2.0, &x, 1, 3.0, &mut y, -1) };
+        assert_eq!(y, [9.0, 7.0, 5.0]);
+
+        let x = vec![c64::new(1.0, 1.0), c64::new(0.0, 2.0)];
+        let mut y = vec![c64::new(f64::NAN, 0.0), c64::new(0.0, f64::INFINITY)];
+        unsafe { zaxpby(2, c64::new(0.0, 1.0), &x, 1, c64::new(0.0, 0.0), &mut y, 1) };
+        assert_eq!(y, [c64::new(-1.0, 1.0), c64::new(-2.0, 0.0)]);
+    }
+
+    #[test]
+    fn imatcopy() {
+        // A 2-by-3 column-major matrix with a padded leading dimension.
+        let mut ab = vec![1.0, 2.0, 0.0, 3.0, 4.0, 0.0, 5.0, 6.0, 0.0];
+        unsafe { dimatcopy(b'C', Transpose::Trans, 2, 3, 2.0, &mut ab, 3, 3) };
+        assert_eq!(ab[..6], [2.0, 6.0, 10.0, 4.0, 8.0, 12.0]);
+
+        // A 3-by-2 row-major matrix becoming a 2-by-3 one.
+        let mut ab = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        unsafe { dimatcopy(b'R', b'T', 3, 2, 1.0, &mut ab, 2, 3) };
+        assert_eq!(ab, [1.0, 3.0, 5.0, 2.0, 4.0, 6.0]);
+
+        let mut ab = vec![
+            c64::new(1.0, 1.0),
+            c64::new(2.0, -1.0),
+            c64::new(3.0, 2.0),
+            c64::new(4.0, 0.0),
+            c64::new(5.0, -3.0),
+            c64::new(6.0, 1.0),
+        ];
+        let conjugate: Vec<_> = ab.iter().map(|x| x.conj()).collect();
+        unsafe {
+            zimatcopy(
+                b'C',
+                Transpose::ConjTrans,
+                2,
+                3,
+                c64::new(1.0, 0.0),
+                &mut ab,
+                2,
+                3,
+            )
+        };
+        assert_eq!(
+            ab,
+            [0, 2, 4, 1, 3, 5]
+                .iter()
+                .map(|&i| conjugate[i])
+                .collect::<Vec<_>>()
+        );
+    }
+
+    /// Create arbitrary but reproducible elements.
+    fn elements(len: usize, seed: f64) -> Vec<c64> {
+        (0..len)
+            .map(|i| c64::new((seed + 0.7 * i as f64).sin(), (seed * i as f64).cos()))
+            .collect()
+    }
+
+    #[test]
+    fn gemmt() {
+        let uplos = [Uplo::Upper, Uplo::Lower];
+        let transposes = [Transpose::No, Transpose::Trans, Transpose::ConjTrans];
+        let (n, k, ldc) = (PANEL + 7, 5, PANEL + 9);
+        let (alpha, beta) = (c64::new(0.5, -1.5), c64::new(2.0, 0.5));
+        for &uplo in &uplos {
+            for &transa in &transposes {
+                for &transb in &transposes {
+                    let lda = if transa == Transpose::No {
+                        n + 1
+                    } else {
+                        k + 2
+                    };
+                    let ldb = if transb == Transpose::No {
+                        k + 3
+                    } else {
+                        n + 2
+                    };
+                    let a = elements(lda * if transa == Transpose::No { k } else { n }, 1.0);
+                    let b = elements(ldb * if transb == Transpose::No { n } else { k }, 2.0);
+                    let c = elements(ldc * n, 3.0);
+                    let mut full = c.clone();
+                    let mut triangle = c.clone();
+                    let (n, k) = (n as i32, k as i32);
+                    let (lda, ldb, ldc) = (lda as i32, ldb as i32, ldc as i32);
+                    unsafe {
+                        ::zgemm(
+                            transa, transb, n, n, k, alpha, &a, lda, &b, ldb, beta, &mut full, ldc,
+                        );
+                        zgemmt(
+                            uplo,
+                            transa,
+                            transb,
+                            n,
+                            k,
+                            alpha,
+                            &a,
+                            lda,
+                            &b,
+                            ldb,
+                            beta,
+                            &mut triangle,
+                            ldc,
+                        );
+                    }
+                    for (index, &value) in triangle.iter().enumerate() {
+                        let (i, j) = (index % ldc as usize, index / ldc as usize);
+                        let inside = i < n as usize
+                            && match uplo {
+                                Uplo::Upper => i <= j,
+                                Uplo::Lower => i >= j,
+                            };
+                        let expected = if inside { full[index] } else { c[index] };
+                        assert!(
+                            (value - expected).norm_sqr() <= 1e-24 * (1.0 + expected.norm_sqr()),
+                            "{:?} {:?} {:?} ({}, {}): {} != {}",
+                            uplo,
+                            transa,
+                            transb,
+                            i,
+                            j,
+                            value,
+                            expected,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn gemmt_quick_return() {
+        for &uplo in b"UL" {
+            let mut c = vec![f64::NAN; 9];
+            c[0] = 1.0;
+            unsafe { dgemmt(uplo, b'N', b'N', 3, 0, 1.0, &[], 3, &[], 1, 0.0, &mut c, 3) };
+            let (inside, outside) = if uplo == b'U' { (7, 5) } else { (5, 7) };
+            assert_eq!(c.iter().filter(|value| **value == 0.0).count(), 6);
+            assert_eq!(c[inside], 0.0);
+            assert!(c[outside].is_nan());
+
+            let mut c = vec![2.0; 4];
+            let a = vec![f64::NAN; 4];
+            unsafe { dgemmt(uplo, b'N', b'T', 2, 2, 0.0, &a, 2, &a, 2, 3.0, &mut c, 2) };
+            let (inside, outside) = if uplo == b'U' { (2, 1) } else { (1, 2) };
+            assert_eq!([c[0], c[inside], c[3], c[outside]], [6.0, 6.0, 6.0, 2.0]);
+        }
+    }
+}
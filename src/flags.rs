@@ -0,0 +1,139 @@
+//! Flags.
+//!
+//! The `unsafe` wrappers take flags as anything that converts into a byte, so
+//! either the enums below or bytes, which are passed to BLAS as is. The checked
+//! wrappers take the enums only. Each enum converts into the corresponding
+//! byte, and bytes convert back into enums regardless of case:
+//!
+//! ```
+//! use blas::Transpose;
+//! use std::convert::TryFrom;
+//!
+//! assert_eq!(u8::from(Transpose::ConjTrans), b'C');
+//! assert_eq!(Transpose::try_from(b't'), Ok(Transpose::Trans));
+//! assert_eq!(Transpose::try_from(b'X'), Err(b'X'));
+//! ```
+
+use std::convert::TryFrom;
+
+/// The operation applied to a matrix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Transpose {
+    /// The matrix as is (`N`).
+    No,
+    /// The transpose (`T`).
+    Trans,
+    /// The conjugate transpose (`C`), which is the transpose for real matrices.
+    ConjTrans,
+}
+
+/// The triangle of a matrix that is referenced.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Uplo {
+    /// The upper triangle (`U`).
+    Upper,
+    /// The lower triangle (`L`).
+    Lower,
+}
+
+/// The kind of the diagonal of a triangular matrix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Diag {
+    /// A diagonal that is read from the matrix (`N`).
+    NonUnit,
+    /// A diagonal that is assumed to consist of ones (`U`).
+    Unit,
+}
+
+/// The side from which a matrix is applied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Side {
+    /// The left side (`L`).
+    Left,
+    /// The right side (`R`).
+    Right,
+}
+
+macro_rules! flag {
+    ($name:ident { $($variant:ident => $byte:expr,)* }) => {
+        impl From<$name> for u8 {
+            #[inline]
+            fn from(flag: $name) -> u8 {
+                match flag {
+                    $($name::$variant => $byte,)*
+                }
+            }
+        }
+
+        impl TryFrom<u8> for $name {
+            type Error = u8;
+
+            #[inline]
+            fn try_from(byte: u8) -> Result<Self, u8> {
+                match byte.to_ascii_uppercase() {
+                    $($byte => Ok($name::$variant),)*
+                    _ => Err(byte),
+                }
+            }
+        }
+    };
+}
+
+flag!(Transpose {
+    No => b'N',
+    Trans => b'T',
+    ConjTrans => b'C',
+});
+
+flag!(Uplo {
+    Upper => b'U',
+    Lower => b'L',
+});
+
+flag!(Diag {
+    NonUnit => b'N',
+    Unit => b'U',
+});
+
+flag!(Side {
+    Left => b'L',
+    Right => b'R',
+});
+
+#[cfg(all(test, feature = "reference", not(feature = "dynamic")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapper() {
+        let a = [1.0, 2.0, 3.0, 4.0];
+        let mut x = [1.0, 1.0];
+        let mut y = [1.0, 1.0];
+        unsafe {
+            ::dtrmv(
+                Uplo::Upper,
+                Transpose::Trans,
+                Diag::Unit,
+                2,
+                &a,
+                2,
+                &mut x,
+                1,
+            );
+            ::dtrmv(b'U', b'T', b'U', 2, &a, 2, &mut y, 1);
+        }
+        assert_eq!(x, [1.0, 4.0]);
+        assert_eq!(x, y);
+    }
+
+    #[test]
+    fn conversion() {
+        for &flag in &[Transpose::No, Transpose::Trans, Transpose::ConjTrans] {
+            assert_eq!(Transpose::try_from(u8::from(flag)), Ok(flag));
+        }
+        assert_eq!(Uplo::try_from(b'l'), Ok(Uplo::Lower));
+        assert_eq!(Diag::try_from(b'u'), Ok(Diag::Unit));
+        assert_eq!(Side::try_from(b'R'), Ok(Side::Right));
+        assert_eq!(Side::try_from(b'U'), Err(b'U'));
+    }
+}
@@ -0,0 +1,2791 @@
+//! Routines taking the hidden lengths of character arguments.
+//!
+//! Fortran passes the length of each character argument as an additional
+//! argument of type `size_t` after the visible ones, which the declarations
+//! of `blas-sys` omit. Omitting them happens to work with most compilers, but
+//! recent versions of gfortran might rely on their presence, and calling a
+//! routine compiled by such a version without them might corrupt the stack.
+//! With the `hidden-length` feature enabled, the wrappers call the routines
+//! of this module, which pass a length of one for every flag of the Level 2
+//! and Level 3 routines. The declarations are generated by
+//! `bin/generate.py --hidden` from those of `blas-sys`.
+
+pub use blas_sys::*;
+
+use libc::{c_char, c_double, c_float, c_int, size_t};
+
+extern "C" {
+    #[link_name = "sgemv_"]
+    fn sgemv(
+        trans: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        x: *const c_float,
+        incx: *const c_int,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const c_int,
+        trans_len: size_t,
+    );
+    #[link_name = "sgbmv_"]
+    fn sgbmv(
+        trans: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        kl: *const c_int,
+        ku: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        x: *const c_float,
+        incx: *const c_int,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const c_int,
+        trans_len: size_t,
+    );
+    #[link_name = "ssymv_"]
+    fn ssymv(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        x: *const c_float,
+        incx: *const c_int,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const c_int,
+        uplo_len: size_t,
+    );
+    #[link_name = "ssbmv_"]
+    fn ssbmv(
+        uplo: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        x: *const c_float,
+        incx: *const c_int,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const c_int,
+        uplo_len: size_t,
+    );
+    #[link_name = "sspmv_"]
+    fn sspmv(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float,
+        ap: *const c_float,
+        x: *const c_float,
+        incx: *const c_int,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const c_int,
+        uplo_len: size_t,
+    );
+    #[link_name = "strmv_"]
+    fn strmv(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        a: *const c_float,
+        lda: *const c_int,
+        b: *mut c_float,
+        incx: *const c_int,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "stbmv_"]
+    fn stbmv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        a: *const c_float,
+        lda: *const c_int,
+        x: *mut c_float,
+        incx: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "stpmv_"]
+    fn stpmv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        ap: *const c_float,
+        x: *mut c_float,
+        incx: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "strsv_"]
+    fn strsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        a: *const c_float,
+        lda: *const c_int,
+        x: *mut c_float,
+        incx: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "stbsv_"]
+    fn stbsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        a: *const c_float,
+        lda: *const c_int,
+        x: *mut c_float,
+        incx: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "stpsv_"]
+    fn stpsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        ap: *const c_float,
+        x: *mut c_float,
+        incx: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ssyr_"]
+    fn ssyr(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const c_int,
+        a: *mut c_float,
+        lda: *const c_int,
+        uplo_len: size_t,
+    );
+    #[link_name = "sspr_"]
+    fn sspr(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const c_int,
+        ap: *mut c_float,
+        uplo_len: size_t,
+    );
+    #[link_name = "ssyr2_"]
+    fn ssyr2(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const c_int,
+        y: *const c_float,
+        incy: *const c_int,
+        a: *mut c_float,
+        lda: *const c_int,
+        uplo_len: size_t,
+    );
+    #[link_name = "sspr2_"]
+    fn sspr2(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const c_int,
+        y: *const c_float,
+        incy: *const c_int,
+        ap: *mut c_float,
+        uplo_len: size_t,
+    );
+    #[link_name = "dgemv_"]
+    fn dgemv(
+        trans: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        x: *const c_double,
+        incx: *const c_int,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const c_int,
+        trans_len: size_t,
+    );
+    #[link_name = "dgbmv_"]
+    fn dgbmv(
+        trans: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        kl: *const c_int,
+        ku: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        x: *const c_double,
+        incx: *const c_int,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const c_int,
+        trans_len: size_t,
+    );
+    #[link_name = "dsymv_"]
+    fn dsymv(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        x: *const c_double,
+        incx: *const c_int,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const c_int,
+        uplo_len: size_t,
+    );
+    #[link_name = "dsbmv_"]
+    fn dsbmv(
+        uplo: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        x: *const c_double,
+        incx: *const c_int,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const c_int,
+        uplo_len: size_t,
+    );
+    #[link_name = "dspmv_"]
+    fn dspmv(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double,
+        ap: *const c_double,
+        x: *const c_double,
+        incx: *const c_int,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const c_int,
+        uplo_len: size_t,
+    );
+    #[link_name = "dtrmv_"]
+    fn dtrmv(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        a: *const c_double,
+        lda: *const c_int,
+        b: *mut c_double,
+        incx: *const c_int,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "dtbmv_"]
+    fn dtbmv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        a: *const c_double,
+        lda: *const c_int,
+        x: *mut c_double,
+        incx: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "dtpmv_"]
+    fn dtpmv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        ap: *const c_double,
+        x: *mut c_double,
+        incx: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "dtrsv_"]
+    fn dtrsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        a: *const c_double,
+        lda: *const c_int,
+        x: *mut c_double,
+        incx: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "dtbsv_"]
+    fn dtbsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        a: *const c_double,
+        lda: *const c_int,
+        x: *mut c_double,
+        incx: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "dtpsv_"]
+    fn dtpsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        ap: *const c_double,
+        x: *mut c_double,
+        incx: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "dsyr_"]
+    fn dsyr(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const c_int,
+        a: *mut c_double,
+        lda: *const c_int,
+        uplo_len: size_t,
+    );
+    #[link_name = "dspr_"]
+    fn dspr(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const c_int,
+        ap: *mut c_double,
+        uplo_len: size_t,
+    );
+    #[link_name = "dsyr2_"]
+    fn dsyr2(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const c_int,
+        y: *const c_double,
+        incy: *const c_int,
+        a: *mut c_double,
+        lda: *const c_int,
+        uplo_len: size_t,
+    );
+    #[link_name = "dspr2_"]
+    fn dspr2(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const c_int,
+        y: *const c_double,
+        incy: *const c_int,
+        ap: *mut c_double,
+        uplo_len: size_t,
+    );
+    #[link_name = "cgemv_"]
+    fn cgemv(
+        trans: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const c_int,
+        trans_len: size_t,
+    );
+    #[link_name = "cgbmv_"]
+    fn cgbmv(
+        trans: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        kl: *const c_int,
+        ku: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const c_int,
+        trans_len: size_t,
+    );
+    #[link_name = "chemv_"]
+    fn chemv(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const c_int,
+        uplo_len: size_t,
+    );
+    #[link_name = "chbmv_"]
+    fn chbmv(
+        uplo: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const c_int,
+        uplo_len: size_t,
+    );
+    #[link_name = "chpmv_"]
+    fn chpmv(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        ap: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const c_int,
+        uplo_len: size_t,
+    );
+    #[link_name = "ctrmv_"]
+    fn ctrmv(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        b: *mut c_float_complex,
+        incx: *const c_int,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ctbmv_"]
+    fn ctbmv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        x: *mut c_float_complex,
+        incx: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ctpmv_"]
+    fn ctpmv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        ap: *const c_float_complex,
+        x: *mut c_float_complex,
+        incx: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ctrsv_"]
+    fn ctrsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        x: *mut c_float_complex,
+        incx: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ctbsv_"]
+    fn ctbsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        x: *mut c_float_complex,
+        incx: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ctpsv_"]
+    fn ctpsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        ap: *const c_float_complex,
+        x: *mut c_float_complex,
+        incx: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "cher_"]
+    fn cher(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        a: *mut c_float_complex,
+        lda: *const c_int,
+        uplo_len: size_t,
+    );
+    #[link_name = "chpr_"]
+    fn chpr(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        ap: *mut c_float_complex,
+        uplo_len: size_t,
+    );
+    #[link_name = "chpr2_"]
+    fn chpr2(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        y: *const c_float_complex,
+        incy: *const c_int,
+        ap: *mut c_float_complex,
+        uplo_len: size_t,
+    );
+    #[link_name = "cher2_"]
+    fn cher2(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const c_int,
+        y: *const c_float_complex,
+        incy: *const c_int,
+        a: *mut c_float_complex,
+        lda: *const c_int,
+        uplo_len: size_t,
+    );
+    #[link_name = "zgemv_"]
+    fn zgemv(
+        trans: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const c_int,
+        trans_len: size_t,
+    );
+    #[link_name = "zgbmv_"]
+    fn zgbmv(
+        trans: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        kl: *const c_int,
+        ku: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const c_int,
+        trans_len: size_t,
+    );
+    #[link_name = "zhemv_"]
+    fn zhemv(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const c_int,
+        uplo_len: size_t,
+    );
+    #[link_name = "zhbmv_"]
+    fn zhbmv(
+        uplo: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const c_int,
+        uplo_len: size_t,
+    );
+    #[link_name = "zhpmv_"]
+    fn zhpmv(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        ap: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const c_int,
+        uplo_len: size_t,
+    );
+    #[link_name = "ztrmv_"]
+    fn ztrmv(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        b: *mut c_double_complex,
+        incx: *const c_int,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ztbmv_"]
+    fn ztbmv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        x: *mut c_double_complex,
+        incx: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ztpmv_"]
+    fn ztpmv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        ap: *const c_double_complex,
+        x: *mut c_double_complex,
+        incx: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ztrsv_"]
+    fn ztrsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        x: *mut c_double_complex,
+        incx: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ztbsv_"]
+    fn ztbsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        x: *mut c_double_complex,
+        incx: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ztpsv_"]
+    fn ztpsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const c_int,
+        ap: *const c_double_complex,
+        x: *mut c_double_complex,
+        incx: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "zher_"]
+    fn zher(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        a: *mut c_double_complex,
+        lda: *const c_int,
+        uplo_len: size_t,
+    );
+    #[link_name = "zhpr_"]
+    fn zhpr(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        ap: *mut c_double_complex,
+        uplo_len: size_t,
+    );
+    #[link_name = "zher2_"]
+    fn zher2(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        y: *const c_double_complex,
+        incy: *const c_int,
+        a: *mut c_double_complex,
+        lda: *const c_int,
+        uplo_len: size_t,
+    );
+    #[link_name = "zhpr2_"]
+    fn zhpr2(
+        uplo: *const c_char,
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const c_int,
+        y: *const c_double_complex,
+        incy: *const c_int,
+        ap: *mut c_double_complex,
+        uplo_len: size_t,
+    );
+}
+
+#[inline]
+pub unsafe fn sgemv_(
+    trans: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const c_int,
+    x: *const c_float,
+    incx: *const c_int,
+    beta: *const c_float,
+    y: *mut c_float,
+    incy: *const c_int,
+) {
+    sgemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn sgbmv_(
+    trans: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    kl: *const c_int,
+    ku: *const c_int,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const c_int,
+    x: *const c_float,
+    incx: *const c_int,
+    beta: *const c_float,
+    y: *mut c_float,
+    incy: *const c_int,
+) {
+    sgbmv(
+        trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn ssymv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const c_int,
+    x: *const c_float,
+    incx: *const c_int,
+    beta: *const c_float,
+    y: *mut c_float,
+    incy: *const c_int,
+) {
+    ssymv(uplo, n, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn ssbmv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const c_int,
+    x: *const c_float,
+    incx: *const c_int,
+    beta: *const c_float,
+    y: *mut c_float,
+    incy: *const c_int,
+) {
+    ssbmv(uplo, n, k, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn sspmv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_float,
+    ap: *const c_float,
+    x: *const c_float,
+    incx: *const c_int,
+    beta: *const c_float,
+    y: *mut c_float,
+    incy: *const c_int,
+) {
+    sspmv(uplo, n, alpha, ap, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn strmv_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    a: *const c_float,
+    lda: *const c_int,
+    b: *mut c_float,
+    incx: *const c_int,
+) {
+    strmv(uplo, transa, diag, n, a, lda, b, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn stbmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    a: *const c_float,
+    lda: *const c_int,
+    x: *mut c_float,
+    incx: *const c_int,
+) {
+    stbmv(uplo, trans, diag, n, k, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn stpmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    ap: *const c_float,
+    x: *mut c_float,
+    incx: *const c_int,
+) {
+    stpmv(uplo, trans, diag, n, ap, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn strsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    a: *const c_float,
+    lda: *const c_int,
+    x: *mut c_float,
+    incx: *const c_int,
+) {
+    strsv(uplo, trans, diag, n, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn stbsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    a: *const c_float,
+    lda: *const c_int,
+    x: *mut c_float,
+    incx: *const c_int,
+) {
+    stbsv(uplo, trans, diag, n, k, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn stpsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    ap: *const c_float,
+    x: *mut c_float,
+    incx: *const c_int,
+) {
+    stpsv(uplo, trans, diag, n, ap, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ssyr_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_float,
+    x: *const c_float,
+    incx: *const c_int,
+    a: *mut c_float,
+    lda: *const c_int,
+) {
+    ssyr(uplo, n, alpha, x, incx, a, lda, 1)
+}
+
+#[inline]
+pub unsafe fn sspr_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_float,
+    x: *const c_float,
+    incx: *const c_int,
+    ap: *mut c_float,
+) {
+    sspr(uplo, n, alpha, x, incx, ap, 1)
+}
+
+#[inline]
+pub unsafe fn ssyr2_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_float,
+    x: *const c_float,
+    incx: *const c_int,
+    y: *const c_float,
+    incy: *const c_int,
+    a: *mut c_float,
+    lda: *const c_int,
+) {
+    ssyr2(uplo, n, alpha, x, incx, y, incy, a, lda, 1)
+}
+
+#[inline]
+pub unsafe fn sspr2_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_float,
+    x: *const c_float,
+    incx: *const c_int,
+    y: *const c_float,
+    incy: *const c_int,
+    ap: *mut c_float,
+) {
+    sspr2(uplo, n, alpha, x, incx, y, incy, ap, 1)
+}
+
+#[inline]
+pub unsafe fn dgemv_(
+    trans: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const c_int,
+    x: *const c_double,
+    incx: *const c_int,
+    beta: *const c_double,
+    y: *mut c_double,
+    incy: *const c_int,
+) {
+    dgemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn dgbmv_(
+    trans: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    kl: *const c_int,
+    ku: *const c_int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const c_int,
+    x: *const c_double,
+    incx: *const c_int,
+    beta: *const c_double,
+    y: *mut c_double,
+    incy: *const c_int,
+) {
+    dgbmv(
+        trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn dsymv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const c_int,
+    x: *const c_double,
+    incx: *const c_int,
+    beta: *const c_double,
+    y: *mut c_double,
+    incy: *const c_int,
+) {
+    dsymv(uplo, n, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn dsbmv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const c_int,
+    x: *const c_double,
+    incx: *const c_int,
+    beta: *const c_double,
+    y: *mut c_double,
+    incy: *const c_int,
+) {
+    dsbmv(uplo, n, k, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn dspmv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_double,
+    ap: *const c_double,
+    x: *const c_double,
+    incx: *const c_int,
+    beta: *const c_double,
+    y: *mut c_double,
+    incy: *const c_int,
+) {
+    dspmv(uplo, n, alpha, ap, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn dtrmv_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    a: *const c_double,
+    lda: *const c_int,
+    b: *mut c_double,
+    incx: *const c_int,
+) {
+    dtrmv(uplo, transa, diag, n, a, lda, b, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn dtbmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    a: *const c_double,
+    lda: *const c_int,
+    x: *mut c_double,
+    incx: *const c_int,
+) {
+    dtbmv(uplo, trans, diag, n, k, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn dtpmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    ap: *const c_double,
+    x: *mut c_double,
+    incx: *const c_int,
+) {
+    dtpmv(uplo, trans, diag, n, ap, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn dtrsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    a: *const c_double,
+    lda: *const c_int,
+    x: *mut c_double,
+    incx: *const c_int,
+) {
+    dtrsv(uplo, trans, diag, n, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn dtbsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    a: *const c_double,
+    lda: *const c_int,
+    x: *mut c_double,
+    incx: *const c_int,
+) {
+    dtbsv(uplo, trans, diag, n, k, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn dtpsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    ap: *const c_double,
+    x: *mut c_double,
+    incx: *const c_int,
+) {
+    dtpsv(uplo, trans, diag, n, ap, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn dsyr_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_double,
+    x: *const c_double,
+    incx: *const c_int,
+    a: *mut c_double,
+    lda: *const c_int,
+) {
+    dsyr(uplo, n, alpha, x, incx, a, lda, 1)
+}
+
+#[inline]
+pub unsafe fn dspr_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_double,
+    x: *const c_double,
+    incx: *const c_int,
+    ap: *mut c_double,
+) {
+    dspr(uplo, n, alpha, x, incx, ap, 1)
+}
+
+#[inline]
+pub unsafe fn dsyr2_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_double,
+    x: *const c_double,
+    incx: *const c_int,
+    y: *const c_double,
+    incy: *const c_int,
+    a: *mut c_double,
+    lda: *const c_int,
+) {
+    dsyr2(uplo, n, alpha, x, incx, y, incy, a, lda, 1)
+}
+
+#[inline]
+pub unsafe fn dspr2_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_double,
+    x: *const c_double,
+    incx: *const c_int,
+    y: *const c_double,
+    incy: *const c_int,
+    ap: *mut c_double,
+) {
+    dspr2(uplo, n, alpha, x, incx, y, incy, ap, 1)
+}
+
+#[inline]
+pub unsafe fn cgemv_(
+    trans: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const c_int,
+    x: *const c_float_complex,
+    incx: *const c_int,
+    beta: *const c_float_complex,
+    y: *mut c_float_complex,
+    incy: *const c_int,
+) {
+    cgemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn cgbmv_(
+    trans: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    kl: *const c_int,
+    ku: *const c_int,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const c_int,
+    x: *const c_float_complex,
+    incx: *const c_int,
+    beta: *const c_float_complex,
+    y: *mut c_float_complex,
+    incy: *const c_int,
+) {
+    cgbmv(
+        trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn chemv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const c_int,
+    x: *const c_float_complex,
+    incx: *const c_int,
+    beta: *const c_float_complex,
+    y: *mut c_float_complex,
+    incy: *const c_int,
+) {
+    chemv(uplo, n, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn chbmv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const c_int,
+    x: *const c_float_complex,
+    incx: *const c_int,
+    beta: *const c_float_complex,
+    y: *mut c_float_complex,
+    incy: *const c_int,
+) {
+    chbmv(uplo, n, k, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn chpmv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_float_complex,
+    ap: *const c_float_complex,
+    x: *const c_float_complex,
+    incx: *const c_int,
+    beta: *const c_float_complex,
+    y: *mut c_float_complex,
+    incy: *const c_int,
+) {
+    chpmv(uplo, n, alpha, ap, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn ctrmv_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    a: *const c_float_complex,
+    lda: *const c_int,
+    b: *mut c_float_complex,
+    incx: *const c_int,
+) {
+    ctrmv(uplo, transa, diag, n, a, lda, b, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ctbmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    a: *const c_float_complex,
+    lda: *const c_int,
+    x: *mut c_float_complex,
+    incx: *const c_int,
+) {
+    ctbmv(uplo, trans, diag, n, k, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ctpmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    ap: *const c_float_complex,
+    x: *mut c_float_complex,
+    incx: *const c_int,
+) {
+    ctpmv(uplo, trans, diag, n, ap, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ctrsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    a: *const c_float_complex,
+    lda: *const c_int,
+    x: *mut c_float_complex,
+    incx: *const c_int,
+) {
+    ctrsv(uplo, trans, diag, n, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ctbsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    a: *const c_float_complex,
+    lda: *const c_int,
+    x: *mut c_float_complex,
+    incx: *const c_int,
+) {
+    ctbsv(uplo, trans, diag, n, k, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ctpsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    ap: *const c_float_complex,
+    x: *mut c_float_complex,
+    incx: *const c_int,
+) {
+    ctpsv(uplo, trans, diag, n, ap, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn cher_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_float,
+    x: *const c_float_complex,
+    incx: *const c_int,
+    a: *mut c_float_complex,
+    lda: *const c_int,
+) {
+    cher(uplo, n, alpha, x, incx, a, lda, 1)
+}
+
+#[inline]
+pub unsafe fn chpr_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_float,
+    x: *const c_float_complex,
+    incx: *const c_int,
+    ap: *mut c_float_complex,
+) {
+    chpr(uplo, n, alpha, x, incx, ap, 1)
+}
+
+#[inline]
+pub unsafe fn chpr2_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_float_complex,
+    x: *const c_float_complex,
+    incx: *const c_int,
+    y: *const c_float_complex,
+    incy: *const c_int,
+    ap: *mut c_float_complex,
+) {
+    chpr2(uplo, n, alpha, x, incx, y, incy, ap, 1)
+}
+
+#[inline]
+pub unsafe fn cher2_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_float_complex,
+    x: *const c_float_complex,
+    incx: *const c_int,
+    y: *const c_float_complex,
+    incy: *const c_int,
+    a: *mut c_float_complex,
+    lda: *const c_int,
+) {
+    cher2(uplo, n, alpha, x, incx, y, incy, a, lda, 1)
+}
+
+#[inline]
+pub unsafe fn zgemv_(
+    trans: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const c_int,
+    x: *const c_double_complex,
+    incx: *const c_int,
+    beta: *const c_double_complex,
+    y: *mut c_double_complex,
+    incy: *const c_int,
+) {
+    zgemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn zgbmv_(
+    trans: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    kl: *const c_int,
+    ku: *const c_int,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const c_int,
+    x: *const c_double_complex,
+    incx: *const c_int,
+    beta: *const c_double_complex,
+    y: *mut c_double_complex,
+    incy: *const c_int,
+) {
+    zgbmv(
+        trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn zhemv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const c_int,
+    x: *const c_double_complex,
+    incx: *const c_int,
+    beta: *const c_double_complex,
+    y: *mut c_double_complex,
+    incy: *const c_int,
+) {
+    zhemv(uplo, n, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn zhbmv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const c_int,
+    x: *const c_double_complex,
+    incx: *const c_int,
+    beta: *const c_double_complex,
+    y: *mut c_double_complex,
+    incy: *const c_int,
+) {
+    zhbmv(uplo, n, k, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn zhpmv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_double_complex,
+    ap: *const c_double_complex,
+    x: *const c_double_complex,
+    incx: *const c_int,
+    beta: *const c_double_complex,
+    y: *mut c_double_complex,
+    incy: *const c_int,
+) {
+    zhpmv(uplo, n, alpha, ap, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn ztrmv_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    a: *const c_double_complex,
+    lda: *const c_int,
+    b: *mut c_double_complex,
+    incx: *const c_int,
+) {
+    ztrmv(uplo, transa, diag, n, a, lda, b, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ztbmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    a: *const c_double_complex,
+    lda: *const c_int,
+    x: *mut c_double_complex,
+    incx: *const c_int,
+) {
+    ztbmv(uplo, trans, diag, n, k, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ztpmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    ap: *const c_double_complex,
+    x: *mut c_double_complex,
+    incx: *const c_int,
+) {
+    ztpmv(uplo, trans, diag, n, ap, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ztrsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    a: *const c_double_complex,
+    lda: *const c_int,
+    x: *mut c_double_complex,
+    incx: *const c_int,
+) {
+    ztrsv(uplo, trans, diag, n, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ztbsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    a: *const c_double_complex,
+    lda: *const c_int,
+    x: *mut c_double_complex,
+    incx: *const c_int,
+) {
+    ztbsv(uplo, trans, diag, n, k, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ztpsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    ap: *const c_double_complex,
+    x: *mut c_double_complex,
+    incx: *const c_int,
+) {
+    ztpsv(uplo, trans, diag, n, ap, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn zher_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_double,
+    x: *const c_double_complex,
+    incx: *const c_int,
+    a: *mut c_double_complex,
+    lda: *const c_int,
+) {
+    zher(uplo, n, alpha, x, incx, a, lda, 1)
+}
+
+#[inline]
+pub unsafe fn zhpr_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_double,
+    x: *const c_double_complex,
+    incx: *const c_int,
+    ap: *mut c_double_complex,
+) {
+    zhpr(uplo, n, alpha, x, incx, ap, 1)
+}
+
+#[inline]
+pub unsafe fn zher2_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_double_complex,
+    x: *const c_double_complex,
+    incx: *const c_int,
+    y: *const c_double_complex,
+    incy: *const c_int,
+    a: *mut c_double_complex,
+    lda: *const c_int,
+) {
+    zher2(uplo, n, alpha, x, incx, y, incy, a, lda, 1)
+}
+
+#[inline]
+pub unsafe fn zhpr2_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const c_double_complex,
+    x: *const c_double_complex,
+    incx: *const c_int,
+    y: *const c_double_complex,
+    incy: *const c_int,
+    ap: *mut c_double_complex,
+) {
+    zhpr2(uplo, n, alpha, x, incx, y, incy, ap, 1)
+}
+
+extern "C" {
+    #[link_name = "sgemm_"]
+    fn sgemm(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        b: *const c_float,
+        ldb: *const c_int,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const c_int,
+        transa_len: size_t,
+        transb_len: size_t,
+    );
+    #[link_name = "ssymm_"]
+    fn ssymm(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        b: *const c_float,
+        ldb: *const c_int,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const c_int,
+        side_len: size_t,
+        uplo_len: size_t,
+    );
+    #[link_name = "ssyrk_"]
+    fn ssyrk(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "ssyr2k_"]
+    fn ssyr2k(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        b: *const c_float,
+        ldb: *const c_int,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "strmm_"]
+    fn strmm(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        b: *mut c_float,
+        ldb: *const c_int,
+        side_len: size_t,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "strsm_"]
+    fn strsm(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const c_int,
+        b: *mut c_float,
+        ldb: *const c_int,
+        side_len: size_t,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "dgemm_"]
+    fn dgemm(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        b: *const c_double,
+        ldb: *const c_int,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const c_int,
+        transa_len: size_t,
+        transb_len: size_t,
+    );
+    #[link_name = "dsymm_"]
+    fn dsymm(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        b: *const c_double,
+        ldb: *const c_int,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const c_int,
+        side_len: size_t,
+        uplo_len: size_t,
+    );
+    #[link_name = "dsyrk_"]
+    fn dsyrk(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "dsyr2k_"]
+    fn dsyr2k(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        b: *const c_double,
+        ldb: *const c_int,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "dtrmm_"]
+    fn dtrmm(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        b: *mut c_double,
+        ldb: *const c_int,
+        side_len: size_t,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "dtrsm_"]
+    fn dtrsm(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const c_int,
+        b: *mut c_double,
+        ldb: *const c_int,
+        side_len: size_t,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "cgemm_"]
+    fn cgemm(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        b: *const c_float_complex,
+        ldb: *const c_int,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const c_int,
+        transa_len: size_t,
+        transb_len: size_t,
+    );
+    #[link_name = "csymm_"]
+    fn csymm(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        b: *const c_float_complex,
+        ldb: *const c_int,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const c_int,
+        side_len: size_t,
+        uplo_len: size_t,
+    );
+    #[link_name = "chemm_"]
+    fn chemm(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        b: *const c_float_complex,
+        ldb: *const c_int,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const c_int,
+        side_len: size_t,
+        uplo_len: size_t,
+    );
+    #[link_name = "csyrk_"]
+    fn csyrk(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "cherk_"]
+    fn cherk(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        beta: *const c_float,
+        c: *mut c_float_complex,
+        ldc: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "csyr2k_"]
+    fn csyr2k(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        b: *const c_float_complex,
+        ldb: *const c_int,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "cher2k_"]
+    fn cher2k(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        b: *const c_float_complex,
+        ldb: *const c_int,
+        beta: *const c_float,
+        c: *mut c_float_complex,
+        ldc: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "ctrmm_"]
+    fn ctrmm(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        b: *mut c_float_complex,
+        ldb: *const c_int,
+        side_len: size_t,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ctrsm_"]
+    fn ctrsm(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const c_int,
+        b: *mut c_float_complex,
+        ldb: *const c_int,
+        side_len: size_t,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "zgemm_"]
+    fn zgemm(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        b: *const c_double_complex,
+        ldb: *const c_int,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const c_int,
+        transa_len: size_t,
+        transb_len: size_t,
+    );
+    #[link_name = "zsymm_"]
+    fn zsymm(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        b: *const c_double_complex,
+        ldb: *const c_int,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const c_int,
+        side_len: size_t,
+        uplo_len: size_t,
+    );
+    #[link_name = "zhemm_"]
+    fn zhemm(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        b: *const c_double_complex,
+        ldb: *const c_int,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const c_int,
+        side_len: size_t,
+        uplo_len: size_t,
+    );
+    #[link_name = "zsyrk_"]
+    fn zsyrk(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "zherk_"]
+    fn zherk(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        beta: *const c_double,
+        c: *mut c_double_complex,
+        ldc: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "zsyr2k_"]
+    fn zsyr2k(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        b: *const c_double_complex,
+        ldb: *const c_int,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "zher2k_"]
+    fn zher2k(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const c_int,
+        k: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        b: *const c_double_complex,
+        ldb: *const c_int,
+        beta: *const c_double,
+        c: *mut c_double_complex,
+        ldc: *const c_int,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "ztrmm_"]
+    fn ztrmm(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        b: *mut c_double_complex,
+        ldb: *const c_int,
+        side_len: size_t,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ztrsm_"]
+    fn ztrsm(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const c_int,
+        n: *const c_int,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const c_int,
+        b: *mut c_double_complex,
+        ldb: *const c_int,
+        side_len: size_t,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+}
+
+#[inline]
+pub unsafe fn sgemm_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const c_int,
+    b: *const c_float,
+    ldb: *const c_int,
+    beta: *const c_float,
+    c: *mut c_float,
+    ldc: *const c_int,
+) {
+    sgemm(
+        transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn ssymm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const c_int,
+    b: *const c_float,
+    ldb: *const c_int,
+    beta: *const c_float,
+    c: *mut c_float,
+    ldc: *const c_int,
+) {
+    ssymm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ssyrk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const c_int,
+    beta: *const c_float,
+    c: *mut c_float,
+    ldc: *const c_int,
+) {
+    ssyrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ssyr2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const c_int,
+    b: *const c_float,
+    ldb: *const c_int,
+    beta: *const c_float,
+    c: *mut c_float,
+    ldc: *const c_int,
+) {
+    ssyr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn strmm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const c_int,
+    b: *mut c_float,
+    ldb: *const c_int,
+) {
+    strmm(
+        side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb, 1, 1, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn strsm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const c_int,
+    b: *mut c_float,
+    ldb: *const c_int,
+) {
+    strsm(
+        side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb, 1, 1, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn dgemm_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const c_int,
+    b: *const c_double,
+    ldb: *const c_int,
+    beta: *const c_double,
+    c: *mut c_double,
+    ldc: *const c_int,
+) {
+    dgemm(
+        transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn dsymm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const c_int,
+    b: *const c_double,
+    ldb: *const c_int,
+    beta: *const c_double,
+    c: *mut c_double,
+    ldc: *const c_int,
+) {
+    dsymm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn dsyrk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const c_int,
+    beta: *const c_double,
+    c: *mut c_double,
+    ldc: *const c_int,
+) {
+    dsyrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn dsyr2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const c_int,
+    b: *const c_double,
+    ldb: *const c_int,
+    beta: *const c_double,
+    c: *mut c_double,
+    ldc: *const c_int,
+) {
+    dsyr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn dtrmm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const c_int,
+    b: *mut c_double,
+    ldb: *const c_int,
+) {
+    dtrmm(
+        side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb, 1, 1, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn dtrsm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const c_int,
+    b: *mut c_double,
+    ldb: *const c_int,
+) {
+    dtrsm(
+        side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb, 1, 1, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn cgemm_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const c_int,
+    b: *const c_float_complex,
+    ldb: *const c_int,
+    beta: *const c_float_complex,
+    c: *mut c_float_complex,
+    ldc: *const c_int,
+) {
+    cgemm(
+        transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn csymm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const c_int,
+    b: *const c_float_complex,
+    ldb: *const c_int,
+    beta: *const c_float_complex,
+    c: *mut c_float_complex,
+    ldc: *const c_int,
+) {
+    csymm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn chemm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const c_int,
+    b: *const c_float_complex,
+    ldb: *const c_int,
+    beta: *const c_float_complex,
+    c: *mut c_float_complex,
+    ldc: *const c_int,
+) {
+    chemm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn csyrk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const c_int,
+    beta: *const c_float_complex,
+    c: *mut c_float_complex,
+    ldc: *const c_int,
+) {
+    csyrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn cherk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_float,
+    a: *const c_float_complex,
+    lda: *const c_int,
+    beta: *const c_float,
+    c: *mut c_float_complex,
+    ldc: *const c_int,
+) {
+    cherk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn csyr2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const c_int,
+    b: *const c_float_complex,
+    ldb: *const c_int,
+    beta: *const c_float_complex,
+    c: *mut c_float_complex,
+    ldc: *const c_int,
+) {
+    csyr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn cher2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const c_int,
+    b: *const c_float_complex,
+    ldb: *const c_int,
+    beta: *const c_float,
+    c: *mut c_float_complex,
+    ldc: *const c_int,
+) {
+    cher2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ctrmm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const c_int,
+    b: *mut c_float_complex,
+    ldb: *const c_int,
+) {
+    ctrmm(
+        side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb, 1, 1, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn ctrsm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const c_int,
+    b: *mut c_float_complex,
+    ldb: *const c_int,
+) {
+    ctrsm(
+        side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb, 1, 1, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn zgemm_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const c_int,
+    b: *const c_double_complex,
+    ldb: *const c_int,
+    beta: *const c_double_complex,
+    c: *mut c_double_complex,
+    ldc: *const c_int,
+) {
+    zgemm(
+        transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn zsymm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const c_int,
+    b: *const c_double_complex,
+    ldb: *const c_int,
+    beta: *const c_double_complex,
+    c: *mut c_double_complex,
+    ldc: *const c_int,
+) {
+    zsymm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn zhemm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const c_int,
+    b: *const c_double_complex,
+    ldb: *const c_int,
+    beta: *const c_double_complex,
+    c: *mut c_double_complex,
+    ldc: *const c_int,
+) {
+    zhemm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn zsyrk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const c_int,
+    beta: *const c_double_complex,
+    c: *mut c_double_complex,
+    ldc: *const c_int,
+) {
+    zsyrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn zherk_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_double,
+    a: *const c_double_complex,
+    lda: *const c_int,
+    beta: *const c_double,
+    c: *mut c_double_complex,
+    ldc: *const c_int,
+) {
+    zherk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn zsyr2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const c_int,
+    b: *const c_double_complex,
+    ldb: *const c_int,
+    beta: *const c_double_complex,
+    c: *mut c_double_complex,
+    ldc: *const c_int,
+) {
+    zsyr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn zher2k_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const c_int,
+    b: *const c_double_complex,
+    ldb: *const c_int,
+    beta: *const c_double,
+    c: *mut c_double_complex,
+    ldc: *const c_int,
+) {
+    zher2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ztrmm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const c_int,
+    b: *mut c_double_complex,
+    ldb: *const c_int,
+) {
+    ztrmm(
+        side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb, 1, 1, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn ztrsm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const c_int,
+    b: *mut c_double_complex,
+    ldb: *const c_int,
+) {
+    ztrsm(
+        side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb, 1, 1, 1, 1,
+    )
+}
@@ -0,0 +1,2786 @@
+//! Routines of the ILP64 interface taking the hidden lengths of character
+//! arguments.
+//!
+//! The declarations are generated by `bin/generate.py --ilp64 --hidden` from
+//! those of `blas-sys`.
+
+pub use super::sys::*;
+
+use blas_sys::{c_double_complex, c_float_complex};
+use libc::{c_char, c_double, c_float, size_t};
+
+extern "C" {
+    #[link_name = "sgemv_64_"]
+    fn sgemv(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        x: *const c_float,
+        incx: *const i64,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const i64,
+        trans_len: size_t,
+    );
+    #[link_name = "sgbmv_64_"]
+    fn sgbmv(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        kl: *const i64,
+        ku: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        x: *const c_float,
+        incx: *const i64,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const i64,
+        trans_len: size_t,
+    );
+    #[link_name = "ssymv_64_"]
+    fn ssymv(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        x: *const c_float,
+        incx: *const i64,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const i64,
+        uplo_len: size_t,
+    );
+    #[link_name = "ssbmv_64_"]
+    fn ssbmv(
+        uplo: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        x: *const c_float,
+        incx: *const i64,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const i64,
+        uplo_len: size_t,
+    );
+    #[link_name = "sspmv_64_"]
+    fn sspmv(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        ap: *const c_float,
+        x: *const c_float,
+        incx: *const i64,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const i64,
+        uplo_len: size_t,
+    );
+    #[link_name = "strmv_64_"]
+    fn strmv(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_float,
+        lda: *const i64,
+        b: *mut c_float,
+        incx: *const i64,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "stbmv_64_"]
+    fn stbmv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_float,
+        lda: *const i64,
+        x: *mut c_float,
+        incx: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "stpmv_64_"]
+    fn stpmv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_float,
+        x: *mut c_float,
+        incx: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "strsv_64_"]
+    fn strsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_float,
+        lda: *const i64,
+        x: *mut c_float,
+        incx: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "stbsv_64_"]
+    fn stbsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_float,
+        lda: *const i64,
+        x: *mut c_float,
+        incx: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "stpsv_64_"]
+    fn stpsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_float,
+        x: *mut c_float,
+        incx: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ssyr_64_"]
+    fn ssyr(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const i64,
+        a: *mut c_float,
+        lda: *const i64,
+        uplo_len: size_t,
+    );
+    #[link_name = "sspr_64_"]
+    fn sspr(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const i64,
+        ap: *mut c_float,
+        uplo_len: size_t,
+    );
+    #[link_name = "ssyr2_64_"]
+    fn ssyr2(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const i64,
+        y: *const c_float,
+        incy: *const i64,
+        a: *mut c_float,
+        lda: *const i64,
+        uplo_len: size_t,
+    );
+    #[link_name = "sspr2_64_"]
+    fn sspr2(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const i64,
+        y: *const c_float,
+        incy: *const i64,
+        ap: *mut c_float,
+        uplo_len: size_t,
+    );
+    #[link_name = "dgemv_64_"]
+    fn dgemv(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        x: *const c_double,
+        incx: *const i64,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const i64,
+        trans_len: size_t,
+    );
+    #[link_name = "dgbmv_64_"]
+    fn dgbmv(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        kl: *const i64,
+        ku: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        x: *const c_double,
+        incx: *const i64,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const i64,
+        trans_len: size_t,
+    );
+    #[link_name = "dsymv_64_"]
+    fn dsymv(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        x: *const c_double,
+        incx: *const i64,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const i64,
+        uplo_len: size_t,
+    );
+    #[link_name = "dsbmv_64_"]
+    fn dsbmv(
+        uplo: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        x: *const c_double,
+        incx: *const i64,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const i64,
+        uplo_len: size_t,
+    );
+    #[link_name = "dspmv_64_"]
+    fn dspmv(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        ap: *const c_double,
+        x: *const c_double,
+        incx: *const i64,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const i64,
+        uplo_len: size_t,
+    );
+    #[link_name = "dtrmv_64_"]
+    fn dtrmv(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_double,
+        lda: *const i64,
+        b: *mut c_double,
+        incx: *const i64,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "dtbmv_64_"]
+    fn dtbmv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_double,
+        lda: *const i64,
+        x: *mut c_double,
+        incx: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "dtpmv_64_"]
+    fn dtpmv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_double,
+        x: *mut c_double,
+        incx: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "dtrsv_64_"]
+    fn dtrsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_double,
+        lda: *const i64,
+        x: *mut c_double,
+        incx: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "dtbsv_64_"]
+    fn dtbsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_double,
+        lda: *const i64,
+        x: *mut c_double,
+        incx: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "dtpsv_64_"]
+    fn dtpsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_double,
+        x: *mut c_double,
+        incx: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "dsyr_64_"]
+    fn dsyr(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const i64,
+        a: *mut c_double,
+        lda: *const i64,
+        uplo_len: size_t,
+    );
+    #[link_name = "dspr_64_"]
+    fn dspr(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const i64,
+        ap: *mut c_double,
+        uplo_len: size_t,
+    );
+    #[link_name = "dsyr2_64_"]
+    fn dsyr2(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const i64,
+        y: *const c_double,
+        incy: *const i64,
+        a: *mut c_double,
+        lda: *const i64,
+        uplo_len: size_t,
+    );
+    #[link_name = "dspr2_64_"]
+    fn dspr2(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const i64,
+        y: *const c_double,
+        incy: *const i64,
+        ap: *mut c_double,
+        uplo_len: size_t,
+    );
+    #[link_name = "cgemv_64_"]
+    fn cgemv(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        x: *const c_float_complex,
+        incx: *const i64,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const i64,
+        trans_len: size_t,
+    );
+    #[link_name = "cgbmv_64_"]
+    fn cgbmv(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        kl: *const i64,
+        ku: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        x: *const c_float_complex,
+        incx: *const i64,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const i64,
+        trans_len: size_t,
+    );
+    #[link_name = "chemv_64_"]
+    fn chemv(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        x: *const c_float_complex,
+        incx: *const i64,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const i64,
+        uplo_len: size_t,
+    );
+    #[link_name = "chbmv_64_"]
+    fn chbmv(
+        uplo: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        x: *const c_float_complex,
+        incx: *const i64,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const i64,
+        uplo_len: size_t,
+    );
+    #[link_name = "chpmv_64_"]
+    fn chpmv(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        ap: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const i64,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const i64,
+        uplo_len: size_t,
+    );
+    #[link_name = "ctrmv_64_"]
+    fn ctrmv(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *mut c_float_complex,
+        incx: *const i64,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ctbmv_64_"]
+    fn ctbmv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_float_complex,
+        lda: *const i64,
+        x: *mut c_float_complex,
+        incx: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ctpmv_64_"]
+    fn ctpmv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_float_complex,
+        x: *mut c_float_complex,
+        incx: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ctrsv_64_"]
+    fn ctrsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_float_complex,
+        lda: *const i64,
+        x: *mut c_float_complex,
+        incx: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ctbsv_64_"]
+    fn ctbsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_float_complex,
+        lda: *const i64,
+        x: *mut c_float_complex,
+        incx: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ctpsv_64_"]
+    fn ctpsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_float_complex,
+        x: *mut c_float_complex,
+        incx: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "cher_64_"]
+    fn cher(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float_complex,
+        incx: *const i64,
+        a: *mut c_float_complex,
+        lda: *const i64,
+        uplo_len: size_t,
+    );
+    #[link_name = "chpr_64_"]
+    fn chpr(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float_complex,
+        incx: *const i64,
+        ap: *mut c_float_complex,
+        uplo_len: size_t,
+    );
+    #[link_name = "chpr2_64_"]
+    fn chpr2(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const i64,
+        y: *const c_float_complex,
+        incy: *const i64,
+        ap: *mut c_float_complex,
+        uplo_len: size_t,
+    );
+    #[link_name = "cher2_64_"]
+    fn cher2(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const i64,
+        y: *const c_float_complex,
+        incy: *const i64,
+        a: *mut c_float_complex,
+        lda: *const i64,
+        uplo_len: size_t,
+    );
+    #[link_name = "zgemv_64_"]
+    fn zgemv(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        x: *const c_double_complex,
+        incx: *const i64,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const i64,
+        trans_len: size_t,
+    );
+    #[link_name = "zgbmv_64_"]
+    fn zgbmv(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        kl: *const i64,
+        ku: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        x: *const c_double_complex,
+        incx: *const i64,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const i64,
+        trans_len: size_t,
+    );
+    #[link_name = "zhemv_64_"]
+    fn zhemv(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        x: *const c_double_complex,
+        incx: *const i64,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const i64,
+        uplo_len: size_t,
+    );
+    #[link_name = "zhbmv_64_"]
+    fn zhbmv(
+        uplo: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        x: *const c_double_complex,
+        incx: *const i64,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const i64,
+        uplo_len: size_t,
+    );
+    #[link_name = "zhpmv_64_"]
+    fn zhpmv(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        ap: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const i64,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const i64,
+        uplo_len: size_t,
+    );
+    #[link_name = "ztrmv_64_"]
+    fn ztrmv(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *mut c_double_complex,
+        incx: *const i64,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ztbmv_64_"]
+    fn ztbmv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_double_complex,
+        lda: *const i64,
+        x: *mut c_double_complex,
+        incx: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ztpmv_64_"]
+    fn ztpmv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_double_complex,
+        x: *mut c_double_complex,
+        incx: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ztrsv_64_"]
+    fn ztrsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_double_complex,
+        lda: *const i64,
+        x: *mut c_double_complex,
+        incx: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ztbsv_64_"]
+    fn ztbsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_double_complex,
+        lda: *const i64,
+        x: *mut c_double_complex,
+        incx: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ztpsv_64_"]
+    fn ztpsv(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_double_complex,
+        x: *mut c_double_complex,
+        incx: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "zher_64_"]
+    fn zher(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double_complex,
+        incx: *const i64,
+        a: *mut c_double_complex,
+        lda: *const i64,
+        uplo_len: size_t,
+    );
+    #[link_name = "zhpr_64_"]
+    fn zhpr(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double_complex,
+        incx: *const i64,
+        ap: *mut c_double_complex,
+        uplo_len: size_t,
+    );
+    #[link_name = "zher2_64_"]
+    fn zher2(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const i64,
+        y: *const c_double_complex,
+        incy: *const i64,
+        a: *mut c_double_complex,
+        lda: *const i64,
+        uplo_len: size_t,
+    );
+    #[link_name = "zhpr2_64_"]
+    fn zhpr2(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const i64,
+        y: *const c_double_complex,
+        incy: *const i64,
+        ap: *mut c_double_complex,
+        uplo_len: size_t,
+    );
+}
+
+#[inline]
+pub unsafe fn sgemv_64_(
+    trans: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const i64,
+    x: *const c_float,
+    incx: *const i64,
+    beta: *const c_float,
+    y: *mut c_float,
+    incy: *const i64,
+) {
+    sgemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn sgbmv_64_(
+    trans: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    kl: *const i64,
+    ku: *const i64,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const i64,
+    x: *const c_float,
+    incx: *const i64,
+    beta: *const c_float,
+    y: *mut c_float,
+    incy: *const i64,
+) {
+    sgbmv(
+        trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn ssymv_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const i64,
+    x: *const c_float,
+    incx: *const i64,
+    beta: *const c_float,
+    y: *mut c_float,
+    incy: *const i64,
+) {
+    ssymv(uplo, n, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn ssbmv_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const i64,
+    x: *const c_float,
+    incx: *const i64,
+    beta: *const c_float,
+    y: *mut c_float,
+    incy: *const i64,
+) {
+    ssbmv(uplo, n, k, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn sspmv_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_float,
+    ap: *const c_float,
+    x: *const c_float,
+    incx: *const i64,
+    beta: *const c_float,
+    y: *mut c_float,
+    incy: *const i64,
+) {
+    sspmv(uplo, n, alpha, ap, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn strmv_64_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    a: *const c_float,
+    lda: *const i64,
+    b: *mut c_float,
+    incx: *const i64,
+) {
+    strmv(uplo, transa, diag, n, a, lda, b, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn stbmv_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    a: *const c_float,
+    lda: *const i64,
+    x: *mut c_float,
+    incx: *const i64,
+) {
+    stbmv(uplo, trans, diag, n, k, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn stpmv_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    ap: *const c_float,
+    x: *mut c_float,
+    incx: *const i64,
+) {
+    stpmv(uplo, trans, diag, n, ap, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn strsv_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    a: *const c_float,
+    lda: *const i64,
+    x: *mut c_float,
+    incx: *const i64,
+) {
+    strsv(uplo, trans, diag, n, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn stbsv_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    a: *const c_float,
+    lda: *const i64,
+    x: *mut c_float,
+    incx: *const i64,
+) {
+    stbsv(uplo, trans, diag, n, k, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn stpsv_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    ap: *const c_float,
+    x: *mut c_float,
+    incx: *const i64,
+) {
+    stpsv(uplo, trans, diag, n, ap, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ssyr_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_float,
+    x: *const c_float,
+    incx: *const i64,
+    a: *mut c_float,
+    lda: *const i64,
+) {
+    ssyr(uplo, n, alpha, x, incx, a, lda, 1)
+}
+
+#[inline]
+pub unsafe fn sspr_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_float,
+    x: *const c_float,
+    incx: *const i64,
+    ap: *mut c_float,
+) {
+    sspr(uplo, n, alpha, x, incx, ap, 1)
+}
+
+#[inline]
+pub unsafe fn ssyr2_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_float,
+    x: *const c_float,
+    incx: *const i64,
+    y: *const c_float,
+    incy: *const i64,
+    a: *mut c_float,
+    lda: *const i64,
+) {
+    ssyr2(uplo, n, alpha, x, incx, y, incy, a, lda, 1)
+}
+
+#[inline]
+pub unsafe fn sspr2_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_float,
+    x: *const c_float,
+    incx: *const i64,
+    y: *const c_float,
+    incy: *const i64,
+    ap: *mut c_float,
+) {
+    sspr2(uplo, n, alpha, x, incx, y, incy, ap, 1)
+}
+
+#[inline]
+pub unsafe fn dgemv_64_(
+    trans: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const i64,
+    x: *const c_double,
+    incx: *const i64,
+    beta: *const c_double,
+    y: *mut c_double,
+    incy: *const i64,
+) {
+    dgemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn dgbmv_64_(
+    trans: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    kl: *const i64,
+    ku: *const i64,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const i64,
+    x: *const c_double,
+    incx: *const i64,
+    beta: *const c_double,
+    y: *mut c_double,
+    incy: *const i64,
+) {
+    dgbmv(
+        trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn dsymv_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const i64,
+    x: *const c_double,
+    incx: *const i64,
+    beta: *const c_double,
+    y: *mut c_double,
+    incy: *const i64,
+) {
+    dsymv(uplo, n, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn dsbmv_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const i64,
+    x: *const c_double,
+    incx: *const i64,
+    beta: *const c_double,
+    y: *mut c_double,
+    incy: *const i64,
+) {
+    dsbmv(uplo, n, k, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn dspmv_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_double,
+    ap: *const c_double,
+    x: *const c_double,
+    incx: *const i64,
+    beta: *const c_double,
+    y: *mut c_double,
+    incy: *const i64,
+) {
+    dspmv(uplo, n, alpha, ap, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn dtrmv_64_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    a: *const c_double,
+    lda: *const i64,
+    b: *mut c_double,
+    incx: *const i64,
+) {
+    dtrmv(uplo, transa, diag, n, a, lda, b, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn dtbmv_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    a: *const c_double,
+    lda: *const i64,
+    x: *mut c_double,
+    incx: *const i64,
+) {
+    dtbmv(uplo, trans, diag, n, k, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn dtpmv_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    ap: *const c_double,
+    x: *mut c_double,
+    incx: *const i64,
+) {
+    dtpmv(uplo, trans, diag, n, ap, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn dtrsv_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    a: *const c_double,
+    lda: *const i64,
+    x: *mut c_double,
+    incx: *const i64,
+) {
+    dtrsv(uplo, trans, diag, n, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn dtbsv_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    a: *const c_double,
+    lda: *const i64,
+    x: *mut c_double,
+    incx: *const i64,
+) {
+    dtbsv(uplo, trans, diag, n, k, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn dtpsv_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    ap: *const c_double,
+    x: *mut c_double,
+    incx: *const i64,
+) {
+    dtpsv(uplo, trans, diag, n, ap, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn dsyr_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_double,
+    x: *const c_double,
+    incx: *const i64,
+    a: *mut c_double,
+    lda: *const i64,
+) {
+    dsyr(uplo, n, alpha, x, incx, a, lda, 1)
+}
+
+#[inline]
+pub unsafe fn dspr_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_double,
+    x: *const c_double,
+    incx: *const i64,
+    ap: *mut c_double,
+) {
+    dspr(uplo, n, alpha, x, incx, ap, 1)
+}
+
+#[inline]
+pub unsafe fn dsyr2_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_double,
+    x: *const c_double,
+    incx: *const i64,
+    y: *const c_double,
+    incy: *const i64,
+    a: *mut c_double,
+    lda: *const i64,
+) {
+    dsyr2(uplo, n, alpha, x, incx, y, incy, a, lda, 1)
+}
+
+#[inline]
+pub unsafe fn dspr2_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_double,
+    x: *const c_double,
+    incx: *const i64,
+    y: *const c_double,
+    incy: *const i64,
+    ap: *mut c_double,
+) {
+    dspr2(uplo, n, alpha, x, incx, y, incy, ap, 1)
+}
+
+#[inline]
+pub unsafe fn cgemv_64_(
+    trans: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const i64,
+    x: *const c_float_complex,
+    incx: *const i64,
+    beta: *const c_float_complex,
+    y: *mut c_float_complex,
+    incy: *const i64,
+) {
+    cgemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn cgbmv_64_(
+    trans: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    kl: *const i64,
+    ku: *const i64,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const i64,
+    x: *const c_float_complex,
+    incx: *const i64,
+    beta: *const c_float_complex,
+    y: *mut c_float_complex,
+    incy: *const i64,
+) {
+    cgbmv(
+        trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn chemv_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const i64,
+    x: *const c_float_complex,
+    incx: *const i64,
+    beta: *const c_float_complex,
+    y: *mut c_float_complex,
+    incy: *const i64,
+) {
+    chemv(uplo, n, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn chbmv_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const i64,
+    x: *const c_float_complex,
+    incx: *const i64,
+    beta: *const c_float_complex,
+    y: *mut c_float_complex,
+    incy: *const i64,
+) {
+    chbmv(uplo, n, k, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn chpmv_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_float_complex,
+    ap: *const c_float_complex,
+    x: *const c_float_complex,
+    incx: *const i64,
+    beta: *const c_float_complex,
+    y: *mut c_float_complex,
+    incy: *const i64,
+) {
+    chpmv(uplo, n, alpha, ap, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn ctrmv_64_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    a: *const c_float_complex,
+    lda: *const i64,
+    b: *mut c_float_complex,
+    incx: *const i64,
+) {
+    ctrmv(uplo, transa, diag, n, a, lda, b, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ctbmv_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    a: *const c_float_complex,
+    lda: *const i64,
+    x: *mut c_float_complex,
+    incx: *const i64,
+) {
+    ctbmv(uplo, trans, diag, n, k, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ctpmv_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    ap: *const c_float_complex,
+    x: *mut c_float_complex,
+    incx: *const i64,
+) {
+    ctpmv(uplo, trans, diag, n, ap, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ctrsv_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    a: *const c_float_complex,
+    lda: *const i64,
+    x: *mut c_float_complex,
+    incx: *const i64,
+) {
+    ctrsv(uplo, trans, diag, n, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ctbsv_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    a: *const c_float_complex,
+    lda: *const i64,
+    x: *mut c_float_complex,
+    incx: *const i64,
+) {
+    ctbsv(uplo, trans, diag, n, k, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ctpsv_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    ap: *const c_float_complex,
+    x: *mut c_float_complex,
+    incx: *const i64,
+) {
+    ctpsv(uplo, trans, diag, n, ap, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn cher_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_float,
+    x: *const c_float_complex,
+    incx: *const i64,
+    a: *mut c_float_complex,
+    lda: *const i64,
+) {
+    cher(uplo, n, alpha, x, incx, a, lda, 1)
+}
+
+#[inline]
+pub unsafe fn chpr_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_float,
+    x: *const c_float_complex,
+    incx: *const i64,
+    ap: *mut c_float_complex,
+) {
+    chpr(uplo, n, alpha, x, incx, ap, 1)
+}
+
+#[inline]
+pub unsafe fn chpr2_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_float_complex,
+    x: *const c_float_complex,
+    incx: *const i64,
+    y: *const c_float_complex,
+    incy: *const i64,
+    ap: *mut c_float_complex,
+) {
+    chpr2(uplo, n, alpha, x, incx, y, incy, ap, 1)
+}
+
+#[inline]
+pub unsafe fn cher2_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_float_complex,
+    x: *const c_float_complex,
+    incx: *const i64,
+    y: *const c_float_complex,
+    incy: *const i64,
+    a: *mut c_float_complex,
+    lda: *const i64,
+) {
+    cher2(uplo, n, alpha, x, incx, y, incy, a, lda, 1)
+}
+
+#[inline]
+pub unsafe fn zgemv_64_(
+    trans: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const i64,
+    x: *const c_double_complex,
+    incx: *const i64,
+    beta: *const c_double_complex,
+    y: *mut c_double_complex,
+    incy: *const i64,
+) {
+    zgemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn zgbmv_64_(
+    trans: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    kl: *const i64,
+    ku: *const i64,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const i64,
+    x: *const c_double_complex,
+    incx: *const i64,
+    beta: *const c_double_complex,
+    y: *mut c_double_complex,
+    incy: *const i64,
+) {
+    zgbmv(
+        trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn zhemv_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const i64,
+    x: *const c_double_complex,
+    incx: *const i64,
+    beta: *const c_double_complex,
+    y: *mut c_double_complex,
+    incy: *const i64,
+) {
+    zhemv(uplo, n, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn zhbmv_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const i64,
+    x: *const c_double_complex,
+    incx: *const i64,
+    beta: *const c_double_complex,
+    y: *mut c_double_complex,
+    incy: *const i64,
+) {
+    zhbmv(uplo, n, k, alpha, a, lda, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn zhpmv_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_double_complex,
+    ap: *const c_double_complex,
+    x: *const c_double_complex,
+    incx: *const i64,
+    beta: *const c_double_complex,
+    y: *mut c_double_complex,
+    incy: *const i64,
+) {
+    zhpmv(uplo, n, alpha, ap, x, incx, beta, y, incy, 1)
+}
+
+#[inline]
+pub unsafe fn ztrmv_64_(
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    a: *const c_double_complex,
+    lda: *const i64,
+    b: *mut c_double_complex,
+    incx: *const i64,
+) {
+    ztrmv(uplo, transa, diag, n, a, lda, b, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ztbmv_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    a: *const c_double_complex,
+    lda: *const i64,
+    x: *mut c_double_complex,
+    incx: *const i64,
+) {
+    ztbmv(uplo, trans, diag, n, k, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ztpmv_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    ap: *const c_double_complex,
+    x: *mut c_double_complex,
+    incx: *const i64,
+) {
+    ztpmv(uplo, trans, diag, n, ap, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ztrsv_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    a: *const c_double_complex,
+    lda: *const i64,
+    x: *mut c_double_complex,
+    incx: *const i64,
+) {
+    ztrsv(uplo, trans, diag, n, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ztbsv_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    a: *const c_double_complex,
+    lda: *const i64,
+    x: *mut c_double_complex,
+    incx: *const i64,
+) {
+    ztbsv(uplo, trans, diag, n, k, a, lda, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ztpsv_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const i64,
+    ap: *const c_double_complex,
+    x: *mut c_double_complex,
+    incx: *const i64,
+) {
+    ztpsv(uplo, trans, diag, n, ap, x, incx, 1, 1, 1)
+}
+
+#[inline]
+pub unsafe fn zher_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_double,
+    x: *const c_double_complex,
+    incx: *const i64,
+    a: *mut c_double_complex,
+    lda: *const i64,
+) {
+    zher(uplo, n, alpha, x, incx, a, lda, 1)
+}
+
+#[inline]
+pub unsafe fn zhpr_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_double,
+    x: *const c_double_complex,
+    incx: *const i64,
+    ap: *mut c_double_complex,
+) {
+    zhpr(uplo, n, alpha, x, incx, ap, 1)
+}
+
+#[inline]
+pub unsafe fn zher2_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_double_complex,
+    x: *const c_double_complex,
+    incx: *const i64,
+    y: *const c_double_complex,
+    incy: *const i64,
+    a: *mut c_double_complex,
+    lda: *const i64,
+) {
+    zher2(uplo, n, alpha, x, incx, y, incy, a, lda, 1)
+}
+
+#[inline]
+pub unsafe fn zhpr2_64_(
+    uplo: *const c_char,
+    n: *const i64,
+    alpha: *const c_double_complex,
+    x: *const c_double_complex,
+    incx: *const i64,
+    y: *const c_double_complex,
+    incy: *const i64,
+    ap: *mut c_double_complex,
+) {
+    zhpr2(uplo, n, alpha, x, incx, y, incy, ap, 1)
+}
+
+extern "C" {
+    #[link_name = "sgemm_64_"]
+    fn sgemm(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        b: *const c_float,
+        ldb: *const i64,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const i64,
+        transa_len: size_t,
+        transb_len: size_t,
+    );
+    #[link_name = "ssymm_64_"]
+    fn ssymm(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        b: *const c_float,
+        ldb: *const i64,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const i64,
+        side_len: size_t,
+        uplo_len: size_t,
+    );
+    #[link_name = "ssyrk_64_"]
+    fn ssyrk(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "ssyr2k_64_"]
+    fn ssyr2k(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        b: *const c_float,
+        ldb: *const i64,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "strmm_64_"]
+    fn strmm(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        b: *mut c_float,
+        ldb: *const i64,
+        side_len: size_t,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "strsm_64_"]
+    fn strsm(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        b: *mut c_float,
+        ldb: *const i64,
+        side_len: size_t,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "dgemm_64_"]
+    fn dgemm(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        b: *const c_double,
+        ldb: *const i64,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const i64,
+        transa_len: size_t,
+        transb_len: size_t,
+    );
+    #[link_name = "dsymm_64_"]
+    fn dsymm(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        b: *const c_double,
+        ldb: *const i64,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const i64,
+        side_len: size_t,
+        uplo_len: size_t,
+    );
+    #[link_name = "dsyrk_64_"]
+    fn dsyrk(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "dsyr2k_64_"]
+    fn dsyr2k(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        b: *const c_double,
+        ldb: *const i64,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "dtrmm_64_"]
+    fn dtrmm(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        b: *mut c_double,
+        ldb: *const i64,
+        side_len: size_t,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "dtrsm_64_"]
+    fn dtrsm(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        b: *mut c_double,
+        ldb: *const i64,
+        side_len: size_t,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "cgemm_64_"]
+    fn cgemm(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *const c_float_complex,
+        ldb: *const i64,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const i64,
+        transa_len: size_t,
+        transb_len: size_t,
+    );
+    #[link_name = "csymm_64_"]
+    fn csymm(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *const c_float_complex,
+        ldb: *const i64,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const i64,
+        side_len: size_t,
+        uplo_len: size_t,
+    );
+    #[link_name = "chemm_64_"]
+    fn chemm(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *const c_float_complex,
+        ldb: *const i64,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const i64,
+        side_len: size_t,
+        uplo_len: size_t,
+    );
+    #[link_name = "csyrk_64_"]
+    fn csyrk(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "cherk_64_"]
+    fn cherk(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float,
+        a: *const c_float_complex,
+        lda: *const i64,
+        beta: *const c_float,
+        c: *mut c_float_complex,
+        ldc: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "csyr2k_64_"]
+    fn csyr2k(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *const c_float_complex,
+        ldb: *const i64,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "cher2k_64_"]
+    fn cher2k(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *const c_float_complex,
+        ldb: *const i64,
+        beta: *const c_float,
+        c: *mut c_float_complex,
+        ldc: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "ctrmm_64_"]
+    fn ctrmm(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *mut c_float_complex,
+        ldb: *const i64,
+        side_len: size_t,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ctrsm_64_"]
+    fn ctrsm(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *mut c_float_complex,
+        ldb: *const i64,
+        side_len: size_t,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "zgemm_64_"]
+    fn zgemm(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *const c_double_complex,
+        ldb: *const i64,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const i64,
+        transa_len: size_t,
+        transb_len: size_t,
+    );
+    #[link_name = "zsymm_64_"]
+    fn zsymm(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *const c_double_complex,
+        ldb: *const i64,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const i64,
+        side_len: size_t,
+        uplo_len: size_t,
+    );
+    #[link_name = "zhemm_64_"]
+    fn zhemm(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *const c_double_complex,
+        ldb: *const i64,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const i64,
+        side_len: size_t,
+        uplo_len: size_t,
+    );
+    #[link_name = "zsyrk_64_"]
+    fn zsyrk(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "zherk_64_"]
+    fn zherk(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double,
+        a: *const c_double_complex,
+        lda: *const i64,
+        beta: *const c_double,
+        c: *mut c_double_complex,
+        ldc: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "zsyr2k_64_"]
+    fn zsyr2k(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *const c_double_complex,
+        ldb: *const i64,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "zher2k_64_"]
+    fn zher2k(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *const c_double_complex,
+        ldb: *const i64,
+        beta: *const c_double,
+        c: *mut c_double_complex,
+        ldc: *const i64,
+        uplo_len: size_t,
+        trans_len: size_t,
+    );
+    #[link_name = "ztrmm_64_"]
+    fn ztrmm(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *mut c_double_complex,
+        ldb: *const i64,
+        side_len: size_t,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+    #[link_name = "ztrsm_64_"]
+    fn ztrsm(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *mut c_double_complex,
+        ldb: *const i64,
+        side_len: size_t,
+        uplo_len: size_t,
+        transa_len: size_t,
+        diag_len: size_t,
+    );
+}
+
+#[inline]
+pub unsafe fn sgemm_64_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    k: *const i64,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const i64,
+    b: *const c_float,
+    ldb: *const i64,
+    beta: *const c_float,
+    c: *mut c_float,
+    ldc: *const i64,
+) {
+    sgemm(
+        transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn ssymm_64_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const i64,
+    b: *const c_float,
+    ldb: *const i64,
+    beta: *const c_float,
+    c: *mut c_float,
+    ldc: *const i64,
+) {
+    ssymm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ssyrk_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const i64,
+    beta: *const c_float,
+    c: *mut c_float,
+    ldc: *const i64,
+) {
+    ssyrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ssyr2k_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const i64,
+    b: *const c_float,
+    ldb: *const i64,
+    beta: *const c_float,
+    c: *mut c_float,
+    ldc: *const i64,
+) {
+    ssyr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn strmm_64_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const i64,
+    b: *mut c_float,
+    ldb: *const i64,
+) {
+    strmm(
+        side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb, 1, 1, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn strsm_64_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const i64,
+    b: *mut c_float,
+    ldb: *const i64,
+) {
+    strsm(
+        side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb, 1, 1, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn dgemm_64_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    k: *const i64,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const i64,
+    b: *const c_double,
+    ldb: *const i64,
+    beta: *const c_double,
+    c: *mut c_double,
+    ldc: *const i64,
+) {
+    dgemm(
+        transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn dsymm_64_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const i64,
+    b: *const c_double,
+    ldb: *const i64,
+    beta: *const c_double,
+    c: *mut c_double,
+    ldc: *const i64,
+) {
+    dsymm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn dsyrk_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const i64,
+    beta: *const c_double,
+    c: *mut c_double,
+    ldc: *const i64,
+) {
+    dsyrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn dsyr2k_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const i64,
+    b: *const c_double,
+    ldb: *const i64,
+    beta: *const c_double,
+    c: *mut c_double,
+    ldc: *const i64,
+) {
+    dsyr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn dtrmm_64_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const i64,
+    b: *mut c_double,
+    ldb: *const i64,
+) {
+    dtrmm(
+        side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb, 1, 1, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn dtrsm_64_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const i64,
+    b: *mut c_double,
+    ldb: *const i64,
+) {
+    dtrsm(
+        side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb, 1, 1, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn cgemm_64_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    k: *const i64,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const i64,
+    b: *const c_float_complex,
+    ldb: *const i64,
+    beta: *const c_float_complex,
+    c: *mut c_float_complex,
+    ldc: *const i64,
+) {
+    cgemm(
+        transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn csymm_64_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const i64,
+    b: *const c_float_complex,
+    ldb: *const i64,
+    beta: *const c_float_complex,
+    c: *mut c_float_complex,
+    ldc: *const i64,
+) {
+    csymm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn chemm_64_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const i64,
+    b: *const c_float_complex,
+    ldb: *const i64,
+    beta: *const c_float_complex,
+    c: *mut c_float_complex,
+    ldc: *const i64,
+) {
+    chemm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn csyrk_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const i64,
+    beta: *const c_float_complex,
+    c: *mut c_float_complex,
+    ldc: *const i64,
+) {
+    csyrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn cherk_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    alpha: *const c_float,
+    a: *const c_float_complex,
+    lda: *const i64,
+    beta: *const c_float,
+    c: *mut c_float_complex,
+    ldc: *const i64,
+) {
+    cherk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn csyr2k_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const i64,
+    b: *const c_float_complex,
+    ldb: *const i64,
+    beta: *const c_float_complex,
+    c: *mut c_float_complex,
+    ldc: *const i64,
+) {
+    csyr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn cher2k_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const i64,
+    b: *const c_float_complex,
+    ldb: *const i64,
+    beta: *const c_float,
+    c: *mut c_float_complex,
+    ldc: *const i64,
+) {
+    cher2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ctrmm_64_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const i64,
+    b: *mut c_float_complex,
+    ldb: *const i64,
+) {
+    ctrmm(
+        side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb, 1, 1, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn ctrsm_64_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    alpha: *const c_float_complex,
+    a: *const c_float_complex,
+    lda: *const i64,
+    b: *mut c_float_complex,
+    ldb: *const i64,
+) {
+    ctrsm(
+        side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb, 1, 1, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn zgemm_64_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    k: *const i64,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const i64,
+    b: *const c_double_complex,
+    ldb: *const i64,
+    beta: *const c_double_complex,
+    c: *mut c_double_complex,
+    ldc: *const i64,
+) {
+    zgemm(
+        transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn zsymm_64_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const i64,
+    b: *const c_double_complex,
+    ldb: *const i64,
+    beta: *const c_double_complex,
+    c: *mut c_double_complex,
+    ldc: *const i64,
+) {
+    zsymm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn zhemm_64_(
+    side: *const c_char,
+    uplo: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const i64,
+    b: *const c_double_complex,
+    ldb: *const i64,
+    beta: *const c_double_complex,
+    c: *mut c_double_complex,
+    ldc: *const i64,
+) {
+    zhemm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn zsyrk_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const i64,
+    beta: *const c_double_complex,
+    c: *mut c_double_complex,
+    ldc: *const i64,
+) {
+    zsyrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn zherk_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    alpha: *const c_double,
+    a: *const c_double_complex,
+    lda: *const i64,
+    beta: *const c_double,
+    c: *mut c_double_complex,
+    ldc: *const i64,
+) {
+    zherk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn zsyr2k_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const i64,
+    b: *const c_double_complex,
+    ldb: *const i64,
+    beta: *const c_double_complex,
+    c: *mut c_double_complex,
+    ldc: *const i64,
+) {
+    zsyr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn zher2k_64_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    n: *const i64,
+    k: *const i64,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const i64,
+    b: *const c_double_complex,
+    ldb: *const i64,
+    beta: *const c_double,
+    c: *mut c_double_complex,
+    ldc: *const i64,
+) {
+    zher2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc, 1, 1)
+}
+
+#[inline]
+pub unsafe fn ztrmm_64_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const i64,
+    b: *mut c_double_complex,
+    ldb: *const i64,
+) {
+    ztrmm(
+        side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb, 1, 1, 1, 1,
+    )
+}
+
+#[inline]
+pub unsafe fn ztrsm_64_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    m: *const i64,
+    n: *const i64,
+    alpha: *const c_double_complex,
+    a: *const c_double_complex,
+    lda: *const i64,
+    b: *mut c_double_complex,
+    ldb: *const i64,
+) {
+    ztrsm(
+        side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb, 1, 1, 1, 1,
+    )
+}
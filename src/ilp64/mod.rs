@@ -0,0 +1,4094 @@
+//! Wrappers for the ILP64 interface.
+//!
+//! ILP64 builds of BLAS, such as those of OpenBLAS and MKL, take 64-bit
+//! integers for dimensions, leading dimensions, and increments, which lifts
+//! the limit of 2^31 - 1 elements per dimension imposed by the LP64 interface
+//! behind the wrappers at the root of the crate. The wrappers of this module,
+//! available with the `ilp64` feature, mirror those at the root but take and
+//! return `i64` and call the routines under the names suffixed with `64_`,
+//! such as `dgemm_64_`, which is how OpenBLAS names them when built with
+//! `INTERFACE64=1 SYMBOLSUFFIX=64_`.
+//!
+//! The routines are called natively, and the library providing them has to
+//! be linked as usual, unless the `dynamic` feature is enabled, in which case
+//! they are looked up in the library loaded at run time. The `reference`
+//! feature provides no ILP64 interface and cannot be combined with this one
+//! without the `dynamic` feature, which takes precedence. The
+//! `complex-by-value` feature applies.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use blas::ilp64::*;
+//!
+//! let n = 3_000_000_000;
+//! let x = vec![1.0; n as usize];
+//! let sum = unsafe { dasum(n, &x, 1) };
+//! ```
+
+use libc::c_char;
+
+use complex;
+use {c32, c64};
+
+#[cfg(all(feature = "hidden-length", not(feature = "dynamic")))]
+pub(crate) mod hidden;
+#[cfg(not(feature = "dynamic"))]
+pub(crate) mod sys;
+
+#[cfg(all(feature = "hidden-length", not(feature = "dynamic")))]
+pub(crate) use self::hidden as ffi;
+#[cfg(not(any(feature = "dynamic", feature = "hidden-length")))]
+pub(crate) use self::sys as ffi;
+#[cfg(feature = "dynamic")]
+pub(crate) use dynamic::ilp64 as ffi;
+
+#[inline]
+pub unsafe fn srotg(a: &mut f32, b: &mut f32, c: &mut f32, s: &mut f32) {
+    trace!(srotg, [], [], [], [a, b, c, s], ffi::srotg_64_(a, b, c, s))
+}
+
+#[inline]
+pub unsafe fn srotmg(d1: &mut f32, d2: &mut f32, x1: &mut f32, y1: f32, param: &mut [f32]) {
+    trace!(
+        srotmg,
+        [],
+        [],
+        [],
+        [d1, d2, x1, y1, param],
+        ffi::srotmg_64_(d1, d2, x1, &y1, param.as_mut_ptr())
+    )
+}
+
+#[inline]
+pub unsafe fn srot(n: i64, x: &mut [f32], incx: i64, y: &mut [f32], incy: i64, c: f32, s: f32) {
+    trace!(
+        srot,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy, c, s],
+        ffi::srot_64_(&n, x.as_mut_ptr(), &incx, y.as_mut_ptr(), &incy, &c, &s)
+    )
+}
+
+#[inline]
+pub unsafe fn srotm(n: i64, x: &mut [f32], incx: i64, y: &mut [f32], incy: i64, param: &[f32]) {
+    trace!(
+        srotm,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy, param],
+        ffi::srotm_64_(
+            &n,
+            x.as_mut_ptr(),
+            &incx,
+            y.as_mut_ptr(),
+            &incy,
+            param.as_ptr()
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn sswap(n: i64, x: &mut [f32], incx: i64, y: &mut [f32], incy: i64) {
+    trace!(
+        sswap,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::sswap_64_(&n, x.as_mut_ptr(), &incx, y.as_mut_ptr(), &incy)
+    )
+}
+
+#[inline]
+pub unsafe fn sscal(n: i64, a: f32, x: &mut [f32], incx: i64) {
+    trace!(
+        sscal,
+        [],
+        [n],
+        [],
+        [n, a, x, incx],
+        ffi::sscal_64_(&n, &a, x.as_mut_ptr(), &incx)
+    )
+}
+
+#[inline]
+pub unsafe fn scopy(n: i64, x: &[f32], incx: i64, y: &mut [f32], incy: i64) {
+    trace!(
+        scopy,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::scopy_64_(&n, x.as_ptr(), &incx, y.as_mut_ptr(), &incy)
+    )
+}
+
+#[inline]
+pub unsafe fn saxpy(n: i64, alpha: f32, x: &[f32], incx: i64, y: &mut [f32], incy: i64) {
+    trace!(
+        saxpy,
+        [],
+        [n],
+        [],
+        [n, alpha, x, incx, y, incy],
+        ffi::saxpy_64_(&n, &alpha, x.as_ptr(), &incx, y.as_mut_ptr(), &incy)
+    )
+}
+
+#[inline]
+pub unsafe fn sdot(n: i64, x: &[f32], incx: i64, y: &[f32], incy: i64) -> f32 {
+    trace!(
+        sdot,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::sdot_64_(&n, x.as_ptr(), &incx, y.as_ptr(), &incy)
+    )
+}
+
+#[inline]
+pub unsafe fn sdsdot(n: i64, sb: &[f32], x: &[f32], incx: i64, y: &[f32], incy: i64) -> f32 {
+    trace!(
+        sdsdot,
+        [],
+        [n],
+        [],
+        [n, sb, x, incx, y, incy],
+        ffi::sdsdot_64_(&n, sb.as_ptr(), x.as_ptr(), &incx, y.as_ptr(), &incy)
+    )
+}
+
+#[inline]
+pub unsafe fn snrm2(n: i64, x: &[f32], incx: i64) -> f32 {
+    trace!(
+        snrm2,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::snrm2_64_(&n, x.as_ptr(), &incx)
+    )
+}
+
+#[inline]
+pub unsafe fn scnrm2(n: i64, x: &[c32], incx: i64) -> f32 {
+    trace!(
+        scnrm2,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::scnrm2_64_(&n, x.as_ptr() as *const _, &incx)
+    )
+}
+
+#[inline]
+pub unsafe fn sasum(n: i64, x: &[f32], incx: i64) -> f32 {
+    trace!(
+        sasum,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::sasum_64_(&n, x.as_ptr(), &incx)
+    )
+}
+
+#[inline]
+pub unsafe fn isamax(n: i64, x: &[f32], incx: i64) -> i64 {
+    trace!(
+        isamax,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::isamax_64_(&n, x.as_ptr(), &incx)
+    )
+}
+
+#[inline]
+pub unsafe fn drotg(a: &mut f64, b: &mut f64, c: &mut f64, s: &mut f64) {
+    trace!(drotg, [], [], [], [a, b, c, s], ffi::drotg_64_(a, b, c, s))
+}
+
+#[inline]
+pub unsafe fn drotmg(d1: &mut f64, d2: &mut f64, x1: &mut f64, y1: f64, param: &mut [f64]) {
+    trace!(
+        drotmg,
+        [],
+        [],
+        [],
+        [d1, d2, x1, y1, param],
+        ffi::drotmg_64_(d1, d2, x1, &y1, param.as_mut_ptr())
+    )
+}
+
+#[inline]
+pub unsafe fn drot(n: i64, x: &mut [f64], incx: i64, y: &mut [f64], incy: i64, c: f64, s: f64) {
+    trace!(
+        drot,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy, c, s],
+        ffi::drot_64_(&n, x.as_mut_ptr(), &incx, y.as_mut_ptr(), &incy, &c, &s)
+    )
+}
+
+#[inline]
+pub unsafe fn drotm(n: i64, x: &mut [f64], incx: i64, y: &mut [f64], incy: i64, param: &[f64]) {
+    trace!(
+        drotm,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy, param],
+        ffi::drotm_64_(
+            &n,
+            x.as_mut_ptr(),
+            &incx,
+            y.as_mut_ptr(),
+            &incy,
+            param.as_ptr()
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dswap(n: i64, x: &mut [f64], incx: i64, y: &mut [f64], incy: i64) {
+    trace!(
+        dswap,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::dswap_64_(&n, x.as_mut_ptr(), &incx, y.as_mut_ptr(), &incy)
+    )
+}
+
+#[inline]
+pub unsafe fn dscal(n: i64, a: f64, x: &mut [f64], incx: i64) {
+    trace!(
+        dscal,
+        [],
+        [n],
+        [],
+        [n, a, x, incx],
+        ffi::dscal_64_(&n, &a, x.as_mut_ptr(), &incx)
+    )
+}
+
+#[inline]
+pub unsafe fn dcopy(n: i64, x: &[f64], incx: i64, y: &mut [f64], incy: i64) {
+    trace!(
+        dcopy,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::dcopy_64_(&n, x.as_ptr(), &incx, y.as_mut_ptr(), &incy)
+    )
+}
+
+#[inline]
+pub unsafe fn daxpy(n: i64, alpha: f64, x: &[f64], incx: i64, y: &mut [f64], incy: i64) {
+    trace!(
+        daxpy,
+        [],
+        [n],
+        [],
+        [n, alpha, x, incx, y, incy],
+        ffi::daxpy_64_(&n, &alpha, x.as_ptr(), &incx, y.as_mut_ptr(), &incy)
+    )
+}
+
+#[inline]
+pub unsafe fn ddot(n: i64, x: &[f64], incx: i64, y: &[f64], incy: i64) -> f64 {
+    trace!(
+        ddot,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::ddot_64_(&n, x.as_ptr(), &incx, y.as_ptr(), &incy)
+    )
+}
+
+#[inline]
+pub unsafe fn dsdot(n: i64, x: &[f32], incx: i64, y: &[f32], incy: i64) -> f64 {
+    trace!(
+        dsdot,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::dsdot_64_(&n, x.as_ptr(), &incx, y.as_ptr(), &incy)
+    )
+}
+
+#[inline]
+pub unsafe fn dnrm2(n: i64, x: &[f64], incx: i64) -> f64 {
+    trace!(
+        dnrm2,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::dnrm2_64_(&n, x.as_ptr(), &incx)
+    )
+}
+
+#[inline]
+pub unsafe fn dznrm2(n: i64, x: &[c64], incx: i64) -> f64 {
+    trace!(
+        dznrm2,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::dznrm2_64_(&n, x.as_ptr() as *const _, &incx)
+    )
+}
+
+#[inline]
+pub unsafe fn dasum(n: i64, x: &[f64], incx: i64) -> f64 {
+    trace!(
+        dasum,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::dasum_64_(&n, x.as_ptr(), &incx)
+    )
+}
+
+#[inline]
+pub unsafe fn idamax(n: i64, x: &[f64], incx: i64) -> i64 {
+    trace!(
+        idamax,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::idamax_64_(&n, x.as_ptr(), &incx)
+    )
+}
+
+#[inline]
+pub unsafe fn crotg(a: &mut c32, b: c32, c: &mut f32, s: &mut c32) {
+    trace!(
+        crotg,
+        [],
+        [],
+        [],
+        [a, b, c, s],
+        ffi::crotg_64_(
+            a as *mut _ as *mut _,
+            &b as *const _ as *const _,
+            c,
+            s as *mut _ as *mut _
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn csrot(n: i64, x: &mut [c32], incx: i64, y: &mut [c32], incy: i64, c: f32, s: f32) {
+    trace!(
+        csrot,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy, c, s],
+        ffi::csrot_64_(
+            &n,
+            x.as_mut_ptr() as *mut _,
+            &incx,
+            y.as_mut_ptr() as *mut _,
+            &incy,
+            &c,
+            &s
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn cswap(n: i64, x: &mut [c32], incx: i64, y: &mut [c32], incy: i64) {
+    trace!(
+        cswap,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::cswap_64_(
+            &n,
+            x.as_mut_ptr() as *mut _,
+            &incx,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn cscal(n: i64, a: c32, x: &mut [c32], incx: i64) {
+    trace!(
+        cscal,
+        [],
+        [n],
+        [],
+        [n, a, x, incx],
+        ffi::cscal_64_(
+            &n,
+            &a as *const _ as *const _,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn csscal(n: i64, a: f32, x: &mut [c32], incx: i64) {
+    trace!(
+        csscal,
+        [],
+        [n],
+        [],
+        [n, a, x, incx],
+        ffi::csscal_64_(&n, &a, x.as_mut_ptr() as *mut _, &incx)
+    )
+}
+
+#[inline]
+pub unsafe fn ccopy(n: i64, x: &[c32], incx: i64, y: &mut [c32], incy: i64) {
+    trace!(
+        ccopy,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::ccopy_64_(
+            &n,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn caxpy(n: i64, alpha: c32, x: &[c32], incx: i64, y: &mut [c32], incy: i64) {
+    trace!(
+        caxpy,
+        [],
+        [n],
+        [],
+        [n, alpha, x, incx, y, incy],
+        ffi::caxpy_64_(
+            &n,
+            &alpha as *const _ as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn cdotu(n: i64, x: &[c32], incx: i64, y: &[c32], incy: i64) -> c32 {
+    trace!(
+        cdotu,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        complex::ilp64::cdotu_64_(
+            &n,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn cdotc(n: i64, x: &[c32], incx: i64, y: &[c32], incy: i64) -> c32 {
+    trace!(
+        cdotc,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        complex::ilp64::cdotc_64_(
+            &n,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn scasum(n: i64, x: &[c32], incx: i64) -> f32 {
+    trace!(
+        scasum,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::scasum_64_(&n, x.as_ptr() as *const _, &incx)
+    )
+}
+
+#[inline]
+pub unsafe fn icamax(n: i64, x: &[c32], incx: i64) -> i64 {
+    trace!(
+        icamax,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::icamax_64_(&n, x.as_ptr() as *const _, &incx)
+    )
+}
+
+#[inline]
+pub unsafe fn zrotg(a: &mut c64, b: c64, c: &mut f64, s: &mut c64) {
+    trace!(
+        zrotg,
+        [],
+        [],
+        [],
+        [a, b, c, s],
+        ffi::zrotg_64_(
+            a as *mut _ as *mut _,
+            &b as *const _ as *const _,
+            c,
+            s as *mut _ as *mut _
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zdrot(n: i64, x: &mut [c64], incx: i64, y: &mut [c64], incy: i64, c: f64, s: f64) {
+    trace!(
+        zdrot,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy, c, s],
+        ffi::zdrot_64_(
+            &n,
+            x.as_mut_ptr() as *mut _,
+            &incx,
+            y.as_mut_ptr() as *mut _,
+            &incy,
+            &c,
+            &s
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zswap(n: i64, x: &mut [c64], incx: i64, y: &mut [c64], incy: i64) {
+    trace!(
+        zswap,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::zswap_64_(
+            &n,
+            x.as_mut_ptr() as *mut _,
+            &incx,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zscal(n: i64, a: c64, x: &mut [c64], incx: i64) {
+    trace!(
+        zscal,
+        [],
+        [n],
+        [],
+        [n, a, x, incx],
+        ffi::zscal_64_(
+            &n,
+            &a as *const _ as *const _,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zdscal(n: i64, a: f64, x: &mut [c64], incx: i64) {
+    trace!(
+        zdscal,
+        [],
+        [n],
+        [],
+        [n, a, x, incx],
+        ffi::zdscal_64_(&n, &a, x.as_mut_ptr() as *mut _, &incx)
+    )
+}
+
+#[inline]
+pub unsafe fn zcopy(n: i64, x: &[c64], incx: i64, y: &mut [c64], incy: i64) {
+    trace!(
+        zcopy,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::zcopy_64_(
+            &n,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zaxpy(n: i64, alpha: c64, x: &[c64], incx: i64, y: &mut [c64], incy: i64) {
+    trace!(
+        zaxpy,
+        [],
+        [n],
+        [],
+        [n, alpha, x, incx, y, incy],
+        ffi::zaxpy_64_(
+            &n,
+            &alpha as *const _ as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zdotu(n: i64, x: &[c64], incx: i64, y: &[c64], incy: i64) -> c64 {
+    trace!(
+        zdotu,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        complex::ilp64::zdotu_64_(
+            &n,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zdotc(n: i64, x: &[c64], incx: i64, y: &[c64], incy: i64) -> c64 {
+    trace!(
+        zdotc,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        complex::ilp64::zdotc_64_(
+            &n,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dzasum(n: i64, x: &[c64], incx: i64) -> f64 {
+    trace!(
+        dzasum,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::dzasum_64_(&n, x.as_ptr() as *const _, &incx)
+    )
+}
+
+#[inline]
+pub unsafe fn izamax(n: i64, x: &[c64], incx: i64) -> i64 {
+    trace!(
+        izamax,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::izamax_64_(&n, x.as_ptr() as *const _, &incx)
+    )
+}
+
+#[inline]
+pub unsafe fn sgemv(
+    trans: impl Into<u8>,
+    m: i64,
+    n: i64,
+    alpha: f32,
+    a: &[f32],
+    lda: i64,
+    x: &[f32],
+    incx: i64,
+    beta: f32,
+    y: &mut [f32],
+    incy: i64,
+) {
+    let trans: u8 = trans.into();
+    trace!(
+        sgemv,
+        [trans],
+        [m, n],
+        [lda],
+        [trans, m, n, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::sgemv_64_(
+            &(trans as c_char),
+            &m,
+            &n,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            x.as_ptr(),
+            &incx,
+            &beta,
+            y.as_mut_ptr(),
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn sgbmv(
+    trans: impl Into<u8>,
+    m: i64,
+    n: i64,
+    kl: i64,
+    ku: i64,
+    alpha: f32,
+    a: &[f32],
+    lda: i64,
+    x: &[f32],
+    incx: i64,
+    beta: f32,
+    y: &mut [f32],
+    incy: i64,
+) {
+    let trans: u8 = trans.into();
+    trace!(
+        sgbmv,
+        [trans],
+        [m, n, kl, ku],
+        [lda],
+        [trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::sgbmv_64_(
+            &(trans as c_char),
+            &m,
+            &n,
+            &kl,
+            &ku,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            x.as_ptr(),
+            &incx,
+            &beta,
+            y.as_mut_ptr(),
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ssymv(
+    uplo: impl Into<u8>,
+    n: i64,
+    alpha: f32,
+    a: &[f32],
+    lda: i64,
+    x: &[f32],
+    incx: i64,
+    beta: f32,
+    y: &mut [f32],
+    incy: i64,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        ssymv,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::ssymv_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            x.as_ptr(),
+            &incx,
+            &beta,
+            y.as_mut_ptr(),
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ssbmv(
+    uplo: impl Into<u8>,
+    n: i64,
+    k: i64,
+    alpha: f32,
+    a: &[f32],
+    lda: i64,
+    x: &[f32],
+    incx: i64,
+    beta: f32,
+    y: &mut [f32],
+    incy: i64,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        ssbmv,
+        [uplo],
+        [n, k],
+        [lda],
+        [uplo, n, k, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::ssbmv_64_(
+            &(uplo as c_char),
+            &n,
+            &k,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            x.as_ptr(),
+            &incx,
+            &beta,
+            y.as_mut_ptr(),
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn sspmv(
+    uplo: impl Into<u8>,
+    n: i64,
+    alpha: f32,
+    ap: &[f32],
+    x: &[f32],
+    incx: i64,
+    beta: f32,
+    y: &mut [f32],
+    incy: i64,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        sspmv,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, ap, x, incx, beta, y, incy],
+        ffi::sspmv_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            ap.as_ptr(),
+            x.as_ptr(),
+            &incx,
+            &beta,
+            y.as_mut_ptr(),
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn strmv(
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    a: &[f32],
+    lda: i64,
+    b: &mut [f32],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        strmv,
+        [uplo, transa, diag],
+        [n],
+        [lda],
+        [uplo, transa, diag, n, a, lda, b, incx],
+        ffi::strmv_64_(
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &n,
+            a.as_ptr(),
+            &lda,
+            b.as_mut_ptr(),
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn stbmv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    k: i64,
+    a: &[f32],
+    lda: i64,
+    x: &mut [f32],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        stbmv,
+        [uplo, trans, diag],
+        [n, k],
+        [lda],
+        [uplo, trans, diag, n, k, a, lda, x, incx],
+        ffi::stbmv_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            &k,
+            a.as_ptr(),
+            &lda,
+            x.as_mut_ptr(),
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn stpmv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    ap: &[f32],
+    x: &mut [f32],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        stpmv,
+        [uplo, trans, diag],
+        [n],
+        [],
+        [uplo, trans, diag, n, ap, x, incx],
+        ffi::stpmv_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            ap.as_ptr(),
+            x.as_mut_ptr(),
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn strsv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    a: &[f32],
+    lda: i64,
+    x: &mut [f32],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        strsv,
+        [uplo, trans, diag],
+        [n],
+        [lda],
+        [uplo, trans, diag, n, a, lda, x, incx],
+        ffi::strsv_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            a.as_ptr(),
+            &lda,
+            x.as_mut_ptr(),
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn stbsv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    k: i64,
+    a: &[f32],
+    lda: i64,
+    x: &mut [f32],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        stbsv,
+        [uplo, trans, diag],
+        [n, k],
+        [lda],
+        [uplo, trans, diag, n, k, a, lda, x, incx],
+        ffi::stbsv_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            &k,
+            a.as_ptr(),
+            &lda,
+            x.as_mut_ptr(),
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn stpsv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    ap: &[f32],
+    x: &mut [f32],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        stpsv,
+        [uplo, trans, diag],
+        [n],
+        [],
+        [uplo, trans, diag, n, ap, x, incx],
+        ffi::stpsv_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            ap.as_ptr(),
+            x.as_mut_ptr(),
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn sger(
+    m: i64,
+    n: i64,
+    alpha: f32,
+    x: &[f32],
+    incx: i64,
+    y: &[f32],
+    incy: i64,
+    a: &mut [f32],
+    lda: i64,
+) {
+    trace!(
+        sger,
+        [],
+        [m, n],
+        [lda],
+        [m, n, alpha, x, incx, y, incy, a, lda],
+        ffi::sger_64_(
+            &m,
+            &n,
+            &alpha,
+            x.as_ptr(),
+            &incx,
+            y.as_ptr(),
+            &incy,
+            a.as_mut_ptr(),
+            &lda
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ssyr(
+    uplo: impl Into<u8>,
+    n: i64,
+    alpha: f32,
+    x: &[f32],
+    incx: i64,
+    a: &mut [f32],
+    lda: i64,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        ssyr,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, x, incx, a, lda],
+        ffi::ssyr_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr(),
+            &incx,
+            a.as_mut_ptr(),
+            &lda
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn sspr(uplo: impl Into<u8>, n: i64, alpha: f32, x: &[f32], incx: i64, ap: &mut [f32]) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        sspr,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, x, incx, ap],
+        ffi::sspr_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr(),
+            &incx,
+            ap.as_mut_ptr()
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ssyr2(
+    uplo: impl Into<u8>,
+    n: i64,
+    alpha: f32,
+    x: &[f32],
+    incx: i64,
+    y: &[f32],
+    incy: i64,
+    a: &mut [f32],
+    lda: i64,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        ssyr2,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, x, incx, y, incy, a, lda],
+        ffi::ssyr2_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr(),
+            &incx,
+            y.as_ptr(),
+            &incy,
+            a.as_mut_ptr(),
+            &lda
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn sspr2(
+    uplo: impl Into<u8>,
+    n: i64,
+    alpha: f32,
+    x: &[f32],
+    incx: i64,
+    y: &[f32],
+    incy: i64,
+    ap: &mut [f32],
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        sspr2,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, x, incx, y, incy, ap],
+        ffi::sspr2_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr(),
+            &incx,
+            y.as_ptr(),
+            &incy,
+            ap.as_mut_ptr()
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dgemv(
+    trans: impl Into<u8>,
+    m: i64,
+    n: i64,
+    alpha: f64,
+    a: &[f64],
+    lda: i64,
+    x: &[f64],
+    incx: i64,
+    beta: f64,
+    y: &mut [f64],
+    incy: i64,
+) {
+    let trans: u8 = trans.into();
+    trace!(
+        dgemv,
+        [trans],
+        [m, n],
+        [lda],
+        [trans, m, n, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::dgemv_64_(
+            &(trans as c_char),
+            &m,
+            &n,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            x.as_ptr(),
+            &incx,
+            &beta,
+            y.as_mut_ptr(),
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dgbmv(
+    trans: impl Into<u8>,
+    m: i64,
+    n: i64,
+    kl: i64,
+    ku: i64,
+    alpha: f64,
+    a: &[f64],
+    lda: i64,
+    x: &[f64],
+    incx: i64,
+    beta: f64,
+    y: &mut [f64],
+    incy: i64,
+) {
+    let trans: u8 = trans.into();
+    trace!(
+        dgbmv,
+        [trans],
+        [m, n, kl, ku],
+        [lda],
+        [trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::dgbmv_64_(
+            &(trans as c_char),
+            &m,
+            &n,
+            &kl,
+            &ku,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            x.as_ptr(),
+            &incx,
+            &beta,
+            y.as_mut_ptr(),
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dsymv(
+    uplo: impl Into<u8>,
+    n: i64,
+    alpha: f64,
+    a: &[f64],
+    lda: i64,
+    x: &[f64],
+    incx: i64,
+    beta: f64,
+    y: &mut [f64],
+    incy: i64,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        dsymv,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::dsymv_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            x.as_ptr(),
+            &incx,
+            &beta,
+            y.as_mut_ptr(),
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dsbmv(
+    uplo: impl Into<u8>,
+    n: i64,
+    k: i64,
+    alpha: f64,
+    a: &[f64],
+    lda: i64,
+    x: &[f64],
+    incx: i64,
+    beta: f64,
+    y: &mut [f64],
+    incy: i64,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        dsbmv,
+        [uplo],
+        [n, k],
+        [lda],
+        [uplo, n, k, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::dsbmv_64_(
+            &(uplo as c_char),
+            &n,
+            &k,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            x.as_ptr(),
+            &incx,
+            &beta,
+            y.as_mut_ptr(),
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dspmv(
+    uplo: impl Into<u8>,
+    n: i64,
+    alpha: f64,
+    ap: &[f64],
+    x: &[f64],
+    incx: i64,
+    beta: f64,
+    y: &mut [f64],
+    incy: i64,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        dspmv,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, ap, x, incx, beta, y, incy],
+        ffi::dspmv_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            ap.as_ptr(),
+            x.as_ptr(),
+            &incx,
+            &beta,
+            y.as_mut_ptr(),
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dtrmv(
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    a: &[f64],
+    lda: i64,
+    b: &mut [f64],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        dtrmv,
+        [uplo, transa, diag],
+        [n],
+        [lda],
+        [uplo, transa, diag, n, a, lda, b, incx],
+        ffi::dtrmv_64_(
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &n,
+            a.as_ptr(),
+            &lda,
+            b.as_mut_ptr(),
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dtbmv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    k: i64,
+    a: &[f64],
+    lda: i64,
+    x: &mut [f64],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        dtbmv,
+        [uplo, trans, diag],
+        [n, k],
+        [lda],
+        [uplo, trans, diag, n, k, a, lda, x, incx],
+        ffi::dtbmv_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            &k,
+            a.as_ptr(),
+            &lda,
+            x.as_mut_ptr(),
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dtpmv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    ap: &[f64],
+    x: &mut [f64],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        dtpmv,
+        [uplo, trans, diag],
+        [n],
+        [],
+        [uplo, trans, diag, n, ap, x, incx],
+        ffi::dtpmv_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            ap.as_ptr(),
+            x.as_mut_ptr(),
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dtrsv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    a: &[f64],
+    lda: i64,
+    x: &mut [f64],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        dtrsv,
+        [uplo, trans, diag],
+        [n],
+        [lda],
+        [uplo, trans, diag, n, a, lda, x, incx],
+        ffi::dtrsv_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            a.as_ptr(),
+            &lda,
+            x.as_mut_ptr(),
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dtbsv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    k: i64,
+    a: &[f64],
+    lda: i64,
+    x: &mut [f64],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        dtbsv,
+        [uplo, trans, diag],
+        [n, k],
+        [lda],
+        [uplo, trans, diag, n, k, a, lda, x, incx],
+        ffi::dtbsv_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            &k,
+            a.as_ptr(),
+            &lda,
+            x.as_mut_ptr(),
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dtpsv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    ap: &[f64],
+    x: &mut [f64],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        dtpsv,
+        [uplo, trans, diag],
+        [n],
+        [],
+        [uplo, trans, diag, n, ap, x, incx],
+        ffi::dtpsv_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            ap.as_ptr(),
+            x.as_mut_ptr(),
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dger(
+    m: i64,
+    n: i64,
+    alpha: f64,
+    x: &[f64],
+    incx: i64,
+    y: &[f64],
+    incy: i64,
+    a: &mut [f64],
+    lda: i64,
+) {
+    trace!(
+        dger,
+        [],
+        [m, n],
+        [lda],
+        [m, n, alpha, x, incx, y, incy, a, lda],
+        ffi::dger_64_(
+            &m,
+            &n,
+            &alpha,
+            x.as_ptr(),
+            &incx,
+            y.as_ptr(),
+            &incy,
+            a.as_mut_ptr(),
+            &lda
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dsyr(
+    uplo: impl Into<u8>,
+    n: i64,
+    alpha: f64,
+    x: &[f64],
+    incx: i64,
+    a: &mut [f64],
+    lda: i64,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        dsyr,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, x, incx, a, lda],
+        ffi::dsyr_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr(),
+            &incx,
+            a.as_mut_ptr(),
+            &lda
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dspr(uplo: impl Into<u8>, n: i64, alpha: f64, x: &[f64], incx: i64, ap: &mut [f64]) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        dspr,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, x, incx, ap],
+        ffi::dspr_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr(),
+            &incx,
+            ap.as_mut_ptr()
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dsyr2(
+    uplo: impl Into<u8>,
+    n: i64,
+    alpha: f64,
+    x: &[f64],
+    incx: i64,
+    y: &[f64],
+    incy: i64,
+    a: &mut [f64],
+    lda: i64,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        dsyr2,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, x, incx, y, incy, a, lda],
+        ffi::dsyr2_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr(),
+            &incx,
+            y.as_ptr(),
+            &incy,
+            a.as_mut_ptr(),
+            &lda
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dspr2(
+    uplo: impl Into<u8>,
+    n: i64,
+    alpha: f64,
+    x: &[f64],
+    incx: i64,
+    y: &[f64],
+    incy: i64,
+    ap: &mut [f64],
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        dspr2,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, x, incx, y, incy, ap],
+        ffi::dspr2_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr(),
+            &incx,
+            y.as_ptr(),
+            &incy,
+            ap.as_mut_ptr()
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn cgemv(
+    trans: impl Into<u8>,
+    m: i64,
+    n: i64,
+    alpha: c32,
+    a: &[c32],
+    lda: i64,
+    x: &[c32],
+    incx: i64,
+    beta: c32,
+    y: &mut [c32],
+    incy: i64,
+) {
+    let trans: u8 = trans.into();
+    trace!(
+        cgemv,
+        [trans],
+        [m, n],
+        [lda],
+        [trans, m, n, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::cgemv_64_(
+            &(trans as c_char),
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_ptr() as *const _,
+            &incx,
+            &beta as *const _ as *const _,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn cgbmv(
+    trans: impl Into<u8>,
+    m: i64,
+    n: i64,
+    kl: i64,
+    ku: i64,
+    alpha: c32,
+    a: &[c32],
+    lda: i64,
+    x: &[c32],
+    incx: i64,
+    beta: c32,
+    y: &mut [c32],
+    incy: i64,
+) {
+    let trans: u8 = trans.into();
+    trace!(
+        cgbmv,
+        [trans],
+        [m, n, kl, ku],
+        [lda],
+        [trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::cgbmv_64_(
+            &(trans as c_char),
+            &m,
+            &n,
+            &kl,
+            &ku,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_ptr() as *const _,
+            &incx,
+            &beta as *const _ as *const _,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn chemv(
+    uplo: impl Into<u8>,
+    n: i64,
+    alpha: c32,
+    a: &[c32],
+    lda: i64,
+    x: &[c32],
+    incx: i64,
+    beta: c32,
+    y: &mut [c32],
+    incy: i64,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        chemv,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::chemv_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_ptr() as *const _,
+            &incx,
+            &beta as *const _ as *const _,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn chbmv(
+    uplo: impl Into<u8>,
+    n: i64,
+    k: i64,
+    alpha: c32,
+    a: &[c32],
+    lda: i64,
+    x: &[c32],
+    incx: i64,
+    beta: c32,
+    y: &mut [c32],
+    incy: i64,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        chbmv,
+        [uplo],
+        [n, k],
+        [lda],
+        [uplo, n, k, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::chbmv_64_(
+            &(uplo as c_char),
+            &n,
+            &k,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_ptr() as *const _,
+            &incx,
+            &beta as *const _ as *const _,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn chpmv(
+    uplo: impl Into<u8>,
+    n: i64,
+    alpha: c32,
+    ap: &[c32],
+    x: &[c32],
+    incx: i64,
+    beta: c32,
+    y: &mut [c32],
+    incy: i64,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        chpmv,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, ap, x, incx, beta, y, incy],
+        ffi::chpmv_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha as *const _ as *const _,
+            ap.as_ptr() as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            &beta as *const _ as *const _,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ctrmv(
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    a: &[c32],
+    lda: i64,
+    b: &mut [c32],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ctrmv,
+        [uplo, transa, diag],
+        [n],
+        [lda],
+        [uplo, transa, diag, n, a, lda, b, incx],
+        ffi::ctrmv_64_(
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &n,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_mut_ptr() as *mut _,
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ctbmv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    k: i64,
+    a: &[c32],
+    lda: i64,
+    x: &mut [c32],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ctbmv,
+        [uplo, trans, diag],
+        [n, k],
+        [lda],
+        [uplo, trans, diag, n, k, a, lda, x, incx],
+        ffi::ctbmv_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            &k,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ctpmv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    ap: &[c32],
+    x: &mut [c32],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ctpmv,
+        [uplo, trans, diag],
+        [n],
+        [],
+        [uplo, trans, diag, n, ap, x, incx],
+        ffi::ctpmv_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            ap.as_ptr() as *const _,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ctrsv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    a: &[c32],
+    lda: i64,
+    x: &mut [c32],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ctrsv,
+        [uplo, trans, diag],
+        [n],
+        [lda],
+        [uplo, trans, diag, n, a, lda, x, incx],
+        ffi::ctrsv_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ctbsv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    k: i64,
+    a: &[c32],
+    lda: i64,
+    x: &mut [c32],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ctbsv,
+        [uplo, trans, diag],
+        [n, k],
+        [lda],
+        [uplo, trans, diag, n, k, a, lda, x, incx],
+        ffi::ctbsv_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            &k,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ctpsv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    ap: &[c32],
+    x: &mut [c32],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ctpsv,
+        [uplo, trans, diag],
+        [n],
+        [],
+        [uplo, trans, diag, n, ap, x, incx],
+        ffi::ctpsv_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            ap.as_ptr() as *const _,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn cgeru(
+    m: i64,
+    n: i64,
+    alpha: c32,
+    x: &[c32],
+    incx: i64,
+    y: &[c32],
+    incy: i64,
+    a: &mut [c32],
+    lda: i64,
+) {
+    trace!(
+        cgeru,
+        [],
+        [m, n],
+        [lda],
+        [m, n, alpha, x, incx, y, incy, a, lda],
+        ffi::cgeru_64_(
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy,
+            a.as_mut_ptr() as *mut _,
+            &lda
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn cgerc(
+    m: i64,
+    n: i64,
+    alpha: c32,
+    x: &[c32],
+    incx: i64,
+    y: &[c32],
+    incy: i64,
+    a: &mut [c32],
+    lda: i64,
+) {
+    trace!(
+        cgerc,
+        [],
+        [m, n],
+        [lda],
+        [m, n, alpha, x, incx, y, incy, a, lda],
+        ffi::cgerc_64_(
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy,
+            a.as_mut_ptr() as *mut _,
+            &lda
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn cher(
+    uplo: impl Into<u8>,
+    n: i64,
+    alpha: f32,
+    x: &[c32],
+    incx: i64,
+    a: &mut [c32],
+    lda: i64,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        cher,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, x, incx, a, lda],
+        ffi::cher_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr() as *const _,
+            &incx,
+            a.as_mut_ptr() as *mut _,
+            &lda
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn chpr(uplo: impl Into<u8>, n: i64, alpha: f32, x: &[c32], incx: i64, ap: &mut [c32]) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        chpr,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, x, incx, ap],
+        ffi::chpr_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr() as *const _,
+            &incx,
+            ap.as_mut_ptr() as *mut _
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn chpr2(
+    uplo: impl Into<u8>,
+    n: i64,
+    alpha: c32,
+    x: &[c32],
+    incx: i64,
+    y: &[c32],
+    incy: i64,
+    ap: &mut [c32],
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        chpr2,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, x, incx, y, incy, ap],
+        ffi::chpr2_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha as *const _ as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy,
+            ap.as_mut_ptr() as *mut _
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn cher2(
+    uplo: impl Into<u8>,
+    n: i64,
+    alpha: c32,
+    x: &[c32],
+    incx: i64,
+    y: &[c32],
+    incy: i64,
+    a: &mut [c32],
+    lda: i64,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        cher2,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, x, incx, y, incy, a, lda],
+        ffi::cher2_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha as *const _ as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy,
+            a.as_mut_ptr() as *mut _,
+            &lda
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zgemv(
+    trans: impl Into<u8>,
+    m: i64,
+    n: i64,
+    alpha: c64,
+    a: &[c64],
+    lda: i64,
+    x: &[c64],
+    incx: i64,
+    beta: c64,
+    y: &mut [c64],
+    incy: i64,
+) {
+    let trans: u8 = trans.into();
+    trace!(
+        zgemv,
+        [trans],
+        [m, n],
+        [lda],
+        [trans, m, n, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::zgemv_64_(
+            &(trans as c_char),
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_ptr() as *const _,
+            &incx,
+            &beta as *const _ as *const _,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zgbmv(
+    trans: impl Into<u8>,
+    m: i64,
+    n: i64,
+    kl: i64,
+    ku: i64,
+    alpha: c64,
+    a: &[c64],
+    lda: i64,
+    x: &[c64],
+    incx: i64,
+    beta: c64,
+    y: &mut [c64],
+    incy: i64,
+) {
+    let trans: u8 = trans.into();
+    trace!(
+        zgbmv,
+        [trans],
+        [m, n, kl, ku],
+        [lda],
+        [trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::zgbmv_64_(
+            &(trans as c_char),
+            &m,
+            &n,
+            &kl,
+            &ku,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_ptr() as *const _,
+            &incx,
+            &beta as *const _ as *const _,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zhemv(
+    uplo: impl Into<u8>,
+    n: i64,
+    alpha: c64,
+    a: &[c64],
+    lda: i64,
+    x: &[c64],
+    incx: i64,
+    beta: c64,
+    y: &mut [c64],
+    incy: i64,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        zhemv,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::zhemv_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_ptr() as *const _,
+            &incx,
+            &beta as *const _ as *const _,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zhbmv(
+    uplo: impl Into<u8>,
+    n: i64,
+    k: i64,
+    alpha: c64,
+    a: &[c64],
+    lda: i64,
+    x: &[c64],
+    incx: i64,
+    beta: c64,
+    y: &mut [c64],
+    incy: i64,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        zhbmv,
+        [uplo],
+        [n, k],
+        [lda],
+        [uplo, n, k, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::zhbmv_64_(
+            &(uplo as c_char),
+            &n,
+            &k,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_ptr() as *const _,
+            &incx,
+            &beta as *const _ as *const _,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zhpmv(
+    uplo: impl Into<u8>,
+    n: i64,
+    alpha: c64,
+    ap: &[c64],
+    x: &[c64],
+    incx: i64,
+    beta: c64,
+    y: &mut [c64],
+    incy: i64,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        zhpmv,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, ap, x, incx, beta, y, incy],
+        ffi::zhpmv_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha as *const _ as *const _,
+            ap.as_ptr() as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            &beta as *const _ as *const _,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ztrmv(
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    a: &[c64],
+    lda: i64,
+    b: &mut [c64],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ztrmv,
+        [uplo, transa, diag],
+        [n],
+        [lda],
+        [uplo, transa, diag, n, a, lda, b, incx],
+        ffi::ztrmv_64_(
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &n,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_mut_ptr() as *mut _,
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ztbmv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    k: i64,
+    a: &[c64],
+    lda: i64,
+    x: &mut [c64],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ztbmv,
+        [uplo, trans, diag],
+        [n, k],
+        [lda],
+        [uplo, trans, diag, n, k, a, lda, x, incx],
+        ffi::ztbmv_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            &k,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ztpmv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    ap: &[c64],
+    x: &mut [c64],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ztpmv,
+        [uplo, trans, diag],
+        [n],
+        [],
+        [uplo, trans, diag, n, ap, x, incx],
+        ffi::ztpmv_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            ap.as_ptr() as *const _,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ztrsv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    a: &[c64],
+    lda: i64,
+    x: &mut [c64],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ztrsv,
+        [uplo, trans, diag],
+        [n],
+        [lda],
+        [uplo, trans, diag, n, a, lda, x, incx],
+        ffi::ztrsv_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ztbsv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    k: i64,
+    a: &[c64],
+    lda: i64,
+    x: &mut [c64],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ztbsv,
+        [uplo, trans, diag],
+        [n, k],
+        [lda],
+        [uplo, trans, diag, n, k, a, lda, x, incx],
+        ffi::ztbsv_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            &k,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ztpsv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i64,
+    ap: &[c64],
+    x: &mut [c64],
+    incx: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ztpsv,
+        [uplo, trans, diag],
+        [n],
+        [],
+        [uplo, trans, diag, n, ap, x, incx],
+        ffi::ztpsv_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            ap.as_ptr() as *const _,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zgeru(
+    m: i64,
+    n: i64,
+    alpha: c64,
+    x: &[c64],
+    incx: i64,
+    y: &[c64],
+    incy: i64,
+    a: &mut [c64],
+    lda: i64,
+) {
+    trace!(
+        zgeru,
+        [],
+        [m, n],
+        [lda],
+        [m, n, alpha, x, incx, y, incy, a, lda],
+        ffi::zgeru_64_(
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy,
+            a.as_mut_ptr() as *mut _,
+            &lda
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zgerc(
+    m: i64,
+    n: i64,
+    alpha: c64,
+    x: &[c64],
+    incx: i64,
+    y: &[c64],
+    incy: i64,
+    a: &mut [c64],
+    lda: i64,
+) {
+    trace!(
+        zgerc,
+        [],
+        [m, n],
+        [lda],
+        [m, n, alpha, x, incx, y, incy, a, lda],
+        ffi::zgerc_64_(
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy,
+            a.as_mut_ptr() as *mut _,
+            &lda
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zher(
+    uplo: impl Into<u8>,
+    n: i64,
+    alpha: f64,
+    x: &[c64],
+    incx: i64,
+    a: &mut [c64],
+    lda: i64,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        zher,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, x, incx, a, lda],
+        ffi::zher_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr() as *const _,
+            &incx,
+            a.as_mut_ptr() as *mut _,
+            &lda
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zhpr(uplo: impl Into<u8>, n: i64, alpha: f64, x: &[c64], incx: i64, ap: &mut [c64]) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        zhpr,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, x, incx, ap],
+        ffi::zhpr_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr() as *const _,
+            &incx,
+            ap.as_mut_ptr() as *mut _
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zher2(
+    uplo: impl Into<u8>,
+    n: i64,
+    alpha: c64,
+    x: &[c64],
+    incx: i64,
+    y: &[c64],
+    incy: i64,
+    a: &mut [c64],
+    lda: i64,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        zher2,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, x, incx, y, incy, a, lda],
+        ffi::zher2_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha as *const _ as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy,
+            a.as_mut_ptr() as *mut _,
+            &lda
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zhpr2(
+    uplo: impl Into<u8>,
+    n: i64,
+    alpha: c64,
+    x: &[c64],
+    incx: i64,
+    y: &[c64],
+    incy: i64,
+    ap: &mut [c64],
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        zhpr2,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, x, incx, y, incy, ap],
+        ffi::zhpr2_64_(
+            &(uplo as c_char),
+            &n,
+            &alpha as *const _ as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy,
+            ap.as_mut_ptr() as *mut _
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn sgemm(
+    transa: impl Into<u8>,
+    transb: impl Into<u8>,
+    m: i64,
+    n: i64,
+    k: i64,
+    alpha: f32,
+    a: &[f32],
+    lda: i64,
+    b: &[f32],
+    ldb: i64,
+    beta: f32,
+    c: &mut [f32],
+    ldc: i64,
+) {
+    let transa: u8 = transa.into();
+    let transb: u8 = transb.into();
+    trace!(
+        sgemm,
+        [transa, transb],
+        [m, n, k],
+        [lda, ldb, ldc],
+        [transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::sgemm_64_(
+            &(transa as c_char),
+            &(transb as c_char),
+            &m,
+            &n,
+            &k,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            b.as_ptr(),
+            &ldb,
+            &beta,
+            c.as_mut_ptr(),
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ssymm(
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    m: i64,
+    n: i64,
+    alpha: f32,
+    a: &[f32],
+    lda: i64,
+    b: &[f32],
+    ldb: i64,
+    beta: f32,
+    c: &mut [f32],
+    ldc: i64,
+) {
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    trace!(
+        ssymm,
+        [side, uplo],
+        [m, n],
+        [lda, ldb, ldc],
+        [side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::ssymm_64_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &m,
+            &n,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            b.as_ptr(),
+            &ldb,
+            &beta,
+            c.as_mut_ptr(),
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ssyrk(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    n: i64,
+    k: i64,
+    alpha: f32,
+    a: &[f32],
+    lda: i64,
+    beta: f32,
+    c: &mut [f32],
+    ldc: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        ssyrk,
+        [uplo, trans],
+        [n, k],
+        [lda, ldc],
+        [uplo, trans, n, k, alpha, a, lda, beta, c, ldc],
+        ffi::ssyrk_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            &beta,
+            c.as_mut_ptr(),
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ssyr2k(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    n: i64,
+    k: i64,
+    alpha: f32,
+    a: &[f32],
+    lda: i64,
+    b: &[f32],
+    ldb: i64,
+    beta: f32,
+    c: &mut [f32],
+    ldc: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        ssyr2k,
+        [uplo, trans],
+        [n, k],
+        [lda, ldb, ldc],
+        [uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::ssyr2k_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            b.as_ptr(),
+            &ldb,
+            &beta,
+            c.as_mut_ptr(),
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn strmm(
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
+    m: i64,
+    n: i64,
+    alpha: f32,
+    a: &[f32],
+    lda: i64,
+    b: &mut [f32],
+    ldb: i64,
+) {
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        strmm,
+        [side, uplo, transa, diag],
+        [m, n],
+        [lda, ldb],
+        [side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb],
+        ffi::strmm_64_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &m,
+            &n,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            b.as_mut_ptr(),
+            &ldb
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn strsm(
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
+    m: i64,
+    n: i64,
+    alpha: f32,
+    a: &[f32],
+    lda: i64,
+    b: &mut [f32],
+    ldb: i64,
+) {
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        strsm,
+        [side, uplo, transa, diag],
+        [m, n],
+        [lda, ldb],
+        [side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb],
+        ffi::strsm_64_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &m,
+            &n,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            b.as_mut_ptr(),
+            &ldb
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dgemm(
+    transa: impl Into<u8>,
+    transb: impl Into<u8>,
+    m: i64,
+    n: i64,
+    k: i64,
+    alpha: f64,
+    a: &[f64],
+    lda: i64,
+    b: &[f64],
+    ldb: i64,
+    beta: f64,
+    c: &mut [f64],
+    ldc: i64,
+) {
+    let transa: u8 = transa.into();
+    let transb: u8 = transb.into();
+    trace!(
+        dgemm,
+        [transa, transb],
+        [m, n, k],
+        [lda, ldb, ldc],
+        [transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::dgemm_64_(
+            &(transa as c_char),
+            &(transb as c_char),
+            &m,
+            &n,
+            &k,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            b.as_ptr(),
+            &ldb,
+            &beta,
+            c.as_mut_ptr(),
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dsymm(
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    m: i64,
+    n: i64,
+    alpha: f64,
+    a: &[f64],
+    lda: i64,
+    b: &[f64],
+    ldb: i64,
+    beta: f64,
+    c: &mut [f64],
+    ldc: i64,
+) {
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    trace!(
+        dsymm,
+        [side, uplo],
+        [m, n],
+        [lda, ldb, ldc],
+        [side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::dsymm_64_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &m,
+            &n,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            b.as_ptr(),
+            &ldb,
+            &beta,
+            c.as_mut_ptr(),
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dsyrk(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    n: i64,
+    k: i64,
+    alpha: f64,
+    a: &[f64],
+    lda: i64,
+    beta: f64,
+    c: &mut [f64],
+    ldc: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        dsyrk,
+        [uplo, trans],
+        [n, k],
+        [lda, ldc],
+        [uplo, trans, n, k, alpha, a, lda, beta, c, ldc],
+        ffi::dsyrk_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            &beta,
+            c.as_mut_ptr(),
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dsyr2k(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    n: i64,
+    k: i64,
+    alpha: f64,
+    a: &[f64],
+    lda: i64,
+    b: &[f64],
+    ldb: i64,
+    beta: f64,
+    c: &mut [f64],
+    ldc: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        dsyr2k,
+        [uplo, trans],
+        [n, k],
+        [lda, ldb, ldc],
+        [uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::dsyr2k_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            b.as_ptr(),
+            &ldb,
+            &beta,
+            c.as_mut_ptr(),
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dtrmm(
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
+    m: i64,
+    n: i64,
+    alpha: f64,
+    a: &[f64],
+    lda: i64,
+    b: &mut [f64],
+    ldb: i64,
+) {
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        dtrmm,
+        [side, uplo, transa, diag],
+        [m, n],
+        [lda, ldb],
+        [side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb],
+        ffi::dtrmm_64_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &m,
+            &n,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            b.as_mut_ptr(),
+            &ldb
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn dtrsm(
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
+    m: i64,
+    n: i64,
+    alpha: f64,
+    a: &[f64],
+    lda: i64,
+    b: &mut [f64],
+    ldb: i64,
+) {
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        dtrsm,
+        [side, uplo, transa, diag],
+        [m, n],
+        [lda, ldb],
+        [side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb],
+        ffi::dtrsm_64_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &m,
+            &n,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            b.as_mut_ptr(),
+            &ldb
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn cgemm(
+    transa: impl Into<u8>,
+    transb: impl Into<u8>,
+    m: i64,
+    n: i64,
+    k: i64,
+    alpha: c32,
+    a: &[c32],
+    lda: i64,
+    b: &[c32],
+    ldb: i64,
+    beta: c32,
+    c: &mut [c32],
+    ldc: i64,
+) {
+    let transa: u8 = transa.into();
+    let transb: u8 = transb.into();
+    trace!(
+        cgemm,
+        [transa, transb],
+        [m, n, k],
+        [lda, ldb, ldc],
+        [transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::cgemm_64_(
+            &(transa as c_char),
+            &(transb as c_char),
+            &m,
+            &n,
+            &k,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_ptr() as *const _,
+            &ldb,
+            &beta as *const _ as *const _,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn csymm(
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    m: i64,
+    n: i64,
+    alpha: c32,
+    a: &[c32],
+    lda: i64,
+    b: &[c32],
+    ldb: i64,
+    beta: c32,
+    c: &mut [c32],
+    ldc: i64,
+) {
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    trace!(
+        csymm,
+        [side, uplo],
+        [m, n],
+        [lda, ldb, ldc],
+        [side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::csymm_64_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_ptr() as *const _,
+            &ldb,
+            &beta as *const _ as *const _,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn chemm(
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    m: i64,
+    n: i64,
+    alpha: c32,
+    a: &[c32],
+    lda: i64,
+    b: &[c32],
+    ldb: i64,
+    beta: c32,
+    c: &mut [c32],
+    ldc: i64,
+) {
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    trace!(
+        chemm,
+        [side, uplo],
+        [m, n],
+        [lda, ldb, ldc],
+        [side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::chemm_64_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_ptr() as *const _,
+            &ldb,
+            &beta as *const _ as *const _,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn csyrk(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    n: i64,
+    k: i64,
+    alpha: c32,
+    a: &[c32],
+    lda: i64,
+    beta: c32,
+    c: &mut [c32],
+    ldc: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        csyrk,
+        [uplo, trans],
+        [n, k],
+        [lda, ldc],
+        [uplo, trans, n, k, alpha, a, lda, beta, c, ldc],
+        ffi::csyrk_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            &beta as *const _ as *const _,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn cherk(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    n: i64,
+    k: i64,
+    alpha: f32,
+    a: &[c32],
+    lda: i64,
+    beta: f32,
+    c: &mut [c32],
+    ldc: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        cherk,
+        [uplo, trans],
+        [n, k],
+        [lda, ldc],
+        [uplo, trans, n, k, alpha, a, lda, beta, c, ldc],
+        ffi::cherk_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha,
+            a.as_ptr() as *const _,
+            &lda,
+            &beta,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn csyr2k(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    n: i64,
+    k: i64,
+    alpha: c32,
+    a: &[c32],
+    lda: i64,
+    b: &[c32],
+    ldb: i64,
+    beta: c32,
+    c: &mut [c32],
+    ldc: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        csyr2k,
+        [uplo, trans],
+        [n, k],
+        [lda, ldb, ldc],
+        [uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::csyr2k_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_ptr() as *const _,
+            &ldb,
+            &beta as *const _ as *const _,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn cher2k(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    n: i64,
+    k: i64,
+    alpha: c32,
+    a: &[c32],
+    lda: i64,
+    b: &[c32],
+    ldb: i64,
+    beta: f32,
+    c: &mut [c32],
+    ldc: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        cher2k,
+        [uplo, trans],
+        [n, k],
+        [lda, ldb, ldc],
+        [uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::cher2k_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_ptr() as *const _,
+            &ldb,
+            &beta,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ctrmm(
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
+    m: i64,
+    n: i64,
+    alpha: c32,
+    a: &[c32],
+    lda: i64,
+    b: &mut [c32],
+    ldb: i64,
+) {
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ctrmm,
+        [side, uplo, transa, diag],
+        [m, n],
+        [lda, ldb],
+        [side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb],
+        ffi::ctrmm_64_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_mut_ptr() as *mut _,
+            &ldb
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ctrsm(
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
+    m: i64,
+    n: i64,
+    alpha: c32,
+    a: &[c32],
+    lda: i64,
+    b: &mut [c32],
+    ldb: i64,
+) {
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ctrsm,
+        [side, uplo, transa, diag],
+        [m, n],
+        [lda, ldb],
+        [side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb],
+        ffi::ctrsm_64_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_mut_ptr() as *mut _,
+            &ldb
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zgemm(
+    transa: impl Into<u8>,
+    transb: impl Into<u8>,
+    m: i64,
+    n: i64,
+    k: i64,
+    alpha: c64,
+    a: &[c64],
+    lda: i64,
+    b: &[c64],
+    ldb: i64,
+    beta: c64,
+    c: &mut [c64],
+    ldc: i64,
+) {
+    let transa: u8 = transa.into();
+    let transb: u8 = transb.into();
+    trace!(
+        zgemm,
+        [transa, transb],
+        [m, n, k],
+        [lda, ldb, ldc],
+        [transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::zgemm_64_(
+            &(transa as c_char),
+            &(transb as c_char),
+            &m,
+            &n,
+            &k,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_ptr() as *const _,
+            &ldb,
+            &beta as *const _ as *const _,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zsymm(
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    m: i64,
+    n: i64,
+    alpha: c64,
+    a: &[c64],
+    lda: i64,
+    b: &[c64],
+    ldb: i64,
+    beta: c64,
+    c: &mut [c64],
+    ldc: i64,
+) {
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    trace!(
+        zsymm,
+        [side, uplo],
+        [m, n],
+        [lda, ldb, ldc],
+        [side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::zsymm_64_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_ptr() as *const _,
+            &ldb,
+            &beta as *const _ as *const _,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zhemm(
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    m: i64,
+    n: i64,
+    alpha: c64,
+    a: &[c64],
+    lda: i64,
+    b: &[c64],
+    ldb: i64,
+    beta: c64,
+    c: &mut [c64],
+    ldc: i64,
+) {
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    trace!(
+        zhemm,
+        [side, uplo],
+        [m, n],
+        [lda, ldb, ldc],
+        [side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::zhemm_64_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_ptr() as *const _,
+            &ldb,
+            &beta as *const _ as *const _,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zsyrk(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    n: i64,
+    k: i64,
+    alpha: c64,
+    a: &[c64],
+    lda: i64,
+    beta: c64,
+    c: &mut [c64],
+    ldc: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        zsyrk,
+        [uplo, trans],
+        [n, k],
+        [lda, ldc],
+        [uplo, trans, n, k, alpha, a, lda, beta, c, ldc],
+        ffi::zsyrk_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            &beta as *const _ as *const _,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zherk(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    n: i64,
+    k: i64,
+    alpha: f64,
+    a: &[c64],
+    lda: i64,
+    beta: f64,
+    c: &mut [c64],
+    ldc: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        zherk,
+        [uplo, trans],
+        [n, k],
+        [lda, ldc],
+        [uplo, trans, n, k, alpha, a, lda, beta, c, ldc],
+        ffi::zherk_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha,
+            a.as_ptr() as *const _,
+            &lda,
+            &beta,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zsyr2k(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    n: i64,
+    k: i64,
+    alpha: c64,
+    a: &[c64],
+    lda: i64,
+    b: &[c64],
+    ldb: i64,
+    beta: c64,
+    c: &mut [c64],
+    ldc: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        zsyr2k,
+        [uplo, trans],
+        [n, k],
+        [lda, ldb, ldc],
+        [uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::zsyr2k_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_ptr() as *const _,
+            &ldb,
+            &beta as *const _ as *const _,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn zher2k(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    n: i64,
+    k: i64,
+    alpha: c64,
+    a: &[c64],
+    lda: i64,
+    b: &[c64],
+    ldb: i64,
+    beta: f64,
+    c: &mut [c64],
+    ldc: i64,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        zher2k,
+        [uplo, trans],
+        [n, k],
+        [lda, ldb, ldc],
+        [uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::zher2k_64_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_ptr() as *const _,
+            &ldb,
+            &beta,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ztrmm(
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
+    m: i64,
+    n: i64,
+    alpha: c64,
+    a: &[c64],
+    lda: i64,
+    b: &mut [c64],
+    ldb: i64,
+) {
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ztrmm,
+        [side, uplo, transa, diag],
+        [m, n],
+        [lda, ldb],
+        [side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb],
+        ffi::ztrmm_64_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_mut_ptr() as *mut _,
+            &ldb
+        )
+    )
+}
+
+#[inline]
+pub unsafe fn ztrsm(
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
+    m: i64,
+    n: i64,
+    alpha: c64,
+    a: &[c64],
+    lda: i64,
+    b: &mut [c64],
+    ldb: i64,
+) {
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ztrsm,
+        [side, uplo, transa, diag],
+        [m, n],
+        [lda, ldb],
+        [side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb],
+        ffi::ztrsm_64_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_mut_ptr() as *mut _,
+            &ldb
+        )
+    )
+}
@@ -0,0 +1,1486 @@
+//! Declarations of the ILP64 interface.
+//!
+//! The declarations are generated by `bin/generate.py --ilp64 --ffi` from
+//! those of `blas-sys`.
+
+use blas_sys::{c_double_complex, c_float_complex};
+#[cfg(not(feature = "hidden-length"))]
+use libc::c_char;
+use libc::{c_double, c_float};
+
+extern "C" {
+    pub fn srotg_64_(a: *mut c_float, b: *mut c_float, c: *mut c_float, s: *mut c_float);
+    pub fn srotmg_64_(
+        d1: *mut c_float,
+        d2: *mut c_float,
+        x1: *mut c_float,
+        y1: *const c_float,
+        param: *mut c_float,
+    );
+    pub fn srot_64_(
+        n: *const i64,
+        x: *mut c_float,
+        incx: *const i64,
+        y: *mut c_float,
+        incy: *const i64,
+        c: *const c_float,
+        s: *const c_float,
+    );
+    pub fn srotm_64_(
+        n: *const i64,
+        x: *mut c_float,
+        incx: *const i64,
+        y: *mut c_float,
+        incy: *const i64,
+        param: *const c_float,
+    );
+    pub fn sswap_64_(
+        n: *const i64,
+        x: *mut c_float,
+        incx: *const i64,
+        y: *mut c_float,
+        incy: *const i64,
+    );
+    pub fn sscal_64_(n: *const i64, a: *const c_float, x: *mut c_float, incx: *const i64);
+    pub fn scopy_64_(
+        n: *const i64,
+        x: *const c_float,
+        incx: *const i64,
+        y: *mut c_float,
+        incy: *const i64,
+    );
+    pub fn saxpy_64_(
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const i64,
+        y: *mut c_float,
+        incy: *const i64,
+    );
+    pub fn sdot_64_(
+        n: *const i64,
+        x: *const c_float,
+        incx: *const i64,
+        y: *const c_float,
+        incy: *const i64,
+    ) -> c_float;
+    pub fn sdsdot_64_(
+        n: *const i64,
+        sb: *const c_float,
+        x: *const c_float,
+        incx: *const i64,
+        y: *const c_float,
+        incy: *const i64,
+    ) -> c_float;
+    pub fn snrm2_64_(n: *const i64, x: *const c_float, incx: *const i64) -> c_float;
+    pub fn scnrm2_64_(n: *const i64, x: *const c_float_complex, incx: *const i64) -> c_float;
+    pub fn sasum_64_(n: *const i64, x: *const c_float, incx: *const i64) -> c_float;
+    pub fn isamax_64_(n: *const i64, x: *const c_float, incx: *const i64) -> i64;
+    pub fn drotg_64_(a: *mut c_double, b: *mut c_double, c: *mut c_double, s: *mut c_double);
+    pub fn drotmg_64_(
+        d1: *mut c_double,
+        d2: *mut c_double,
+        x1: *mut c_double,
+        y1: *const c_double,
+        param: *mut c_double,
+    );
+    pub fn drot_64_(
+        n: *const i64,
+        x: *mut c_double,
+        incx: *const i64,
+        y: *mut c_double,
+        incy: *const i64,
+        c: *const c_double,
+        s: *const c_double,
+    );
+    pub fn drotm_64_(
+        n: *const i64,
+        x: *mut c_double,
+        incx: *const i64,
+        y: *mut c_double,
+        incy: *const i64,
+        param: *const c_double,
+    );
+    pub fn dswap_64_(
+        n: *const i64,
+        x: *mut c_double,
+        incx: *const i64,
+        y: *mut c_double,
+        incy: *const i64,
+    );
+    pub fn dscal_64_(n: *const i64, a: *const c_double, x: *mut c_double, incx: *const i64);
+    pub fn dcopy_64_(
+        n: *const i64,
+        x: *const c_double,
+        incx: *const i64,
+        y: *mut c_double,
+        incy: *const i64,
+    );
+    pub fn daxpy_64_(
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const i64,
+        y: *mut c_double,
+        incy: *const i64,
+    );
+    pub fn ddot_64_(
+        n: *const i64,
+        x: *const c_double,
+        incx: *const i64,
+        y: *const c_double,
+        incy: *const i64,
+    ) -> c_double;
+    pub fn dsdot_64_(
+        n: *const i64,
+        x: *const c_float,
+        incx: *const i64,
+        y: *const c_float,
+        incy: *const i64,
+    ) -> c_double;
+    pub fn dnrm2_64_(n: *const i64, x: *const c_double, incx: *const i64) -> c_double;
+    pub fn dznrm2_64_(n: *const i64, x: *const c_double_complex, incx: *const i64) -> c_double;
+    pub fn dasum_64_(n: *const i64, x: *const c_double, incx: *const i64) -> c_double;
+    pub fn idamax_64_(n: *const i64, x: *const c_double, incx: *const i64) -> i64;
+    pub fn crotg_64_(
+        a: *mut c_float_complex,
+        b: *const c_float_complex,
+        c: *mut c_float,
+        s: *mut c_float_complex,
+    );
+    pub fn csrot_64_(
+        n: *const i64,
+        x: *mut c_float_complex,
+        incx: *const i64,
+        y: *mut c_float_complex,
+        incy: *const i64,
+        c: *const c_float,
+        s: *const c_float,
+    );
+    pub fn cswap_64_(
+        n: *const i64,
+        x: *mut c_float_complex,
+        incx: *const i64,
+        y: *mut c_float_complex,
+        incy: *const i64,
+    );
+    pub fn cscal_64_(
+        n: *const i64,
+        a: *const c_float_complex,
+        x: *mut c_float_complex,
+        incx: *const i64,
+    );
+    pub fn csscal_64_(n: *const i64, a: *const c_float, x: *mut c_float_complex, incx: *const i64);
+    pub fn ccopy_64_(
+        n: *const i64,
+        x: *const c_float_complex,
+        incx: *const i64,
+        y: *mut c_float_complex,
+        incy: *const i64,
+    );
+    pub fn caxpy_64_(
+        n: *const i64,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const i64,
+        y: *mut c_float_complex,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "complex-by-value"))]
+    pub fn cdotu_64_(
+        pres: *mut c_float_complex,
+        n: *const i64,
+        x: *const c_float_complex,
+        incx: *const i64,
+        y: *const c_float_complex,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "complex-by-value"))]
+    pub fn cdotc_64_(
+        pres: *mut c_float_complex,
+        n: *const i64,
+        x: *const c_float_complex,
+        incx: *const i64,
+        y: *const c_float_complex,
+        incy: *const i64,
+    );
+    pub fn scasum_64_(n: *const i64, x: *const c_float_complex, incx: *const i64) -> c_float;
+    pub fn icamax_64_(n: *const i64, x: *const c_float_complex, incx: *const i64) -> i64;
+    pub fn zrotg_64_(
+        a: *mut c_double_complex,
+        b: *const c_double_complex,
+        c: *mut c_double,
+        s: *mut c_double_complex,
+    );
+    pub fn zdrot_64_(
+        n: *const i64,
+        x: *mut c_double_complex,
+        incx: *const i64,
+        y: *mut c_double_complex,
+        incy: *const i64,
+        c: *const c_double,
+        s: *const c_double,
+    );
+    pub fn zswap_64_(
+        n: *const i64,
+        x: *mut c_double_complex,
+        incx: *const i64,
+        y: *mut c_double_complex,
+        incy: *const i64,
+    );
+    pub fn zscal_64_(
+        n: *const i64,
+        a: *const c_double_complex,
+        x: *mut c_double_complex,
+        incx: *const i64,
+    );
+    pub fn zdscal_64_(
+        n: *const i64,
+        a: *const c_double,
+        x: *mut c_double_complex,
+        incx: *const i64,
+    );
+    pub fn zcopy_64_(
+        n: *const i64,
+        x: *const c_double_complex,
+        incx: *const i64,
+        y: *mut c_double_complex,
+        incy: *const i64,
+    );
+    pub fn zaxpy_64_(
+        n: *const i64,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const i64,
+        y: *mut c_double_complex,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "complex-by-value"))]
+    pub fn zdotu_64_(
+        pres: *mut c_double_complex,
+        n: *const i64,
+        x: *const c_double_complex,
+        incx: *const i64,
+        y: *const c_double_complex,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "complex-by-value"))]
+    pub fn zdotc_64_(
+        pres: *mut c_double_complex,
+        n: *const i64,
+        x: *const c_double_complex,
+        incx: *const i64,
+        y: *const c_double_complex,
+        incy: *const i64,
+    );
+    pub fn dzasum_64_(n: *const i64, x: *const c_double_complex, incx: *const i64) -> c_double;
+    pub fn izamax_64_(n: *const i64, x: *const c_double_complex, incx: *const i64) -> i64;
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn sgemv_64_(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        x: *const c_float,
+        incx: *const i64,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn sgbmv_64_(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        kl: *const i64,
+        ku: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        x: *const c_float,
+        incx: *const i64,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ssymv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        x: *const c_float,
+        incx: *const i64,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ssbmv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        x: *const c_float,
+        incx: *const i64,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn sspmv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        ap: *const c_float,
+        x: *const c_float,
+        incx: *const i64,
+        beta: *const c_float,
+        y: *mut c_float,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn strmv_64_(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_float,
+        lda: *const i64,
+        b: *mut c_float,
+        incx: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn stbmv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_float,
+        lda: *const i64,
+        x: *mut c_float,
+        incx: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn stpmv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_float,
+        x: *mut c_float,
+        incx: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn strsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_float,
+        lda: *const i64,
+        x: *mut c_float,
+        incx: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn stbsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_float,
+        lda: *const i64,
+        x: *mut c_float,
+        incx: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn stpsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_float,
+        x: *mut c_float,
+        incx: *const i64,
+    );
+    pub fn sger_64_(
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const i64,
+        y: *const c_float,
+        incy: *const i64,
+        a: *mut c_float,
+        lda: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ssyr_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const i64,
+        a: *mut c_float,
+        lda: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn sspr_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const i64,
+        ap: *mut c_float,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ssyr2_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const i64,
+        y: *const c_float,
+        incy: *const i64,
+        a: *mut c_float,
+        lda: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn sspr2_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float,
+        incx: *const i64,
+        y: *const c_float,
+        incy: *const i64,
+        ap: *mut c_float,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn dgemv_64_(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        x: *const c_double,
+        incx: *const i64,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn dgbmv_64_(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        kl: *const i64,
+        ku: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        x: *const c_double,
+        incx: *const i64,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn dsymv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        x: *const c_double,
+        incx: *const i64,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn dsbmv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        x: *const c_double,
+        incx: *const i64,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn dspmv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        ap: *const c_double,
+        x: *const c_double,
+        incx: *const i64,
+        beta: *const c_double,
+        y: *mut c_double,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn dtrmv_64_(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_double,
+        lda: *const i64,
+        b: *mut c_double,
+        incx: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn dtbmv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_double,
+        lda: *const i64,
+        x: *mut c_double,
+        incx: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn dtpmv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_double,
+        x: *mut c_double,
+        incx: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn dtrsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_double,
+        lda: *const i64,
+        x: *mut c_double,
+        incx: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn dtbsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_double,
+        lda: *const i64,
+        x: *mut c_double,
+        incx: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn dtpsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_double,
+        x: *mut c_double,
+        incx: *const i64,
+    );
+    pub fn dger_64_(
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const i64,
+        y: *const c_double,
+        incy: *const i64,
+        a: *mut c_double,
+        lda: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn dsyr_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const i64,
+        a: *mut c_double,
+        lda: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn dspr_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const i64,
+        ap: *mut c_double,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn dsyr2_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const i64,
+        y: *const c_double,
+        incy: *const i64,
+        a: *mut c_double,
+        lda: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn dspr2_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double,
+        incx: *const i64,
+        y: *const c_double,
+        incy: *const i64,
+        ap: *mut c_double,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn cgemv_64_(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        x: *const c_float_complex,
+        incx: *const i64,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn cgbmv_64_(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        kl: *const i64,
+        ku: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        x: *const c_float_complex,
+        incx: *const i64,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn chemv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        x: *const c_float_complex,
+        incx: *const i64,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn chbmv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        x: *const c_float_complex,
+        incx: *const i64,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn chpmv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        ap: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const i64,
+        beta: *const c_float_complex,
+        y: *mut c_float_complex,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ctrmv_64_(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *mut c_float_complex,
+        incx: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ctbmv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_float_complex,
+        lda: *const i64,
+        x: *mut c_float_complex,
+        incx: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ctpmv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_float_complex,
+        x: *mut c_float_complex,
+        incx: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ctrsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_float_complex,
+        lda: *const i64,
+        x: *mut c_float_complex,
+        incx: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ctbsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_float_complex,
+        lda: *const i64,
+        x: *mut c_float_complex,
+        incx: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ctpsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_float_complex,
+        x: *mut c_float_complex,
+        incx: *const i64,
+    );
+    pub fn cgeru_64_(
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const i64,
+        y: *const c_float_complex,
+        incy: *const i64,
+        a: *mut c_float_complex,
+        lda: *const i64,
+    );
+    pub fn cgerc_64_(
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const i64,
+        y: *const c_float_complex,
+        incy: *const i64,
+        a: *mut c_float_complex,
+        lda: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn cher_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float_complex,
+        incx: *const i64,
+        a: *mut c_float_complex,
+        lda: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn chpr_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float,
+        x: *const c_float_complex,
+        incx: *const i64,
+        ap: *mut c_float_complex,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn chpr2_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const i64,
+        y: *const c_float_complex,
+        incy: *const i64,
+        ap: *mut c_float_complex,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn cher2_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        x: *const c_float_complex,
+        incx: *const i64,
+        y: *const c_float_complex,
+        incy: *const i64,
+        a: *mut c_float_complex,
+        lda: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn zgemv_64_(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        x: *const c_double_complex,
+        incx: *const i64,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn zgbmv_64_(
+        trans: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        kl: *const i64,
+        ku: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        x: *const c_double_complex,
+        incx: *const i64,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn zhemv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        x: *const c_double_complex,
+        incx: *const i64,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn zhbmv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        x: *const c_double_complex,
+        incx: *const i64,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn zhpmv_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        ap: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const i64,
+        beta: *const c_double_complex,
+        y: *mut c_double_complex,
+        incy: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ztrmv_64_(
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *mut c_double_complex,
+        incx: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ztbmv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_double_complex,
+        lda: *const i64,
+        x: *mut c_double_complex,
+        incx: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ztpmv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_double_complex,
+        x: *mut c_double_complex,
+        incx: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ztrsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        a: *const c_double_complex,
+        lda: *const i64,
+        x: *mut c_double_complex,
+        incx: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ztbsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        a: *const c_double_complex,
+        lda: *const i64,
+        x: *mut c_double_complex,
+        incx: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ztpsv_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        diag: *const c_char,
+        n: *const i64,
+        ap: *const c_double_complex,
+        x: *mut c_double_complex,
+        incx: *const i64,
+    );
+    pub fn zgeru_64_(
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const i64,
+        y: *const c_double_complex,
+        incy: *const i64,
+        a: *mut c_double_complex,
+        lda: *const i64,
+    );
+    pub fn zgerc_64_(
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const i64,
+        y: *const c_double_complex,
+        incy: *const i64,
+        a: *mut c_double_complex,
+        lda: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn zher_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double_complex,
+        incx: *const i64,
+        a: *mut c_double_complex,
+        lda: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn zhpr_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double,
+        x: *const c_double_complex,
+        incx: *const i64,
+        ap: *mut c_double_complex,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn zher2_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const i64,
+        y: *const c_double_complex,
+        incy: *const i64,
+        a: *mut c_double_complex,
+        lda: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn zhpr2_64_(
+        uplo: *const c_char,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        x: *const c_double_complex,
+        incx: *const i64,
+        y: *const c_double_complex,
+        incy: *const i64,
+        ap: *mut c_double_complex,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn sgemm_64_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        b: *const c_float,
+        ldb: *const i64,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ssymm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        b: *const c_float,
+        ldb: *const i64,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ssyrk_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ssyr2k_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        b: *const c_float,
+        ldb: *const i64,
+        beta: *const c_float,
+        c: *mut c_float,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn strmm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        b: *mut c_float,
+        ldb: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn strsm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float,
+        a: *const c_float,
+        lda: *const i64,
+        b: *mut c_float,
+        ldb: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn dgemm_64_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        b: *const c_double,
+        ldb: *const i64,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn dsymm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        b: *const c_double,
+        ldb: *const i64,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn dsyrk_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn dsyr2k_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        b: *const c_double,
+        ldb: *const i64,
+        beta: *const c_double,
+        c: *mut c_double,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn dtrmm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        b: *mut c_double,
+        ldb: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn dtrsm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double,
+        a: *const c_double,
+        lda: *const i64,
+        b: *mut c_double,
+        ldb: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn cgemm_64_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *const c_float_complex,
+        ldb: *const i64,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn csymm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *const c_float_complex,
+        ldb: *const i64,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn chemm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *const c_float_complex,
+        ldb: *const i64,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn csyrk_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn cherk_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float,
+        a: *const c_float_complex,
+        lda: *const i64,
+        beta: *const c_float,
+        c: *mut c_float_complex,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn csyr2k_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *const c_float_complex,
+        ldb: *const i64,
+        beta: *const c_float_complex,
+        c: *mut c_float_complex,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn cher2k_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *const c_float_complex,
+        ldb: *const i64,
+        beta: *const c_float,
+        c: *mut c_float_complex,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ctrmm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *mut c_float_complex,
+        ldb: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ctrsm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_float_complex,
+        a: *const c_float_complex,
+        lda: *const i64,
+        b: *mut c_float_complex,
+        ldb: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn zgemm_64_(
+        transa: *const c_char,
+        transb: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *const c_double_complex,
+        ldb: *const i64,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn zsymm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *const c_double_complex,
+        ldb: *const i64,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn zhemm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *const c_double_complex,
+        ldb: *const i64,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn zsyrk_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn zherk_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double,
+        a: *const c_double_complex,
+        lda: *const i64,
+        beta: *const c_double,
+        c: *mut c_double_complex,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn zsyr2k_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *const c_double_complex,
+        ldb: *const i64,
+        beta: *const c_double_complex,
+        c: *mut c_double_complex,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn zher2k_64_(
+        uplo: *const c_char,
+        trans: *const c_char,
+        n: *const i64,
+        k: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *const c_double_complex,
+        ldb: *const i64,
+        beta: *const c_double,
+        c: *mut c_double_complex,
+        ldc: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ztrmm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *mut c_double_complex,
+        ldb: *const i64,
+    );
+    #[cfg(not(feature = "hidden-length"))]
+    pub fn ztrsm_64_(
+        side: *const c_char,
+        uplo: *const c_char,
+        transa: *const c_char,
+        diag: *const c_char,
+        m: *const i64,
+        n: *const i64,
+        alpha: *const c_double_complex,
+        a: *const c_double_complex,
+        lda: *const i64,
+        b: *mut c_double_complex,
+        ldb: *const i64,
+    );
+}
@@ -40,15 +40,239 @@
 //! );
 //! ```
 //!
+//! ## Complex functions
+//!
+//! [`cdotu`], [`cdotc`], [`zdotu`], and [`zdotc`] return their result by
+//! value. By default, BLAS is assumed to pass the result through a hidden
+//! first argument, as f2c and g77 do. Enable the `complex-by-value` feature
+//! for libraries that return it in registers, as gfortran and OpenBLAS do.
+//!
+//! ## Character arguments
+//!
+//! Fortran passes the length of each character argument, such as `trans` or
+//! `uplo`, as a hidden argument after the visible ones, which is omitted by
+//! default. Enable the `hidden-length` feature to pass it to the Level 2 and
+//! Level 3 routines, which recent versions of gfortran might require.
+//!
+//! ## Threads
+//!
+//! [`set_num_threads`] and [`get_num_threads`] control the number of threads
+//! used by the library in use if it is OpenBLAS, BLIS, or MKL, and
+//! [`backend_info`] tells which one it is.
+//!
+//! ## Tracing
+//!
+//! The `trace` feature reports each call to a sink; see [`trace`] for
+//! details. The `counter` feature, which implies `trace`, adds [`counter`],
+//! which accumulates the theoretical numbers of floating-point operations and
+//! bytes touched per routine. Without the features, tracing and counting cost
+//! nothing.
+//!
+//! The `record` feature, which implies `trace`, adds [`record`], which writes
+//! the calls with their arguments to a file and replays them, reporting the
+//! outputs that differ. The `blas-replay` binary, which also requires the
+//! `dynamic` feature, replays a recording using the library given by
+//! `BLAS_LIBRARY`.
+//!
+//! ## Extensions
+//!
+//! [`saxpby`], [`somatcopy`], [`simatcopy`], [`sgemmt`], and their
+//! counterparts for the other types are common extensions to BLAS. They are
+//! passed on to the library in use if it provides them, as OpenBLAS and MKL
+//! do; otherwise, they are performed in Rust in terms of the other routines.
+//!
+//! ## Half precision
+//!
+//! The `half` feature adds [`mixed`], which multiplies matrices stored in 16
+//! bits using `f32` arithmetic.
+//!
+//! ## Reference implementation
+//!
+//! The `reference` feature replaces the native library with a pure-Rust
+//! implementation of all routines, which follows the reference implementation
+//! from Netlib. Nothing needs to be linked then, which is convenient for
+//! testing and for targets without BLAS, but the implementation is not
+//! optimized.
+//!
+//! ## Dynamic loading
+//!
+//! The `dynamic` feature defers the choice of the library until run time; see
+//! [`dynamic`] for details.
+//!
+//! ## 64-bit integers
+//!
+//! The `ilp64` feature adds [`ilp64`], which mirrors the wrappers at the root
+//! of the crate for ILP64 builds of BLAS, taking `i64` dimensions and
+//! increments. It cannot be combined with the `reference` feature unless the
+//! `dynamic` feature is enabled too, in which case the routines are looked up
+//! in the library loaded at run time, so that a build with all features
+//! enabled needs no library at link time.
+//!
 //! [architecture]: https://blas-lapack-rs.github.io/architecture
 //! [blas]: https://en.wikipedia.org/wiki/BLAS
-
-extern crate blas_sys as ffi;
+//! [`backend_info`]: fn.backend_info.html
+//! [`cdotc`]: fn.cdotc.html
+//! [`cdotu`]: fn.cdotu.html
+//! [`counter`]: counter/index.html
+//! [`dynamic`]: dynamic/index.html
+//! [`get_num_threads`]: fn.get_num_threads.html
+//! [`ilp64`]: ilp64/index.html
+//! [`mixed`]: mixed/index.html
+//! [`record`]: record/index.html
+//! [`saxpby`]: fn.saxpby.html
+//! [`set_num_threads`]: fn.set_num_threads.html
+//! [`sgemmt`]: fn.sgemmt.html
+//! [`simatcopy`]: fn.simatcopy.html
+//! [`somatcopy`]: fn.somatcopy.html
+//! [`trace`]: trace/index.html
+//! [`zdotc`]: fn.zdotc.html
+//! [`zdotu`]: fn.zdotu.html
+
+#![allow(clippy::missing_safety_doc, clippy::too_many_arguments)]
+
+extern crate blas_sys;
+#[cfg(feature = "half")]
+extern crate half;
 extern crate libc;
 extern crate num_complex as num;
 
 use libc::c_char;
 
+#[cfg(feature = "record")]
+macro_rules! record {
+    ($name:ident, [$($argument:ident),*], $call:expr) => {{
+        let recording = ::record::before(stringify!($name), |buffers| {
+            (
+                vec![$(::record::Argument::width(&$argument)),*],
+                vec![$((stringify!($argument), ::record::Argument::value(&$argument, buffers))),*],
+            )
+        });
+        let result = $call;
+        if let Some(recording) = recording {
+            ::record::after(
+                recording,
+                |buffers| vec![$((stringify!($argument), ::record::Argument::output(&$argument, buffers))),*],
+                ::record::Argument::value(&result, true),
+            );
+        }
+        result
+    }};
+}
+
+#[cfg(all(feature = "trace", not(feature = "record")))]
+macro_rules! record {
+    ($name:ident, [$($argument:ident),*], $call:expr) => {
+        $call
+    };
+}
+
+#[cfg(feature = "trace")]
+macro_rules! trace {
+    (
+        $name:ident,
+        [$($flag:ident),*],
+        [$($dimension:ident),*],
+        [$($leading:ident),*],
+        [$($argument:ident),*],
+        $call:expr
+    ) => {
+        record!($name, [$($argument),*], {
+            let start = ::trace::start();
+            let result = $call;
+            if let Some(start) = start {
+                ::trace::record(
+                    stringify!($name),
+                    vec![$((stringify!($flag), $flag as char)),*],
+                    vec![$((stringify!($dimension), i64::from($dimension))),*],
+                    vec![$((stringify!($leading), i64::from($leading))),*],
+                    start.elapsed(),
+                );
+            }
+            result
+        })
+    };
+}
+
+#[cfg(not(feature = "trace"))]
+macro_rules! trace {
+    (
+        $name:ident,
+        [$($flag:ident),*],
+        [$($dimension:ident),*],
+        [$($leading:ident),*],
+        [$($argument:ident),*],
+        $call:expr
+    ) => {
+        $call
+    };
+}
+
+pub mod batch;
+pub mod checked;
+#[cfg(feature = "counter")]
+pub mod counter;
+#[cfg(feature = "dynamic")]
+pub mod dynamic;
+#[cfg(feature = "ilp64")]
+pub mod ilp64;
+pub mod matrix;
+#[cfg(feature = "half")]
+pub mod mixed;
+#[cfg(feature = "record")]
+pub mod record;
+pub mod row_major;
+pub mod tiled;
+#[cfg(feature = "trace")]
+pub mod trace;
+pub mod vector;
+#[cfg(feature = "xerbla")]
+pub mod xerbla;
+
+mod backend;
+mod complex;
+mod error;
+mod extension;
+mod flags;
+#[cfg(all(unix, not(any(feature = "dynamic", feature = "reference"))))]
+mod native;
+mod parallel;
+mod scalar;
+
+#[cfg(all(
+    feature = "hidden-length",
+    not(any(feature = "dynamic", feature = "reference")),
+))]
+mod hidden;
+#[cfg(all(feature = "reference", not(feature = "dynamic")))]
+mod reference;
+
+#[cfg(not(any(feature = "dynamic", feature = "hidden-length", feature = "reference")))]
+use blas_sys as ffi;
+#[cfg(feature = "dynamic")]
+use dynamic as ffi;
+#[cfg(all(
+    feature = "hidden-length",
+    not(any(feature = "dynamic", feature = "reference")),
+))]
+use hidden as ffi;
+#[cfg(all(feature = "reference", not(feature = "dynamic")))]
+use reference as ffi;
+
+#[cfg(feature = "dynamic")]
+use dynamic as native;
+
+#[cfg(all(feature = "ilp64", feature = "reference", not(feature = "dynamic")))]
+compile_error!(
+    "the `ilp64` feature requires a native library or the `dynamic` feature, as the \
+     `reference` feature provides no ILP64 interface"
+);
+
+pub use backend::*;
+pub use error::{Error, Result};
+pub use extension::*;
+pub use flags::{Diag, Side, Transpose, Uplo};
+pub use scalar::Blas;
+
 /// A complex number with 32-bit parts.
 #[allow(non_camel_case_types)]
 pub type c32 = num::Complex<f32>;
@@ -59,373 +283,705 @@ pub type c64 = num::Complex<f64>;
 
 #[inline]
 pub unsafe fn srotg(a: &mut f32, b: &mut f32, c: &mut f32, s: &mut f32) {
-    ffi::srotg_(a, b, c, s)
+    trace!(srotg, [], [], [], [a, b, c, s], ffi::srotg_(a, b, c, s))
 }
 
 #[inline]
 pub unsafe fn srotmg(d1: &mut f32, d2: &mut f32, x1: &mut f32, y1: f32, param: &mut [f32]) {
-    ffi::srotmg_(d1, d2, x1, &y1, param.as_mut_ptr())
+    trace!(
+        srotmg,
+        [],
+        [],
+        [],
+        [d1, d2, x1, y1, param],
+        ffi::srotmg_(d1, d2, x1, &y1, param.as_mut_ptr())
+    )
 }
 
 #[inline]
 pub unsafe fn srot(n: i32, x: &mut [f32], incx: i32, y: &mut [f32], incy: i32, c: f32, s: f32) {
-    ffi::srot_(&n, x.as_mut_ptr(), &incx, y.as_mut_ptr(), &incy, &c, &s)
+    trace!(
+        srot,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy, c, s],
+        ffi::srot_(&n, x.as_mut_ptr(), &incx, y.as_mut_ptr(), &incy, &c, &s)
+    )
 }
 
 #[inline]
 pub unsafe fn srotm(n: i32, x: &mut [f32], incx: i32, y: &mut [f32], incy: i32, param: &[f32]) {
-    ffi::srotm_(
-        &n,
-        x.as_mut_ptr(),
-        &incx,
-        y.as_mut_ptr(),
-        &incy,
-        param.as_ptr(),
+    trace!(
+        srotm,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy, param],
+        ffi::srotm_(
+            &n,
+            x.as_mut_ptr(),
+            &incx,
+            y.as_mut_ptr(),
+            &incy,
+            param.as_ptr()
+        )
     )
 }
 
 #[inline]
 pub unsafe fn sswap(n: i32, x: &mut [f32], incx: i32, y: &mut [f32], incy: i32) {
-    ffi::sswap_(&n, x.as_mut_ptr(), &incx, y.as_mut_ptr(), &incy)
+    trace!(
+        sswap,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::sswap_(&n, x.as_mut_ptr(), &incx, y.as_mut_ptr(), &incy)
+    )
 }
 
 #[inline]
 pub unsafe fn sscal(n: i32, a: f32, x: &mut [f32], incx: i32) {
-    ffi::sscal_(&n, &a, x.as_mut_ptr(), &incx)
+    trace!(
+        sscal,
+        [],
+        [n],
+        [],
+        [n, a, x, incx],
+        ffi::sscal_(&n, &a, x.as_mut_ptr(), &incx)
+    )
 }
 
 #[inline]
 pub unsafe fn scopy(n: i32, x: &[f32], incx: i32, y: &mut [f32], incy: i32) {
-    ffi::scopy_(&n, x.as_ptr(), &incx, y.as_mut_ptr(), &incy)
+    trace!(
+        scopy,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::scopy_(&n, x.as_ptr(), &incx, y.as_mut_ptr(), &incy)
+    )
 }
 
 #[inline]
 pub unsafe fn saxpy(n: i32, alpha: f32, x: &[f32], incx: i32, y: &mut [f32], incy: i32) {
-    ffi::saxpy_(&n, &alpha, x.as_ptr(), &incx, y.as_mut_ptr(), &incy)
+    trace!(
+        saxpy,
+        [],
+        [n],
+        [],
+        [n, alpha, x, incx, y, incy],
+        ffi::saxpy_(&n, &alpha, x.as_ptr(), &incx, y.as_mut_ptr(), &incy)
+    )
 }
 
 #[inline]
 pub unsafe fn sdot(n: i32, x: &[f32], incx: i32, y: &[f32], incy: i32) -> f32 {
-    ffi::sdot_(&n, x.as_ptr(), &incx, y.as_ptr(), &incy)
+    trace!(
+        sdot,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::sdot_(&n, x.as_ptr(), &incx, y.as_ptr(), &incy)
+    )
 }
 
 #[inline]
 pub unsafe fn sdsdot(n: i32, sb: &[f32], x: &[f32], incx: i32, y: &[f32], incy: i32) -> f32 {
-    ffi::sdsdot_(&n, sb.as_ptr(), x.as_ptr(), &incx, y.as_ptr(), &incy)
+    trace!(
+        sdsdot,
+        [],
+        [n],
+        [],
+        [n, sb, x, incx, y, incy],
+        ffi::sdsdot_(&n, sb.as_ptr(), x.as_ptr(), &incx, y.as_ptr(), &incy)
+    )
 }
 
 #[inline]
 pub unsafe fn snrm2(n: i32, x: &[f32], incx: i32) -> f32 {
-    ffi::snrm2_(&n, x.as_ptr(), &incx)
+    trace!(
+        snrm2,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::snrm2_(&n, x.as_ptr(), &incx)
+    )
 }
 
 #[inline]
 pub unsafe fn scnrm2(n: i32, x: &[c32], incx: i32) -> f32 {
-    ffi::scnrm2_(&n, x.as_ptr() as *const _, &incx)
+    trace!(
+        scnrm2,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::scnrm2_(&n, x.as_ptr() as *const _, &incx)
+    )
 }
 
 #[inline]
 pub unsafe fn sasum(n: i32, x: &[f32], incx: i32) -> f32 {
-    ffi::sasum_(&n, x.as_ptr(), &incx)
+    trace!(
+        sasum,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::sasum_(&n, x.as_ptr(), &incx)
+    )
 }
 
 #[inline]
 pub unsafe fn isamax(n: i32, x: &[f32], incx: i32) -> usize {
-    ffi::isamax_(&n, x.as_ptr(), &incx) as usize
+    trace!(
+        isamax,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::isamax_(&n, x.as_ptr(), &incx) as usize
+    )
 }
 
 #[inline]
 pub unsafe fn drotg(a: &mut f64, b: &mut f64, c: &mut f64, s: &mut f64) {
-    ffi::drotg_(a, b, c, s)
+    trace!(drotg, [], [], [], [a, b, c, s], ffi::drotg_(a, b, c, s))
 }
 
 #[inline]
 pub unsafe fn drotmg(d1: &mut f64, d2: &mut f64, x1: &mut f64, y1: f64, param: &mut [f64]) {
-    ffi::drotmg_(d1, d2, x1, &y1, param.as_mut_ptr())
+    trace!(
+        drotmg,
+        [],
+        [],
+        [],
+        [d1, d2, x1, y1, param],
+        ffi::drotmg_(d1, d2, x1, &y1, param.as_mut_ptr())
+    )
 }
 
 #[inline]
 pub unsafe fn drot(n: i32, x: &mut [f64], incx: i32, y: &mut [f64], incy: i32, c: f64, s: f64) {
-    ffi::drot_(&n, x.as_mut_ptr(), &incx, y.as_mut_ptr(), &incy, &c, &s)
+    trace!(
+        drot,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy, c, s],
+        ffi::drot_(&n, x.as_mut_ptr(), &incx, y.as_mut_ptr(), &incy, &c, &s)
+    )
 }
 
 #[inline]
 pub unsafe fn drotm(n: i32, x: &mut [f64], incx: i32, y: &mut [f64], incy: i32, param: &[f64]) {
-    ffi::drotm_(
-        &n,
-        x.as_mut_ptr(),
-        &incx,
-        y.as_mut_ptr(),
-        &incy,
-        param.as_ptr(),
+    trace!(
+        drotm,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy, param],
+        ffi::drotm_(
+            &n,
+            x.as_mut_ptr(),
+            &incx,
+            y.as_mut_ptr(),
+            &incy,
+            param.as_ptr()
+        )
     )
 }
 
 #[inline]
 pub unsafe fn dswap(n: i32, x: &mut [f64], incx: i32, y: &mut [f64], incy: i32) {
-    ffi::dswap_(&n, x.as_mut_ptr(), &incx, y.as_mut_ptr(), &incy)
+    trace!(
+        dswap,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::dswap_(&n, x.as_mut_ptr(), &incx, y.as_mut_ptr(), &incy)
+    )
 }
 
 #[inline]
 pub unsafe fn dscal(n: i32, a: f64, x: &mut [f64], incx: i32) {
-    ffi::dscal_(&n, &a, x.as_mut_ptr(), &incx)
+    trace!(
+        dscal,
+        [],
+        [n],
+        [],
+        [n, a, x, incx],
+        ffi::dscal_(&n, &a, x.as_mut_ptr(), &incx)
+    )
 }
 
 #[inline]
 pub unsafe fn dcopy(n: i32, x: &[f64], incx: i32, y: &mut [f64], incy: i32) {
-    ffi::dcopy_(&n, x.as_ptr(), &incx, y.as_mut_ptr(), &incy)
+    trace!(
+        dcopy,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::dcopy_(&n, x.as_ptr(), &incx, y.as_mut_ptr(), &incy)
+    )
 }
 
 #[inline]
 pub unsafe fn daxpy(n: i32, alpha: f64, x: &[f64], incx: i32, y: &mut [f64], incy: i32) {
-    ffi::daxpy_(&n, &alpha, x.as_ptr(), &incx, y.as_mut_ptr(), &incy)
+    trace!(
+        daxpy,
+        [],
+        [n],
+        [],
+        [n, alpha, x, incx, y, incy],
+        ffi::daxpy_(&n, &alpha, x.as_ptr(), &incx, y.as_mut_ptr(), &incy)
+    )
 }
 
 #[inline]
 pub unsafe fn ddot(n: i32, x: &[f64], incx: i32, y: &[f64], incy: i32) -> f64 {
-    ffi::ddot_(&n, x.as_ptr(), &incx, y.as_ptr(), &incy)
+    trace!(
+        ddot,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::ddot_(&n, x.as_ptr(), &incx, y.as_ptr(), &incy)
+    )
 }
 
 #[inline]
 pub unsafe fn dsdot(n: i32, x: &[f32], incx: i32, y: &[f32], incy: i32) -> f64 {
-    ffi::dsdot_(&n, x.as_ptr(), &incx, y.as_ptr(), &incy)
+    trace!(
+        dsdot,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::dsdot_(&n, x.as_ptr(), &incx, y.as_ptr(), &incy)
+    )
 }
 
 #[inline]
 pub unsafe fn dnrm2(n: i32, x: &[f64], incx: i32) -> f64 {
-    ffi::dnrm2_(&n, x.as_ptr(), &incx)
+    trace!(
+        dnrm2,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::dnrm2_(&n, x.as_ptr(), &incx)
+    )
 }
 
 #[inline]
 pub unsafe fn dznrm2(n: i32, x: &[c64], incx: i32) -> f64 {
-    ffi::dznrm2_(&n, x.as_ptr() as *const _, &incx)
+    trace!(
+        dznrm2,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::dznrm2_(&n, x.as_ptr() as *const _, &incx)
+    )
 }
 
 #[inline]
 pub unsafe fn dasum(n: i32, x: &[f64], incx: i32) -> f64 {
-    ffi::dasum_(&n, x.as_ptr(), &incx)
+    trace!(
+        dasum,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::dasum_(&n, x.as_ptr(), &incx)
+    )
 }
 
 #[inline]
 pub unsafe fn idamax(n: i32, x: &[f64], incx: i32) -> usize {
-    ffi::idamax_(&n, x.as_ptr(), &incx) as usize
+    trace!(
+        idamax,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::idamax_(&n, x.as_ptr(), &incx) as usize
+    )
 }
 
 #[inline]
 pub unsafe fn crotg(a: &mut c32, b: c32, c: &mut f32, s: &mut c32) {
-    ffi::crotg_(
-        a as *mut _ as *mut _,
-        &b as *const _ as *const _,
-        c,
-        s as *mut _ as *mut _,
+    trace!(
+        crotg,
+        [],
+        [],
+        [],
+        [a, b, c, s],
+        ffi::crotg_(
+            a as *mut _ as *mut _,
+            &b as *const _ as *const _,
+            c,
+            s as *mut _ as *mut _
+        )
     )
 }
 
 #[inline]
 pub unsafe fn csrot(n: i32, x: &mut [c32], incx: i32, y: &mut [c32], incy: i32, c: f32, s: f32) {
-    ffi::csrot_(
-        &n,
-        x.as_mut_ptr() as *mut _,
-        &incx,
-        y.as_mut_ptr() as *mut _,
-        &incy,
-        &c,
-        &s,
+    trace!(
+        csrot,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy, c, s],
+        ffi::csrot_(
+            &n,
+            x.as_mut_ptr() as *mut _,
+            &incx,
+            y.as_mut_ptr() as *mut _,
+            &incy,
+            &c,
+            &s
+        )
     )
 }
 
 #[inline]
 pub unsafe fn cswap(n: i32, x: &mut [c32], incx: i32, y: &mut [c32], incy: i32) {
-    ffi::cswap_(
-        &n,
-        x.as_mut_ptr() as *mut _,
-        &incx,
-        y.as_mut_ptr() as *mut _,
-        &incy,
+    trace!(
+        cswap,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::cswap_(
+            &n,
+            x.as_mut_ptr() as *mut _,
+            &incx,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn cscal(n: i32, a: c32, x: &mut [c32], incx: i32) {
-    ffi::cscal_(
-        &n,
-        &a as *const _ as *const _,
-        x.as_mut_ptr() as *mut _,
-        &incx,
+    trace!(
+        cscal,
+        [],
+        [n],
+        [],
+        [n, a, x, incx],
+        ffi::cscal_(
+            &n,
+            &a as *const _ as *const _,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
     )
 }
 
 #[inline]
 pub unsafe fn csscal(n: i32, a: f32, x: &mut [c32], incx: i32) {
-    ffi::csscal_(&n, &a, x.as_mut_ptr() as *mut _, &incx)
+    trace!(
+        csscal,
+        [],
+        [n],
+        [],
+        [n, a, x, incx],
+        ffi::csscal_(&n, &a, x.as_mut_ptr() as *mut _, &incx)
+    )
 }
 
 #[inline]
 pub unsafe fn ccopy(n: i32, x: &[c32], incx: i32, y: &mut [c32], incy: i32) {
-    ffi::ccopy_(
-        &n,
-        x.as_ptr() as *const _,
-        &incx,
-        y.as_mut_ptr() as *mut _,
-        &incy,
+    trace!(
+        ccopy,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::ccopy_(
+            &n,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn caxpy(n: i32, alpha: c32, x: &[c32], incx: i32, y: &mut [c32], incy: i32) {
-    ffi::caxpy_(
-        &n,
-        &alpha as *const _ as *const _,
-        x.as_ptr() as *const _,
-        &incx,
-        y.as_mut_ptr() as *mut _,
-        &incy,
+    trace!(
+        caxpy,
+        [],
+        [n],
+        [],
+        [n, alpha, x, incx, y, incy],
+        ffi::caxpy_(
+            &n,
+            &alpha as *const _ as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
     )
 }
 
 #[inline]
-pub unsafe fn cdotu(pres: &mut [c32], n: i32, x: &[c32], incx: i32, y: &[c32], incy: i32) {
-    ffi::cdotu_(
-        pres.as_mut_ptr() as *mut _,
-        &n,
-        x.as_ptr() as *const _,
-        &incx,
-        y.as_ptr() as *const _,
-        &incy,
+pub unsafe fn cdotu(n: i32, x: &[c32], incx: i32, y: &[c32], incy: i32) -> c32 {
+    trace!(
+        cdotu,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        complex::cdotu_(
+            &n,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy
+        )
     )
 }
 
 #[inline]
-pub unsafe fn cdotc(pres: &mut [c32], n: i32, x: &[c32], incx: i32, y: &[c32], incy: i32) {
-    ffi::cdotc_(
-        pres.as_mut_ptr() as *mut _,
-        &n,
-        x.as_ptr() as *const _,
-        &incx,
-        y.as_ptr() as *const _,
-        &incy,
+pub unsafe fn cdotc(n: i32, x: &[c32], incx: i32, y: &[c32], incy: i32) -> c32 {
+    trace!(
+        cdotc,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        complex::cdotc_(
+            &n,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn scasum(n: i32, x: &[c32], incx: i32) -> f32 {
-    ffi::scasum_(&n, x.as_ptr() as *const _, &incx)
+    trace!(
+        scasum,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::scasum_(&n, x.as_ptr() as *const _, &incx)
+    )
 }
 
 #[inline]
 pub unsafe fn icamax(n: i32, x: &[c32], incx: i32) -> usize {
-    ffi::icamax_(&n, x.as_ptr() as *const _, &incx) as usize
+    trace!(
+        icamax,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::icamax_(&n, x.as_ptr() as *const _, &incx) as usize
+    )
 }
 
 #[inline]
 pub unsafe fn zrotg(a: &mut c64, b: c64, c: &mut f64, s: &mut c64) {
-    ffi::zrotg_(
-        a as *mut _ as *mut _,
-        &b as *const _ as *const _,
-        c,
-        s as *mut _ as *mut _,
+    trace!(
+        zrotg,
+        [],
+        [],
+        [],
+        [a, b, c, s],
+        ffi::zrotg_(
+            a as *mut _ as *mut _,
+            &b as *const _ as *const _,
+            c,
+            s as *mut _ as *mut _
+        )
     )
 }
 
 #[inline]
 pub unsafe fn zdrot(n: i32, x: &mut [c64], incx: i32, y: &mut [c64], incy: i32, c: f64, s: f64) {
-    ffi::zdrot_(
-        &n,
-        x.as_mut_ptr() as *mut _,
-        &incx,
-        y.as_mut_ptr() as *mut _,
-        &incy,
-        &c,
-        &s,
+    trace!(
+        zdrot,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy, c, s],
+        ffi::zdrot_(
+            &n,
+            x.as_mut_ptr() as *mut _,
+            &incx,
+            y.as_mut_ptr() as *mut _,
+            &incy,
+            &c,
+            &s
+        )
     )
 }
 
 #[inline]
 pub unsafe fn zswap(n: i32, x: &mut [c64], incx: i32, y: &mut [c64], incy: i32) {
-    ffi::zswap_(
-        &n,
-        x.as_mut_ptr() as *mut _,
-        &incx,
-        y.as_mut_ptr() as *mut _,
-        &incy,
+    trace!(
+        zswap,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::zswap_(
+            &n,
+            x.as_mut_ptr() as *mut _,
+            &incx,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn zscal(n: i32, a: c64, x: &mut [c64], incx: i32) {
-    ffi::zscal_(
-        &n,
-        &a as *const _ as *const _,
-        x.as_mut_ptr() as *mut _,
-        &incx,
+    trace!(
+        zscal,
+        [],
+        [n],
+        [],
+        [n, a, x, incx],
+        ffi::zscal_(
+            &n,
+            &a as *const _ as *const _,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
     )
 }
 
 #[inline]
 pub unsafe fn zdscal(n: i32, a: f64, x: &mut [c64], incx: i32) {
-    ffi::zdscal_(&n, &a, x.as_mut_ptr() as *mut _, &incx)
+    trace!(
+        zdscal,
+        [],
+        [n],
+        [],
+        [n, a, x, incx],
+        ffi::zdscal_(&n, &a, x.as_mut_ptr() as *mut _, &incx)
+    )
 }
 
 #[inline]
 pub unsafe fn zcopy(n: i32, x: &[c64], incx: i32, y: &mut [c64], incy: i32) {
-    ffi::zcopy_(
-        &n,
-        x.as_ptr() as *const _,
-        &incx,
-        y.as_mut_ptr() as *mut _,
-        &incy,
+    trace!(
+        zcopy,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        ffi::zcopy_(
+            &n,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn zaxpy(n: i32, alpha: c64, x: &[c64], incx: i32, y: &mut [c64], incy: i32) {
-    ffi::zaxpy_(
-        &n,
-        &alpha as *const _ as *const _,
-        x.as_ptr() as *const _,
-        &incx,
-        y.as_mut_ptr() as *mut _,
-        &incy,
+    trace!(
+        zaxpy,
+        [],
+        [n],
+        [],
+        [n, alpha, x, incx, y, incy],
+        ffi::zaxpy_(
+            &n,
+            &alpha as *const _ as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
     )
 }
 
 #[inline]
-pub unsafe fn zdotu(pres: &mut [c64], n: i32, x: &[c64], incx: i32, y: &[c64], incy: i32) {
-    ffi::zdotu_(
-        pres.as_mut_ptr() as *mut _,
-        &n,
-        x.as_ptr() as *const _,
-        &incx,
-        y.as_ptr() as *const _,
-        &incy,
+pub unsafe fn zdotu(n: i32, x: &[c64], incx: i32, y: &[c64], incy: i32) -> c64 {
+    trace!(
+        zdotu,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        complex::zdotu_(
+            &n,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy
+        )
     )
 }
 
 #[inline]
-pub unsafe fn zdotc(pres: &mut [c64], n: i32, x: &[c64], incx: i32, y: &[c64], incy: i32) {
-    ffi::zdotc_(
-        pres.as_mut_ptr() as *mut _,
-        &n,
-        x.as_ptr() as *const _,
-        &incx,
-        y.as_ptr() as *const _,
-        &incy,
+pub unsafe fn zdotc(n: i32, x: &[c64], incx: i32, y: &[c64], incy: i32) -> c64 {
+    trace!(
+        zdotc,
+        [],
+        [n],
+        [],
+        [n, x, incx, y, incy],
+        complex::zdotc_(
+            &n,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn dzasum(n: i32, x: &[c64], incx: i32) -> f64 {
-    ffi::dzasum_(&n, x.as_ptr() as *const _, &incx)
+    trace!(
+        dzasum,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::dzasum_(&n, x.as_ptr() as *const _, &incx)
+    )
 }
 
 #[inline]
 pub unsafe fn izamax(n: i32, x: &[c64], incx: i32) -> usize {
-    ffi::izamax_(&n, x.as_ptr() as *const _, &incx) as usize
+    trace!(
+        izamax,
+        [],
+        [n],
+        [],
+        [n, x, incx],
+        ffi::izamax_(&n, x.as_ptr() as *const _, &incx) as usize
+    )
 }
 
 #[inline]
 pub unsafe fn sgemv(
-    trans: u8,
+    trans: impl Into<u8>,
     m: i32,
     n: i32,
     alpha: f32,
@@ -437,24 +993,32 @@ pub unsafe fn sgemv(
     y: &mut [f32],
     incy: i32,
 ) {
-    ffi::sgemv_(
-        &(trans as c_char),
-        &m,
-        &n,
-        &alpha,
-        a.as_ptr(),
-        &lda,
-        x.as_ptr(),
-        &incx,
-        &beta,
-        y.as_mut_ptr(),
-        &incy,
+    let trans: u8 = trans.into();
+    trace!(
+        sgemv,
+        [trans],
+        [m, n],
+        [lda],
+        [trans, m, n, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::sgemv_(
+            &(trans as c_char),
+            &m,
+            &n,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            x.as_ptr(),
+            &incx,
+            &beta,
+            y.as_mut_ptr(),
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn sgbmv(
-    trans: u8,
+    trans: impl Into<u8>,
     m: i32,
     n: i32,
     kl: i32,
@@ -468,26 +1032,34 @@ pub unsafe fn sgbmv(
     y: &mut [f32],
     incy: i32,
 ) {
-    ffi::sgbmv_(
-        &(trans as c_char),
-        &m,
-        &n,
-        &kl,
-        &ku,
-        &alpha,
-        a.as_ptr(),
-        &lda,
-        x.as_ptr(),
-        &incx,
-        &beta,
-        y.as_mut_ptr(),
-        &incy,
+    let trans: u8 = trans.into();
+    trace!(
+        sgbmv,
+        [trans],
+        [m, n, kl, ku],
+        [lda],
+        [trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::sgbmv_(
+            &(trans as c_char),
+            &m,
+            &n,
+            &kl,
+            &ku,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            x.as_ptr(),
+            &incx,
+            &beta,
+            y.as_mut_ptr(),
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn ssymv(
-    uplo: u8,
+    uplo: impl Into<u8>,
     n: i32,
     alpha: f32,
     a: &[f32],
@@ -498,23 +1070,31 @@ pub unsafe fn ssymv(
     y: &mut [f32],
     incy: i32,
 ) {
-    ffi::ssymv_(
-        &(uplo as c_char),
-        &n,
-        &alpha,
-        a.as_ptr(),
-        &lda,
-        x.as_ptr(),
-        &incx,
-        &beta,
-        y.as_mut_ptr(),
-        &incy,
+    let uplo: u8 = uplo.into();
+    trace!(
+        ssymv,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::ssymv_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            x.as_ptr(),
+            &incx,
+            &beta,
+            y.as_mut_ptr(),
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn ssbmv(
-    uplo: u8,
+    uplo: impl Into<u8>,
     n: i32,
     k: i32,
     alpha: f32,
@@ -526,24 +1106,32 @@ pub unsafe fn ssbmv(
     y: &mut [f32],
     incy: i32,
 ) {
-    ffi::ssbmv_(
-        &(uplo as c_char),
-        &n,
-        &k,
-        &alpha,
-        a.as_ptr(),
-        &lda,
-        x.as_ptr(),
-        &incx,
-        &beta,
-        y.as_mut_ptr(),
-        &incy,
+    let uplo: u8 = uplo.into();
+    trace!(
+        ssbmv,
+        [uplo],
+        [n, k],
+        [lda],
+        [uplo, n, k, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::ssbmv_(
+            &(uplo as c_char),
+            &n,
+            &k,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            x.as_ptr(),
+            &incx,
+            &beta,
+            y.as_mut_ptr(),
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn sspmv(
-    uplo: u8,
+    uplo: impl Into<u8>,
     n: i32,
     alpha: f32,
     ap: &[f32],
@@ -553,47 +1141,65 @@ pub unsafe fn sspmv(
     y: &mut [f32],
     incy: i32,
 ) {
-    ffi::sspmv_(
-        &(uplo as c_char),
-        &n,
-        &alpha,
-        ap.as_ptr(),
-        x.as_ptr(),
-        &incx,
-        &beta,
-        y.as_mut_ptr(),
-        &incy,
+    let uplo: u8 = uplo.into();
+    trace!(
+        sspmv,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, ap, x, incx, beta, y, incy],
+        ffi::sspmv_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            ap.as_ptr(),
+            x.as_ptr(),
+            &incx,
+            &beta,
+            y.as_mut_ptr(),
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn strmv(
-    uplo: u8,
-    transa: u8,
-    diag: u8,
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
     n: i32,
     a: &[f32],
     lda: i32,
     b: &mut [f32],
     incx: i32,
 ) {
-    ffi::strmv_(
-        &(uplo as c_char),
-        &(transa as c_char),
-        &(diag as c_char),
-        &n,
-        a.as_ptr(),
-        &lda,
-        b.as_mut_ptr(),
-        &incx,
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        strmv,
+        [uplo, transa, diag],
+        [n],
+        [lda],
+        [uplo, transa, diag, n, a, lda, b, incx],
+        ffi::strmv_(
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &n,
+            a.as_ptr(),
+            &lda,
+            b.as_mut_ptr(),
+            &incx
+        )
     )
 }
 
 #[inline]
 pub unsafe fn stbmv(
-    uplo: u8,
-    trans: u8,
-    diag: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
     n: i32,
     k: i32,
     a: &[f32],
@@ -601,60 +1207,98 @@ pub unsafe fn stbmv(
     x: &mut [f32],
     incx: i32,
 ) {
-    ffi::stbmv_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &(diag as c_char),
-        &n,
-        &k,
-        a.as_ptr(),
-        &lda,
-        x.as_mut_ptr(),
-        &incx,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        stbmv,
+        [uplo, trans, diag],
+        [n, k],
+        [lda],
+        [uplo, trans, diag, n, k, a, lda, x, incx],
+        ffi::stbmv_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            &k,
+            a.as_ptr(),
+            &lda,
+            x.as_mut_ptr(),
+            &incx
+        )
     )
 }
 
 #[inline]
-pub unsafe fn stpmv(uplo: u8, trans: u8, diag: u8, n: i32, ap: &[f32], x: &mut [f32], incx: i32) {
-    ffi::stpmv_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &(diag as c_char),
-        &n,
-        ap.as_ptr(),
-        x.as_mut_ptr(),
-        &incx,
+pub unsafe fn stpmv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i32,
+    ap: &[f32],
+    x: &mut [f32],
+    incx: i32,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        stpmv,
+        [uplo, trans, diag],
+        [n],
+        [],
+        [uplo, trans, diag, n, ap, x, incx],
+        ffi::stpmv_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            ap.as_ptr(),
+            x.as_mut_ptr(),
+            &incx
+        )
     )
 }
 
 #[inline]
 pub unsafe fn strsv(
-    uplo: u8,
-    trans: u8,
-    diag: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
     n: i32,
     a: &[f32],
     lda: i32,
     x: &mut [f32],
     incx: i32,
 ) {
-    ffi::strsv_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &(diag as c_char),
-        &n,
-        a.as_ptr(),
-        &lda,
-        x.as_mut_ptr(),
-        &incx,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        strsv,
+        [uplo, trans, diag],
+        [n],
+        [lda],
+        [uplo, trans, diag, n, a, lda, x, incx],
+        ffi::strsv_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            a.as_ptr(),
+            &lda,
+            x.as_mut_ptr(),
+            &incx
+        )
     )
 }
 
 #[inline]
 pub unsafe fn stbsv(
-    uplo: u8,
-    trans: u8,
-    diag: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
     n: i32,
     k: i32,
     a: &[f32],
@@ -662,29 +1306,57 @@ pub unsafe fn stbsv(
     x: &mut [f32],
     incx: i32,
 ) {
-    ffi::stbsv_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &(diag as c_char),
-        &n,
-        &k,
-        a.as_ptr(),
-        &lda,
-        x.as_mut_ptr(),
-        &incx,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        stbsv,
+        [uplo, trans, diag],
+        [n, k],
+        [lda],
+        [uplo, trans, diag, n, k, a, lda, x, incx],
+        ffi::stbsv_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            &k,
+            a.as_ptr(),
+            &lda,
+            x.as_mut_ptr(),
+            &incx
+        )
     )
 }
 
 #[inline]
-pub unsafe fn stpsv(uplo: u8, trans: u8, diag: u8, n: i32, ap: &[f32], x: &mut [f32], incx: i32) {
-    ffi::stpsv_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &(diag as c_char),
-        &n,
-        ap.as_ptr(),
-        x.as_mut_ptr(),
-        &incx,
+pub unsafe fn stpsv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i32,
+    ap: &[f32],
+    x: &mut [f32],
+    incx: i32,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        stpsv,
+        [uplo, trans, diag],
+        [n],
+        [],
+        [uplo, trans, diag, n, ap, x, incx],
+        ffi::stpsv_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            ap.as_ptr(),
+            x.as_mut_ptr(),
+            &incx
+        )
     )
 }
 
@@ -700,47 +1372,78 @@ pub unsafe fn sger(
     a: &mut [f32],
     lda: i32,
 ) {
-    ffi::sger_(
-        &m,
-        &n,
-        &alpha,
-        x.as_ptr(),
-        &incx,
-        y.as_ptr(),
-        &incy,
-        a.as_mut_ptr(),
-        &lda,
+    trace!(
+        sger,
+        [],
+        [m, n],
+        [lda],
+        [m, n, alpha, x, incx, y, incy, a, lda],
+        ffi::sger_(
+            &m,
+            &n,
+            &alpha,
+            x.as_ptr(),
+            &incx,
+            y.as_ptr(),
+            &incy,
+            a.as_mut_ptr(),
+            &lda
+        )
     )
 }
 
 #[inline]
-pub unsafe fn ssyr(uplo: u8, n: i32, alpha: f32, x: &[f32], incx: i32, a: &mut [f32], lda: i32) {
-    ffi::ssyr_(
-        &(uplo as c_char),
-        &n,
-        &alpha,
-        x.as_ptr(),
-        &incx,
-        a.as_mut_ptr(),
-        &lda,
+pub unsafe fn ssyr(
+    uplo: impl Into<u8>,
+    n: i32,
+    alpha: f32,
+    x: &[f32],
+    incx: i32,
+    a: &mut [f32],
+    lda: i32,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        ssyr,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, x, incx, a, lda],
+        ffi::ssyr_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr(),
+            &incx,
+            a.as_mut_ptr(),
+            &lda
+        )
     )
 }
 
 #[inline]
-pub unsafe fn sspr(uplo: u8, n: i32, alpha: f32, x: &[f32], incx: i32, ap: &mut [f32]) {
-    ffi::sspr_(
-        &(uplo as c_char),
-        &n,
-        &alpha,
-        x.as_ptr(),
-        &incx,
-        ap.as_mut_ptr(),
+pub unsafe fn sspr(uplo: impl Into<u8>, n: i32, alpha: f32, x: &[f32], incx: i32, ap: &mut [f32]) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        sspr,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, x, incx, ap],
+        ffi::sspr_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr(),
+            &incx,
+            ap.as_mut_ptr()
+        )
     )
 }
 
 #[inline]
 pub unsafe fn ssyr2(
-    uplo: u8,
+    uplo: impl Into<u8>,
     n: i32,
     alpha: f32,
     x: &[f32],
@@ -750,22 +1453,30 @@ pub unsafe fn ssyr2(
     a: &mut [f32],
     lda: i32,
 ) {
-    ffi::ssyr2_(
-        &(uplo as c_char),
-        &n,
-        &alpha,
-        x.as_ptr(),
-        &incx,
-        y.as_ptr(),
-        &incy,
-        a.as_mut_ptr(),
-        &lda,
+    let uplo: u8 = uplo.into();
+    trace!(
+        ssyr2,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, x, incx, y, incy, a, lda],
+        ffi::ssyr2_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr(),
+            &incx,
+            y.as_ptr(),
+            &incy,
+            a.as_mut_ptr(),
+            &lda
+        )
     )
 }
 
 #[inline]
 pub unsafe fn sspr2(
-    uplo: u8,
+    uplo: impl Into<u8>,
     n: i32,
     alpha: f32,
     x: &[f32],
@@ -774,21 +1485,29 @@ pub unsafe fn sspr2(
     incy: i32,
     ap: &mut [f32],
 ) {
-    ffi::sspr2_(
-        &(uplo as c_char),
-        &n,
-        &alpha,
-        x.as_ptr(),
-        &incx,
-        y.as_ptr(),
-        &incy,
-        ap.as_mut_ptr(),
+    let uplo: u8 = uplo.into();
+    trace!(
+        sspr2,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, x, incx, y, incy, ap],
+        ffi::sspr2_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr(),
+            &incx,
+            y.as_ptr(),
+            &incy,
+            ap.as_mut_ptr()
+        )
     )
 }
 
 #[inline]
 pub unsafe fn dgemv(
-    trans: u8,
+    trans: impl Into<u8>,
     m: i32,
     n: i32,
     alpha: f64,
@@ -800,24 +1519,32 @@ pub unsafe fn dgemv(
     y: &mut [f64],
     incy: i32,
 ) {
-    ffi::dgemv_(
-        &(trans as c_char),
-        &m,
-        &n,
-        &alpha,
-        a.as_ptr(),
-        &lda,
-        x.as_ptr(),
-        &incx,
-        &beta,
-        y.as_mut_ptr(),
-        &incy,
+    let trans: u8 = trans.into();
+    trace!(
+        dgemv,
+        [trans],
+        [m, n],
+        [lda],
+        [trans, m, n, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::dgemv_(
+            &(trans as c_char),
+            &m,
+            &n,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            x.as_ptr(),
+            &incx,
+            &beta,
+            y.as_mut_ptr(),
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn dgbmv(
-    trans: u8,
+    trans: impl Into<u8>,
     m: i32,
     n: i32,
     kl: i32,
@@ -831,26 +1558,34 @@ pub unsafe fn dgbmv(
     y: &mut [f64],
     incy: i32,
 ) {
-    ffi::dgbmv_(
-        &(trans as c_char),
-        &m,
-        &n,
-        &kl,
-        &ku,
-        &alpha,
-        a.as_ptr(),
-        &lda,
-        x.as_ptr(),
-        &incx,
-        &beta,
-        y.as_mut_ptr(),
-        &incy,
+    let trans: u8 = trans.into();
+    trace!(
+        dgbmv,
+        [trans],
+        [m, n, kl, ku],
+        [lda],
+        [trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::dgbmv_(
+            &(trans as c_char),
+            &m,
+            &n,
+            &kl,
+            &ku,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            x.as_ptr(),
+            &incx,
+            &beta,
+            y.as_mut_ptr(),
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn dsymv(
-    uplo: u8,
+    uplo: impl Into<u8>,
     n: i32,
     alpha: f64,
     a: &[f64],
@@ -861,23 +1596,31 @@ pub unsafe fn dsymv(
     y: &mut [f64],
     incy: i32,
 ) {
-    ffi::dsymv_(
-        &(uplo as c_char),
-        &n,
-        &alpha,
-        a.as_ptr(),
-        &lda,
-        x.as_ptr(),
-        &incx,
-        &beta,
-        y.as_mut_ptr(),
-        &incy,
+    let uplo: u8 = uplo.into();
+    trace!(
+        dsymv,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::dsymv_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            x.as_ptr(),
+            &incx,
+            &beta,
+            y.as_mut_ptr(),
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn dsbmv(
-    uplo: u8,
+    uplo: impl Into<u8>,
     n: i32,
     k: i32,
     alpha: f64,
@@ -889,24 +1632,32 @@ pub unsafe fn dsbmv(
     y: &mut [f64],
     incy: i32,
 ) {
-    ffi::dsbmv_(
-        &(uplo as c_char),
-        &n,
-        &k,
-        &alpha,
-        a.as_ptr(),
-        &lda,
-        x.as_ptr(),
-        &incx,
-        &beta,
-        y.as_mut_ptr(),
-        &incy,
+    let uplo: u8 = uplo.into();
+    trace!(
+        dsbmv,
+        [uplo],
+        [n, k],
+        [lda],
+        [uplo, n, k, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::dsbmv_(
+            &(uplo as c_char),
+            &n,
+            &k,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            x.as_ptr(),
+            &incx,
+            &beta,
+            y.as_mut_ptr(),
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn dspmv(
-    uplo: u8,
+    uplo: impl Into<u8>,
     n: i32,
     alpha: f64,
     ap: &[f64],
@@ -916,47 +1667,65 @@ pub unsafe fn dspmv(
     y: &mut [f64],
     incy: i32,
 ) {
-    ffi::dspmv_(
-        &(uplo as c_char),
-        &n,
-        &alpha,
-        ap.as_ptr(),
-        x.as_ptr(),
-        &incx,
-        &beta,
-        y.as_mut_ptr(),
-        &incy,
+    let uplo: u8 = uplo.into();
+    trace!(
+        dspmv,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, ap, x, incx, beta, y, incy],
+        ffi::dspmv_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            ap.as_ptr(),
+            x.as_ptr(),
+            &incx,
+            &beta,
+            y.as_mut_ptr(),
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn dtrmv(
-    uplo: u8,
-    transa: u8,
-    diag: u8,
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
     n: i32,
     a: &[f64],
     lda: i32,
     b: &mut [f64],
     incx: i32,
 ) {
-    ffi::dtrmv_(
-        &(uplo as c_char),
-        &(transa as c_char),
-        &(diag as c_char),
-        &n,
-        a.as_ptr(),
-        &lda,
-        b.as_mut_ptr(),
-        &incx,
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        dtrmv,
+        [uplo, transa, diag],
+        [n],
+        [lda],
+        [uplo, transa, diag, n, a, lda, b, incx],
+        ffi::dtrmv_(
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &n,
+            a.as_ptr(),
+            &lda,
+            b.as_mut_ptr(),
+            &incx
+        )
     )
 }
 
 #[inline]
 pub unsafe fn dtbmv(
-    uplo: u8,
-    trans: u8,
-    diag: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
     n: i32,
     k: i32,
     a: &[f64],
@@ -964,60 +1733,98 @@ pub unsafe fn dtbmv(
     x: &mut [f64],
     incx: i32,
 ) {
-    ffi::dtbmv_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &(diag as c_char),
-        &n,
-        &k,
-        a.as_ptr(),
-        &lda,
-        x.as_mut_ptr(),
-        &incx,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        dtbmv,
+        [uplo, trans, diag],
+        [n, k],
+        [lda],
+        [uplo, trans, diag, n, k, a, lda, x, incx],
+        ffi::dtbmv_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            &k,
+            a.as_ptr(),
+            &lda,
+            x.as_mut_ptr(),
+            &incx
+        )
     )
 }
 
 #[inline]
-pub unsafe fn dtpmv(uplo: u8, trans: u8, diag: u8, n: i32, ap: &[f64], x: &mut [f64], incx: i32) {
-    ffi::dtpmv_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &(diag as c_char),
-        &n,
-        ap.as_ptr(),
-        x.as_mut_ptr(),
-        &incx,
+pub unsafe fn dtpmv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i32,
+    ap: &[f64],
+    x: &mut [f64],
+    incx: i32,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        dtpmv,
+        [uplo, trans, diag],
+        [n],
+        [],
+        [uplo, trans, diag, n, ap, x, incx],
+        ffi::dtpmv_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            ap.as_ptr(),
+            x.as_mut_ptr(),
+            &incx
+        )
     )
 }
 
 #[inline]
 pub unsafe fn dtrsv(
-    uplo: u8,
-    trans: u8,
-    diag: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
     n: i32,
     a: &[f64],
     lda: i32,
     x: &mut [f64],
     incx: i32,
 ) {
-    ffi::dtrsv_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &(diag as c_char),
-        &n,
-        a.as_ptr(),
-        &lda,
-        x.as_mut_ptr(),
-        &incx,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        dtrsv,
+        [uplo, trans, diag],
+        [n],
+        [lda],
+        [uplo, trans, diag, n, a, lda, x, incx],
+        ffi::dtrsv_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            a.as_ptr(),
+            &lda,
+            x.as_mut_ptr(),
+            &incx
+        )
     )
 }
 
 #[inline]
 pub unsafe fn dtbsv(
-    uplo: u8,
-    trans: u8,
-    diag: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
     n: i32,
     k: i32,
     a: &[f64],
@@ -1025,29 +1832,57 @@ pub unsafe fn dtbsv(
     x: &mut [f64],
     incx: i32,
 ) {
-    ffi::dtbsv_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &(diag as c_char),
-        &n,
-        &k,
-        a.as_ptr(),
-        &lda,
-        x.as_mut_ptr(),
-        &incx,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        dtbsv,
+        [uplo, trans, diag],
+        [n, k],
+        [lda],
+        [uplo, trans, diag, n, k, a, lda, x, incx],
+        ffi::dtbsv_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            &k,
+            a.as_ptr(),
+            &lda,
+            x.as_mut_ptr(),
+            &incx
+        )
     )
 }
 
 #[inline]
-pub unsafe fn dtpsv(uplo: u8, trans: u8, diag: u8, n: i32, ap: &[f64], x: &mut [f64], incx: i32) {
-    ffi::dtpsv_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &(diag as c_char),
-        &n,
-        ap.as_ptr(),
-        x.as_mut_ptr(),
-        &incx,
+pub unsafe fn dtpsv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i32,
+    ap: &[f64],
+    x: &mut [f64],
+    incx: i32,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        dtpsv,
+        [uplo, trans, diag],
+        [n],
+        [],
+        [uplo, trans, diag, n, ap, x, incx],
+        ffi::dtpsv_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            ap.as_ptr(),
+            x.as_mut_ptr(),
+            &incx
+        )
     )
 }
 
@@ -1063,47 +1898,78 @@ pub unsafe fn dger(
     a: &mut [f64],
     lda: i32,
 ) {
-    ffi::dger_(
-        &m,
-        &n,
-        &alpha,
-        x.as_ptr(),
-        &incx,
-        y.as_ptr(),
-        &incy,
-        a.as_mut_ptr(),
-        &lda,
+    trace!(
+        dger,
+        [],
+        [m, n],
+        [lda],
+        [m, n, alpha, x, incx, y, incy, a, lda],
+        ffi::dger_(
+            &m,
+            &n,
+            &alpha,
+            x.as_ptr(),
+            &incx,
+            y.as_ptr(),
+            &incy,
+            a.as_mut_ptr(),
+            &lda
+        )
     )
 }
 
 #[inline]
-pub unsafe fn dsyr(uplo: u8, n: i32, alpha: f64, x: &[f64], incx: i32, a: &mut [f64], lda: i32) {
-    ffi::dsyr_(
-        &(uplo as c_char),
-        &n,
-        &alpha,
-        x.as_ptr(),
-        &incx,
-        a.as_mut_ptr(),
-        &lda,
+pub unsafe fn dsyr(
+    uplo: impl Into<u8>,
+    n: i32,
+    alpha: f64,
+    x: &[f64],
+    incx: i32,
+    a: &mut [f64],
+    lda: i32,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        dsyr,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, x, incx, a, lda],
+        ffi::dsyr_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr(),
+            &incx,
+            a.as_mut_ptr(),
+            &lda
+        )
     )
 }
 
 #[inline]
-pub unsafe fn dspr(uplo: u8, n: i32, alpha: f64, x: &[f64], incx: i32, ap: &mut [f64]) {
-    ffi::dspr_(
-        &(uplo as c_char),
-        &n,
-        &alpha,
-        x.as_ptr(),
-        &incx,
-        ap.as_mut_ptr(),
+pub unsafe fn dspr(uplo: impl Into<u8>, n: i32, alpha: f64, x: &[f64], incx: i32, ap: &mut [f64]) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        dspr,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, x, incx, ap],
+        ffi::dspr_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr(),
+            &incx,
+            ap.as_mut_ptr()
+        )
     )
 }
 
 #[inline]
 pub unsafe fn dsyr2(
-    uplo: u8,
+    uplo: impl Into<u8>,
     n: i32,
     alpha: f64,
     x: &[f64],
@@ -1113,22 +1979,30 @@ pub unsafe fn dsyr2(
     a: &mut [f64],
     lda: i32,
 ) {
-    ffi::dsyr2_(
-        &(uplo as c_char),
-        &n,
-        &alpha,
-        x.as_ptr(),
-        &incx,
-        y.as_ptr(),
-        &incy,
-        a.as_mut_ptr(),
-        &lda,
+    let uplo: u8 = uplo.into();
+    trace!(
+        dsyr2,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, x, incx, y, incy, a, lda],
+        ffi::dsyr2_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr(),
+            &incx,
+            y.as_ptr(),
+            &incy,
+            a.as_mut_ptr(),
+            &lda
+        )
     )
 }
 
 #[inline]
 pub unsafe fn dspr2(
-    uplo: u8,
+    uplo: impl Into<u8>,
     n: i32,
     alpha: f64,
     x: &[f64],
@@ -1137,21 +2011,29 @@ pub unsafe fn dspr2(
     incy: i32,
     ap: &mut [f64],
 ) {
-    ffi::dspr2_(
-        &(uplo as c_char),
-        &n,
-        &alpha,
-        x.as_ptr(),
-        &incx,
-        y.as_ptr(),
-        &incy,
-        ap.as_mut_ptr(),
+    let uplo: u8 = uplo.into();
+    trace!(
+        dspr2,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, x, incx, y, incy, ap],
+        ffi::dspr2_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr(),
+            &incx,
+            y.as_ptr(),
+            &incy,
+            ap.as_mut_ptr()
+        )
     )
 }
 
 #[inline]
 pub unsafe fn cgemv(
-    trans: u8,
+    trans: impl Into<u8>,
     m: i32,
     n: i32,
     alpha: c32,
@@ -1163,24 +2045,32 @@ pub unsafe fn cgemv(
     y: &mut [c32],
     incy: i32,
 ) {
-    ffi::cgemv_(
-        &(trans as c_char),
-        &m,
-        &n,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        x.as_ptr() as *const _,
-        &incx,
-        &beta as *const _ as *const _,
-        y.as_mut_ptr() as *mut _,
-        &incy,
+    let trans: u8 = trans.into();
+    trace!(
+        cgemv,
+        [trans],
+        [m, n],
+        [lda],
+        [trans, m, n, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::cgemv_(
+            &(trans as c_char),
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_ptr() as *const _,
+            &incx,
+            &beta as *const _ as *const _,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn cgbmv(
-    trans: u8,
+    trans: impl Into<u8>,
     m: i32,
     n: i32,
     kl: i32,
@@ -1194,26 +2084,34 @@ pub unsafe fn cgbmv(
     y: &mut [c32],
     incy: i32,
 ) {
-    ffi::cgbmv_(
-        &(trans as c_char),
-        &m,
-        &n,
-        &kl,
-        &ku,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        x.as_ptr() as *const _,
-        &incx,
-        &beta as *const _ as *const _,
-        y.as_mut_ptr() as *mut _,
-        &incy,
+    let trans: u8 = trans.into();
+    trace!(
+        cgbmv,
+        [trans],
+        [m, n, kl, ku],
+        [lda],
+        [trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::cgbmv_(
+            &(trans as c_char),
+            &m,
+            &n,
+            &kl,
+            &ku,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_ptr() as *const _,
+            &incx,
+            &beta as *const _ as *const _,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn chemv(
-    uplo: u8,
+    uplo: impl Into<u8>,
     n: i32,
     alpha: c32,
     a: &[c32],
@@ -1224,23 +2122,31 @@ pub unsafe fn chemv(
     y: &mut [c32],
     incy: i32,
 ) {
-    ffi::chemv_(
-        &(uplo as c_char),
-        &n,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        x.as_ptr() as *const _,
-        &incx,
-        &beta as *const _ as *const _,
-        y.as_mut_ptr() as *mut _,
-        &incy,
+    let uplo: u8 = uplo.into();
+    trace!(
+        chemv,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::chemv_(
+            &(uplo as c_char),
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_ptr() as *const _,
+            &incx,
+            &beta as *const _ as *const _,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn chbmv(
-    uplo: u8,
+    uplo: impl Into<u8>,
     n: i32,
     k: i32,
     alpha: c32,
@@ -1252,24 +2158,32 @@ pub unsafe fn chbmv(
     y: &mut [c32],
     incy: i32,
 ) {
-    ffi::chbmv_(
-        &(uplo as c_char),
-        &n,
-        &k,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        x.as_ptr() as *const _,
-        &incx,
-        &beta as *const _ as *const _,
-        y.as_mut_ptr() as *mut _,
-        &incy,
+    let uplo: u8 = uplo.into();
+    trace!(
+        chbmv,
+        [uplo],
+        [n, k],
+        [lda],
+        [uplo, n, k, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::chbmv_(
+            &(uplo as c_char),
+            &n,
+            &k,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_ptr() as *const _,
+            &incx,
+            &beta as *const _ as *const _,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn chpmv(
-    uplo: u8,
+    uplo: impl Into<u8>,
     n: i32,
     alpha: c32,
     ap: &[c32],
@@ -1279,47 +2193,65 @@ pub unsafe fn chpmv(
     y: &mut [c32],
     incy: i32,
 ) {
-    ffi::chpmv_(
-        &(uplo as c_char),
-        &n,
-        &alpha as *const _ as *const _,
-        ap.as_ptr() as *const _,
-        x.as_ptr() as *const _,
-        &incx,
-        &beta as *const _ as *const _,
-        y.as_mut_ptr() as *mut _,
-        &incy,
+    let uplo: u8 = uplo.into();
+    trace!(
+        chpmv,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, ap, x, incx, beta, y, incy],
+        ffi::chpmv_(
+            &(uplo as c_char),
+            &n,
+            &alpha as *const _ as *const _,
+            ap.as_ptr() as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            &beta as *const _ as *const _,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn ctrmv(
-    uplo: u8,
-    transa: u8,
-    diag: u8,
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
     n: i32,
     a: &[c32],
     lda: i32,
     b: &mut [c32],
     incx: i32,
 ) {
-    ffi::ctrmv_(
-        &(uplo as c_char),
-        &(transa as c_char),
-        &(diag as c_char),
-        &n,
-        a.as_ptr() as *const _,
-        &lda,
-        b.as_mut_ptr() as *mut _,
-        &incx,
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ctrmv,
+        [uplo, transa, diag],
+        [n],
+        [lda],
+        [uplo, transa, diag, n, a, lda, b, incx],
+        ffi::ctrmv_(
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &n,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_mut_ptr() as *mut _,
+            &incx
+        )
     )
 }
 
 #[inline]
 pub unsafe fn ctbmv(
-    uplo: u8,
-    trans: u8,
-    diag: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
     n: i32,
     k: i32,
     a: &[c32],
@@ -1327,60 +2259,98 @@ pub unsafe fn ctbmv(
     x: &mut [c32],
     incx: i32,
 ) {
-    ffi::ctbmv_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &(diag as c_char),
-        &n,
-        &k,
-        a.as_ptr() as *const _,
-        &lda,
-        x.as_mut_ptr() as *mut _,
-        &incx,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ctbmv,
+        [uplo, trans, diag],
+        [n, k],
+        [lda],
+        [uplo, trans, diag, n, k, a, lda, x, incx],
+        ffi::ctbmv_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            &k,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
     )
 }
 
 #[inline]
-pub unsafe fn ctpmv(uplo: u8, trans: u8, diag: u8, n: i32, ap: &[c32], x: &mut [c32], incx: i32) {
-    ffi::ctpmv_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &(diag as c_char),
-        &n,
-        ap.as_ptr() as *const _,
-        x.as_mut_ptr() as *mut _,
-        &incx,
+pub unsafe fn ctpmv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i32,
+    ap: &[c32],
+    x: &mut [c32],
+    incx: i32,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ctpmv,
+        [uplo, trans, diag],
+        [n],
+        [],
+        [uplo, trans, diag, n, ap, x, incx],
+        ffi::ctpmv_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            ap.as_ptr() as *const _,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
     )
 }
 
 #[inline]
 pub unsafe fn ctrsv(
-    uplo: u8,
-    trans: u8,
-    diag: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
     n: i32,
     a: &[c32],
     lda: i32,
     x: &mut [c32],
     incx: i32,
 ) {
-    ffi::ctrsv_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &(diag as c_char),
-        &n,
-        a.as_ptr() as *const _,
-        &lda,
-        x.as_mut_ptr() as *mut _,
-        &incx,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ctrsv,
+        [uplo, trans, diag],
+        [n],
+        [lda],
+        [uplo, trans, diag, n, a, lda, x, incx],
+        ffi::ctrsv_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
     )
 }
 
 #[inline]
 pub unsafe fn ctbsv(
-    uplo: u8,
-    trans: u8,
-    diag: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
     n: i32,
     k: i32,
     a: &[c32],
@@ -1388,29 +2358,57 @@ pub unsafe fn ctbsv(
     x: &mut [c32],
     incx: i32,
 ) {
-    ffi::ctbsv_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &(diag as c_char),
-        &n,
-        &k,
-        a.as_ptr() as *const _,
-        &lda,
-        x.as_mut_ptr() as *mut _,
-        &incx,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ctbsv,
+        [uplo, trans, diag],
+        [n, k],
+        [lda],
+        [uplo, trans, diag, n, k, a, lda, x, incx],
+        ffi::ctbsv_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            &k,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
     )
 }
 
 #[inline]
-pub unsafe fn ctpsv(uplo: u8, trans: u8, diag: u8, n: i32, ap: &[c32], x: &mut [c32], incx: i32) {
-    ffi::ctpsv_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &(diag as c_char),
-        &n,
-        ap.as_ptr() as *const _,
-        x.as_mut_ptr() as *mut _,
-        &incx,
+pub unsafe fn ctpsv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i32,
+    ap: &[c32],
+    x: &mut [c32],
+    incx: i32,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ctpsv,
+        [uplo, trans, diag],
+        [n],
+        [],
+        [uplo, trans, diag, n, ap, x, incx],
+        ffi::ctpsv_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            ap.as_ptr() as *const _,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
     )
 }
 
@@ -1426,16 +2424,23 @@ pub unsafe fn cgeru(
     a: &mut [c32],
     lda: i32,
 ) {
-    ffi::cgeru_(
-        &m,
-        &n,
-        &alpha as *const _ as *const _,
-        x.as_ptr() as *const _,
-        &incx,
-        y.as_ptr() as *const _,
-        &incy,
-        a.as_mut_ptr() as *mut _,
-        &lda,
+    trace!(
+        cgeru,
+        [],
+        [m, n],
+        [lda],
+        [m, n, alpha, x, incx, y, incy, a, lda],
+        ffi::cgeru_(
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy,
+            a.as_mut_ptr() as *mut _,
+            &lda
+        )
     )
 }
 
@@ -1451,47 +2456,78 @@ pub unsafe fn cgerc(
     a: &mut [c32],
     lda: i32,
 ) {
-    ffi::cgerc_(
-        &m,
-        &n,
-        &alpha as *const _ as *const _,
-        x.as_ptr() as *const _,
-        &incx,
-        y.as_ptr() as *const _,
-        &incy,
-        a.as_mut_ptr() as *mut _,
-        &lda,
+    trace!(
+        cgerc,
+        [],
+        [m, n],
+        [lda],
+        [m, n, alpha, x, incx, y, incy, a, lda],
+        ffi::cgerc_(
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy,
+            a.as_mut_ptr() as *mut _,
+            &lda
+        )
     )
 }
 
 #[inline]
-pub unsafe fn cher(uplo: u8, n: i32, alpha: f32, x: &[c32], incx: i32, a: &mut [c32], lda: i32) {
-    ffi::cher_(
-        &(uplo as c_char),
-        &n,
-        &alpha,
-        x.as_ptr() as *const _,
-        &incx,
-        a.as_mut_ptr() as *mut _,
-        &lda,
+pub unsafe fn cher(
+    uplo: impl Into<u8>,
+    n: i32,
+    alpha: f32,
+    x: &[c32],
+    incx: i32,
+    a: &mut [c32],
+    lda: i32,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        cher,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, x, incx, a, lda],
+        ffi::cher_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr() as *const _,
+            &incx,
+            a.as_mut_ptr() as *mut _,
+            &lda
+        )
     )
 }
 
 #[inline]
-pub unsafe fn chpr(uplo: u8, n: i32, alpha: f32, x: &[c32], incx: i32, ap: &mut [c32]) {
-    ffi::chpr_(
-        &(uplo as c_char),
-        &n,
-        &alpha,
-        x.as_ptr() as *const _,
-        &incx,
-        ap.as_mut_ptr() as *mut _,
+pub unsafe fn chpr(uplo: impl Into<u8>, n: i32, alpha: f32, x: &[c32], incx: i32, ap: &mut [c32]) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        chpr,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, x, incx, ap],
+        ffi::chpr_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr() as *const _,
+            &incx,
+            ap.as_mut_ptr() as *mut _
+        )
     )
 }
 
 #[inline]
 pub unsafe fn chpr2(
-    uplo: u8,
+    uplo: impl Into<u8>,
     n: i32,
     alpha: c32,
     x: &[c32],
@@ -1500,21 +2536,29 @@ pub unsafe fn chpr2(
     incy: i32,
     ap: &mut [c32],
 ) {
-    ffi::chpr2_(
-        &(uplo as c_char),
-        &n,
-        &alpha as *const _ as *const _,
-        x.as_ptr() as *const _,
-        &incx,
-        y.as_ptr() as *const _,
-        &incy,
-        ap.as_mut_ptr() as *mut _,
+    let uplo: u8 = uplo.into();
+    trace!(
+        chpr2,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, x, incx, y, incy, ap],
+        ffi::chpr2_(
+            &(uplo as c_char),
+            &n,
+            &alpha as *const _ as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy,
+            ap.as_mut_ptr() as *mut _
+        )
     )
 }
 
 #[inline]
 pub unsafe fn cher2(
-    uplo: u8,
+    uplo: impl Into<u8>,
     n: i32,
     alpha: c32,
     x: &[c32],
@@ -1524,22 +2568,30 @@ pub unsafe fn cher2(
     a: &mut [c32],
     lda: i32,
 ) {
-    ffi::cher2_(
-        &(uplo as c_char),
-        &n,
-        &alpha as *const _ as *const _,
-        x.as_ptr() as *const _,
-        &incx,
-        y.as_ptr() as *const _,
-        &incy,
-        a.as_mut_ptr() as *mut _,
-        &lda,
+    let uplo: u8 = uplo.into();
+    trace!(
+        cher2,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, x, incx, y, incy, a, lda],
+        ffi::cher2_(
+            &(uplo as c_char),
+            &n,
+            &alpha as *const _ as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy,
+            a.as_mut_ptr() as *mut _,
+            &lda
+        )
     )
 }
 
 #[inline]
 pub unsafe fn zgemv(
-    trans: u8,
+    trans: impl Into<u8>,
     m: i32,
     n: i32,
     alpha: c64,
@@ -1551,24 +2603,32 @@ pub unsafe fn zgemv(
     y: &mut [c64],
     incy: i32,
 ) {
-    ffi::zgemv_(
-        &(trans as c_char),
-        &m,
-        &n,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        x.as_ptr() as *const _,
-        &incx,
-        &beta as *const _ as *const _,
-        y.as_mut_ptr() as *mut _,
-        &incy,
+    let trans: u8 = trans.into();
+    trace!(
+        zgemv,
+        [trans],
+        [m, n],
+        [lda],
+        [trans, m, n, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::zgemv_(
+            &(trans as c_char),
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_ptr() as *const _,
+            &incx,
+            &beta as *const _ as *const _,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn zgbmv(
-    trans: u8,
+    trans: impl Into<u8>,
     m: i32,
     n: i32,
     kl: i32,
@@ -1582,26 +2642,34 @@ pub unsafe fn zgbmv(
     y: &mut [c64],
     incy: i32,
 ) {
-    ffi::zgbmv_(
-        &(trans as c_char),
-        &m,
-        &n,
-        &kl,
-        &ku,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        x.as_ptr() as *const _,
-        &incx,
-        &beta as *const _ as *const _,
-        y.as_mut_ptr() as *mut _,
-        &incy,
+    let trans: u8 = trans.into();
+    trace!(
+        zgbmv,
+        [trans],
+        [m, n, kl, ku],
+        [lda],
+        [trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::zgbmv_(
+            &(trans as c_char),
+            &m,
+            &n,
+            &kl,
+            &ku,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_ptr() as *const _,
+            &incx,
+            &beta as *const _ as *const _,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn zhemv(
-    uplo: u8,
+    uplo: impl Into<u8>,
     n: i32,
     alpha: c64,
     a: &[c64],
@@ -1612,23 +2680,31 @@ pub unsafe fn zhemv(
     y: &mut [c64],
     incy: i32,
 ) {
-    ffi::zhemv_(
-        &(uplo as c_char),
-        &n,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        x.as_ptr() as *const _,
-        &incx,
-        &beta as *const _ as *const _,
-        y.as_mut_ptr() as *mut _,
-        &incy,
+    let uplo: u8 = uplo.into();
+    trace!(
+        zhemv,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::zhemv_(
+            &(uplo as c_char),
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_ptr() as *const _,
+            &incx,
+            &beta as *const _ as *const _,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn zhbmv(
-    uplo: u8,
+    uplo: impl Into<u8>,
     n: i32,
     k: i32,
     alpha: c64,
@@ -1640,24 +2716,32 @@ pub unsafe fn zhbmv(
     y: &mut [c64],
     incy: i32,
 ) {
-    ffi::zhbmv_(
-        &(uplo as c_char),
-        &n,
-        &k,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        x.as_ptr() as *const _,
-        &incx,
-        &beta as *const _ as *const _,
-        y.as_mut_ptr() as *mut _,
-        &incy,
+    let uplo: u8 = uplo.into();
+    trace!(
+        zhbmv,
+        [uplo],
+        [n, k],
+        [lda],
+        [uplo, n, k, alpha, a, lda, x, incx, beta, y, incy],
+        ffi::zhbmv_(
+            &(uplo as c_char),
+            &n,
+            &k,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_ptr() as *const _,
+            &incx,
+            &beta as *const _ as *const _,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn zhpmv(
-    uplo: u8,
+    uplo: impl Into<u8>,
     n: i32,
     alpha: c64,
     ap: &[c64],
@@ -1667,47 +2751,65 @@ pub unsafe fn zhpmv(
     y: &mut [c64],
     incy: i32,
 ) {
-    ffi::zhpmv_(
-        &(uplo as c_char),
-        &n,
-        &alpha as *const _ as *const _,
-        ap.as_ptr() as *const _,
-        x.as_ptr() as *const _,
-        &incx,
-        &beta as *const _ as *const _,
-        y.as_mut_ptr() as *mut _,
-        &incy,
+    let uplo: u8 = uplo.into();
+    trace!(
+        zhpmv,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, ap, x, incx, beta, y, incy],
+        ffi::zhpmv_(
+            &(uplo as c_char),
+            &n,
+            &alpha as *const _ as *const _,
+            ap.as_ptr() as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            &beta as *const _ as *const _,
+            y.as_mut_ptr() as *mut _,
+            &incy
+        )
     )
 }
 
 #[inline]
 pub unsafe fn ztrmv(
-    uplo: u8,
-    transa: u8,
-    diag: u8,
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
     n: i32,
     a: &[c64],
     lda: i32,
     b: &mut [c64],
     incx: i32,
 ) {
-    ffi::ztrmv_(
-        &(uplo as c_char),
-        &(transa as c_char),
-        &(diag as c_char),
-        &n,
-        a.as_ptr() as *const _,
-        &lda,
-        b.as_mut_ptr() as *mut _,
-        &incx,
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ztrmv,
+        [uplo, transa, diag],
+        [n],
+        [lda],
+        [uplo, transa, diag, n, a, lda, b, incx],
+        ffi::ztrmv_(
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &n,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_mut_ptr() as *mut _,
+            &incx
+        )
     )
 }
 
 #[inline]
 pub unsafe fn ztbmv(
-    uplo: u8,
-    trans: u8,
-    diag: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
     n: i32,
     k: i32,
     a: &[c64],
@@ -1715,60 +2817,98 @@ pub unsafe fn ztbmv(
     x: &mut [c64],
     incx: i32,
 ) {
-    ffi::ztbmv_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &(diag as c_char),
-        &n,
-        &k,
-        a.as_ptr() as *const _,
-        &lda,
-        x.as_mut_ptr() as *mut _,
-        &incx,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ztbmv,
+        [uplo, trans, diag],
+        [n, k],
+        [lda],
+        [uplo, trans, diag, n, k, a, lda, x, incx],
+        ffi::ztbmv_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            &k,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
     )
 }
 
 #[inline]
-pub unsafe fn ztpmv(uplo: u8, trans: u8, diag: u8, n: i32, ap: &[c64], x: &mut [c64], incx: i32) {
-    ffi::ztpmv_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &(diag as c_char),
-        &n,
-        ap.as_ptr() as *const _,
-        x.as_mut_ptr() as *mut _,
-        &incx,
+pub unsafe fn ztpmv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i32,
+    ap: &[c64],
+    x: &mut [c64],
+    incx: i32,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ztpmv,
+        [uplo, trans, diag],
+        [n],
+        [],
+        [uplo, trans, diag, n, ap, x, incx],
+        ffi::ztpmv_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            ap.as_ptr() as *const _,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
     )
 }
 
 #[inline]
 pub unsafe fn ztrsv(
-    uplo: u8,
-    trans: u8,
-    diag: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
     n: i32,
     a: &[c64],
     lda: i32,
     x: &mut [c64],
     incx: i32,
 ) {
-    ffi::ztrsv_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &(diag as c_char),
-        &n,
-        a.as_ptr() as *const _,
-        &lda,
-        x.as_mut_ptr() as *mut _,
-        &incx,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ztrsv,
+        [uplo, trans, diag],
+        [n],
+        [lda],
+        [uplo, trans, diag, n, a, lda, x, incx],
+        ffi::ztrsv_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
     )
 }
 
 #[inline]
 pub unsafe fn ztbsv(
-    uplo: u8,
-    trans: u8,
-    diag: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
     n: i32,
     k: i32,
     a: &[c64],
@@ -1776,29 +2916,57 @@ pub unsafe fn ztbsv(
     x: &mut [c64],
     incx: i32,
 ) {
-    ffi::ztbsv_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &(diag as c_char),
-        &n,
-        &k,
-        a.as_ptr() as *const _,
-        &lda,
-        x.as_mut_ptr() as *mut _,
-        &incx,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ztbsv,
+        [uplo, trans, diag],
+        [n, k],
+        [lda],
+        [uplo, trans, diag, n, k, a, lda, x, incx],
+        ffi::ztbsv_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            &k,
+            a.as_ptr() as *const _,
+            &lda,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
     )
 }
 
 #[inline]
-pub unsafe fn ztpsv(uplo: u8, trans: u8, diag: u8, n: i32, ap: &[c64], x: &mut [c64], incx: i32) {
-    ffi::ztpsv_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &(diag as c_char),
-        &n,
-        ap.as_ptr() as *const _,
-        x.as_mut_ptr() as *mut _,
-        &incx,
+pub unsafe fn ztpsv(
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
+    diag: impl Into<u8>,
+    n: i32,
+    ap: &[c64],
+    x: &mut [c64],
+    incx: i32,
+) {
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ztpsv,
+        [uplo, trans, diag],
+        [n],
+        [],
+        [uplo, trans, diag, n, ap, x, incx],
+        ffi::ztpsv_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &(diag as c_char),
+            &n,
+            ap.as_ptr() as *const _,
+            x.as_mut_ptr() as *mut _,
+            &incx
+        )
     )
 }
 
@@ -1814,16 +2982,23 @@ pub unsafe fn zgeru(
     a: &mut [c64],
     lda: i32,
 ) {
-    ffi::zgeru_(
-        &m,
-        &n,
-        &alpha as *const _ as *const _,
-        x.as_ptr() as *const _,
-        &incx,
-        y.as_ptr() as *const _,
-        &incy,
-        a.as_mut_ptr() as *mut _,
-        &lda,
+    trace!(
+        zgeru,
+        [],
+        [m, n],
+        [lda],
+        [m, n, alpha, x, incx, y, incy, a, lda],
+        ffi::zgeru_(
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy,
+            a.as_mut_ptr() as *mut _,
+            &lda
+        )
     )
 }
 
@@ -1839,47 +3014,78 @@ pub unsafe fn zgerc(
     a: &mut [c64],
     lda: i32,
 ) {
-    ffi::zgerc_(
-        &m,
-        &n,
-        &alpha as *const _ as *const _,
-        x.as_ptr() as *const _,
-        &incx,
-        y.as_ptr() as *const _,
-        &incy,
-        a.as_mut_ptr() as *mut _,
-        &lda,
+    trace!(
+        zgerc,
+        [],
+        [m, n],
+        [lda],
+        [m, n, alpha, x, incx, y, incy, a, lda],
+        ffi::zgerc_(
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy,
+            a.as_mut_ptr() as *mut _,
+            &lda
+        )
     )
 }
 
 #[inline]
-pub unsafe fn zher(uplo: u8, n: i32, alpha: f64, x: &[c64], incx: i32, a: &mut [c64], lda: i32) {
-    ffi::zher_(
-        &(uplo as c_char),
-        &n,
-        &alpha,
-        x.as_ptr() as *const _,
-        &incx,
-        a.as_mut_ptr() as *mut _,
-        &lda,
+pub unsafe fn zher(
+    uplo: impl Into<u8>,
+    n: i32,
+    alpha: f64,
+    x: &[c64],
+    incx: i32,
+    a: &mut [c64],
+    lda: i32,
+) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        zher,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, x, incx, a, lda],
+        ffi::zher_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr() as *const _,
+            &incx,
+            a.as_mut_ptr() as *mut _,
+            &lda
+        )
     )
 }
 
 #[inline]
-pub unsafe fn zhpr(uplo: u8, n: i32, alpha: f64, x: &[c64], incx: i32, ap: &mut [c64]) {
-    ffi::zhpr_(
-        &(uplo as c_char),
-        &n,
-        &alpha,
-        x.as_ptr() as *const _,
-        &incx,
-        ap.as_mut_ptr() as *mut _,
+pub unsafe fn zhpr(uplo: impl Into<u8>, n: i32, alpha: f64, x: &[c64], incx: i32, ap: &mut [c64]) {
+    let uplo: u8 = uplo.into();
+    trace!(
+        zhpr,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, x, incx, ap],
+        ffi::zhpr_(
+            &(uplo as c_char),
+            &n,
+            &alpha,
+            x.as_ptr() as *const _,
+            &incx,
+            ap.as_mut_ptr() as *mut _
+        )
     )
 }
 
 #[inline]
 pub unsafe fn zher2(
-    uplo: u8,
+    uplo: impl Into<u8>,
     n: i32,
     alpha: c64,
     x: &[c64],
@@ -1889,22 +3095,30 @@ pub unsafe fn zher2(
     a: &mut [c64],
     lda: i32,
 ) {
-    ffi::zher2_(
-        &(uplo as c_char),
-        &n,
-        &alpha as *const _ as *const _,
-        x.as_ptr() as *const _,
-        &incx,
-        y.as_ptr() as *const _,
-        &incy,
-        a.as_mut_ptr() as *mut _,
-        &lda,
+    let uplo: u8 = uplo.into();
+    trace!(
+        zher2,
+        [uplo],
+        [n],
+        [lda],
+        [uplo, n, alpha, x, incx, y, incy, a, lda],
+        ffi::zher2_(
+            &(uplo as c_char),
+            &n,
+            &alpha as *const _ as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy,
+            a.as_mut_ptr() as *mut _,
+            &lda
+        )
     )
 }
 
 #[inline]
 pub unsafe fn zhpr2(
-    uplo: u8,
+    uplo: impl Into<u8>,
     n: i32,
     alpha: c64,
     x: &[c64],
@@ -1913,22 +3127,30 @@ pub unsafe fn zhpr2(
     incy: i32,
     ap: &mut [c64],
 ) {
-    ffi::zhpr2_(
-        &(uplo as c_char),
-        &n,
-        &alpha as *const _ as *const _,
-        x.as_ptr() as *const _,
-        &incx,
-        y.as_ptr() as *const _,
-        &incy,
-        ap.as_mut_ptr() as *mut _,
+    let uplo: u8 = uplo.into();
+    trace!(
+        zhpr2,
+        [uplo],
+        [n],
+        [],
+        [uplo, n, alpha, x, incx, y, incy, ap],
+        ffi::zhpr2_(
+            &(uplo as c_char),
+            &n,
+            &alpha as *const _ as *const _,
+            x.as_ptr() as *const _,
+            &incx,
+            y.as_ptr() as *const _,
+            &incy,
+            ap.as_mut_ptr() as *mut _
+        )
     )
 }
 
 #[inline]
 pub unsafe fn sgemm(
-    transa: u8,
-    transb: u8,
+    transa: impl Into<u8>,
+    transb: impl Into<u8>,
     m: i32,
     n: i32,
     k: i32,
@@ -1941,27 +3163,36 @@ pub unsafe fn sgemm(
     c: &mut [f32],
     ldc: i32,
 ) {
-    ffi::sgemm_(
-        &(transa as c_char),
-        &(transb as c_char),
-        &m,
-        &n,
-        &k,
-        &alpha,
-        a.as_ptr(),
-        &lda,
-        b.as_ptr(),
-        &ldb,
-        &beta,
-        c.as_mut_ptr(),
-        &ldc,
+    let transa: u8 = transa.into();
+    let transb: u8 = transb.into();
+    trace!(
+        sgemm,
+        [transa, transb],
+        [m, n, k],
+        [lda, ldb, ldc],
+        [transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::sgemm_(
+            &(transa as c_char),
+            &(transb as c_char),
+            &m,
+            &n,
+            &k,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            b.as_ptr(),
+            &ldb,
+            &beta,
+            c.as_mut_ptr(),
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn ssymm(
-    side: u8,
-    uplo: u8,
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
     m: i32,
     n: i32,
     alpha: f32,
@@ -1973,26 +3204,35 @@ pub unsafe fn ssymm(
     c: &mut [f32],
     ldc: i32,
 ) {
-    ffi::ssymm_(
-        &(side as c_char),
-        &(uplo as c_char),
-        &m,
-        &n,
-        &alpha,
-        a.as_ptr(),
-        &lda,
-        b.as_ptr(),
-        &ldb,
-        &beta,
-        c.as_mut_ptr(),
-        &ldc,
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    trace!(
+        ssymm,
+        [side, uplo],
+        [m, n],
+        [lda, ldb, ldc],
+        [side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::ssymm_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &m,
+            &n,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            b.as_ptr(),
+            &ldb,
+            &beta,
+            c.as_mut_ptr(),
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn ssyrk(
-    uplo: u8,
-    trans: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
     n: i32,
     k: i32,
     alpha: f32,
@@ -2002,24 +3242,33 @@ pub unsafe fn ssyrk(
     c: &mut [f32],
     ldc: i32,
 ) {
-    ffi::ssyrk_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &n,
-        &k,
-        &alpha,
-        a.as_ptr(),
-        &lda,
-        &beta,
-        c.as_mut_ptr(),
-        &ldc,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        ssyrk,
+        [uplo, trans],
+        [n, k],
+        [lda, ldc],
+        [uplo, trans, n, k, alpha, a, lda, beta, c, ldc],
+        ffi::ssyrk_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            &beta,
+            c.as_mut_ptr(),
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn ssyr2k(
-    uplo: u8,
-    trans: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
     n: i32,
     k: i32,
     alpha: f32,
@@ -2031,28 +3280,37 @@ pub unsafe fn ssyr2k(
     c: &mut [f32],
     ldc: i32,
 ) {
-    ffi::ssyr2k_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &n,
-        &k,
-        &alpha,
-        a.as_ptr(),
-        &lda,
-        b.as_ptr(),
-        &ldb,
-        &beta,
-        c.as_mut_ptr(),
-        &ldc,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        ssyr2k,
+        [uplo, trans],
+        [n, k],
+        [lda, ldb, ldc],
+        [uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::ssyr2k_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            b.as_ptr(),
+            &ldb,
+            &beta,
+            c.as_mut_ptr(),
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn strmm(
-    side: u8,
-    uplo: u8,
-    transa: u8,
-    diag: u8,
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
     m: i32,
     n: i32,
     alpha: f32,
@@ -2061,27 +3319,38 @@ pub unsafe fn strmm(
     b: &mut [f32],
     ldb: i32,
 ) {
-    ffi::strmm_(
-        &(side as c_char),
-        &(uplo as c_char),
-        &(transa as c_char),
-        &(diag as c_char),
-        &m,
-        &n,
-        &alpha,
-        a.as_ptr(),
-        &lda,
-        b.as_mut_ptr(),
-        &ldb,
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        strmm,
+        [side, uplo, transa, diag],
+        [m, n],
+        [lda, ldb],
+        [side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb],
+        ffi::strmm_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &m,
+            &n,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            b.as_mut_ptr(),
+            &ldb
+        )
     )
 }
 
 #[inline]
 pub unsafe fn strsm(
-    side: u8,
-    uplo: u8,
-    transa: u8,
-    diag: u8,
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
     m: i32,
     n: i32,
     alpha: f32,
@@ -2090,25 +3359,36 @@ pub unsafe fn strsm(
     b: &mut [f32],
     ldb: i32,
 ) {
-    ffi::strsm_(
-        &(side as c_char),
-        &(uplo as c_char),
-        &(transa as c_char),
-        &(diag as c_char),
-        &m,
-        &n,
-        &alpha,
-        a.as_ptr(),
-        &lda,
-        b.as_mut_ptr(),
-        &ldb,
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        strsm,
+        [side, uplo, transa, diag],
+        [m, n],
+        [lda, ldb],
+        [side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb],
+        ffi::strsm_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &m,
+            &n,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            b.as_mut_ptr(),
+            &ldb
+        )
     )
 }
 
 #[inline]
 pub unsafe fn dgemm(
-    transa: u8,
-    transb: u8,
+    transa: impl Into<u8>,
+    transb: impl Into<u8>,
     m: i32,
     n: i32,
     k: i32,
@@ -2121,27 +3401,36 @@ pub unsafe fn dgemm(
     c: &mut [f64],
     ldc: i32,
 ) {
-    ffi::dgemm_(
-        &(transa as c_char),
-        &(transb as c_char),
-        &m,
-        &n,
-        &k,
-        &alpha,
-        a.as_ptr(),
-        &lda,
-        b.as_ptr(),
-        &ldb,
-        &beta,
-        c.as_mut_ptr(),
-        &ldc,
+    let transa: u8 = transa.into();
+    let transb: u8 = transb.into();
+    trace!(
+        dgemm,
+        [transa, transb],
+        [m, n, k],
+        [lda, ldb, ldc],
+        [transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::dgemm_(
+            &(transa as c_char),
+            &(transb as c_char),
+            &m,
+            &n,
+            &k,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            b.as_ptr(),
+            &ldb,
+            &beta,
+            c.as_mut_ptr(),
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn dsymm(
-    side: u8,
-    uplo: u8,
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
     m: i32,
     n: i32,
     alpha: f64,
@@ -2153,26 +3442,35 @@ pub unsafe fn dsymm(
     c: &mut [f64],
     ldc: i32,
 ) {
-    ffi::dsymm_(
-        &(side as c_char),
-        &(uplo as c_char),
-        &m,
-        &n,
-        &alpha,
-        a.as_ptr(),
-        &lda,
-        b.as_ptr(),
-        &ldb,
-        &beta,
-        c.as_mut_ptr(),
-        &ldc,
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    trace!(
+        dsymm,
+        [side, uplo],
+        [m, n],
+        [lda, ldb, ldc],
+        [side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::dsymm_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &m,
+            &n,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            b.as_ptr(),
+            &ldb,
+            &beta,
+            c.as_mut_ptr(),
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn dsyrk(
-    uplo: u8,
-    trans: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
     n: i32,
     k: i32,
     alpha: f64,
@@ -2182,24 +3480,33 @@ pub unsafe fn dsyrk(
     c: &mut [f64],
     ldc: i32,
 ) {
-    ffi::dsyrk_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &n,
-        &k,
-        &alpha,
-        a.as_ptr(),
-        &lda,
-        &beta,
-        c.as_mut_ptr(),
-        &ldc,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        dsyrk,
+        [uplo, trans],
+        [n, k],
+        [lda, ldc],
+        [uplo, trans, n, k, alpha, a, lda, beta, c, ldc],
+        ffi::dsyrk_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            &beta,
+            c.as_mut_ptr(),
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn dsyr2k(
-    uplo: u8,
-    trans: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
     n: i32,
     k: i32,
     alpha: f64,
@@ -2211,28 +3518,37 @@ pub unsafe fn dsyr2k(
     c: &mut [f64],
     ldc: i32,
 ) {
-    ffi::dsyr2k_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &n,
-        &k,
-        &alpha,
-        a.as_ptr(),
-        &lda,
-        b.as_ptr(),
-        &ldb,
-        &beta,
-        c.as_mut_ptr(),
-        &ldc,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        dsyr2k,
+        [uplo, trans],
+        [n, k],
+        [lda, ldb, ldc],
+        [uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::dsyr2k_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            b.as_ptr(),
+            &ldb,
+            &beta,
+            c.as_mut_ptr(),
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn dtrmm(
-    side: u8,
-    uplo: u8,
-    transa: u8,
-    diag: u8,
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
     m: i32,
     n: i32,
     alpha: f64,
@@ -2241,27 +3557,38 @@ pub unsafe fn dtrmm(
     b: &mut [f64],
     ldb: i32,
 ) {
-    ffi::dtrmm_(
-        &(side as c_char),
-        &(uplo as c_char),
-        &(transa as c_char),
-        &(diag as c_char),
-        &m,
-        &n,
-        &alpha,
-        a.as_ptr(),
-        &lda,
-        b.as_mut_ptr(),
-        &ldb,
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        dtrmm,
+        [side, uplo, transa, diag],
+        [m, n],
+        [lda, ldb],
+        [side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb],
+        ffi::dtrmm_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &m,
+            &n,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            b.as_mut_ptr(),
+            &ldb
+        )
     )
 }
 
 #[inline]
 pub unsafe fn dtrsm(
-    side: u8,
-    uplo: u8,
-    transa: u8,
-    diag: u8,
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
     m: i32,
     n: i32,
     alpha: f64,
@@ -2270,25 +3597,36 @@ pub unsafe fn dtrsm(
     b: &mut [f64],
     ldb: i32,
 ) {
-    ffi::dtrsm_(
-        &(side as c_char),
-        &(uplo as c_char),
-        &(transa as c_char),
-        &(diag as c_char),
-        &m,
-        &n,
-        &alpha,
-        a.as_ptr(),
-        &lda,
-        b.as_mut_ptr(),
-        &ldb,
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        dtrsm,
+        [side, uplo, transa, diag],
+        [m, n],
+        [lda, ldb],
+        [side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb],
+        ffi::dtrsm_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &m,
+            &n,
+            &alpha,
+            a.as_ptr(),
+            &lda,
+            b.as_mut_ptr(),
+            &ldb
+        )
     )
 }
 
 #[inline]
 pub unsafe fn cgemm(
-    transa: u8,
-    transb: u8,
+    transa: impl Into<u8>,
+    transb: impl Into<u8>,
     m: i32,
     n: i32,
     k: i32,
@@ -2301,27 +3639,36 @@ pub unsafe fn cgemm(
     c: &mut [c32],
     ldc: i32,
 ) {
-    ffi::cgemm_(
-        &(transa as c_char),
-        &(transb as c_char),
-        &m,
-        &n,
-        &k,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        b.as_ptr() as *const _,
-        &ldb,
-        &beta as *const _ as *const _,
-        c.as_mut_ptr() as *mut _,
-        &ldc,
+    let transa: u8 = transa.into();
+    let transb: u8 = transb.into();
+    trace!(
+        cgemm,
+        [transa, transb],
+        [m, n, k],
+        [lda, ldb, ldc],
+        [transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::cgemm_(
+            &(transa as c_char),
+            &(transb as c_char),
+            &m,
+            &n,
+            &k,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_ptr() as *const _,
+            &ldb,
+            &beta as *const _ as *const _,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn csymm(
-    side: u8,
-    uplo: u8,
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
     m: i32,
     n: i32,
     alpha: c32,
@@ -2333,26 +3680,35 @@ pub unsafe fn csymm(
     c: &mut [c32],
     ldc: i32,
 ) {
-    ffi::csymm_(
-        &(side as c_char),
-        &(uplo as c_char),
-        &m,
-        &n,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        b.as_ptr() as *const _,
-        &ldb,
-        &beta as *const _ as *const _,
-        c.as_mut_ptr() as *mut _,
-        &ldc,
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    trace!(
+        csymm,
+        [side, uplo],
+        [m, n],
+        [lda, ldb, ldc],
+        [side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::csymm_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_ptr() as *const _,
+            &ldb,
+            &beta as *const _ as *const _,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn chemm(
-    side: u8,
-    uplo: u8,
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
     m: i32,
     n: i32,
     alpha: c32,
@@ -2364,26 +3720,35 @@ pub unsafe fn chemm(
     c: &mut [c32],
     ldc: i32,
 ) {
-    ffi::chemm_(
-        &(side as c_char),
-        &(uplo as c_char),
-        &m,
-        &n,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        b.as_ptr() as *const _,
-        &ldb,
-        &beta as *const _ as *const _,
-        c.as_mut_ptr() as *mut _,
-        &ldc,
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    trace!(
+        chemm,
+        [side, uplo],
+        [m, n],
+        [lda, ldb, ldc],
+        [side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::chemm_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_ptr() as *const _,
+            &ldb,
+            &beta as *const _ as *const _,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn csyrk(
-    uplo: u8,
-    trans: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
     n: i32,
     k: i32,
     alpha: c32,
@@ -2393,24 +3758,33 @@ pub unsafe fn csyrk(
     c: &mut [c32],
     ldc: i32,
 ) {
-    ffi::csyrk_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &n,
-        &k,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        &beta as *const _ as *const _,
-        c.as_mut_ptr() as *mut _,
-        &ldc,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        csyrk,
+        [uplo, trans],
+        [n, k],
+        [lda, ldc],
+        [uplo, trans, n, k, alpha, a, lda, beta, c, ldc],
+        ffi::csyrk_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            &beta as *const _ as *const _,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn cherk(
-    uplo: u8,
-    trans: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
     n: i32,
     k: i32,
     alpha: f32,
@@ -2420,24 +3794,33 @@ pub unsafe fn cherk(
     c: &mut [c32],
     ldc: i32,
 ) {
-    ffi::cherk_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &n,
-        &k,
-        &alpha,
-        a.as_ptr() as *const _,
-        &lda,
-        &beta,
-        c.as_mut_ptr() as *mut _,
-        &ldc,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        cherk,
+        [uplo, trans],
+        [n, k],
+        [lda, ldc],
+        [uplo, trans, n, k, alpha, a, lda, beta, c, ldc],
+        ffi::cherk_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha,
+            a.as_ptr() as *const _,
+            &lda,
+            &beta,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn csyr2k(
-    uplo: u8,
-    trans: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
     n: i32,
     k: i32,
     alpha: c32,
@@ -2449,26 +3832,35 @@ pub unsafe fn csyr2k(
     c: &mut [c32],
     ldc: i32,
 ) {
-    ffi::csyr2k_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &n,
-        &k,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        b.as_ptr() as *const _,
-        &ldb,
-        &beta as *const _ as *const _,
-        c.as_mut_ptr() as *mut _,
-        &ldc,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        csyr2k,
+        [uplo, trans],
+        [n, k],
+        [lda, ldb, ldc],
+        [uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::csyr2k_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_ptr() as *const _,
+            &ldb,
+            &beta as *const _ as *const _,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn cher2k(
-    uplo: u8,
-    trans: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
     n: i32,
     k: i32,
     alpha: c32,
@@ -2480,28 +3872,37 @@ pub unsafe fn cher2k(
     c: &mut [c32],
     ldc: i32,
 ) {
-    ffi::cher2k_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &n,
-        &k,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        b.as_ptr() as *const _,
-        &ldb,
-        &beta,
-        c.as_mut_ptr() as *mut _,
-        &ldc,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        cher2k,
+        [uplo, trans],
+        [n, k],
+        [lda, ldb, ldc],
+        [uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::cher2k_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_ptr() as *const _,
+            &ldb,
+            &beta,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn ctrmm(
-    side: u8,
-    uplo: u8,
-    transa: u8,
-    diag: u8,
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
     m: i32,
     n: i32,
     alpha: c32,
@@ -2510,27 +3911,38 @@ pub unsafe fn ctrmm(
     b: &mut [c32],
     ldb: i32,
 ) {
-    ffi::ctrmm_(
-        &(side as c_char),
-        &(uplo as c_char),
-        &(transa as c_char),
-        &(diag as c_char),
-        &m,
-        &n,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        b.as_mut_ptr() as *mut _,
-        &ldb,
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ctrmm,
+        [side, uplo, transa, diag],
+        [m, n],
+        [lda, ldb],
+        [side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb],
+        ffi::ctrmm_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_mut_ptr() as *mut _,
+            &ldb
+        )
     )
 }
 
 #[inline]
 pub unsafe fn ctrsm(
-    side: u8,
-    uplo: u8,
-    transa: u8,
-    diag: u8,
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
     m: i32,
     n: i32,
     alpha: c32,
@@ -2539,25 +3951,36 @@ pub unsafe fn ctrsm(
     b: &mut [c32],
     ldb: i32,
 ) {
-    ffi::ctrsm_(
-        &(side as c_char),
-        &(uplo as c_char),
-        &(transa as c_char),
-        &(diag as c_char),
-        &m,
-        &n,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        b.as_mut_ptr() as *mut _,
-        &ldb,
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ctrsm,
+        [side, uplo, transa, diag],
+        [m, n],
+        [lda, ldb],
+        [side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb],
+        ffi::ctrsm_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_mut_ptr() as *mut _,
+            &ldb
+        )
     )
 }
 
 #[inline]
 pub unsafe fn zgemm(
-    transa: u8,
-    transb: u8,
+    transa: impl Into<u8>,
+    transb: impl Into<u8>,
     m: i32,
     n: i32,
     k: i32,
@@ -2570,27 +3993,36 @@ pub unsafe fn zgemm(
     c: &mut [c64],
     ldc: i32,
 ) {
-    ffi::zgemm_(
-        &(transa as c_char),
-        &(transb as c_char),
-        &m,
-        &n,
-        &k,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        b.as_ptr() as *const _,
-        &ldb,
-        &beta as *const _ as *const _,
-        c.as_mut_ptr() as *mut _,
-        &ldc,
+    let transa: u8 = transa.into();
+    let transb: u8 = transb.into();
+    trace!(
+        zgemm,
+        [transa, transb],
+        [m, n, k],
+        [lda, ldb, ldc],
+        [transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::zgemm_(
+            &(transa as c_char),
+            &(transb as c_char),
+            &m,
+            &n,
+            &k,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_ptr() as *const _,
+            &ldb,
+            &beta as *const _ as *const _,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn zsymm(
-    side: u8,
-    uplo: u8,
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
     m: i32,
     n: i32,
     alpha: c64,
@@ -2602,26 +4034,35 @@ pub unsafe fn zsymm(
     c: &mut [c64],
     ldc: i32,
 ) {
-    ffi::zsymm_(
-        &(side as c_char),
-        &(uplo as c_char),
-        &m,
-        &n,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        b.as_ptr() as *const _,
-        &ldb,
-        &beta as *const _ as *const _,
-        c.as_mut_ptr() as *mut _,
-        &ldc,
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    trace!(
+        zsymm,
+        [side, uplo],
+        [m, n],
+        [lda, ldb, ldc],
+        [side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::zsymm_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_ptr() as *const _,
+            &ldb,
+            &beta as *const _ as *const _,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn zhemm(
-    side: u8,
-    uplo: u8,
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
     m: i32,
     n: i32,
     alpha: c64,
@@ -2633,26 +4074,35 @@ pub unsafe fn zhemm(
     c: &mut [c64],
     ldc: i32,
 ) {
-    ffi::zhemm_(
-        &(side as c_char),
-        &(uplo as c_char),
-        &m,
-        &n,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        b.as_ptr() as *const _,
-        &ldb,
-        &beta as *const _ as *const _,
-        c.as_mut_ptr() as *mut _,
-        &ldc,
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    trace!(
+        zhemm,
+        [side, uplo],
+        [m, n],
+        [lda, ldb, ldc],
+        [side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::zhemm_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_ptr() as *const _,
+            &ldb,
+            &beta as *const _ as *const _,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn zsyrk(
-    uplo: u8,
-    trans: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
     n: i32,
     k: i32,
     alpha: c64,
@@ -2662,24 +4112,33 @@ pub unsafe fn zsyrk(
     c: &mut [c64],
     ldc: i32,
 ) {
-    ffi::zsyrk_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &n,
-        &k,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        &beta as *const _ as *const _,
-        c.as_mut_ptr() as *mut _,
-        &ldc,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        zsyrk,
+        [uplo, trans],
+        [n, k],
+        [lda, ldc],
+        [uplo, trans, n, k, alpha, a, lda, beta, c, ldc],
+        ffi::zsyrk_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            &beta as *const _ as *const _,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn zherk(
-    uplo: u8,
-    trans: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
     n: i32,
     k: i32,
     alpha: f64,
@@ -2689,24 +4148,33 @@ pub unsafe fn zherk(
     c: &mut [c64],
     ldc: i32,
 ) {
-    ffi::zherk_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &n,
-        &k,
-        &alpha,
-        a.as_ptr() as *const _,
-        &lda,
-        &beta,
-        c.as_mut_ptr() as *mut _,
-        &ldc,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        zherk,
+        [uplo, trans],
+        [n, k],
+        [lda, ldc],
+        [uplo, trans, n, k, alpha, a, lda, beta, c, ldc],
+        ffi::zherk_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha,
+            a.as_ptr() as *const _,
+            &lda,
+            &beta,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn zsyr2k(
-    uplo: u8,
-    trans: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
     n: i32,
     k: i32,
     alpha: c64,
@@ -2718,26 +4186,35 @@ pub unsafe fn zsyr2k(
     c: &mut [c64],
     ldc: i32,
 ) {
-    ffi::zsyr2k_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &n,
-        &k,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        b.as_ptr() as *const _,
-        &ldb,
-        &beta as *const _ as *const _,
-        c.as_mut_ptr() as *mut _,
-        &ldc,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        zsyr2k,
+        [uplo, trans],
+        [n, k],
+        [lda, ldb, ldc],
+        [uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::zsyr2k_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_ptr() as *const _,
+            &ldb,
+            &beta as *const _ as *const _,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn zher2k(
-    uplo: u8,
-    trans: u8,
+    uplo: impl Into<u8>,
+    trans: impl Into<u8>,
     n: i32,
     k: i32,
     alpha: c64,
@@ -2749,28 +4226,37 @@ pub unsafe fn zher2k(
     c: &mut [c64],
     ldc: i32,
 ) {
-    ffi::zher2k_(
-        &(uplo as c_char),
-        &(trans as c_char),
-        &n,
-        &k,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        b.as_ptr() as *const _,
-        &ldb,
-        &beta,
-        c.as_mut_ptr() as *mut _,
-        &ldc,
+    let uplo: u8 = uplo.into();
+    let trans: u8 = trans.into();
+    trace!(
+        zher2k,
+        [uplo, trans],
+        [n, k],
+        [lda, ldb, ldc],
+        [uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc],
+        ffi::zher2k_(
+            &(uplo as c_char),
+            &(trans as c_char),
+            &n,
+            &k,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_ptr() as *const _,
+            &ldb,
+            &beta,
+            c.as_mut_ptr() as *mut _,
+            &ldc
+        )
     )
 }
 
 #[inline]
 pub unsafe fn ztrmm(
-    side: u8,
-    uplo: u8,
-    transa: u8,
-    diag: u8,
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
     m: i32,
     n: i32,
     alpha: c64,
@@ -2779,27 +4265,38 @@ pub unsafe fn ztrmm(
     b: &mut [c64],
     ldb: i32,
 ) {
-    ffi::ztrmm_(
-        &(side as c_char),
-        &(uplo as c_char),
-        &(transa as c_char),
-        &(diag as c_char),
-        &m,
-        &n,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        b.as_mut_ptr() as *mut _,
-        &ldb,
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ztrmm,
+        [side, uplo, transa, diag],
+        [m, n],
+        [lda, ldb],
+        [side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb],
+        ffi::ztrmm_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_mut_ptr() as *mut _,
+            &ldb
+        )
     )
 }
 
 #[inline]
 pub unsafe fn ztrsm(
-    side: u8,
-    uplo: u8,
-    transa: u8,
-    diag: u8,
+    side: impl Into<u8>,
+    uplo: impl Into<u8>,
+    transa: impl Into<u8>,
+    diag: impl Into<u8>,
     m: i32,
     n: i32,
     alpha: c64,
@@ -2808,17 +4305,28 @@ pub unsafe fn ztrsm(
     b: &mut [c64],
     ldb: i32,
 ) {
-    ffi::ztrsm_(
-        &(side as c_char),
-        &(uplo as c_char),
-        &(transa as c_char),
-        &(diag as c_char),
-        &m,
-        &n,
-        &alpha as *const _ as *const _,
-        a.as_ptr() as *const _,
-        &lda,
-        b.as_mut_ptr() as *mut _,
-        &ldb,
+    let side: u8 = side.into();
+    let uplo: u8 = uplo.into();
+    let transa: u8 = transa.into();
+    let diag: u8 = diag.into();
+    trace!(
+        ztrsm,
+        [side, uplo, transa, diag],
+        [m, n],
+        [lda, ldb],
+        [side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb],
+        ffi::ztrsm_(
+            &(side as c_char),
+            &(uplo as c_char),
+            &(transa as c_char),
+            &(diag as c_char),
+            &m,
+            &n,
+            &alpha as *const _ as *const _,
+            a.as_ptr() as *const _,
+            &lda,
+            b.as_mut_ptr() as *mut _,
+            &ldb
+        )
     )
 }
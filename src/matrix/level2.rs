@@ -0,0 +1,250 @@
+use super::{inverted, shape, stored, Layout, MatMut, MatRef};
+use checked::Check;
+use row_major::{conjugate, conjugated};
+use vector::{StridedVec, StridedVecMut};
+use {Blas, Diag, Result, Transpose, Uplo};
+
+/// Compute `y := alpha * op(a) * x + beta * y`.
+pub fn gemv<T: Blas>(
+    trans: Transpose,
+    alpha: T,
+    a: MatRef<T>,
+    x: StridedVec<T>,
+    beta: T,
+    y: &mut StridedVecMut<T>,
+) -> Result<()> {
+    let check = Check::new("gemv");
+    let (m, n) = shape(trans, &a);
+    if x.len != n {
+        return Err(check.argument(4, "x", "must have as many elements as op(a) has columns"));
+    }
+    if y.len != m {
+        return Err(check.argument(6, "y", "must have as many elements as op(a) has rows"));
+    }
+    let (rows, cols) = a.storage();
+    let (rows, cols, ld) = (rows as i32, cols as i32, a.ld as i32);
+    let (lenx, leny) = (x.len as i32, y.len as i32);
+    unsafe {
+        if a.layout == Layout::RowMajor && trans == Transpose::ConjTrans {
+            let x = conjugated(lenx, x.data, x.inc);
+            conjugate(leny, y.data, y.inc);
+            T::gemv(
+                Transpose::No,
+                rows,
+                cols,
+                alpha.conj(),
+                a.data,
+                ld,
+                &x,
+                1,
+                beta.conj(),
+                y.data,
+                y.inc,
+            );
+            conjugate(leny, y.data, y.inc);
+        } else {
+            let trans = inverted(trans, a.layout, false);
+            T::gemv(
+                trans, rows, cols, alpha, a.data, ld, x.data, x.inc, beta, y.data, y.inc,
+            );
+        }
+    }
+    check.finish()
+}
+
+/// Compute `y := alpha * a * x + beta * y` for a Hermitian `a`, which is
+/// `?symv` for real types.
+pub fn hemv<T: Blas>(
+    uplo: Uplo,
+    alpha: T,
+    a: MatRef<T>,
+    x: StridedVec<T>,
+    beta: T,
+    y: &mut StridedVecMut<T>,
+) -> Result<()> {
+    let check = Check::new("hemv");
+    if a.rows != a.cols {
+        return Err(check.argument(3, "a", "must be square"));
+    }
+    if x.len != a.rows {
+        return Err(check.argument(4, "x", "must have as many elements as a has rows"));
+    }
+    if y.len != a.rows {
+        return Err(check.argument(6, "y", "must have as many elements as a has rows"));
+    }
+    let uplo = stored(uplo, a.layout);
+    let (n, ld) = (a.rows as i32, a.ld as i32);
+    unsafe {
+        if a.layout == Layout::RowMajor {
+            // The storage holds the conjugate of the matrix.
+            let x = conjugated(n, x.data, x.inc);
+            conjugate(n, y.data, y.inc);
+            T::hemv(
+                uplo,
+                n,
+                alpha.conj(),
+                a.data,
+                ld,
+                &x,
+                1,
+                beta.conj(),
+                y.data,
+                y.inc,
+            );
+            conjugate(n, y.data, y.inc);
+        } else {
+            T::hemv(
+                uplo, n, alpha, a.data, ld, x.data, x.inc, beta, y.data, y.inc,
+            );
+        }
+    }
+    check.finish()
+}
+
+macro_rules! ger {
+    ($(#[$attribute:meta])* fn $name:ident($conjugate:expr)) => {
+        $(#[$attribute])*
+        pub fn $name<T: Blas>(
+            alpha: T,
+            x: StridedVec<T>,
+            y: StridedVec<T>,
+            a: &mut MatMut<T>,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            if x.len != a.rows {
+                return Err(check.argument(2, "x", "must have as many elements as a has rows"));
+            }
+            if y.len != a.cols {
+                return Err(check.argument(3, "y", "must have as many elements as a has columns"));
+            }
+            let (m, n) = a.storage();
+            let (m, n, ld) = (m as i32, n as i32, a.ld as i32);
+            unsafe {
+                match a.layout {
+                    Layout::ColMajor => {
+                        T::$name(m, n, alpha, x.data, x.inc, y.data, y.inc, a.data, ld)
+                    }
+                    Layout::RowMajor if $conjugate => {
+                        let y = conjugated(m, y.data, y.inc);
+                        T::ger(m, n, alpha, &y, 1, x.data, x.inc, a.data, ld)
+                    }
+                    Layout::RowMajor => {
+                        T::ger(m, n, alpha, y.data, y.inc, x.data, x.inc, a.data, ld)
+                    }
+                }
+            }
+            check.finish()
+        }
+    };
+}
+
+ger! {
+    /// Compute `a := alpha * x * y^T + a`.
+    fn ger(false)
+}
+
+ger! {
+    /// Compute `a := alpha * x * y^H + a`, which is `?ger` for real types.
+    fn gerc(true)
+}
+
+/// Compute `a := alpha * x * x^H + a` for a Hermitian `a`, which is `?syr`
+/// for real types.
+pub fn her<T: Blas>(uplo: Uplo, alpha: T::Real, x: StridedVec<T>, a: &mut MatMut<T>) -> Result<()> {
+    let check = Check::new("her");
+    if a.rows != a.cols {
+        return Err(check.argument(4, "a", "must be square"));
+    }
+    if x.len != a.rows {
+        return Err(check.argument(3, "x", "must have as many elements as a has rows"));
+    }
+    let uplo = stored(uplo, a.layout);
+    let (n, ld) = (a.rows as i32, a.ld as i32);
+    unsafe {
+        if a.layout == Layout::RowMajor {
+            let x = conjugated(n, x.data, x.inc);
+            T::her(uplo, n, alpha, &x, 1, a.data, ld);
+        } else {
+            T::her(uplo, n, alpha, x.data, x.inc, a.data, ld);
+        }
+    }
+    check.finish()
+}
+
+/// Compute `a := alpha * x * y^H + conj(alpha) * y * x^H + a` for a Hermitian
+/// `a`, which is `?syr2` for real types.
+pub fn her2<T: Blas>(
+    uplo: Uplo,
+    alpha: T,
+    x: StridedVec<T>,
+    y: StridedVec<T>,
+    a: &mut MatMut<T>,
+) -> Result<()> {
+    let check = Check::new("her2");
+    if a.rows != a.cols {
+        return Err(check.argument(5, "a", "must be square"));
+    }
+    if x.len != a.rows {
+        return Err(check.argument(3, "x", "must have as many elements as a has rows"));
+    }
+    if y.len != a.rows {
+        return Err(check.argument(4, "y", "must have as many elements as a has rows"));
+    }
+    let uplo = stored(uplo, a.layout);
+    let (n, ld) = (a.rows as i32, a.ld as i32);
+    unsafe {
+        if a.layout == Layout::RowMajor {
+            let x = conjugated(n, x.data, x.inc);
+            let y = conjugated(n, y.data, y.inc);
+            T::her2(uplo, n, alpha.conj(), &x, 1, &y, 1, a.data, ld);
+        } else {
+            T::her2(uplo, n, alpha, x.data, x.inc, y.data, y.inc, a.data, ld);
+        }
+    }
+    check.finish()
+}
+
+macro_rules! triangular {
+    ($(#[$attribute:meta])* fn $name:ident) => {
+        $(#[$attribute])*
+        pub fn $name<T: Blas>(
+            uplo: Uplo,
+            trans: Transpose,
+            diag: Diag,
+            a: MatRef<T>,
+            x: &mut StridedVecMut<T>,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            if a.rows != a.cols {
+                return Err(check.argument(4, "a", "must be square"));
+            }
+            if x.len != a.rows {
+                return Err(check.argument(5, "x", "must have as many elements as a has rows"));
+            }
+            let uplo = stored(uplo, a.layout);
+            let (n, ld) = (a.rows as i32, a.ld as i32);
+            unsafe {
+                if a.layout == Layout::RowMajor && trans == Transpose::ConjTrans {
+                    conjugate(n, x.data, x.inc);
+                    T::$name(uplo, Transpose::No, diag, n, a.data, ld, x.data, x.inc);
+                    conjugate(n, x.data, x.inc);
+                } else {
+                    let trans = inverted(trans, a.layout, false);
+                    T::$name(uplo, trans, diag, n, a.data, ld, x.data, x.inc);
+                }
+            }
+            check.finish()
+        }
+    };
+}
+
+triangular! {
+    /// Compute `x := op(a) * x` for a triangular `a`.
+    fn trmv
+}
+
+triangular! {
+    /// Solve `op(a) * x = b` for a triangular `a`, overwriting `b` stored in
+    /// `x`.
+    fn trsv
+}
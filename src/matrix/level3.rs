@@ -0,0 +1,339 @@
+use super::{effective, flipped, inverted, shape, stored, Layout, MatMut, MatRef};
+use checked::Check;
+use row_major::rank2k_alpha;
+use {Blas, Diag, Result, Side, Transpose, Uplo};
+
+/// Compute `c := alpha * op(a) * op(b) + beta * c`.
+///
+/// The layouts of the three matrices can be chosen independently, except that
+/// `ConjTrans` cannot be applied to a matrix whose layout differs from the one
+/// of `c`.
+pub fn gemm<T: Blas>(
+    transa: Transpose,
+    transb: Transpose,
+    alpha: T,
+    a: MatRef<T>,
+    b: MatRef<T>,
+    beta: T,
+    c: &mut MatMut<T>,
+) -> Result<()> {
+    let check = Check::new("gemm");
+    let (_, k) = shape(transa, &a);
+    let (transa, transb) = operations(&check, transa, transb, &a, &b, c)?;
+    let transposed = c.layout == Layout::RowMajor;
+    let (m, n, k) = (c.rows as i32, c.cols as i32, k as i32);
+    let (lda, ldb, ldc) = (a.ld as i32, b.ld as i32, c.ld as i32);
+    unsafe {
+        if transposed {
+            T::gemm(
+                transb, transa, n, m, k, alpha, b.data, ldb, a.data, lda, beta, c.data, ldc,
+            );
+        } else {
+            T::gemm(
+                transa, transb, m, n, k, alpha, a.data, lda, b.data, ldb, beta, c.data, ldc,
+            );
+        }
+    }
+    check.finish()
+}
+
+/// Validate the operands of [`gemm`] and return the operations to apply to
+/// the column-major storage of `a` and `b`.
+///
+/// [`gemm`]: fn.gemm.html
+pub(crate) fn operations<T>(
+    check: &Check,
+    transa: Transpose,
+    transb: Transpose,
+    a: &MatRef<T>,
+    b: &MatRef<T>,
+    c: &MatMut<T>,
+) -> Result<(Transpose, Transpose)> {
+    let (m, k) = shape(transa, a);
+    let (l, n) = shape(transb, b);
+    if l != k {
+        return Err(check.argument(5, "b", "must match the number of columns of op(a)"));
+    }
+    if c.rows != m {
+        return Err(check.argument(7, "c", "must have as many rows as op(a)"));
+    }
+    if c.cols != n {
+        return Err(check.argument(7, "c", "must have as many columns as op(b)"));
+    }
+    let transposed = c.layout == Layout::RowMajor;
+    let transa = match effective(transa, a.layout, transposed) {
+        Some(transa) => transa,
+        _ => return Err(check.argument(1, "transa", "must not be ConjTrans for this layout")),
+    };
+    let transb = match effective(transb, b.layout, transposed) {
+        Some(transb) => transb,
+        _ => return Err(check.argument(2, "transb", "must not be ConjTrans for this layout")),
+    };
+    Ok((transa, transb))
+}
+
+/// Ensure that a matrix has the same layout as the output matrix.
+#[inline]
+fn layout(
+    check: &Check,
+    position: usize,
+    name: &'static str,
+    layout: Layout,
+    target: Layout,
+) -> Result<()> {
+    if layout != target {
+        return Err(check.argument(position, name, "must have the same layout as the output"));
+    }
+    Ok(())
+}
+
+macro_rules! symm {
+    ($(#[$attribute:meta])* fn $name:ident) => {
+        $(#[$attribute])*
+        pub fn $name<T: Blas>(
+            side: Side,
+            uplo: Uplo,
+            alpha: T,
+            a: MatRef<T>,
+            b: MatRef<T>,
+            beta: T,
+            c: &mut MatMut<T>,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            let order = if side == Side::Left { c.rows } else { c.cols };
+            if a.rows != order || a.cols != order {
+                return Err(check.argument(4, "a", "must be square and match c on the given side"));
+            }
+            if b.rows != c.rows || b.cols != c.cols {
+                return Err(check.argument(5, "b", "must have the same shape as c"));
+            }
+            layout(&check, 4, "a", a.layout, c.layout)?;
+            layout(&check, 5, "b", b.layout, c.layout)?;
+            let side = flipped(side, c.layout);
+            let uplo = stored(uplo, c.layout);
+            let (m, n) = c.storage();
+            unsafe {
+                T::$name(
+                    side,
+                    uplo,
+                    m as i32,
+                    n as i32,
+                    alpha,
+                    a.data,
+                    a.ld as i32,
+                    b.data,
+                    b.ld as i32,
+                    beta,
+                    c.data,
+                    c.ld as i32,
+                );
+            }
+            check.finish()
+        }
+    };
+}
+
+symm! {
+    /// Compute `c := alpha * a * b + beta * c` or `c := alpha * b * a + beta *
+    /// c` for a symmetric `a`.
+    ///
+    /// All matrices must have the same layout.
+    fn symm
+}
+
+symm! {
+    /// Compute `c := alpha * a * b + beta * c` or `c := alpha * b * a + beta *
+    /// c` for a Hermitian `a`.
+    ///
+    /// All matrices must have the same layout.
+    fn hemm
+}
+
+macro_rules! syrk {
+    ($(#[$attribute:meta])* fn $name:ident($scalar:ty, $forbidden:ident, $conjugate:expr)) => {
+        $(#[$attribute])*
+        pub fn $name<T: Blas>(
+            uplo: Uplo,
+            trans: Transpose,
+            alpha: $scalar,
+            a: MatRef<T>,
+            beta: $scalar,
+            c: &mut MatMut<T>,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            if trans == Transpose::$forbidden {
+                return Err(check.argument(
+                    2,
+                    "trans",
+                    concat!("must not be ", stringify!($forbidden)),
+                ));
+            }
+            let (n, k) = shape(trans, &a);
+            if c.rows != n || c.cols != n {
+                return Err(check.argument(6, "c", "must be square and match the rows of op(a)"));
+            }
+            layout(&check, 4, "a", a.layout, c.layout)?;
+            let uplo = stored(uplo, c.layout);
+            let trans = inverted(trans, c.layout, $conjugate);
+            unsafe {
+                T::$name(
+                    uplo,
+                    trans,
+                    n as i32,
+                    k as i32,
+                    alpha,
+                    a.data,
+                    a.ld as i32,
+                    beta,
+                    c.data,
+                    c.ld as i32,
+                );
+            }
+            check.finish()
+        }
+    };
+}
+
+syrk! {
+    /// Compute `c := alpha * op(a) * op(a)^T + beta * c` for a symmetric `c`.
+    ///
+    /// All matrices must have the same layout.
+    fn syrk(T, ConjTrans, false)
+}
+
+syrk! {
+    /// Compute `c := alpha * op(a) * op(a)^H + beta * c` for a Hermitian `c`.
+    ///
+    /// All matrices must have the same layout.
+    fn herk(T::Real, Trans, true)
+}
+
+macro_rules! syr2k {
+    ($(#[$attribute:meta])* fn $name:ident($beta:ty, $forbidden:ident, $conjugate:expr)) => {
+        $(#[$attribute])*
+        pub fn $name<T: Blas>(
+            uplo: Uplo,
+            trans: Transpose,
+            alpha: T,
+            a: MatRef<T>,
+            b: MatRef<T>,
+            beta: $beta,
+            c: &mut MatMut<T>,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            if trans == Transpose::$forbidden {
+                return Err(check.argument(
+                    2,
+                    "trans",
+                    concat!("must not be ", stringify!($forbidden)),
+                ));
+            }
+            let (n, k) = shape(trans, &a);
+            if b.rows != a.rows || b.cols != a.cols {
+                return Err(check.argument(5, "b", "must have the same shape as a"));
+            }
+            if c.rows != n || c.cols != n {
+                return Err(check.argument(7, "c", "must be square and match the rows of op(a)"));
+            }
+            layout(&check, 4, "a", a.layout, c.layout)?;
+            layout(&check, 5, "b", b.layout, c.layout)?;
+            let alpha = if c.layout == Layout::RowMajor {
+                rank2k_alpha(alpha, $conjugate)
+            } else {
+                alpha
+            };
+            let uplo = stored(uplo, c.layout);
+            let trans = inverted(trans, c.layout, $conjugate);
+            unsafe {
+                T::$name(
+                    uplo,
+                    trans,
+                    n as i32,
+                    k as i32,
+                    alpha,
+                    a.data,
+                    a.ld as i32,
+                    b.data,
+                    b.ld as i32,
+                    beta,
+                    c.data,
+                    c.ld as i32,
+                );
+            }
+            check.finish()
+        }
+    };
+}
+
+syr2k! {
+    /// Compute `c := alpha * op(a) * op(b)^T + alpha * op(b) * op(a)^T + beta *
+    /// c` for a symmetric `c`.
+    ///
+    /// All matrices must have the same layout.
+    fn syr2k(T, ConjTrans, false)
+}
+
+syr2k! {
+    /// Compute `c := alpha * op(a) * op(b)^H + conj(alpha) * op(b) * op(a)^H +
+    /// beta * c` for a Hermitian `c`.
+    ///
+    /// All matrices must have the same layout.
+    fn her2k(T::Real, Trans, true)
+}
+
+macro_rules! trmm {
+    ($(#[$attribute:meta])* fn $name:ident) => {
+        $(#[$attribute])*
+        pub fn $name<T: Blas>(
+            side: Side,
+            uplo: Uplo,
+            transa: Transpose,
+            diag: Diag,
+            alpha: T,
+            a: MatRef<T>,
+            b: &mut MatMut<T>,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            let order = if side == Side::Left { b.rows } else { b.cols };
+            if a.rows != order || a.cols != order {
+                return Err(check.argument(6, "a", "must be square and match b on the given side"));
+            }
+            layout(&check, 6, "a", a.layout, b.layout)?;
+            let side = flipped(side, b.layout);
+            let uplo = stored(uplo, b.layout);
+            let (m, n) = b.storage();
+            unsafe {
+                T::$name(
+                    side,
+                    uplo,
+                    transa,
+                    diag,
+                    m as i32,
+                    n as i32,
+                    alpha,
+                    a.data,
+                    a.ld as i32,
+                    b.data,
+                    b.ld as i32,
+                );
+            }
+            check.finish()
+        }
+    };
+}
+
+trmm! {
+    /// Compute `b := alpha * op(a) * b` or `b := alpha * b * op(a)` for a
+    /// triangular `a`.
+    ///
+    /// Both matrices must have the same layout.
+    fn trmm
+}
+
+trmm! {
+    /// Solve `op(a) * x = alpha * b` or `x * op(a) = alpha * b` for a
+    /// triangular `a`, overwriting `b` with `x`.
+    ///
+    /// Both matrices must have the same layout.
+    fn trsm
+}
@@ -0,0 +1,1021 @@
+//! Matrix views.
+//!
+//! A [`MatRef`] or [`MatMut`] borrows a buffer together with the number of
+//! rows and columns, the leading dimension, and the [`Layout`] of the matrix.
+//! The metadata are validated once when the view is created, and views can be
+//! narrowed down to submatrices without copying. The functions in this module
+//! take views instead of dimensions and fill in `m`, `n`, `k`, and the leading
+//! dimensions themselves.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use blas::matrix::{self, Layout, MatMut, MatRef};
+//! use blas::Transpose;
+//!
+//! let a = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+//! let b = vec![1.0, 0.0, 0.0, 1.0];
+//! let mut c = vec![0.0; 6];
+//!
+//! let a = MatRef::new(&a, 3, 2, Layout::ColMajor).unwrap();
+//! let b = MatRef::new(&b, 2, 2, Layout::ColMajor).unwrap();
+//! let mut c = MatMut::new(&mut c, 3, 2, Layout::ColMajor).unwrap();
+//!
+//! matrix::gemm(Transpose::No, Transpose::No, 1.0, a, b, 0.0, &mut c).unwrap();
+//! ```
+//!
+//! Row-major views are passed to BLAS as the column-major storage of their
+//! transposes, applying the same identities as CBLAS does, which are shared
+//! with [`row_major`](../row_major/index.html).
+//!
+//! [`Layout`]: enum.Layout.html
+//! [`MatMut`]: struct.MatMut.html
+//! [`MatRef`]: struct.MatRef.html
+
+use std::ops::{Index, IndexMut, Range};
+
+use checked::Check;
+use row_major::{flip_side, flip_uplo, invert};
+use {Result, Side, Transpose, Uplo};
+
+mod level2;
+mod level3;
+
+pub use self::level2::*;
+pub use self::level3::*;
+
+/// The order in which the elements of a matrix are stored.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Layout {
+    /// Consecutive elements of a column are adjacent (Fortran order).
+    ColMajor,
+    /// Consecutive elements of a row are adjacent (C order).
+    RowMajor,
+}
+
+/// An immutable matrix view.
+#[derive(Debug)]
+pub struct MatRef<'a, T: 'a> {
+    data: &'a [T],
+    rows: usize,
+    cols: usize,
+    ld: usize,
+    layout: Layout,
+}
+
+/// A mutable matrix view.
+#[derive(Debug)]
+pub struct MatMut<'a, T: 'a> {
+    data: &'a mut [T],
+    rows: usize,
+    cols: usize,
+    ld: usize,
+    layout: Layout,
+}
+
+impl<'a, T> Clone for MatRef<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for MatRef<'a, T> {}
+
+macro_rules! accessors {
+    () => {
+        /// Return the number of rows.
+        #[inline]
+        pub fn rows(&self) -> usize {
+            self.rows
+        }
+
+        /// Return the number of columns.
+        #[inline]
+        pub fn cols(&self) -> usize {
+            self.cols
+        }
+
+        /// Return the leading dimension.
+        #[inline]
+        pub fn ld(&self) -> usize {
+            self.ld
+        }
+
+        /// Return the layout.
+        #[inline]
+        pub fn layout(&self) -> Layout {
+            self.layout
+        }
+
+        /// Return the underlying buffer starting with the first element.
+        #[inline]
+        pub fn as_slice(&self) -> &[T] {
+            self.data
+        }
+
+        /// Return the position of an element in the underlying buffer.
+        #[inline]
+        fn offset(&self, row: usize, col: usize) -> usize {
+            match self.layout {
+                Layout::ColMajor => row + col * self.ld,
+                Layout::RowMajor => col + row * self.ld,
+            }
+        }
+
+        /// Return the number of rows and columns of the column-major storage.
+        #[inline]
+        fn storage(&self) -> (usize, usize) {
+            match self.layout {
+                Layout::ColMajor => (self.rows, self.cols),
+                Layout::RowMajor => (self.cols, self.rows),
+            }
+        }
+
+        /// Return the range of the underlying buffer covered by a submatrix.
+        #[inline]
+        fn range(&self, rows: &Range<usize>, cols: &Range<usize>) -> Range<usize> {
+            assert!(
+                rows.start <= rows.end && rows.end <= self.rows,
+                "the row range is out of bounds",
+            );
+            assert!(
+                cols.start <= cols.end && cols.end <= self.cols,
+                "the column range is out of bounds",
+            );
+            let start = self.offset(rows.start, cols.start).min(self.data.len());
+            let (inner, outer) = match self.layout {
+                Layout::ColMajor => (rows.len(), cols.len()),
+                Layout::RowMajor => (cols.len(), rows.len()),
+            };
+            start..(start + extent(inner, outer, self.ld))
+        }
+    };
+}
+
+impl<'a, T> MatRef<'a, T> {
+    /// Create a view with the smallest possible leading dimension.
+    #[inline]
+    pub fn new(data: &'a [T], rows: usize, cols: usize, layout: Layout) -> Result<Self> {
+        let ld = match layout {
+            Layout::ColMajor => rows.max(1),
+            Layout::RowMajor => cols.max(1),
+        };
+        validate("MatRef::new", data.len(), rows, cols, ld, layout, None)?;
+        Ok(MatRef {
+            data,
+            rows,
+            cols,
+            ld,
+            layout,
+        })
+    }
+
+    /// Create a view with a custom leading dimension.
+    #[inline]
+    pub fn with_ld(
+        data: &'a [T],
+        rows: usize,
+        cols: usize,
+        ld: usize,
+        layout: Layout,
+    ) -> Result<Self> {
+        validate(
+            "MatRef::with_ld",
+            data.len(),
+            rows,
+            cols,
+            ld,
+            layout,
+            Some(4),
+        )?;
+        Ok(MatRef {
+            data,
+            rows,
+            cols,
+            ld,
+            layout,
+        })
+    }
+
+    accessors!();
+
+    /// Return a submatrix.
+    ///
+    /// ## Panics
+    ///
+    /// The function panics if the ranges are out of bounds.
+    #[inline]
+    pub fn submatrix(&self, rows: Range<usize>, cols: Range<usize>) -> MatRef<'a, T> {
+        let range = self.range(&rows, &cols);
+        MatRef {
+            data: &self.data[range],
+            rows: rows.len(),
+            cols: cols.len(),
+            ld: self.ld,
+            layout: self.layout,
+        }
+    }
+
+    /// Return the transpose, which shares the buffer and has the opposite
+    /// layout.
+    #[inline]
+    pub fn transpose(&self) -> MatRef<'a, T> {
+        MatRef {
+            data: self.data,
+            rows: self.cols,
+            cols: self.rows,
+            ld: self.ld,
+            layout: match self.layout {
+                Layout::ColMajor => Layout::RowMajor,
+                Layout::RowMajor => Layout::ColMajor,
+            },
+        }
+    }
+}
+
+impl<'a, T> MatMut<'a, T> {
+    /// Create a view with the smallest possible leading dimension.
+    #[inline]
+    pub fn new(data: &'a mut [T], rows: usize, cols: usize, layout: Layout) -> Result<Self> {
+        let ld = match layout {
+            Layout::ColMajor => rows.max(1),
+            Layout::RowMajor => cols.max(1),
+        };
+        validate("MatMut::new", data.len(), rows, cols, ld, layout, None)?;
+        Ok(MatMut {
+            data,
+            rows,
+            cols,
+            ld,
+            layout,
+        })
+    }
+
+    /// Create a view with a custom leading dimension.
+    #[inline]
+    pub fn with_ld(
+        data: &'a mut [T],
+        rows: usize,
+        cols: usize,
+        ld: usize,
+        layout: Layout,
+    ) -> Result<Self> {
+        validate(
+            "MatMut::with_ld",
+            data.len(),
+            rows,
+            cols,
+            ld,
+            layout,
+            Some(4),
+        )?;
+        Ok(MatMut {
+            data,
+            rows,
+            cols,
+            ld,
+            layout,
+        })
+    }
+
+    accessors!();
+
+    /// Return the underlying buffer starting with the first element.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.data
+    }
+
+    /// Return an immutable view of the same matrix.
+    #[inline]
+    pub fn as_ref(&self) -> MatRef<'_, T> {
+        MatRef {
+            data: self.data,
+            rows: self.rows,
+            cols: self.cols,
+            ld: self.ld,
+            layout: self.layout,
+        }
+    }
+
+    /// Return a mutable view of the same matrix with a shorter lifetime.
+    #[inline]
+    pub fn as_mut(&mut self) -> MatMut<'_, T> {
+        MatMut {
+            data: self.data,
+            rows: self.rows,
+            cols: self.cols,
+            ld: self.ld,
+            layout: self.layout,
+        }
+    }
+
+    /// Return a submatrix.
+    ///
+    /// ## Panics
+    ///
+    /// The function panics if the ranges are out of bounds.
+    #[inline]
+    pub fn submatrix(&self, rows: Range<usize>, cols: Range<usize>) -> MatRef<'_, T> {
+        self.as_ref().submatrix(rows, cols)
+    }
+
+    /// Return a mutable submatrix.
+    ///
+    /// ## Panics
+    ///
+    /// The function panics if the ranges are out of bounds.
+    #[inline]
+    pub fn submatrix_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> MatMut<'_, T> {
+        let range = self.range(&rows, &cols);
+        MatMut {
+            data: &mut self.data[range],
+            rows: rows.len(),
+            cols: cols.len(),
+            ld: self.ld,
+            layout: self.layout,
+        }
+    }
+
+    /// Convert into a mutable submatrix with the same lifetime.
+    ///
+    /// ## Panics
+    ///
+    /// The function panics if the ranges are out of bounds.
+    #[inline]
+    pub fn into_submatrix(self, rows: Range<usize>, cols: Range<usize>) -> MatMut<'a, T> {
+        let range = self.range(&rows, &cols);
+        MatMut {
+            data: &mut self.data[range],
+            rows: rows.len(),
+            cols: cols.len(),
+            ld: self.ld,
+            layout: self.layout,
+        }
+    }
+}
+
+impl<'a, T> Index<(usize, usize)> for MatRef<'a, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "the index is out of bounds"
+        );
+        &self.data[self.offset(row, col)]
+    }
+}
+
+impl<'a, T> Index<(usize, usize)> for MatMut<'a, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "the index is out of bounds"
+        );
+        &self.data[self.offset(row, col)]
+    }
+}
+
+impl<'a, T> IndexMut<(usize, usize)> for MatMut<'a, T> {
+    #[inline]
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "the index is out of bounds"
+        );
+        let offset = self.offset(row, col);
+        &mut self.data[offset]
+    }
+}
+
+impl<'a, T> From<MatMut<'a, T>> for MatRef<'a, T> {
+    #[inline]
+    fn from(matrix: MatMut<'a, T>) -> Self {
+        let MatMut {
+            data,
+            rows,
+            cols,
+            ld,
+            layout,
+        } = matrix;
+        MatRef {
+            data,
+            rows,
+            cols,
+            ld,
+            layout,
+        }
+    }
+}
+
+/// Return the number of elements spanned by `outer` runs of `inner` elements
+/// that are `ld` elements apart.
+#[inline]
+fn extent(inner: usize, outer: usize, ld: usize) -> usize {
+    if inner == 0 || outer == 0 {
+        0
+    } else {
+        ld.saturating_mul(outer - 1).saturating_add(inner)
+    }
+}
+
+fn validate(
+    routine: &'static str,
+    len: usize,
+    rows: usize,
+    cols: usize,
+    ld: usize,
+    layout: Layout,
+    ld_position: Option<usize>,
+) -> Result<()> {
+    let check = Check::new(routine);
+    let (inner, outer) = match layout {
+        Layout::ColMajor => (rows, cols),
+        Layout::RowMajor => (cols, rows),
+    };
+    let limit = i32::MAX as usize;
+    if rows > limit {
+        return Err(check.argument(2, "rows", "must not exceed i32::MAX"));
+    }
+    if cols > limit {
+        return Err(check.argument(3, "cols", "must not exceed i32::MAX"));
+    }
+    if let Some(position) = ld_position {
+        if ld < inner.max(1) {
+            return Err(match layout {
+                Layout::ColMajor => check.argument(position, "ld", "must be at least max(1, rows)"),
+                Layout::RowMajor => check.argument(position, "ld", "must be at least max(1, cols)"),
+            });
+        }
+        if ld > limit {
+            return Err(check.argument(position, "ld", "must not exceed i32::MAX"));
+        }
+    }
+    check.length(1, "data", len, extent(inner, outer, ld))
+}
+
+/// Return the operation to apply to the column-major storage of a matrix in
+/// order to obtain `op(matrix)`, transposed once more if `transposed`.
+///
+/// The result is `None` if the operation amounts to conjugation without
+/// transposition, which BLAS cannot express.
+#[inline]
+fn effective(trans: Transpose, layout: Layout, transposed: bool) -> Option<Transpose> {
+    let (transpose, conjugate) = match trans {
+        Transpose::No => (false, false),
+        Transpose::Trans => (true, false),
+        Transpose::ConjTrans => (true, true),
+    };
+    match (
+        transpose ^ (layout == Layout::RowMajor) ^ transposed,
+        conjugate,
+    ) {
+        (false, false) => Some(Transpose::No),
+        (true, false) => Some(Transpose::Trans),
+        (true, true) => Some(Transpose::ConjTrans),
+        (false, true) => None,
+    }
+}
+
+/// Return the triangle of the column-major storage that corresponds to a
+/// triangle of a matrix with the given layout.
+#[inline]
+fn stored(uplo: Uplo, layout: Layout) -> Uplo {
+    match layout {
+        Layout::ColMajor => uplo,
+        Layout::RowMajor => flip_uplo(uplo),
+    }
+}
+
+/// Return the side that corresponds to a side of a matrix with the given
+/// layout in the column-major storage.
+#[inline]
+fn flipped(side: Side, layout: Layout) -> Side {
+    match layout {
+        Layout::ColMajor => side,
+        Layout::RowMajor => flip_side(side),
+    }
+}
+
+/// Return the operation on the column-major storage of a matrix with the
+/// given layout, where `conjugate` selects the Hermitian variant.
+///
+/// As in [`row_major`](../row_major/index.html), `ConjTrans` maps to `No` for
+/// a row-major matrix, in which case the caller is responsible for the
+/// conjugation.
+#[inline]
+fn inverted(trans: Transpose, layout: Layout, conjugate: bool) -> Transpose {
+    match layout {
+        Layout::ColMajor => trans,
+        Layout::RowMajor => invert(trans, conjugate),
+    }
+}
+
+/// Return the shape of `op(matrix)`.
+#[inline]
+fn shape<T>(trans: Transpose, matrix: &MatRef<T>) -> (usize, usize) {
+    match trans {
+        Transpose::No => (matrix.rows, matrix.cols),
+        _ => (matrix.cols, matrix.rows),
+    }
+}
+
+#[cfg(all(test, feature = "reference", not(feature = "dynamic")))]
+mod tests {
+    use super::*;
+    use vector::{StridedVec, StridedVecMut};
+    use {c64, Diag, Side};
+
+    const LAYOUTS: [Layout; 2] = [Layout::ColMajor, Layout::RowMajor];
+    const TRANSPOSES: [Transpose; 3] = [Transpose::No, Transpose::Trans, Transpose::ConjTrans];
+    const UPLOS: [Uplo; 2] = [Uplo::Upper, Uplo::Lower];
+
+    /// A value that must not be read.
+    const GARBAGE: c64 = c64 { re: 1e3, im: -1e3 };
+
+    fn store<F: Fn(usize, usize) -> c64>(
+        rows: usize,
+        cols: usize,
+        layout: Layout,
+        f: F,
+    ) -> Vec<c64> {
+        let mut data = vec![c64::new(0.0, 0.0); rows * cols];
+        for i in 0..rows {
+            for j in 0..cols {
+                match layout {
+                    Layout::ColMajor => data[i + j * rows] = f(i, j),
+                    Layout::RowMajor => data[j + i * cols] = f(i, j),
+                }
+            }
+        }
+        data
+    }
+
+    fn element(i: usize, j: usize, seed: f64) -> c64 {
+        c64::new(
+            seed + i as f64 + 0.5 * j as f64,
+            seed - 0.25 * i as f64 + j as f64,
+        )
+    }
+
+    fn hermitian(i: usize, j: usize) -> c64 {
+        if i < j {
+            element(i, j, 1.0)
+        } else if i > j {
+            element(j, i, 1.0).conj()
+        } else {
+            c64::new(element(i, i, 1.0).re, 0.0)
+        }
+    }
+
+    fn referenced(uplo: Uplo, i: usize, j: usize) -> bool {
+        match uplo {
+            Uplo::Upper => i <= j,
+            Uplo::Lower => i >= j,
+        }
+    }
+
+    fn op(trans: Transpose, a: &MatRef<c64>, i: usize, j: usize) -> c64 {
+        match trans {
+            Transpose::No => a[(i, j)],
+            Transpose::Trans => a[(j, i)],
+            Transpose::ConjTrans => a[(j, i)].conj(),
+        }
+    }
+
+    fn elements(a: MatRef<c64>) -> Vec<c64> {
+        (0..a.rows())
+            .flat_map(|i| (0..a.cols()).map(move |j| a[(i, j)]))
+            .collect()
+    }
+
+    fn vector(n: usize, seed: f64) -> Vec<c64> {
+        (0..n)
+            .map(|i| c64::new(seed - i as f64, 0.5 + seed * i as f64))
+            .collect()
+    }
+
+    fn assert_close(x: &[c64], y: &[c64]) {
+        assert_eq!(x.len(), y.len());
+        for (x, y) in x.iter().zip(y) {
+            assert!((x - y).norm_sqr() < 1e-20, "{} != {}", x, y);
+        }
+    }
+
+    #[test]
+    fn gemv() {
+        let (alpha, beta) = (c64::new(0.5, 1.0), c64::new(2.0, -0.5));
+        for &layout in &LAYOUTS {
+            for &trans in &TRANSPOSES {
+                let data = store(3, 2, layout, |i, j| element(i, j, 1.0));
+                let a = MatRef::new(&data, 3, 2, layout).unwrap();
+                let (m, n) = shape(trans, &a);
+                let x = vector(2 * n - 1, 2.0);
+                let mut y = vector(m, -1.0);
+                let expected = (0..m)
+                    .map(|i| {
+                        let sum = (0..n)
+                            .map(|j| op(trans, &a, i, j) * x[2 * (n - 1 - j)])
+                            .sum::<c64>();
+                        alpha * sum + beta * y[m - 1 - i]
+                    })
+                    .rev()
+                    .collect::<Vec<_>>();
+                let x = StridedVec::new(&x, n, -2).unwrap();
+                let mut view = StridedVecMut::new(&mut y, m, -1).unwrap();
+                super::gemv(trans, alpha, a, x, beta, &mut view).unwrap();
+                assert_close(&y, &expected);
+            }
+        }
+    }
+
+    #[test]
+    fn hemv() {
+        let (alpha, beta) = (c64::new(0.5, 1.0), c64::new(2.0, -0.5));
+        for &layout in &LAYOUTS {
+            for &uplo in &UPLOS {
+                let data = store(3, 3, layout, |i, j| {
+                    if referenced(uplo, i, j) {
+                        hermitian(i, j)
+                    } else {
+                        GARBAGE
+                    }
+                });
+                let a = MatRef::new(&data, 3, 3, layout).unwrap();
+                let x = vector(3, 2.0);
+                let mut y = vector(3, -1.0);
+                let expected = (0..3)
+                    .map(|i| {
+                        let sum = (0..3).map(|j| hermitian(i, j) * x[j]).sum::<c64>();
+                        alpha * sum + beta * y[i]
+                    })
+                    .collect::<Vec<_>>();
+                super::hemv(uplo, alpha, a, x[..].into(), beta, &mut (&mut y[..]).into()).unwrap();
+                assert_close(&y, &expected);
+            }
+        }
+    }
+
+    #[test]
+    fn ger() {
+        let alpha = c64::new(0.5, 1.0);
+        for &layout in &LAYOUTS {
+            for &conjugate in &[false, true] {
+                let mut data = store(3, 2, layout, |i, j| element(i, j, 1.0));
+                let (x, y) = (vector(3, 2.0), vector(2, -1.0));
+                let expected = (0..3)
+                    .flat_map(|i| (0..2).map(move |j| (i, j)))
+                    .map(|(i, j)| {
+                        let y = if conjugate { y[j].conj() } else { y[j] };
+                        element(i, j, 1.0) + alpha * x[i] * y
+                    })
+                    .collect::<Vec<_>>();
+                let mut a = MatMut::new(&mut data, 3, 2, layout).unwrap();
+                if conjugate {
+                    super::gerc(alpha, x[..].into(), y[..].into(), &mut a).unwrap();
+                } else {
+                    super::ger(alpha, x[..].into(), y[..].into(), &mut a).unwrap();
+                }
+                assert_close(&elements(a.as_ref()), &expected);
+            }
+        }
+    }
+
+    #[test]
+    fn her() {
+        let alpha = c64::new(0.5, 1.0);
+        for &layout in &LAYOUTS {
+            for &uplo in &UPLOS {
+                let initial = |i, j| {
+                    if referenced(uplo, i, j) {
+                        hermitian(i, j)
+                    } else {
+                        GARBAGE
+                    }
+                };
+                let (x, y) = (vector(3, 2.0), vector(3, -1.0));
+                let update = |i, j, value: c64| {
+                    if referenced(uplo, i, j) {
+                        value
+                    } else {
+                        c64::new(0.0, 0.0)
+                    }
+                };
+                let (rank1, rank2) = (0..3)
+                    .flat_map(|i| (0..3).map(move |j| (i, j)))
+                    .map(|(i, j)| {
+                        let one = x[i] * x[j].conj() * 2.0;
+                        let two = alpha * x[i] * y[j].conj() + alpha.conj() * y[i] * x[j].conj();
+                        (
+                            initial(i, j) + update(i, j, one),
+                            initial(i, j) + update(i, j, two),
+                        )
+                    })
+                    .unzip::<_, _, Vec<_>, Vec<_>>();
+
+                let mut data = store(3, 3, layout, initial);
+                let mut a = MatMut::new(&mut data, 3, 3, layout).unwrap();
+                super::her(uplo, 2.0, x[..].into(), &mut a).unwrap();
+                assert_close(&elements(a.as_ref()), &rank1);
+
+                let mut data = store(3, 3, layout, initial);
+                let mut a = MatMut::new(&mut data, 3, 3, layout).unwrap();
+                super::her2(uplo, alpha, x[..].into(), y[..].into(), &mut a).unwrap();
+                assert_close(&elements(a.as_ref()), &rank2);
+            }
+        }
+    }
+
+    #[test]
+    fn triangular() {
+        for &layout in &LAYOUTS {
+            for &uplo in &UPLOS {
+                for &trans in &TRANSPOSES {
+                    for &diag in &[Diag::NonUnit, Diag::Unit] {
+                        let data = store(3, 3, layout, |i, j| {
+                            if i == j && diag == Diag::Unit || !referenced(uplo, i, j) {
+                                GARBAGE
+                            } else {
+                                element(i, j, 2.0)
+                            }
+                        });
+                        let full = store(3, 3, Layout::ColMajor, |i, j| {
+                            if i == j && diag == Diag::Unit {
+                                c64::new(1.0, 0.0)
+                            } else if referenced(uplo, i, j) {
+                                element(i, j, 2.0)
+                            } else {
+                                c64::new(0.0, 0.0)
+                            }
+                        });
+                        let full = MatRef::new(&full, 3, 3, Layout::ColMajor).unwrap();
+                        let a = MatRef::new(&data, 3, 3, layout).unwrap();
+                        let b = vector(3, 1.0);
+                        let product = (0..3)
+                            .map(|i| (0..3).map(|j| op(trans, &full, i, j) * b[j]).sum())
+                            .collect::<Vec<c64>>();
+
+                        let mut x = b.clone();
+                        trmv(uplo, trans, diag, a, &mut (&mut x[..]).into()).unwrap();
+                        assert_close(&x, &product);
+                        trsv(uplo, trans, diag, a, &mut (&mut x[..]).into()).unwrap();
+                        assert_close(&x, &b);
+                    }
+                }
+            }
+        }
+    }
+
+    fn product(
+        transa: Transpose,
+        transb: Transpose,
+        a: &MatRef<c64>,
+        b: &MatRef<c64>,
+        c: &MatRef<c64>,
+        alpha: c64,
+        beta: c64,
+    ) -> Vec<c64> {
+        let (m, k) = shape(transa, a);
+        let n = shape(transb, b).1;
+        (0..m)
+            .flat_map(|i| (0..n).map(move |j| (i, j)))
+            .map(|(i, j)| {
+                let sum = (0..k)
+                    .map(|l| op(transa, a, i, l) * op(transb, b, l, j))
+                    .sum::<c64>();
+                alpha * sum + beta * c[(i, j)]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn gemm() {
+        let (alpha, beta) = (c64::new(0.5, 1.0), c64::new(2.0, -0.5));
+        for &transa in &TRANSPOSES {
+            for &transb in &TRANSPOSES {
+                for &layouta in &LAYOUTS {
+                    for &layoutb in &LAYOUTS {
+                        for &layoutc in &LAYOUTS {
+                            let (m, n, k) = (3, 4, 2);
+                            let (ra, ca) = if transa == Transpose::No {
+                                (m, k)
+                            } else {
+                                (k, m)
+                            };
+                            let (rb, cb) = if transb == Transpose::No {
+                                (k, n)
+                            } else {
+                                (n, k)
+                            };
+                            let a = store(ra, ca, layouta, |i, j| element(i, j, 1.0));
+                            let b = store(rb, cb, layoutb, |i, j| element(i, j, -2.0));
+                            let mut c = store(m, n, layoutc, |i, j| element(i, j, 0.5));
+                            let a = MatRef::new(&a, ra, ca, layouta).unwrap();
+                            let b = MatRef::new(&b, rb, cb, layoutb).unwrap();
+                            let mut c = MatMut::new(&mut c, m, n, layoutc).unwrap();
+                            let expected =
+                                product(transa, transb, &a, &b, &c.as_ref(), alpha, beta);
+                            let result = super::gemm(transa, transb, alpha, a, b, beta, &mut c);
+                            let conjugated = (transa == Transpose::ConjTrans && layouta != layoutc)
+                                || (transb == Transpose::ConjTrans && layoutb != layoutc);
+                            if conjugated {
+                                assert!(result.is_err());
+                            } else {
+                                result.unwrap();
+                                assert_close(&elements(c.as_ref()), &expected);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn submatrix() {
+        let (alpha, beta) = (c64::new(0.5, 1.0), c64::new(2.0, -0.5));
+        for &layout in &LAYOUTS {
+            let a = store(5, 6, layout, |i, j| element(i, j, 1.0));
+            let b = store(4, 5, layout, |i, j| element(i, j, -2.0));
+            let mut c = store(6, 7, layout, |i, j| element(i, j, 0.5));
+            let a = MatRef::new(&a, 5, 6, layout).unwrap().submatrix(1..4, 2..4);
+            let b = MatRef::new(&b, 4, 5, layout).unwrap().submatrix(2..4, 1..5);
+            let mut c = MatMut::new(&mut c, 6, 7, layout).unwrap();
+            let outside = elements(c.as_ref());
+            let (dense_a, dense_b) = (elements(a), elements(b));
+            let dense_a = MatRef::new(&dense_a, 3, 2, Layout::RowMajor).unwrap();
+            let dense_b = MatRef::new(&dense_b, 2, 4, Layout::RowMajor).unwrap();
+            let expected = {
+                let c = c.submatrix(2..5, 3..7);
+                product(
+                    Transpose::No,
+                    Transpose::No,
+                    &dense_a,
+                    &dense_b,
+                    &c,
+                    alpha,
+                    beta,
+                )
+            };
+            {
+                let mut c = c.submatrix_mut(2..5, 3..7);
+                super::gemm(Transpose::No, Transpose::No, alpha, a, b, beta, &mut c).unwrap();
+                assert_close(&elements(c.as_ref()), &expected);
+            }
+            for (index, (value, old)) in elements(c.as_ref()).iter().zip(&outside).enumerate() {
+                let (i, j) = (index / 7, index % 7);
+                if !(2..5).contains(&i) || !(3..7).contains(&j) {
+                    assert_eq!(value, old);
+                }
+            }
+
+            let mut x = vector(5, 1.0);
+            let mut expected = x.clone();
+            for (i, value) in expected.iter_mut().enumerate().skip(1).take(3) {
+                *value += (0..2)
+                    .map(|j| a[(i - 1, j)] * c64::new(1.0, 0.0))
+                    .sum::<c64>();
+            }
+            let ones = [c64::new(1.0, 0.0); 2];
+            let mut y = StridedVecMut::new(&mut x[1..4], 3, 1).unwrap();
+            super::gemv(
+                Transpose::No,
+                c64::new(1.0, 0.0),
+                a,
+                ones[..].into(),
+                c64::new(1.0, 0.0),
+                &mut y,
+            )
+            .unwrap();
+            assert_close(&x, &expected);
+        }
+    }
+
+    #[test]
+    fn transpose() {
+        let (alpha, beta) = (c64::new(0.5, 1.0), c64::new(2.0, -0.5));
+        for &layout in &LAYOUTS {
+            let a = store(2, 3, layout, |i, j| element(i, j, 1.0));
+            let b = store(2, 4, layout, |i, j| element(i, j, -2.0));
+            let a = MatRef::new(&a, 2, 3, layout).unwrap();
+            let b = MatRef::new(&b, 2, 4, layout).unwrap();
+            let mut c = store(3, 4, layout, |i, j| element(i, j, 0.5));
+            let mut c = MatMut::new(&mut c, 3, 4, layout).unwrap();
+            let mut d = c.as_ref().as_slice().to_vec();
+            let mut d = MatMut::new(&mut d, 3, 4, layout).unwrap();
+            assert_eq!(a.transpose().rows(), 3);
+            assert_eq!(a.transpose()[(2, 1)], a[(1, 2)]);
+            super::gemm(
+                Transpose::No,
+                Transpose::No,
+                alpha,
+                a.transpose(),
+                b,
+                beta,
+                &mut c,
+            )
+            .unwrap();
+            super::gemm(Transpose::Trans, Transpose::No, alpha, a, b, beta, &mut d).unwrap();
+            assert_close(&elements(c.as_ref()), &elements(d.as_ref()));
+
+            let x = vector(2, 1.0);
+            let (mut y, mut z) = (vector(3, 2.0), vector(3, 2.0));
+            super::gemv(
+                Transpose::No,
+                alpha,
+                a.transpose(),
+                x[..].into(),
+                beta,
+                &mut (&mut y[..]).into(),
+            )
+            .unwrap();
+            super::gemv(
+                Transpose::Trans,
+                alpha,
+                a,
+                x[..].into(),
+                beta,
+                &mut (&mut z[..]).into(),
+            )
+            .unwrap();
+            assert_close(&y, &z);
+        }
+    }
+
+    /// Check that a routine yields the same logical result for both layouts.
+    fn layouts<F>(rows: usize, cols: usize, f: F)
+    where
+        F: Fn(Layout, &mut MatMut<c64>) -> Result<()>,
+    {
+        let mut results = vec![];
+        for &layout in &LAYOUTS {
+            let mut c = store(rows, cols, layout, |i, j| element(i, j, 0.5));
+            let mut c = MatMut::new(&mut c, rows, cols, layout).unwrap();
+            f(layout, &mut c).unwrap();
+            results.push(elements(c.as_ref()));
+        }
+        assert_close(&results[0], &results[1]);
+    }
+
+    #[test]
+    fn level3() {
+        let alpha = c64::new(0.5, 1.0);
+        for &uplo in &UPLOS {
+            for &side in &[Side::Left, Side::Right] {
+                let order = if side == Side::Left { 3 } else { 4 };
+                layouts(3, 4, |layout, c| {
+                    let a = store(order, order, layout, hermitian);
+                    let a = MatRef::new(&a, order, order, layout).unwrap();
+                    let b = store(3, 4, layout, |i, j| element(i, j, -1.0));
+                    let b = MatRef::new(&b, 3, 4, layout).unwrap();
+                    super::hemm(side, uplo, alpha, a, b, alpha, c)?;
+                    super::symm(side, uplo, alpha, a, b, alpha, c)
+                });
+                for &trans in &TRANSPOSES {
+                    layouts(3, 4, |layout, b| {
+                        let a = store(order, order, layout, |i, j| element(i, j, 3.0));
+                        let a = MatRef::new(&a, order, order, layout).unwrap();
+                        super::trmm(side, uplo, trans, Diag::NonUnit, alpha, a, b)?;
+                        super::trsm(side, uplo, trans, Diag::Unit, alpha, a, b)
+                    });
+                }
+            }
+            for &trans in &[Transpose::No, Transpose::ConjTrans] {
+                layouts(3, 3, |layout, c| {
+                    let (rows, cols) = if trans == Transpose::No {
+                        (3, 2)
+                    } else {
+                        (2, 3)
+                    };
+                    let a = store(rows, cols, layout, |i, j| element(i, j, 1.0));
+                    let a = MatRef::new(&a, rows, cols, layout).unwrap();
+                    let b = store(rows, cols, layout, |i, j| element(i, j, -1.0));
+                    let b = MatRef::new(&b, rows, cols, layout).unwrap();
+                    super::herk(uplo, trans, 0.5, a, 2.0, c)?;
+                    super::her2k(uplo, trans, alpha, a, b, 2.0, c)
+                });
+            }
+            for &trans in &[Transpose::No, Transpose::Trans] {
+                layouts(3, 3, |layout, c| {
+                    let (rows, cols) = if trans == Transpose::No {
+                        (3, 2)
+                    } else {
+                        (2, 3)
+                    };
+                    let a = store(rows, cols, layout, |i, j| element(i, j, 1.0));
+                    let a = MatRef::new(&a, rows, cols, layout).unwrap();
+                    let b = store(rows, cols, layout, |i, j| element(i, j, -1.0));
+                    let b = MatRef::new(&b, rows, cols, layout).unwrap();
+                    super::syrk(uplo, trans, alpha, a, alpha, c)?;
+                    super::syr2k(uplo, trans, alpha, a, b, alpha, c)
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,446 @@
+//! Mixed-precision routines.
+//!
+//! With the `half` feature enabled, matrices stored as [`f16`] or [`bf16`]
+//! can be multiplied using [`sgemm`]. The operands are converted to `f32`
+//! block by block, the products are accumulated in `f32`, and the result is
+//! either rounded back to 16 bits or written as `f32`, depending on the type
+//! of the output.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use blas::mixed::{hgemm, f16};
+//!
+//! let (m, n, k) = (2, 2, 3);
+//! let a = vec![f16::from_f32(1.0); 6];
+//! let b = vec![f16::from_f32(0.5); 6];
+//! let mut c = vec![0.0f32; 4];
+//!
+//! unsafe {
+//!     hgemm(b'N', b'N', m, n, k, 1.0, &a, m, &b, k, 0.0, &mut c, m);
+//! }
+//! ```
+//!
+//! [`bf16`]: struct.bf16.html
+//! [`f16`]: struct.f16.html
+//! [`sgemm`]: ../fn.sgemm.html
+
+use std::convert::TryFrom;
+
+pub use half::{bf16, f16};
+
+use error::xerbla;
+use Transpose;
+
+/// The number of rows or columns of the blocks converted at a time.
+const BLOCK: usize = 256;
+
+/// An element type that can be converted to and from `f32`.
+pub trait Element: Copy + Send + Sync + 'static {
+    /// Convert to `f32`.
+    fn to_f32(self) -> f32;
+
+    /// Convert from `f32`, rounding to the nearest representable value.
+    fn from_f32(value: f32) -> Self;
+
+    /// Return the buffer as `f32` values if the type is `f32`.
+    #[inline]
+    fn as_f32(_: &mut [Self]) -> Option<&mut [f32]> {
+        None
+    }
+}
+
+impl Element for f16 {
+    #[inline]
+    fn to_f32(self) -> f32 {
+        f16::to_f32(self)
+    }
+
+    #[inline]
+    fn from_f32(value: f32) -> Self {
+        f16::from_f32(value)
+    }
+}
+
+impl Element for bf16 {
+    #[inline]
+    fn to_f32(self) -> f32 {
+        bf16::to_f32(self)
+    }
+
+    #[inline]
+    fn from_f32(value: f32) -> Self {
+        bf16::from_f32(value)
+    }
+}
+
+impl Element for f32 {
+    #[inline]
+    fn to_f32(self) -> f32 {
+        self
+    }
+
+    #[inline]
+    fn from_f32(value: f32) -> Self {
+        value
+    }
+
+    #[inline]
+    fn as_f32(data: &mut [f32]) -> Option<&mut [f32]> {
+        Some(data)
+    }
+}
+
+/// Compute `c := alpha * op(a) * op(b) + beta * c` for [`f16`] operands
+/// with `f32` accumulation.
+///
+/// The arguments are as for [`sgemm`]. The output is either [`f16`] or
+/// `f32`.
+///
+/// [`f16`]: struct.f16.html
+/// [`sgemm`]: ../fn.sgemm.html
+#[inline]
+pub unsafe fn hgemm<C: Element>(
+    transa: impl Into<u8>,
+    transb: impl Into<u8>,
+    m: i32,
+    n: i32,
+    k: i32,
+    alpha: f32,
+    a: &[f16],
+    lda: i32,
+    b: &[f16],
+    ldb: i32,
+    beta: f32,
+    c: &mut [C],
+    ldc: i32,
+) {
+    let transa: u8 = transa.into();
+    let transb: u8 = transb.into();
+    gemm(
+        "hgemm", transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    )
+}
+
+/// Compute `c := alpha * op(a) * op(b) + beta * c` for [`bf16`] operands
+/// with `f32` accumulation.
+///
+/// The arguments are as for [`sgemm`]. The output is either [`bf16`] or
+/// `f32`.
+///
+/// [`bf16`]: struct.bf16.html
+/// [`sgemm`]: ../fn.sgemm.html
+#[inline]
+pub unsafe fn bf16gemm<C: Element>(
+    transa: impl Into<u8>,
+    transb: impl Into<u8>,
+    m: i32,
+    n: i32,
+    k: i32,
+    alpha: f32,
+    a: &[bf16],
+    lda: i32,
+    b: &[bf16],
+    ldb: i32,
+    beta: f32,
+    c: &mut [C],
+    ldc: i32,
+) {
+    let transa: u8 = transa.into();
+    let transb: u8 = transb.into();
+    gemm(
+        "bf16gemm", transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    )
+}
+
+/// Multiply in `f32`, where the output is held in `f32` throughout, either in
+/// place or converted once, so that each panel of the operands is converted
+/// only once.
+unsafe fn gemm<A: Element, C: Element>(
+    routine: &str,
+    transa: u8,
+    transb: u8,
+    m: i32,
+    n: i32,
+    k: i32,
+    alpha: f32,
+    a: &[A],
+    lda: i32,
+    b: &[A],
+    ldb: i32,
+    beta: f32,
+    c: &mut [C],
+    ldc: i32,
+) {
+    let transa = match Transpose::try_from(transa) {
+        Ok(transa) => transa,
+        _ => return xerbla(routine, 1),
+    };
+    let transb = match Transpose::try_from(transb) {
+        Ok(transb) => transb,
+        _ => return xerbla(routine, 2),
+    };
+    if m < 0 {
+        return xerbla(routine, 3);
+    }
+    if n < 0 {
+        return xerbla(routine, 4);
+    }
+    if k < 0 {
+        return xerbla(routine, 5);
+    }
+    if lda < (if transa == Transpose::No { m } else { k }).max(1) {
+        return xerbla(routine, 8);
+    }
+    if ldb < (if transb == Transpose::No { k } else { n }).max(1) {
+        return xerbla(routine, 10);
+    }
+    if ldc < m.max(1) {
+        return xerbla(routine, 13);
+    }
+    if m == 0 || n == 0 {
+        return;
+    }
+    let (m, n, k) = (m as usize, n as usize, k as usize);
+    let (lda, ldb, ldc) = (lda as usize, ldb as usize, ldc as usize);
+    if let Some(c) = C::as_f32(c) {
+        product(
+            transa,
+            transb,
+            (m, n, k),
+            alpha,
+            (a, lda),
+            (b, ldb),
+            beta,
+            c,
+            ldc,
+        );
+        return;
+    }
+    let mut output = Vec::with_capacity(m * n);
+    for j in 0..n {
+        let column = &c[j * ldc..][..m];
+        if beta == 0.0 {
+            output.extend(column.iter().map(|_| 0.0));
+        } else {
+            output.extend(column.iter().map(|&value| value.to_f32()));
+        }
+    }
+    product(
+        transa,
+        transb,
+        (m, n, k),
+        alpha,
+        (a, lda),
+        (b, ldb),
+        beta,
+        &mut output,
+        m,
+    );
+    for j in 0..n {
+        let column = &mut c[j * ldc..][..m];
+        for (value, &result) in column.iter_mut().zip(&output[j * m..]) {
+            *value = C::from_f32(result);
+        }
+    }
+}
+
+/// Compute the output in `f32` panel by panel along the inner dimension,
+/// where each panel of `op(a)` is converted once and the matching panel of
+/// `op(b)` block of columns by block of columns.
+unsafe fn product<A: Element>(
+    transa: Transpose,
+    transb: Transpose,
+    (m, n, k): (usize, usize, usize),
+    alpha: f32,
+    (a, lda): (&[A], usize),
+    (b, ldb): (&[A], usize),
+    beta: f32,
+    c: &mut [f32],
+    ldc: usize,
+) {
+    let (mut panela, mut panelb) = (vec![], vec![]);
+    let mut inner = 0;
+    loop {
+        let depth = BLOCK.min(k - inner);
+        let lda = if transa == Transpose::No {
+            convert(&mut panela, a, lda, (0, inner), (m, depth))
+        } else {
+            convert(&mut panela, a, lda, (inner, 0), (depth, m))
+        };
+        for start in (0..n).step_by(BLOCK) {
+            let cols = BLOCK.min(n - start);
+            let ldb = if transb == Transpose::No {
+                convert(&mut panelb, b, ldb, (inner, start), (depth, cols))
+            } else {
+                convert(&mut panelb, b, ldb, (start, inner), (cols, depth))
+            };
+            ::sgemm(
+                transa,
+                transb,
+                m as i32,
+                cols as i32,
+                depth as i32,
+                alpha,
+                &panela,
+                lda as i32,
+                &panelb,
+                ldb as i32,
+                if inner == 0 { beta } else { 1.0 },
+                &mut c[start * ldc..],
+                ldc as i32,
+            );
+        }
+        inner += depth;
+        if inner >= k {
+            break;
+        }
+    }
+}
+
+/// Convert the block of `data` with `rows` rows and `cols` columns starting
+/// at `(row, col)` into a column-major buffer and return the leading
+/// dimension of the buffer.
+#[inline]
+fn convert<A: Element>(
+    buffer: &mut Vec<f32>,
+    data: &[A],
+    ld: usize,
+    (row, col): (usize, usize),
+    (rows, cols): (usize, usize),
+) -> usize {
+    buffer.clear();
+    if rows > 0 {
+        for j in 0..cols {
+            let column = &data[row + (col + j) * ld..][..rows];
+            buffer.extend(column.iter().map(|&value| value.to_f32()));
+        }
+    }
+    rows.max(1)
+}
+
+#[cfg(all(test, feature = "reference", not(feature = "dynamic")))]
+mod tests {
+    use super::*;
+
+    /// Create arbitrary but reproducible elements.
+    fn elements<A: Element>(len: usize, seed: f32) -> Vec<A> {
+        (0..len)
+            .map(|i| A::from_f32((seed + 0.7 * i as f32).sin()))
+            .collect()
+    }
+
+    #[test]
+    fn rounding() {
+        let (a, b) = (vec![f16::ONE], vec![f16::ONE; 2]);
+        let mut c = vec![f16::from_f32(2048.0), f16::from_f32(2050.0)];
+        unsafe { hgemm(b'N', b'N', 1, 2, 1, 1.0, &a, 1, &b, 1, 1.0, &mut c, 1) };
+        assert_eq!(c, [f16::from_f32(2048.0), f16::from_f32(2052.0)]);
+
+        let (a, b) = (vec![bf16::ONE], vec![bf16::ONE; 2]);
+        let mut c = vec![bf16::from_f32(256.0), bf16::from_f32(258.0)];
+        unsafe {
+            bf16gemm(
+                Transpose::No,
+                Transpose::Trans,
+                1,
+                2,
+                1,
+                1.0,
+                &a,
+                1,
+                &b,
+                2,
+                1.0,
+                &mut c,
+                1,
+            )
+        };
+        assert_eq!(c, [bf16::from_f32(256.0), bf16::from_f32(260.0)]);
+    }
+
+    #[test]
+    fn output() {
+        let transposes = [(b'N', b'N'), (b'T', b'N'), (b'N', b'C'), (b'T', b'T')];
+        let (m, n, k) = (5, BLOCK + 3, BLOCK + 9);
+        let widen = |x: &[f16]| x.iter().map(|x| x.to_f32()).collect::<Vec<_>>();
+        for &(transa, transb) in &transposes {
+            let lda = if transa == b'N' { m + 1 } else { k };
+            let ldb = if transb == b'N' { k + 2 } else { n };
+            let a: Vec<f16> = elements(lda * if transa == b'N' { k } else { m }, 1.0);
+            let b: Vec<f16> = elements(ldb * if transb == b'N' { n } else { k }, 2.0);
+            let mut half: Vec<f16> = elements(m * n, 3.0);
+            let mut single = widen(&half);
+            let mut expected = single.clone();
+            let (m, n, k, lda, ldb) = (m as i32, n as i32, k as i32, lda as i32, ldb as i32);
+            unsafe {
+                hgemm(
+                    transa,
+                    transb,
+                    m,
+                    n,
+                    k,
+                    0.5,
+                    &a,
+                    lda,
+                    &b,
+                    ldb,
+                    2.0,
+                    &mut single,
+                    m,
+                );
+                hgemm(
+                    transa, transb, m, n, k, 0.5, &a, lda, &b, ldb, 2.0, &mut half, m,
+                );
+                ::sgemm(
+                    transa,
+                    transb,
+                    m,
+                    n,
+                    k,
+                    0.5,
+                    &widen(&a),
+                    lda,
+                    &widen(&b),
+                    ldb,
+                    2.0,
+                    &mut expected,
+                    m,
+                );
+            }
+            for (&value, &expected) in single.iter().zip(&expected) {
+                assert!((value - expected).abs() <= 1e-4 * (1.0 + expected.abs()));
+            }
+            let rounded: Vec<_> = single.iter().map(|&x| f16::from_f32(x)).collect();
+            assert_eq!(half, rounded);
+        }
+    }
+
+    #[test]
+    fn overwrite() {
+        let a: Vec<bf16> = elements(6, 1.0);
+        let b: Vec<bf16> = elements(6, 2.0);
+        let mut c = vec![f32::NAN; 4];
+        let mut half = vec![bf16::NAN; 4];
+        unsafe {
+            bf16gemm(b'N', b'N', 2, 2, 3, 1.0, &a, 2, &b, 3, 0.0, &mut c, 2);
+            bf16gemm(b'N', b'N', 2, 2, 3, 1.0, &a, 2, &b, 3, 0.0, &mut half, 2);
+        }
+        assert!(c.iter().all(|value| value.is_finite()));
+        let rounded: Vec<_> = c.iter().map(|&x| bf16::from_f32(x)).collect();
+        assert_eq!(half, rounded);
+    }
+
+    #[test]
+    fn empty() {
+        let mut c = vec![f16::from_f32(1.5), f16::NAN, f16::from_f32(-3.0)];
+        unsafe { hgemm(b'N', b'N', 1, 3, 0, 1.0, &[], 1, &[], 1, 2.0, &mut c, 1) };
+        assert_eq!(c[0], f16::from_f32(3.0));
+        assert!(c[1].is_nan());
+        assert_eq!(c[2], f16::from_f32(-6.0));
+
+        let mut c = vec![f32::NAN; 3];
+        unsafe { hgemm(b'N', b'N', 1, 3, 0, 1.0, &[], 1, &[], 1, 0.0, &mut c, 1) };
+        assert_eq!(c, [0.0; 3]);
+    }
+}
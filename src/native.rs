@@ -0,0 +1,41 @@
+//! Routines beyond the reference interface provided by the library linked
+//! into the process.
+//!
+//! The routines are looked up among the symbols of the process the first time
+//! they are needed, in the way [`backend_info`] identifies the library.
+//!
+//! [`backend_info`]: ../fn.backend_info.html
+
+use std::mem;
+
+use libc::c_char;
+
+macro_rules! extensions {
+    ($($(#[alias($($alias:ident),*)])* fn $name:ident($($argument:ident: $ty:ty),* $(,)*) $(-> $output:ty)*;)*) => {
+        $(
+            /// Return the routine if the process provides it.
+            pub(crate) fn $name() -> Option<unsafe extern "C" fn($($ty),*) $(-> $output)*> {
+                static FUNCTION: ::std::sync::OnceLock<Option<unsafe extern "C" fn($($ty),*) $(-> $output)*>> =
+                    ::std::sync::OnceLock::new();
+                *FUNCTION.get_or_init(|| unsafe {
+                    super::symbol(concat!(stringify!($name), "\0"))
+                        $($(.or_else(|| super::symbol(concat!(stringify!($alias), "\0"))))*)*
+                })
+            }
+        )*
+    };
+}
+
+#[path = "dynamic/extensions.rs"]
+mod extensions;
+
+pub(crate) use self::extensions::*;
+
+unsafe fn symbol<F: Copy>(name: &str) -> Option<F> {
+    let pointer = libc::dlsym(libc::RTLD_DEFAULT, name.as_ptr() as *const c_char);
+    if pointer.is_null() {
+        None
+    } else {
+        Some(mem::transmute_copy(&pointer))
+    }
+}
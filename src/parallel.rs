@@ -0,0 +1,205 @@
+//! Parallel execution.
+
+use std::mem;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+use std::thread;
+
+/// The number of floating-point operations that justifies a thread.
+const GRAIN: f64 = 1e6;
+
+/// Return the number of threads worth using for `tasks` independent tasks
+/// amounting to `flops` floating-point operations.
+pub(crate) fn threads(tasks: usize, flops: f64) -> usize {
+    available().min(tasks).min((flops / GRAIN) as usize).max(1)
+}
+
+/// Return the number of threads that can run in parallel.
+pub(crate) fn available() -> usize {
+    static AVAILABLE: OnceLock<usize> = OnceLock::new();
+    *AVAILABLE.get_or_init(|| {
+        thread::available_parallelism()
+            .map(|count| count.get())
+            .unwrap_or(1)
+    })
+}
+
+/// Run `tasks` tasks on `threads` threads, giving task `i` the part of `data`
+/// starting at `i * stride`.
+///
+/// The parts of different tasks do not overlap as long as each task accesses
+/// at most `stride` elements.
+pub(crate) fn chunks<T, F>(data: &mut [T], stride: usize, tasks: usize, threads: usize, task: F)
+where
+    T: Send,
+    F: Fn(usize, &mut [T]) + Sync,
+{
+    if threads <= 1 || stride == 0 {
+        for i in 0..tasks {
+            task(i, &mut data[i * stride..]);
+        }
+        return;
+    }
+    let count = tasks.div_ceil(threads);
+    let mut parts: Vec<_> = data
+        .chunks_mut(count * stride)
+        .enumerate()
+        .map(|(j, chunk)| (j * count, chunk))
+        .take_while(|&(start, _)| start < tasks)
+        .collect();
+    let threads = parts.len();
+    each(&mut parts, threads, |(start, chunk)| {
+        for i in *start..tasks.min(*start + count) {
+            task(i, &mut chunk[(i - *start) * stride..]);
+        }
+    });
+}
+
+/// Run `task` for each of `items` on `threads` threads.
+///
+/// The items are handed out one at a time, so that tasks of different sizes
+/// are balanced across the threads.
+pub(crate) fn each<T, F>(items: &mut [T], threads: usize, task: F)
+where
+    T: Send,
+    F: Fn(&mut T) + Sync,
+{
+    if threads <= 1 {
+        items.iter_mut().for_each(task);
+        return;
+    }
+    let queue = Mutex::new(items.iter_mut());
+    broadcast(threads, || loop {
+        let item = match queue.lock().unwrap().next() {
+            Some(item) => item,
+            None => break,
+        };
+        task(item);
+    });
+}
+
+/// A job run by one of the threads kept alive between calls.
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Threads kept alive between calls, which take jobs from a shared channel.
+struct Workers {
+    sender: Mutex<Sender<Job>>,
+    receiver: Arc<Mutex<Receiver<Job>>>,
+    /// The number of threads not running a job nor reserved for one.
+    idle: Mutex<usize>,
+}
+
+impl Workers {
+    fn get() -> &'static Workers {
+        static WORKERS: OnceLock<Workers> = OnceLock::new();
+        WORKERS.get_or_init(|| {
+            let (sender, receiver) = mpsc::channel();
+            Workers {
+                sender: Mutex::new(sender),
+                receiver: Arc::new(Mutex::new(receiver)),
+                idle: Mutex::new(0),
+            }
+        })
+    }
+
+    /// Reserve `count` threads, spawning those missing, so that jobs sent
+    /// afterwards do not wait for threads blocked in nested calls.
+    fn reserve(&'static self, count: usize) {
+        let mut idle = self.idle.lock().unwrap();
+        while *idle < count {
+            let receiver = self.receiver.clone();
+            thread::Builder::new()
+                .name("blas".into())
+                .spawn(move || loop {
+                    let job = match receiver.lock().unwrap().recv() {
+                        Ok(job) => job,
+                        Err(_) => break,
+                    };
+                    job();
+                    *Workers::get().idle.lock().unwrap() += 1;
+                })
+                .expect("failed to spawn a thread");
+            *idle += 1;
+        }
+        *idle -= count;
+    }
+}
+
+/// Run `task` on `threads` threads, the calling one and reused ones, and wait
+/// for all of them to finish, resuming the panic of a task if any.
+fn broadcast<F: Fn() + Sync>(threads: usize, task: F) {
+    if threads <= 1 {
+        return task();
+    }
+    let workers = Workers::get();
+    workers.reserve(threads - 1);
+    // The number of jobs still running and whether one of them panicked.
+    let latch = Arc::new((Mutex::new((threads - 1, false)), Condvar::new()));
+    let task: &(dyn Fn() + Sync) = &task;
+    // The lifetime is extended as the jobs are waited for below, even if the
+    // task panics on the calling thread.
+    let task: &'static (dyn Fn() + Sync) = unsafe { mem::transmute(task) };
+    {
+        let sender = workers.sender.lock().unwrap();
+        for _ in 1..threads {
+            let latch = latch.clone();
+            let job = Box::new(move || {
+                let result = panic::catch_unwind(AssertUnwindSafe(task));
+                let (state, condvar) = &*latch;
+                let mut state = state.lock().unwrap();
+                state.0 -= 1;
+                state.1 |= result.is_err();
+                condvar.notify_one();
+            });
+            sender.send(job).expect("the threads have stopped");
+        }
+    }
+    let result = panic::catch_unwind(AssertUnwindSafe(task));
+    let (state, condvar) = &*latch;
+    let mut state = state.lock().unwrap();
+    while state.0 > 0 {
+        state = condvar.wait(state).unwrap();
+    }
+    if let Err(payload) = result {
+        panic::resume_unwind(payload);
+    }
+    if state.1 {
+        panic!("a task panicked on another thread");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn each() {
+        let mut items: Vec<_> = (0..100).collect();
+        for _ in 0..3 {
+            super::each(&mut items, 4, |item| *item += 1);
+        }
+        assert_eq!(items, (3..103).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn chunks() {
+        let mut data = vec![0; 30];
+        super::chunks(&mut data, 3, 10, 4, |i, chunk| chunk[..3].fill(i));
+        assert_eq!(data, (0..30).map(|i| i / 3).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn nested() {
+        let mut items = vec![vec![0; 10]; 8];
+        super::each(&mut items, 8, |inner| {
+            super::each(inner, 8, |item| *item += 1)
+        });
+        assert!(items.iter().flatten().all(|&item| item == 1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panic() {
+        let mut items: Vec<_> = (0..16).collect();
+        super::each(&mut items, 4, |item| assert_ne!(*item, 7));
+    }
+}
@@ -0,0 +1,2056 @@
+//! Execution of recorded calls.
+
+use std::io;
+
+use super::{invalid, Argument, Arguments, Value};
+use {c32, c64};
+
+/// The values of the output arguments and of the result of a call.
+type Outcome = (Vec<(&'static str, Value)>, Value);
+
+/// Define a function executing a call given the name of the routine and the
+/// arguments, where the integers are of type `$int` and the wrappers are those
+/// of `$module`, if any, or else those at the root of the crate.
+macro_rules! execute {
+    ($(#[$attribute:meta])* $name:ident, $int:ty $(, $module:ident)*) => {
+        $(#[$attribute])*
+        pub unsafe fn $name(routine: &str, arguments: &Arguments) -> io::Result<Outcome> {
+            match routine {
+                "srotg" => {
+                    let mut a: f32 = arguments.get("a")?;
+                    let mut b: f32 = arguments.get("b")?;
+                    let mut c: f32 = arguments.get("c")?;
+                    let mut s: f32 = arguments.get("s")?;
+                    $(::$module)*::srotg(&mut a, &mut b, &mut c, &mut s);
+                    Ok((
+                        vec![
+                            ("a", a.value(true)),
+                            ("b", b.value(true)),
+                            ("c", c.value(true)),
+                            ("s", s.value(true)),
+                        ],
+                        Value::None,
+                    ))
+                }
+                "srotmg" => {
+                    let mut d1: f32 = arguments.get("d1")?;
+                    let mut d2: f32 = arguments.get("d2")?;
+                    let mut x1: f32 = arguments.get("x1")?;
+                    let y1: f32 = arguments.get("y1")?;
+                    let mut param: Vec<f32> = arguments.get("param")?;
+                    $(::$module)*::srotmg(&mut d1, &mut d2, &mut x1, y1, &mut param);
+                    Ok((
+                        vec![
+                            ("d1", d1.value(true)),
+                            ("d2", d2.value(true)),
+                            ("x1", x1.value(true)),
+                            ("param", param.value(true)),
+                        ],
+                        Value::None,
+                    ))
+                }
+                "srot" => {
+                    let n: $int = arguments.get("n")?;
+                    let mut x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut y: Vec<f32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let c: f32 = arguments.get("c")?;
+                    let s: f32 = arguments.get("s")?;
+                    $(::$module)*::srot(n, &mut x, incx, &mut y, incy, c, s);
+                    Ok((
+                        vec![("x", x.value(true)), ("y", y.value(true))],
+                        Value::None,
+                    ))
+                }
+                "srotm" => {
+                    let n: $int = arguments.get("n")?;
+                    let mut x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut y: Vec<f32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let param: Vec<f32> = arguments.get("param")?;
+                    $(::$module)*::srotm(n, &mut x, incx, &mut y, incy, &param);
+                    Ok((
+                        vec![("x", x.value(true)), ("y", y.value(true))],
+                        Value::None,
+                    ))
+                }
+                "sswap" => {
+                    let n: $int = arguments.get("n")?;
+                    let mut x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut y: Vec<f32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::sswap(n, &mut x, incx, &mut y, incy);
+                    Ok((
+                        vec![("x", x.value(true)), ("y", y.value(true))],
+                        Value::None,
+                    ))
+                }
+                "sscal" => {
+                    let n: $int = arguments.get("n")?;
+                    let a: f32 = arguments.get("a")?;
+                    let mut x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::sscal(n, a, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "scopy" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut y: Vec<f32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::scopy(n, &x, incx, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "saxpy" => {
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f32 = arguments.get("alpha")?;
+                    let x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut y: Vec<f32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::saxpy(n, alpha, &x, incx, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "sdot" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<f32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let result = $(::$module)*::sdot(n, &x, incx, &y, incy);
+                    Ok((vec![], result.value(true)))
+                }
+                "sdsdot" => {
+                    let n: $int = arguments.get("n")?;
+                    let sb: Vec<f32> = arguments.get("sb")?;
+                    let x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<f32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let result = $(::$module)*::sdsdot(n, &sb, &x, incx, &y, incy);
+                    Ok((vec![], result.value(true)))
+                }
+                "snrm2" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let result = $(::$module)*::snrm2(n, &x, incx);
+                    Ok((vec![], result.value(true)))
+                }
+                "scnrm2" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let result = $(::$module)*::scnrm2(n, &x, incx);
+                    Ok((vec![], result.value(true)))
+                }
+                "sasum" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let result = $(::$module)*::sasum(n, &x, incx);
+                    Ok((vec![], result.value(true)))
+                }
+                "isamax" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let result = $(::$module)*::isamax(n, &x, incx);
+                    Ok((vec![], result.value(true)))
+                }
+                "drotg" => {
+                    let mut a: f64 = arguments.get("a")?;
+                    let mut b: f64 = arguments.get("b")?;
+                    let mut c: f64 = arguments.get("c")?;
+                    let mut s: f64 = arguments.get("s")?;
+                    $(::$module)*::drotg(&mut a, &mut b, &mut c, &mut s);
+                    Ok((
+                        vec![
+                            ("a", a.value(true)),
+                            ("b", b.value(true)),
+                            ("c", c.value(true)),
+                            ("s", s.value(true)),
+                        ],
+                        Value::None,
+                    ))
+                }
+                "drotmg" => {
+                    let mut d1: f64 = arguments.get("d1")?;
+                    let mut d2: f64 = arguments.get("d2")?;
+                    let mut x1: f64 = arguments.get("x1")?;
+                    let y1: f64 = arguments.get("y1")?;
+                    let mut param: Vec<f64> = arguments.get("param")?;
+                    $(::$module)*::drotmg(&mut d1, &mut d2, &mut x1, y1, &mut param);
+                    Ok((
+                        vec![
+                            ("d1", d1.value(true)),
+                            ("d2", d2.value(true)),
+                            ("x1", x1.value(true)),
+                            ("param", param.value(true)),
+                        ],
+                        Value::None,
+                    ))
+                }
+                "drot" => {
+                    let n: $int = arguments.get("n")?;
+                    let mut x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut y: Vec<f64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let c: f64 = arguments.get("c")?;
+                    let s: f64 = arguments.get("s")?;
+                    $(::$module)*::drot(n, &mut x, incx, &mut y, incy, c, s);
+                    Ok((
+                        vec![("x", x.value(true)), ("y", y.value(true))],
+                        Value::None,
+                    ))
+                }
+                "drotm" => {
+                    let n: $int = arguments.get("n")?;
+                    let mut x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut y: Vec<f64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let param: Vec<f64> = arguments.get("param")?;
+                    $(::$module)*::drotm(n, &mut x, incx, &mut y, incy, &param);
+                    Ok((
+                        vec![("x", x.value(true)), ("y", y.value(true))],
+                        Value::None,
+                    ))
+                }
+                "dswap" => {
+                    let n: $int = arguments.get("n")?;
+                    let mut x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut y: Vec<f64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::dswap(n, &mut x, incx, &mut y, incy);
+                    Ok((
+                        vec![("x", x.value(true)), ("y", y.value(true))],
+                        Value::None,
+                    ))
+                }
+                "dscal" => {
+                    let n: $int = arguments.get("n")?;
+                    let a: f64 = arguments.get("a")?;
+                    let mut x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::dscal(n, a, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "dcopy" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut y: Vec<f64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::dcopy(n, &x, incx, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "daxpy" => {
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f64 = arguments.get("alpha")?;
+                    let x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut y: Vec<f64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::daxpy(n, alpha, &x, incx, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "ddot" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<f64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let result = $(::$module)*::ddot(n, &x, incx, &y, incy);
+                    Ok((vec![], result.value(true)))
+                }
+                "dsdot" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<f32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let result = $(::$module)*::dsdot(n, &x, incx, &y, incy);
+                    Ok((vec![], result.value(true)))
+                }
+                "dnrm2" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let result = $(::$module)*::dnrm2(n, &x, incx);
+                    Ok((vec![], result.value(true)))
+                }
+                "dznrm2" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let result = $(::$module)*::dznrm2(n, &x, incx);
+                    Ok((vec![], result.value(true)))
+                }
+                "dasum" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let result = $(::$module)*::dasum(n, &x, incx);
+                    Ok((vec![], result.value(true)))
+                }
+                "idamax" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let result = $(::$module)*::idamax(n, &x, incx);
+                    Ok((vec![], result.value(true)))
+                }
+                "crotg" => {
+                    let mut a: c32 = arguments.get("a")?;
+                    let b: c32 = arguments.get("b")?;
+                    let mut c: f32 = arguments.get("c")?;
+                    let mut s: c32 = arguments.get("s")?;
+                    $(::$module)*::crotg(&mut a, b, &mut c, &mut s);
+                    Ok((
+                        vec![
+                            ("a", a.value(true)),
+                            ("c", c.value(true)),
+                            ("s", s.value(true)),
+                        ],
+                        Value::None,
+                    ))
+                }
+                "csrot" => {
+                    let n: $int = arguments.get("n")?;
+                    let mut x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut y: Vec<c32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let c: f32 = arguments.get("c")?;
+                    let s: f32 = arguments.get("s")?;
+                    $(::$module)*::csrot(n, &mut x, incx, &mut y, incy, c, s);
+                    Ok((
+                        vec![("x", x.value(true)), ("y", y.value(true))],
+                        Value::None,
+                    ))
+                }
+                "cswap" => {
+                    let n: $int = arguments.get("n")?;
+                    let mut x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut y: Vec<c32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::cswap(n, &mut x, incx, &mut y, incy);
+                    Ok((
+                        vec![("x", x.value(true)), ("y", y.value(true))],
+                        Value::None,
+                    ))
+                }
+                "cscal" => {
+                    let n: $int = arguments.get("n")?;
+                    let a: c32 = arguments.get("a")?;
+                    let mut x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::cscal(n, a, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "csscal" => {
+                    let n: $int = arguments.get("n")?;
+                    let a: f32 = arguments.get("a")?;
+                    let mut x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::csscal(n, a, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "ccopy" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut y: Vec<c32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::ccopy(n, &x, incx, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "caxpy" => {
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c32 = arguments.get("alpha")?;
+                    let x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut y: Vec<c32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::caxpy(n, alpha, &x, incx, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "cdotu" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<c32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let result = $(::$module)*::cdotu(n, &x, incx, &y, incy);
+                    Ok((vec![], result.value(true)))
+                }
+                "cdotc" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<c32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let result = $(::$module)*::cdotc(n, &x, incx, &y, incy);
+                    Ok((vec![], result.value(true)))
+                }
+                "scasum" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let result = $(::$module)*::scasum(n, &x, incx);
+                    Ok((vec![], result.value(true)))
+                }
+                "icamax" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let result = $(::$module)*::icamax(n, &x, incx);
+                    Ok((vec![], result.value(true)))
+                }
+                "zrotg" => {
+                    let mut a: c64 = arguments.get("a")?;
+                    let b: c64 = arguments.get("b")?;
+                    let mut c: f64 = arguments.get("c")?;
+                    let mut s: c64 = arguments.get("s")?;
+                    $(::$module)*::zrotg(&mut a, b, &mut c, &mut s);
+                    Ok((
+                        vec![
+                            ("a", a.value(true)),
+                            ("c", c.value(true)),
+                            ("s", s.value(true)),
+                        ],
+                        Value::None,
+                    ))
+                }
+                "zdrot" => {
+                    let n: $int = arguments.get("n")?;
+                    let mut x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut y: Vec<c64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let c: f64 = arguments.get("c")?;
+                    let s: f64 = arguments.get("s")?;
+                    $(::$module)*::zdrot(n, &mut x, incx, &mut y, incy, c, s);
+                    Ok((
+                        vec![("x", x.value(true)), ("y", y.value(true))],
+                        Value::None,
+                    ))
+                }
+                "zswap" => {
+                    let n: $int = arguments.get("n")?;
+                    let mut x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut y: Vec<c64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::zswap(n, &mut x, incx, &mut y, incy);
+                    Ok((
+                        vec![("x", x.value(true)), ("y", y.value(true))],
+                        Value::None,
+                    ))
+                }
+                "zscal" => {
+                    let n: $int = arguments.get("n")?;
+                    let a: c64 = arguments.get("a")?;
+                    let mut x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::zscal(n, a, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "zdscal" => {
+                    let n: $int = arguments.get("n")?;
+                    let a: f64 = arguments.get("a")?;
+                    let mut x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::zdscal(n, a, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "zcopy" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut y: Vec<c64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::zcopy(n, &x, incx, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "zaxpy" => {
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c64 = arguments.get("alpha")?;
+                    let x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut y: Vec<c64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::zaxpy(n, alpha, &x, incx, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "zdotu" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<c64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let result = $(::$module)*::zdotu(n, &x, incx, &y, incy);
+                    Ok((vec![], result.value(true)))
+                }
+                "zdotc" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<c64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let result = $(::$module)*::zdotc(n, &x, incx, &y, incy);
+                    Ok((vec![], result.value(true)))
+                }
+                "dzasum" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let result = $(::$module)*::dzasum(n, &x, incx);
+                    Ok((vec![], result.value(true)))
+                }
+                "izamax" => {
+                    let n: $int = arguments.get("n")?;
+                    let x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let result = $(::$module)*::izamax(n, &x, incx);
+                    Ok((vec![], result.value(true)))
+                }
+                "sgemv" => {
+                    let trans: u8 = arguments.get("trans")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f32 = arguments.get("alpha")?;
+                    let a: Vec<f32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let beta: f32 = arguments.get("beta")?;
+                    let mut y: Vec<f32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::sgemv(trans, m, n, alpha, &a, lda, &x, incx, beta, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "sgbmv" => {
+                    let trans: u8 = arguments.get("trans")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let kl: $int = arguments.get("kl")?;
+                    let ku: $int = arguments.get("ku")?;
+                    let alpha: f32 = arguments.get("alpha")?;
+                    let a: Vec<f32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let beta: f32 = arguments.get("beta")?;
+                    let mut y: Vec<f32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::sgbmv(
+                        trans, m, n, kl, ku, alpha, &a, lda, &x, incx, beta, &mut y, incy,
+                    );
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "ssymv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f32 = arguments.get("alpha")?;
+                    let a: Vec<f32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let beta: f32 = arguments.get("beta")?;
+                    let mut y: Vec<f32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::ssymv(uplo, n, alpha, &a, lda, &x, incx, beta, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "ssbmv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let alpha: f32 = arguments.get("alpha")?;
+                    let a: Vec<f32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let beta: f32 = arguments.get("beta")?;
+                    let mut y: Vec<f32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::ssbmv(uplo, n, k, alpha, &a, lda, &x, incx, beta, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "sspmv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f32 = arguments.get("alpha")?;
+                    let ap: Vec<f32> = arguments.get("ap")?;
+                    let x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let beta: f32 = arguments.get("beta")?;
+                    let mut y: Vec<f32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::sspmv(uplo, n, alpha, &ap, &x, incx, beta, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "strmv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let transa: u8 = arguments.get("transa")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let a: Vec<f32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut b: Vec<f32> = arguments.get("b")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::strmv(uplo, transa, diag, n, &a, lda, &mut b, incx);
+                    Ok((vec![("b", b.value(true))], Value::None))
+                }
+                "stbmv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let a: Vec<f32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::stbmv(uplo, trans, diag, n, k, &a, lda, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "stpmv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let ap: Vec<f32> = arguments.get("ap")?;
+                    let mut x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::stpmv(uplo, trans, diag, n, &ap, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "strsv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let a: Vec<f32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::strsv(uplo, trans, diag, n, &a, lda, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "stbsv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let a: Vec<f32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::stbsv(uplo, trans, diag, n, k, &a, lda, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "stpsv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let ap: Vec<f32> = arguments.get("ap")?;
+                    let mut x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::stpsv(uplo, trans, diag, n, &ap, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "sger" => {
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f32 = arguments.get("alpha")?;
+                    let x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<f32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let mut a: Vec<f32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    $(::$module)*::sger(m, n, alpha, &x, incx, &y, incy, &mut a, lda);
+                    Ok((vec![("a", a.value(true))], Value::None))
+                }
+                "ssyr" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f32 = arguments.get("alpha")?;
+                    let x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut a: Vec<f32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    $(::$module)*::ssyr(uplo, n, alpha, &x, incx, &mut a, lda);
+                    Ok((vec![("a", a.value(true))], Value::None))
+                }
+                "sspr" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f32 = arguments.get("alpha")?;
+                    let x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut ap: Vec<f32> = arguments.get("ap")?;
+                    $(::$module)*::sspr(uplo, n, alpha, &x, incx, &mut ap);
+                    Ok((vec![("ap", ap.value(true))], Value::None))
+                }
+                "ssyr2" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f32 = arguments.get("alpha")?;
+                    let x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<f32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let mut a: Vec<f32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    $(::$module)*::ssyr2(uplo, n, alpha, &x, incx, &y, incy, &mut a, lda);
+                    Ok((vec![("a", a.value(true))], Value::None))
+                }
+                "sspr2" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f32 = arguments.get("alpha")?;
+                    let x: Vec<f32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<f32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let mut ap: Vec<f32> = arguments.get("ap")?;
+                    $(::$module)*::sspr2(uplo, n, alpha, &x, incx, &y, incy, &mut ap);
+                    Ok((vec![("ap", ap.value(true))], Value::None))
+                }
+                "dgemv" => {
+                    let trans: u8 = arguments.get("trans")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f64 = arguments.get("alpha")?;
+                    let a: Vec<f64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let beta: f64 = arguments.get("beta")?;
+                    let mut y: Vec<f64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::dgemv(trans, m, n, alpha, &a, lda, &x, incx, beta, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "dgbmv" => {
+                    let trans: u8 = arguments.get("trans")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let kl: $int = arguments.get("kl")?;
+                    let ku: $int = arguments.get("ku")?;
+                    let alpha: f64 = arguments.get("alpha")?;
+                    let a: Vec<f64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let beta: f64 = arguments.get("beta")?;
+                    let mut y: Vec<f64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::dgbmv(
+                        trans, m, n, kl, ku, alpha, &a, lda, &x, incx, beta, &mut y, incy,
+                    );
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "dsymv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f64 = arguments.get("alpha")?;
+                    let a: Vec<f64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let beta: f64 = arguments.get("beta")?;
+                    let mut y: Vec<f64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::dsymv(uplo, n, alpha, &a, lda, &x, incx, beta, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "dsbmv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let alpha: f64 = arguments.get("alpha")?;
+                    let a: Vec<f64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let beta: f64 = arguments.get("beta")?;
+                    let mut y: Vec<f64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::dsbmv(uplo, n, k, alpha, &a, lda, &x, incx, beta, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "dspmv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f64 = arguments.get("alpha")?;
+                    let ap: Vec<f64> = arguments.get("ap")?;
+                    let x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let beta: f64 = arguments.get("beta")?;
+                    let mut y: Vec<f64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::dspmv(uplo, n, alpha, &ap, &x, incx, beta, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "dtrmv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let transa: u8 = arguments.get("transa")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let a: Vec<f64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut b: Vec<f64> = arguments.get("b")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::dtrmv(uplo, transa, diag, n, &a, lda, &mut b, incx);
+                    Ok((vec![("b", b.value(true))], Value::None))
+                }
+                "dtbmv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let a: Vec<f64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::dtbmv(uplo, trans, diag, n, k, &a, lda, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "dtpmv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let ap: Vec<f64> = arguments.get("ap")?;
+                    let mut x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::dtpmv(uplo, trans, diag, n, &ap, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "dtrsv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let a: Vec<f64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::dtrsv(uplo, trans, diag, n, &a, lda, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "dtbsv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let a: Vec<f64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::dtbsv(uplo, trans, diag, n, k, &a, lda, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "dtpsv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let ap: Vec<f64> = arguments.get("ap")?;
+                    let mut x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::dtpsv(uplo, trans, diag, n, &ap, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "dger" => {
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f64 = arguments.get("alpha")?;
+                    let x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<f64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let mut a: Vec<f64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    $(::$module)*::dger(m, n, alpha, &x, incx, &y, incy, &mut a, lda);
+                    Ok((vec![("a", a.value(true))], Value::None))
+                }
+                "dsyr" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f64 = arguments.get("alpha")?;
+                    let x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut a: Vec<f64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    $(::$module)*::dsyr(uplo, n, alpha, &x, incx, &mut a, lda);
+                    Ok((vec![("a", a.value(true))], Value::None))
+                }
+                "dspr" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f64 = arguments.get("alpha")?;
+                    let x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut ap: Vec<f64> = arguments.get("ap")?;
+                    $(::$module)*::dspr(uplo, n, alpha, &x, incx, &mut ap);
+                    Ok((vec![("ap", ap.value(true))], Value::None))
+                }
+                "dsyr2" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f64 = arguments.get("alpha")?;
+                    let x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<f64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let mut a: Vec<f64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    $(::$module)*::dsyr2(uplo, n, alpha, &x, incx, &y, incy, &mut a, lda);
+                    Ok((vec![("a", a.value(true))], Value::None))
+                }
+                "dspr2" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f64 = arguments.get("alpha")?;
+                    let x: Vec<f64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<f64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let mut ap: Vec<f64> = arguments.get("ap")?;
+                    $(::$module)*::dspr2(uplo, n, alpha, &x, incx, &y, incy, &mut ap);
+                    Ok((vec![("ap", ap.value(true))], Value::None))
+                }
+                "cgemv" => {
+                    let trans: u8 = arguments.get("trans")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c32 = arguments.get("alpha")?;
+                    let a: Vec<c32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let beta: c32 = arguments.get("beta")?;
+                    let mut y: Vec<c32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::cgemv(trans, m, n, alpha, &a, lda, &x, incx, beta, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "cgbmv" => {
+                    let trans: u8 = arguments.get("trans")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let kl: $int = arguments.get("kl")?;
+                    let ku: $int = arguments.get("ku")?;
+                    let alpha: c32 = arguments.get("alpha")?;
+                    let a: Vec<c32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let beta: c32 = arguments.get("beta")?;
+                    let mut y: Vec<c32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::cgbmv(
+                        trans, m, n, kl, ku, alpha, &a, lda, &x, incx, beta, &mut y, incy,
+                    );
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "chemv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c32 = arguments.get("alpha")?;
+                    let a: Vec<c32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let beta: c32 = arguments.get("beta")?;
+                    let mut y: Vec<c32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::chemv(uplo, n, alpha, &a, lda, &x, incx, beta, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "chbmv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let alpha: c32 = arguments.get("alpha")?;
+                    let a: Vec<c32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let beta: c32 = arguments.get("beta")?;
+                    let mut y: Vec<c32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::chbmv(uplo, n, k, alpha, &a, lda, &x, incx, beta, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "chpmv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c32 = arguments.get("alpha")?;
+                    let ap: Vec<c32> = arguments.get("ap")?;
+                    let x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let beta: c32 = arguments.get("beta")?;
+                    let mut y: Vec<c32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::chpmv(uplo, n, alpha, &ap, &x, incx, beta, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "ctrmv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let transa: u8 = arguments.get("transa")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let a: Vec<c32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut b: Vec<c32> = arguments.get("b")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::ctrmv(uplo, transa, diag, n, &a, lda, &mut b, incx);
+                    Ok((vec![("b", b.value(true))], Value::None))
+                }
+                "ctbmv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let a: Vec<c32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::ctbmv(uplo, trans, diag, n, k, &a, lda, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "ctpmv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let ap: Vec<c32> = arguments.get("ap")?;
+                    let mut x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::ctpmv(uplo, trans, diag, n, &ap, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "ctrsv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let a: Vec<c32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::ctrsv(uplo, trans, diag, n, &a, lda, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "ctbsv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let a: Vec<c32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::ctbsv(uplo, trans, diag, n, k, &a, lda, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "ctpsv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let ap: Vec<c32> = arguments.get("ap")?;
+                    let mut x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::ctpsv(uplo, trans, diag, n, &ap, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "cgeru" => {
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c32 = arguments.get("alpha")?;
+                    let x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<c32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let mut a: Vec<c32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    $(::$module)*::cgeru(m, n, alpha, &x, incx, &y, incy, &mut a, lda);
+                    Ok((vec![("a", a.value(true))], Value::None))
+                }
+                "cgerc" => {
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c32 = arguments.get("alpha")?;
+                    let x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<c32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let mut a: Vec<c32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    $(::$module)*::cgerc(m, n, alpha, &x, incx, &y, incy, &mut a, lda);
+                    Ok((vec![("a", a.value(true))], Value::None))
+                }
+                "cher" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f32 = arguments.get("alpha")?;
+                    let x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut a: Vec<c32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    $(::$module)*::cher(uplo, n, alpha, &x, incx, &mut a, lda);
+                    Ok((vec![("a", a.value(true))], Value::None))
+                }
+                "chpr" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f32 = arguments.get("alpha")?;
+                    let x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut ap: Vec<c32> = arguments.get("ap")?;
+                    $(::$module)*::chpr(uplo, n, alpha, &x, incx, &mut ap);
+                    Ok((vec![("ap", ap.value(true))], Value::None))
+                }
+                "chpr2" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c32 = arguments.get("alpha")?;
+                    let x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<c32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let mut ap: Vec<c32> = arguments.get("ap")?;
+                    $(::$module)*::chpr2(uplo, n, alpha, &x, incx, &y, incy, &mut ap);
+                    Ok((vec![("ap", ap.value(true))], Value::None))
+                }
+                "cher2" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c32 = arguments.get("alpha")?;
+                    let x: Vec<c32> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<c32> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let mut a: Vec<c32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    $(::$module)*::cher2(uplo, n, alpha, &x, incx, &y, incy, &mut a, lda);
+                    Ok((vec![("a", a.value(true))], Value::None))
+                }
+                "zgemv" => {
+                    let trans: u8 = arguments.get("trans")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c64 = arguments.get("alpha")?;
+                    let a: Vec<c64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let beta: c64 = arguments.get("beta")?;
+                    let mut y: Vec<c64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::zgemv(trans, m, n, alpha, &a, lda, &x, incx, beta, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "zgbmv" => {
+                    let trans: u8 = arguments.get("trans")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let kl: $int = arguments.get("kl")?;
+                    let ku: $int = arguments.get("ku")?;
+                    let alpha: c64 = arguments.get("alpha")?;
+                    let a: Vec<c64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let beta: c64 = arguments.get("beta")?;
+                    let mut y: Vec<c64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::zgbmv(
+                        trans, m, n, kl, ku, alpha, &a, lda, &x, incx, beta, &mut y, incy,
+                    );
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "zhemv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c64 = arguments.get("alpha")?;
+                    let a: Vec<c64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let beta: c64 = arguments.get("beta")?;
+                    let mut y: Vec<c64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::zhemv(uplo, n, alpha, &a, lda, &x, incx, beta, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "zhbmv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let alpha: c64 = arguments.get("alpha")?;
+                    let a: Vec<c64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let beta: c64 = arguments.get("beta")?;
+                    let mut y: Vec<c64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::zhbmv(uplo, n, k, alpha, &a, lda, &x, incx, beta, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "zhpmv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c64 = arguments.get("alpha")?;
+                    let ap: Vec<c64> = arguments.get("ap")?;
+                    let x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let beta: c64 = arguments.get("beta")?;
+                    let mut y: Vec<c64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    $(::$module)*::zhpmv(uplo, n, alpha, &ap, &x, incx, beta, &mut y, incy);
+                    Ok((vec![("y", y.value(true))], Value::None))
+                }
+                "ztrmv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let transa: u8 = arguments.get("transa")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let a: Vec<c64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut b: Vec<c64> = arguments.get("b")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::ztrmv(uplo, transa, diag, n, &a, lda, &mut b, incx);
+                    Ok((vec![("b", b.value(true))], Value::None))
+                }
+                "ztbmv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let a: Vec<c64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::ztbmv(uplo, trans, diag, n, k, &a, lda, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "ztpmv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let ap: Vec<c64> = arguments.get("ap")?;
+                    let mut x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::ztpmv(uplo, trans, diag, n, &ap, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "ztrsv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let a: Vec<c64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::ztrsv(uplo, trans, diag, n, &a, lda, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "ztbsv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let a: Vec<c64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::ztbsv(uplo, trans, diag, n, k, &a, lda, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "ztpsv" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let n: $int = arguments.get("n")?;
+                    let ap: Vec<c64> = arguments.get("ap")?;
+                    let mut x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    $(::$module)*::ztpsv(uplo, trans, diag, n, &ap, &mut x, incx);
+                    Ok((vec![("x", x.value(true))], Value::None))
+                }
+                "zgeru" => {
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c64 = arguments.get("alpha")?;
+                    let x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<c64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let mut a: Vec<c64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    $(::$module)*::zgeru(m, n, alpha, &x, incx, &y, incy, &mut a, lda);
+                    Ok((vec![("a", a.value(true))], Value::None))
+                }
+                "zgerc" => {
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c64 = arguments.get("alpha")?;
+                    let x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<c64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let mut a: Vec<c64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    $(::$module)*::zgerc(m, n, alpha, &x, incx, &y, incy, &mut a, lda);
+                    Ok((vec![("a", a.value(true))], Value::None))
+                }
+                "zher" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f64 = arguments.get("alpha")?;
+                    let x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut a: Vec<c64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    $(::$module)*::zher(uplo, n, alpha, &x, incx, &mut a, lda);
+                    Ok((vec![("a", a.value(true))], Value::None))
+                }
+                "zhpr" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f64 = arguments.get("alpha")?;
+                    let x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let mut ap: Vec<c64> = arguments.get("ap")?;
+                    $(::$module)*::zhpr(uplo, n, alpha, &x, incx, &mut ap);
+                    Ok((vec![("ap", ap.value(true))], Value::None))
+                }
+                "zher2" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c64 = arguments.get("alpha")?;
+                    let x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<c64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let mut a: Vec<c64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    $(::$module)*::zher2(uplo, n, alpha, &x, incx, &y, incy, &mut a, lda);
+                    Ok((vec![("a", a.value(true))], Value::None))
+                }
+                "zhpr2" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c64 = arguments.get("alpha")?;
+                    let x: Vec<c64> = arguments.get("x")?;
+                    let incx: $int = arguments.get("incx")?;
+                    let y: Vec<c64> = arguments.get("y")?;
+                    let incy: $int = arguments.get("incy")?;
+                    let mut ap: Vec<c64> = arguments.get("ap")?;
+                    $(::$module)*::zhpr2(uplo, n, alpha, &x, incx, &y, incy, &mut ap);
+                    Ok((vec![("ap", ap.value(true))], Value::None))
+                }
+                "sgemm" => {
+                    let transa: u8 = arguments.get("transa")?;
+                    let transb: u8 = arguments.get("transb")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let alpha: f32 = arguments.get("alpha")?;
+                    let a: Vec<f32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let b: Vec<f32> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    let beta: f32 = arguments.get("beta")?;
+                    let mut c: Vec<f32> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::sgemm(
+                        transa, transb, m, n, k, alpha, &a, lda, &b, ldb, beta, &mut c, ldc,
+                    );
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "ssymm" => {
+                    let side: u8 = arguments.get("side")?;
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f32 = arguments.get("alpha")?;
+                    let a: Vec<f32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let b: Vec<f32> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    let beta: f32 = arguments.get("beta")?;
+                    let mut c: Vec<f32> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::ssymm(
+                        side,
+                        uplo,
+                        m,
+                        n,
+                        alpha,
+                        &a,
+                        lda,
+                        &b,
+                        ldb,
+                        beta,
+                        &mut c,
+                        ldc,
+                    );
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "ssyrk" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let alpha: f32 = arguments.get("alpha")?;
+                    let a: Vec<f32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let beta: f32 = arguments.get("beta")?;
+                    let mut c: Vec<f32> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::ssyrk(uplo, trans, n, k, alpha, &a, lda, beta, &mut c, ldc);
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "ssyr2k" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let alpha: f32 = arguments.get("alpha")?;
+                    let a: Vec<f32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let b: Vec<f32> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    let beta: f32 = arguments.get("beta")?;
+                    let mut c: Vec<f32> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::ssyr2k(
+                        uplo, trans, n, k, alpha, &a, lda, &b, ldb, beta, &mut c, ldc,
+                    );
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "strmm" => {
+                    let side: u8 = arguments.get("side")?;
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let transa: u8 = arguments.get("transa")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f32 = arguments.get("alpha")?;
+                    let a: Vec<f32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut b: Vec<f32> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    $(::$module)*::strmm(
+                        side,
+                        uplo,
+                        transa,
+                        diag,
+                        m,
+                        n,
+                        alpha,
+                        &a,
+                        lda,
+                        &mut b,
+                        ldb,
+                    );
+                    Ok((vec![("b", b.value(true))], Value::None))
+                }
+                "strsm" => {
+                    let side: u8 = arguments.get("side")?;
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let transa: u8 = arguments.get("transa")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f32 = arguments.get("alpha")?;
+                    let a: Vec<f32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut b: Vec<f32> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    $(::$module)*::strsm(
+                        side,
+                        uplo,
+                        transa,
+                        diag,
+                        m,
+                        n,
+                        alpha,
+                        &a,
+                        lda,
+                        &mut b,
+                        ldb,
+                    );
+                    Ok((vec![("b", b.value(true))], Value::None))
+                }
+                "dgemm" => {
+                    let transa: u8 = arguments.get("transa")?;
+                    let transb: u8 = arguments.get("transb")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let alpha: f64 = arguments.get("alpha")?;
+                    let a: Vec<f64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let b: Vec<f64> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    let beta: f64 = arguments.get("beta")?;
+                    let mut c: Vec<f64> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::dgemm(
+                        transa, transb, m, n, k, alpha, &a, lda, &b, ldb, beta, &mut c, ldc,
+                    );
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "dsymm" => {
+                    let side: u8 = arguments.get("side")?;
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f64 = arguments.get("alpha")?;
+                    let a: Vec<f64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let b: Vec<f64> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    let beta: f64 = arguments.get("beta")?;
+                    let mut c: Vec<f64> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::dsymm(
+                        side,
+                        uplo,
+                        m,
+                        n,
+                        alpha,
+                        &a,
+                        lda,
+                        &b,
+                        ldb,
+                        beta,
+                        &mut c,
+                        ldc,
+                    );
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "dsyrk" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let alpha: f64 = arguments.get("alpha")?;
+                    let a: Vec<f64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let beta: f64 = arguments.get("beta")?;
+                    let mut c: Vec<f64> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::dsyrk(uplo, trans, n, k, alpha, &a, lda, beta, &mut c, ldc);
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "dsyr2k" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let alpha: f64 = arguments.get("alpha")?;
+                    let a: Vec<f64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let b: Vec<f64> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    let beta: f64 = arguments.get("beta")?;
+                    let mut c: Vec<f64> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::dsyr2k(
+                        uplo, trans, n, k, alpha, &a, lda, &b, ldb, beta, &mut c, ldc,
+                    );
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "dtrmm" => {
+                    let side: u8 = arguments.get("side")?;
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let transa: u8 = arguments.get("transa")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f64 = arguments.get("alpha")?;
+                    let a: Vec<f64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut b: Vec<f64> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    $(::$module)*::dtrmm(
+                        side,
+                        uplo,
+                        transa,
+                        diag,
+                        m,
+                        n,
+                        alpha,
+                        &a,
+                        lda,
+                        &mut b,
+                        ldb,
+                    );
+                    Ok((vec![("b", b.value(true))], Value::None))
+                }
+                "dtrsm" => {
+                    let side: u8 = arguments.get("side")?;
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let transa: u8 = arguments.get("transa")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: f64 = arguments.get("alpha")?;
+                    let a: Vec<f64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut b: Vec<f64> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    $(::$module)*::dtrsm(
+                        side,
+                        uplo,
+                        transa,
+                        diag,
+                        m,
+                        n,
+                        alpha,
+                        &a,
+                        lda,
+                        &mut b,
+                        ldb,
+                    );
+                    Ok((vec![("b", b.value(true))], Value::None))
+                }
+                "cgemm" => {
+                    let transa: u8 = arguments.get("transa")?;
+                    let transb: u8 = arguments.get("transb")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let alpha: c32 = arguments.get("alpha")?;
+                    let a: Vec<c32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let b: Vec<c32> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    let beta: c32 = arguments.get("beta")?;
+                    let mut c: Vec<c32> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::cgemm(
+                        transa, transb, m, n, k, alpha, &a, lda, &b, ldb, beta, &mut c, ldc,
+                    );
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "csymm" => {
+                    let side: u8 = arguments.get("side")?;
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c32 = arguments.get("alpha")?;
+                    let a: Vec<c32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let b: Vec<c32> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    let beta: c32 = arguments.get("beta")?;
+                    let mut c: Vec<c32> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::csymm(
+                        side,
+                        uplo,
+                        m,
+                        n,
+                        alpha,
+                        &a,
+                        lda,
+                        &b,
+                        ldb,
+                        beta,
+                        &mut c,
+                        ldc,
+                    );
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "chemm" => {
+                    let side: u8 = arguments.get("side")?;
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c32 = arguments.get("alpha")?;
+                    let a: Vec<c32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let b: Vec<c32> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    let beta: c32 = arguments.get("beta")?;
+                    let mut c: Vec<c32> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::chemm(
+                        side,
+                        uplo,
+                        m,
+                        n,
+                        alpha,
+                        &a,
+                        lda,
+                        &b,
+                        ldb,
+                        beta,
+                        &mut c,
+                        ldc,
+                    );
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "csyrk" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let alpha: c32 = arguments.get("alpha")?;
+                    let a: Vec<c32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let beta: c32 = arguments.get("beta")?;
+                    let mut c: Vec<c32> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::csyrk(uplo, trans, n, k, alpha, &a, lda, beta, &mut c, ldc);
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "cherk" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let alpha: f32 = arguments.get("alpha")?;
+                    let a: Vec<c32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let beta: f32 = arguments.get("beta")?;
+                    let mut c: Vec<c32> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::cherk(uplo, trans, n, k, alpha, &a, lda, beta, &mut c, ldc);
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "csyr2k" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let alpha: c32 = arguments.get("alpha")?;
+                    let a: Vec<c32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let b: Vec<c32> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    let beta: c32 = arguments.get("beta")?;
+                    let mut c: Vec<c32> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::csyr2k(
+                        uplo, trans, n, k, alpha, &a, lda, &b, ldb, beta, &mut c, ldc,
+                    );
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "cher2k" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let alpha: c32 = arguments.get("alpha")?;
+                    let a: Vec<c32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let b: Vec<c32> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    let beta: f32 = arguments.get("beta")?;
+                    let mut c: Vec<c32> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::cher2k(
+                        uplo, trans, n, k, alpha, &a, lda, &b, ldb, beta, &mut c, ldc,
+                    );
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "ctrmm" => {
+                    let side: u8 = arguments.get("side")?;
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let transa: u8 = arguments.get("transa")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c32 = arguments.get("alpha")?;
+                    let a: Vec<c32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut b: Vec<c32> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    $(::$module)*::ctrmm(
+                        side,
+                        uplo,
+                        transa,
+                        diag,
+                        m,
+                        n,
+                        alpha,
+                        &a,
+                        lda,
+                        &mut b,
+                        ldb,
+                    );
+                    Ok((vec![("b", b.value(true))], Value::None))
+                }
+                "ctrsm" => {
+                    let side: u8 = arguments.get("side")?;
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let transa: u8 = arguments.get("transa")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c32 = arguments.get("alpha")?;
+                    let a: Vec<c32> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut b: Vec<c32> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    $(::$module)*::ctrsm(
+                        side,
+                        uplo,
+                        transa,
+                        diag,
+                        m,
+                        n,
+                        alpha,
+                        &a,
+                        lda,
+                        &mut b,
+                        ldb,
+                    );
+                    Ok((vec![("b", b.value(true))], Value::None))
+                }
+                "zgemm" => {
+                    let transa: u8 = arguments.get("transa")?;
+                    let transb: u8 = arguments.get("transb")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let alpha: c64 = arguments.get("alpha")?;
+                    let a: Vec<c64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let b: Vec<c64> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    let beta: c64 = arguments.get("beta")?;
+                    let mut c: Vec<c64> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::zgemm(
+                        transa, transb, m, n, k, alpha, &a, lda, &b, ldb, beta, &mut c, ldc,
+                    );
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "zsymm" => {
+                    let side: u8 = arguments.get("side")?;
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c64 = arguments.get("alpha")?;
+                    let a: Vec<c64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let b: Vec<c64> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    let beta: c64 = arguments.get("beta")?;
+                    let mut c: Vec<c64> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::zsymm(
+                        side,
+                        uplo,
+                        m,
+                        n,
+                        alpha,
+                        &a,
+                        lda,
+                        &b,
+                        ldb,
+                        beta,
+                        &mut c,
+                        ldc,
+                    );
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "zhemm" => {
+                    let side: u8 = arguments.get("side")?;
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c64 = arguments.get("alpha")?;
+                    let a: Vec<c64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let b: Vec<c64> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    let beta: c64 = arguments.get("beta")?;
+                    let mut c: Vec<c64> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::zhemm(
+                        side,
+                        uplo,
+                        m,
+                        n,
+                        alpha,
+                        &a,
+                        lda,
+                        &b,
+                        ldb,
+                        beta,
+                        &mut c,
+                        ldc,
+                    );
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "zsyrk" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let alpha: c64 = arguments.get("alpha")?;
+                    let a: Vec<c64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let beta: c64 = arguments.get("beta")?;
+                    let mut c: Vec<c64> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::zsyrk(uplo, trans, n, k, alpha, &a, lda, beta, &mut c, ldc);
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "zherk" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let alpha: f64 = arguments.get("alpha")?;
+                    let a: Vec<c64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let beta: f64 = arguments.get("beta")?;
+                    let mut c: Vec<c64> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::zherk(uplo, trans, n, k, alpha, &a, lda, beta, &mut c, ldc);
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "zsyr2k" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let alpha: c64 = arguments.get("alpha")?;
+                    let a: Vec<c64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let b: Vec<c64> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    let beta: c64 = arguments.get("beta")?;
+                    let mut c: Vec<c64> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::zsyr2k(
+                        uplo, trans, n, k, alpha, &a, lda, &b, ldb, beta, &mut c, ldc,
+                    );
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "zher2k" => {
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let trans: u8 = arguments.get("trans")?;
+                    let n: $int = arguments.get("n")?;
+                    let k: $int = arguments.get("k")?;
+                    let alpha: c64 = arguments.get("alpha")?;
+                    let a: Vec<c64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let b: Vec<c64> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    let beta: f64 = arguments.get("beta")?;
+                    let mut c: Vec<c64> = arguments.get("c")?;
+                    let ldc: $int = arguments.get("ldc")?;
+                    $(::$module)*::zher2k(
+                        uplo, trans, n, k, alpha, &a, lda, &b, ldb, beta, &mut c, ldc,
+                    );
+                    Ok((vec![("c", c.value(true))], Value::None))
+                }
+                "ztrmm" => {
+                    let side: u8 = arguments.get("side")?;
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let transa: u8 = arguments.get("transa")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c64 = arguments.get("alpha")?;
+                    let a: Vec<c64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut b: Vec<c64> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    $(::$module)*::ztrmm(
+                        side,
+                        uplo,
+                        transa,
+                        diag,
+                        m,
+                        n,
+                        alpha,
+                        &a,
+                        lda,
+                        &mut b,
+                        ldb,
+                    );
+                    Ok((vec![("b", b.value(true))], Value::None))
+                }
+                "ztrsm" => {
+                    let side: u8 = arguments.get("side")?;
+                    let uplo: u8 = arguments.get("uplo")?;
+                    let transa: u8 = arguments.get("transa")?;
+                    let diag: u8 = arguments.get("diag")?;
+                    let m: $int = arguments.get("m")?;
+                    let n: $int = arguments.get("n")?;
+                    let alpha: c64 = arguments.get("alpha")?;
+                    let a: Vec<c64> = arguments.get("a")?;
+                    let lda: $int = arguments.get("lda")?;
+                    let mut b: Vec<c64> = arguments.get("b")?;
+                    let ldb: $int = arguments.get("ldb")?;
+                    $(::$module)*::ztrsm(
+                        side,
+                        uplo,
+                        transa,
+                        diag,
+                        m,
+                        n,
+                        alpha,
+                        &a,
+                        lda,
+                        &mut b,
+                        ldb,
+                    );
+                    Ok((vec![("b", b.value(true))], Value::None))
+                }
+                _ => Err(invalid(format!("the routine `{}` is unknown", routine))),
+            }
+        }
+    };
+}
+
+execute!(
+    /// Execute a call to a wrapper at the root of the crate.
+    execute,
+    i32
+);
+
+#[cfg(feature = "ilp64")]
+execute!(
+    /// Execute a call to a wrapper in `ilp64`.
+    execute64,
+    i64,
+    ilp64
+);
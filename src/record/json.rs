@@ -0,0 +1,207 @@
+//! Reading and writing of the subset of JSON used by recordings.
+
+use std::fmt::{self, Write};
+
+/// A JSON value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// Parse a value spanning the whole of a string.
+    pub fn parse(text: &str) -> Option<Json> {
+        let mut parser = Parser {
+            text: text.as_bytes(),
+            position: 0,
+        };
+        let value = parser.value()?;
+        parser.space();
+        if parser.position == parser.text.len() {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Return the value of a field if the value is an object.
+    pub fn field(&self, name: &str) -> Option<&Json> {
+        match *self {
+            Json::Object(ref fields) => fields
+                .iter()
+                .find(|(other, _)| other == name)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+}
+
+struct Parser<'a> {
+    text: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn value(&mut self) -> Option<Json> {
+        self.space();
+        match *self.text.get(self.position)? {
+            b'n' => self.keyword("null", Json::Null),
+            b't' => self.keyword("true", Json::Bool(true)),
+            b'f' => self.keyword("false", Json::Bool(false)),
+            b'"' => self.string().map(Json::String),
+            b'[' => {
+                self.position += 1;
+                let mut values = vec![];
+                if !self.close(b']') {
+                    loop {
+                        values.push(self.value()?);
+                        if self.close(b']') {
+                            break;
+                        }
+                        self.expect(b',')?;
+                    }
+                }
+                Some(Json::Array(values))
+            }
+            b'{' => {
+                self.position += 1;
+                let mut fields = vec![];
+                if !self.close(b'}') {
+                    loop {
+                        self.space();
+                        let name = self.string()?;
+                        self.expect(b':')?;
+                        fields.push((name, self.value()?));
+                        if self.close(b'}') {
+                            break;
+                        }
+                        self.expect(b',')?;
+                    }
+                }
+                Some(Json::Object(fields))
+            }
+            _ => self.number(),
+        }
+    }
+
+    fn keyword(&mut self, keyword: &str, value: Json) -> Option<Json> {
+        if self.text[self.position..].starts_with(keyword.as_bytes()) {
+            self.position += keyword.len();
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    fn number(&mut self) -> Option<Json> {
+        let start = self.position;
+        while let Some(&byte) = self.text.get(self.position) {
+            match byte {
+                b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E' => self.position += 1,
+                _ => break,
+            }
+        }
+        let text = ::std::str::from_utf8(&self.text[start..self.position]).ok()?;
+        text.parse().ok().map(Json::Number)
+    }
+
+    fn string(&mut self) -> Option<String> {
+        if self.text.get(self.position) != Some(&b'"') {
+            return None;
+        }
+        self.position += 1;
+        let mut string = vec![];
+        loop {
+            let byte = *self.text.get(self.position)?;
+            self.position += 1;
+            match byte {
+                b'"' => break,
+                b'\\' => {
+                    let escape = *self.text.get(self.position)?;
+                    self.position += 1;
+                    let character = match escape {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => {
+                            let digits = self.text.get(self.position..self.position + 4)?;
+                            self.position += 4;
+                            let digits = ::std::str::from_utf8(digits).ok()?;
+                            ::std::char::from_u32(u32::from_str_radix(digits, 16).ok()?)?
+                        }
+                        _ => return None,
+                    };
+                    let mut buffer = [0; 4];
+                    string.extend_from_slice(character.encode_utf8(&mut buffer).as_bytes());
+                }
+                _ => string.push(byte),
+            }
+        }
+        String::from_utf8(string).ok()
+    }
+
+    fn close(&mut self, byte: u8) -> bool {
+        self.space();
+        if self.text.get(self.position) == Some(&byte) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        if self.close(byte) {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn space(&mut self) {
+        while let Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r') =
+            self.text.get(self.position)
+        {
+            self.position += 1;
+        }
+    }
+}
+
+/// Write a string, escaping it as necessary.
+pub fn string<W: Write>(output: &mut W, value: &str) -> fmt::Result {
+    output.write_char('"')?;
+    for character in value.chars() {
+        match character {
+            '"' => output.write_str("\\\"")?,
+            '\\' => output.write_str("\\\\")?,
+            character if (character as u32) < 0x20 || character == '\u{7f}' => {
+                write!(output, "\\u{:04x}", character as u32)?
+            }
+            character => output.write_char(character)?,
+        }
+    }
+    output.write_char('"')
+}
+
+/// Write a number, writing values that are not finite as strings.
+pub fn number<W: Write>(output: &mut W, value: f64) -> fmt::Result {
+    if value.is_nan() {
+        output.write_str("\"NaN\"")
+    } else if value.is_infinite() && value > 0.0 {
+        output.write_str("\"Infinity\"")
+    } else if value.is_infinite() {
+        output.write_str("\"-Infinity\"")
+    } else {
+        write!(output, "{:?}", value)
+    }
+}
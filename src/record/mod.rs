@@ -0,0 +1,784 @@
+//! Recording and replay of calls.
+//!
+//! With the `record` feature enabled, the calls to the wrappers at the root
+//! of the crate and in [`ilp64`] can be written as lines of JSON, each giving
+//! the name of the routine, the names and values of the arguments, and the
+//! values of the output arguments and of the result after the call. The
+//! contents of vectors and matrices are included optionally. [`replay`]
+//! executes the calls of a recording again using the library in use and
+//! reports the outputs that differ from the recorded ones.
+//!
+//! ## Format
+//!
+//! The first line is a header, and each subsequent line is a call:
+//!
+//! ```text
+//! {"format":"blas","version":2,"buffers":true}
+//! {"routine":"ddot","integer":32,"arguments":{"n":2,"x":[1.0,2.0],"incx":1,"y":[3.0,4.0],"incy":1},"outputs":{},"result":11.0}
+//! ```
+//!
+//! The calls of routines taking integers give their width in bits, which is
+//! 32 for the wrappers at the root of the crate and 64 for those in
+//! [`ilp64`]. The latter are replayed only with the `ilp64` feature enabled.
+//! Calls without a width, as in recordings of the first version, take 32-bit
+//! integers.
+//!
+//! Characters are strings, and integers and real numbers are numbers, with
+//! the real numbers that are not finite written as `"NaN"`, `"Infinity"`,
+//! and `"-Infinity"`. Complex numbers are arrays of the real and imaginary
+//! parts, and vectors and matrices are arrays of their elements or, if their
+//! contents are not included, objects giving their lengths, as in
+//! `{"length":9}`.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use std::fs::File;
+//! use std::io::BufReader;
+//!
+//! use blas::record;
+//!
+//! record::start(File::create("calls.jsonl").unwrap(), true).unwrap();
+//! let x = vec![1.0, 2.0, 3.0];
+//! let norm = unsafe { blas::dnrm2(3, &x, 1) };
+//! record::stop().unwrap();
+//!
+//! let file = BufReader::new(File::open("calls.jsonl").unwrap());
+//! let report = record::replay(file, 1e-12).unwrap();
+//! for difference in &report.differences {
+//!     println!("{}", difference);
+//! }
+//! ```
+//!
+//! [`ilp64`]: ../ilp64/index.html
+//! [`replay`]: fn.replay.html
+
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use {c32, c64};
+
+mod execute;
+mod json;
+mod validate;
+
+use self::json::Json;
+
+/// The name of the format.
+const FORMAT: &str = "blas";
+
+/// The version of the format.
+const VERSION: u32 = 2;
+
+/// The versions of the format that can be replayed.
+const VERSIONS: [u32; 2] = [1, 2];
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static RECORDER: Mutex<Option<Recorder>> = Mutex::new(None);
+
+/// The result of a replay.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Report {
+    /// The number of calls executed.
+    pub calls: usize,
+    /// The outputs that differ from the recorded ones by more than the
+    /// tolerance.
+    pub differences: Vec<Difference>,
+}
+
+/// An output that differs from the recorded one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Difference {
+    /// The position of the call in the recording starting from zero.
+    pub call: usize,
+    /// The name of the routine.
+    pub routine: String,
+    /// The name of the output argument or `result` for the result.
+    pub argument: String,
+    /// The largest absolute difference between the elements, which is
+    /// infinite if the output differs in length, in a character, or in being
+    /// NaN.
+    pub error: f64,
+}
+
+/// A recording in progress.
+struct Recorder {
+    writer: Box<dyn Write + Send>,
+    buffers: bool,
+    error: Option<io::Error>,
+}
+
+/// A call being recorded.
+pub(crate) struct Recording {
+    routine: &'static str,
+    integer: Option<u32>,
+    arguments: Vec<(&'static str, Value)>,
+    buffers: bool,
+}
+
+/// The value of an argument or of a result.
+pub(crate) enum Value {
+    None,
+    Char(u8),
+    Integer(i64),
+    Real(f64),
+    Complex(c64),
+    Reals(Vec<f64>),
+    Complexes(Vec<c64>),
+    Length(usize),
+}
+
+/// An argument or a result that can be recorded.
+pub(crate) trait Argument {
+    /// Return the value, where the contents of a vector or matrix are
+    /// included only if `buffers` is set.
+    fn value(&self, buffers: bool) -> Value;
+
+    /// Return the value if the argument is an output.
+    #[inline]
+    fn output(&self, _: bool) -> Option<Value> {
+        None
+    }
+
+    /// Return the width in bits if the argument is an integer of the
+    /// interface.
+    #[inline]
+    fn width(&self) -> Option<u32> {
+        None
+    }
+}
+
+/// An element of a vector or matrix.
+trait Element: Copy {
+    fn values(data: &[Self]) -> Value;
+}
+
+/// A value that can be read from a recording.
+trait Decode: Sized {
+    fn decode(value: &Json) -> Option<Self>;
+}
+
+/// The arguments of a call being replayed.
+struct Arguments<'a> {
+    call: usize,
+    fields: &'a Json,
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "call {} to {}: `{}` differs by {:e}",
+            self.call, self.routine, self.argument, self.error
+        )
+    }
+}
+
+impl Value {
+    fn write<W: fmt::Write>(&self, output: &mut W) -> fmt::Result {
+        match *self {
+            Value::None => output.write_str("null"),
+            Value::Char(value) => json::string(output, &(value as char).to_string()),
+            Value::Integer(value) => write!(output, "{}", value),
+            Value::Real(value) => json::number(output, value),
+            Value::Complex(value) => complex(output, value),
+            Value::Reals(ref values) => {
+                output.write_char('[')?;
+                for (i, &value) in values.iter().enumerate() {
+                    if i > 0 {
+                        output.write_char(',')?;
+                    }
+                    json::number(output, value)?;
+                }
+                output.write_char(']')
+            }
+            Value::Complexes(ref values) => {
+                output.write_char('[')?;
+                for (i, &value) in values.iter().enumerate() {
+                    if i > 0 {
+                        output.write_char(',')?;
+                    }
+                    complex(output, value)?;
+                }
+                output.write_char(']')
+            }
+            Value::Length(length) => write!(output, "{{\"length\":{}}}", length),
+        }
+    }
+}
+
+impl Argument for () {
+    #[inline]
+    fn value(&self, _: bool) -> Value {
+        Value::None
+    }
+}
+
+impl Argument for u8 {
+    #[inline]
+    fn value(&self, _: bool) -> Value {
+        Value::Char(*self)
+    }
+}
+
+impl Argument for i32 {
+    #[inline]
+    fn value(&self, _: bool) -> Value {
+        Value::Integer(i64::from(*self))
+    }
+
+    #[inline]
+    fn width(&self) -> Option<u32> {
+        Some(32)
+    }
+}
+
+impl Argument for i64 {
+    #[inline]
+    fn value(&self, _: bool) -> Value {
+        Value::Integer(*self)
+    }
+
+    #[inline]
+    fn width(&self) -> Option<u32> {
+        Some(64)
+    }
+}
+
+impl Argument for usize {
+    #[inline]
+    fn value(&self, _: bool) -> Value {
+        Value::Integer(*self as i64)
+    }
+}
+
+impl Argument for f32 {
+    #[inline]
+    fn value(&self, _: bool) -> Value {
+        Value::Real(f64::from(*self))
+    }
+}
+
+impl Argument for f64 {
+    #[inline]
+    fn value(&self, _: bool) -> Value {
+        Value::Real(*self)
+    }
+}
+
+impl Argument for c32 {
+    #[inline]
+    fn value(&self, _: bool) -> Value {
+        Value::Complex(c64::new(f64::from(self.re), f64::from(self.im)))
+    }
+}
+
+impl Argument for c64 {
+    #[inline]
+    fn value(&self, _: bool) -> Value {
+        Value::Complex(*self)
+    }
+}
+
+impl<T: Argument> Argument for &mut T {
+    #[inline]
+    fn value(&self, buffers: bool) -> Value {
+        (**self).value(buffers)
+    }
+
+    #[inline]
+    fn output(&self, buffers: bool) -> Option<Value> {
+        Some(self.value(buffers))
+    }
+
+    #[inline]
+    fn width(&self) -> Option<u32> {
+        (**self).width()
+    }
+}
+
+impl<T: Element> Argument for &[T] {
+    #[inline]
+    fn value(&self, buffers: bool) -> Value {
+        buffer(self, buffers)
+    }
+}
+
+impl<T: Element> Argument for &mut [T] {
+    #[inline]
+    fn value(&self, buffers: bool) -> Value {
+        buffer(self, buffers)
+    }
+
+    #[inline]
+    fn output(&self, buffers: bool) -> Option<Value> {
+        Some(self.value(buffers))
+    }
+}
+
+impl<T: Element> Argument for Vec<T> {
+    #[inline]
+    fn value(&self, buffers: bool) -> Value {
+        buffer(self, buffers)
+    }
+}
+
+impl Element for f32 {
+    fn values(data: &[Self]) -> Value {
+        Value::Reals(data.iter().map(|&value| f64::from(value)).collect())
+    }
+}
+
+impl Element for f64 {
+    fn values(data: &[Self]) -> Value {
+        Value::Reals(data.to_vec())
+    }
+}
+
+impl Element for c32 {
+    fn values(data: &[Self]) -> Value {
+        Value::Complexes(
+            data.iter()
+                .map(|value| c64::new(f64::from(value.re), f64::from(value.im)))
+                .collect(),
+        )
+    }
+}
+
+impl Element for c64 {
+    fn values(data: &[Self]) -> Value {
+        Value::Complexes(data.to_vec())
+    }
+}
+
+impl Decode for u8 {
+    fn decode(value: &Json) -> Option<Self> {
+        match *value {
+            Json::String(ref value) => {
+                let mut characters = value.chars();
+                match (characters.next(), characters.next()) {
+                    (Some(character), None) if (character as u32) < 256 => Some(character as u8),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Decode for i32 {
+    fn decode(value: &Json) -> Option<Self> {
+        match *value {
+            Json::Number(value)
+                if value.fract() == 0.0
+                    && value >= f64::from(i32::MIN)
+                    && value <= f64::from(i32::MAX) =>
+            {
+                Some(value as i32)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Decode for i64 {
+    fn decode(value: &Json) -> Option<Self> {
+        match *value {
+            Json::Number(value)
+                if value.fract() == 0.0 && value >= -(2f64.powi(63)) && value < 2f64.powi(63) =>
+            {
+                Some(value as i64)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Decode for f32 {
+    fn decode(value: &Json) -> Option<Self> {
+        f64::decode(value).map(|value| value as f32)
+    }
+}
+
+impl Decode for f64 {
+    fn decode(value: &Json) -> Option<Self> {
+        match *value {
+            Json::Number(value) => Some(value),
+            Json::String(ref value) => match &value[..] {
+                "NaN" => Some(f64::NAN),
+                "Infinity" => Some(f64::INFINITY),
+                "-Infinity" => Some(-f64::INFINITY),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl Decode for c32 {
+    fn decode(value: &Json) -> Option<Self> {
+        c64::decode(value).map(|value| c32::new(value.re as f32, value.im as f32))
+    }
+}
+
+impl Decode for c64 {
+    fn decode(value: &Json) -> Option<Self> {
+        match *value {
+            Json::Array(ref parts) if parts.len() == 2 => {
+                Some(c64::new(f64::decode(&parts[0])?, f64::decode(&parts[1])?))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<T: Decode + Clone + Default> Decode for Vec<T> {
+    fn decode(value: &Json) -> Option<Self> {
+        match *value {
+            Json::Array(ref values) => values.iter().map(T::decode).collect(),
+            _ => length(value).map(|length| vec![T::default(); length]),
+        }
+    }
+}
+
+impl<'a> Arguments<'a> {
+    /// Check if the contents of all vectors and matrices are included.
+    fn complete(&self) -> bool {
+        match *self.fields {
+            Json::Object(ref fields) => fields
+                .iter()
+                .all(|(_, value)| !matches!(*value, Json::Object(_))),
+            _ => true,
+        }
+    }
+
+    /// Return the length of a vector or matrix given its name.
+    fn length(&self, name: &str) -> io::Result<usize> {
+        self.fields.field(name).and_then(length).ok_or_else(|| {
+            invalid(format!(
+                "call {} lacks a valid argument `{}`",
+                self.call, name
+            ))
+        })
+    }
+
+    /// Return the value of an argument given its name.
+    fn get<T: Decode>(&self, name: &str) -> io::Result<T> {
+        self.fields.field(name).and_then(T::decode).ok_or_else(|| {
+            invalid(format!(
+                "call {} lacks a valid argument `{}`",
+                self.call, name
+            ))
+        })
+    }
+}
+
+/// Start recording calls, writing them to `writer`, where the contents of
+/// vectors and matrices are included only if `buffers` is set.
+///
+/// The recording in progress, if any, is stopped without reporting errors.
+pub fn start<W: Write + Send + 'static>(mut writer: W, buffers: bool) -> io::Result<()> {
+    writeln!(
+        writer,
+        "{{\"format\":\"{}\",\"version\":{},\"buffers\":{}}}",
+        FORMAT, VERSION, buffers
+    )?;
+    *RECORDER.lock().unwrap() = Some(Recorder {
+        writer: Box::new(writer),
+        buffers,
+        error: None,
+    });
+    ENABLED.store(true, Ordering::Release);
+    Ok(())
+}
+
+/// Stop recording calls and flush the writer.
+///
+/// The function returns the first error encountered while writing, if any.
+pub fn stop() -> io::Result<()> {
+    ENABLED.store(false, Ordering::Release);
+    match RECORDER.lock().unwrap().take() {
+        Some(Recorder {
+            error: Some(error), ..
+        }) => Err(error),
+        Some(mut recorder) => recorder.writer.flush(),
+        _ => Ok(()),
+    }
+}
+
+/// Execute the calls of a recording using the library in use and compare
+/// the outputs with the recorded ones.
+///
+/// The outputs differing by more than `tolerance` are reported. Vectors and
+/// matrices whose contents are not included in the recording are filled with
+/// zeros, and the outputs of the calls taking them are not compared.
+///
+/// Each call is validated before it is executed as by the wrappers in
+/// [`checked`], and a call with an invalid flag, dimension, or increment or
+/// with a vector or matrix shorter than its dimensions require is reported
+/// as an error of kind `InvalidData`.
+///
+/// [`checked`]: ../checked/index.html
+pub fn replay<R: BufRead>(reader: R, tolerance: f64) -> io::Result<Report> {
+    let mut lines = reader.lines().enumerate();
+    let header = match lines.next() {
+        Some((number, line)) => parse(&line?, number)?,
+        _ => return Err(invalid("the recording is empty".into())),
+    };
+    if header.field("format") != Some(&Json::String(FORMAT.into()))
+        || !VERSIONS
+            .iter()
+            .any(|&version| header.field("version") == Some(&Json::Number(f64::from(version))))
+    {
+        return Err(invalid("the recording has an unknown format".into()));
+    }
+    let mut report = Report::default();
+    for (number, line) in lines {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let call = parse(&line, number)?;
+        let index = report.calls;
+        let routine = match call.field("routine") {
+            Some(Json::String(routine)) => routine,
+            _ => return Err(invalid(format!("call {} lacks a routine", index))),
+        };
+        let arguments = Arguments {
+            call: index,
+            fields: call.field("arguments").unwrap_or(&Json::Null),
+        };
+        validate::validate(routine, &arguments)?;
+        // The call has been validated, so the wrappers are given buffers as
+        // long as the dimensions require.
+        let (outputs, result) = match call.field("integer") {
+            None => unsafe { execute::execute(routine, &arguments)? },
+            Some(&Json::Number(width)) if width == 32.0 => unsafe {
+                execute::execute(routine, &arguments)?
+            },
+            #[cfg(feature = "ilp64")]
+            Some(&Json::Number(width)) if width == 64.0 => unsafe {
+                execute::execute64(routine, &arguments)?
+            },
+            #[cfg(not(feature = "ilp64"))]
+            Some(&Json::Number(width)) if width == 64.0 => {
+                return Err(invalid(format!(
+                    "call {} takes 64-bit integers, which requires the `ilp64` feature",
+                    index
+                )))
+            }
+            _ => {
+                return Err(invalid(format!(
+                    "call {} has an unknown integer width",
+                    index
+                )))
+            }
+        };
+        report.calls += 1;
+        if !arguments.complete() {
+            continue;
+        }
+        let recorded = call.field("outputs");
+        let outputs = outputs
+            .iter()
+            .filter_map(|&(name, ref value)| {
+                recorded
+                    .and_then(|recorded| recorded.field(name))
+                    .map(|expected| (name, expected, value))
+            })
+            .chain(
+                call.field("result")
+                    .map(|expected| ("result", expected, &result)),
+            );
+        for (name, expected, value) in outputs {
+            match difference(expected, value) {
+                Some(error) if error > tolerance => report.differences.push(Difference {
+                    call: index,
+                    routine: routine.clone(),
+                    argument: name.into(),
+                    error,
+                }),
+                _ => {}
+            }
+        }
+    }
+    Ok(report)
+}
+
+/// Capture the arguments of a call if calls are being recorded.
+///
+/// The width of the integers is that of the first integer argument, if any.
+#[inline]
+pub(crate) fn before<F>(routine: &'static str, arguments: F) -> Option<Recording>
+where
+    F: FnOnce(bool) -> (Vec<Option<u32>>, Vec<(&'static str, Value)>),
+{
+    if !ENABLED.load(Ordering::Acquire) {
+        return None;
+    }
+    let buffers = RECORDER.lock().unwrap().as_ref()?.buffers;
+    let (widths, arguments) = arguments(buffers);
+    Some(Recording {
+        routine,
+        integer: widths.into_iter().flatten().next(),
+        arguments,
+        buffers,
+    })
+}
+
+/// Capture the outputs and the result of a call and write the call.
+pub(crate) fn after<F>(recording: Recording, outputs: F, result: Value)
+where
+    F: FnOnce(bool) -> Vec<(&'static str, Option<Value>)>,
+{
+    let outputs = outputs(recording.buffers)
+        .into_iter()
+        .filter_map(|(name, value)| value.map(|value| (name, value)))
+        .collect::<Vec<_>>();
+    let mut line = String::new();
+    write(&mut line, &recording, &outputs, &result).unwrap();
+    let mut recorder = RECORDER.lock().unwrap();
+    if let Some(ref mut recorder) = *recorder {
+        if recorder.error.is_none() {
+            if let Err(error) = recorder.writer.write_all(line.as_bytes()) {
+                recorder.error = Some(error);
+            }
+        }
+    }
+}
+
+/// Write a call as a line.
+fn write<W: fmt::Write>(
+    output: &mut W,
+    recording: &Recording,
+    outputs: &[(&'static str, Value)],
+    result: &Value,
+) -> fmt::Result {
+    output.write_str("{\"routine\":")?;
+    json::string(output, recording.routine)?;
+    if let Some(integer) = recording.integer {
+        write!(output, ",\"integer\":{}", integer)?;
+    }
+    output.write_str(",\"arguments\":")?;
+    object(output, &recording.arguments)?;
+    output.write_str(",\"outputs\":")?;
+    object(output, outputs)?;
+    output.write_str(",\"result\":")?;
+    result.write(output)?;
+    output.write_str("}\n")
+}
+
+fn object<W: fmt::Write>(output: &mut W, fields: &[(&'static str, Value)]) -> fmt::Result {
+    output.write_char('{')?;
+    for (i, &(name, ref value)) in fields.iter().enumerate() {
+        if i > 0 {
+            output.write_char(',')?;
+        }
+        json::string(output, name)?;
+        output.write_char(':')?;
+        value.write(output)?;
+    }
+    output.write_char('}')
+}
+
+fn complex<W: fmt::Write>(output: &mut W, value: c64) -> fmt::Result {
+    output.write_char('[')?;
+    json::number(output, value.re)?;
+    output.write_char(',')?;
+    json::number(output, value.im)?;
+    output.write_char(']')
+}
+
+#[inline]
+fn buffer<T: Element>(data: &[T], buffers: bool) -> Value {
+    if buffers {
+        T::values(data)
+    } else {
+        Value::Length(data.len())
+    }
+}
+
+/// Return the largest absolute difference between a recorded output and a
+/// computed one if they are comparable.
+fn difference(expected: &Json, actual: &Value) -> Option<f64> {
+    let complex = |actual: c64, expected: c64| {
+        distance(actual.re, expected.re).max(distance(actual.im, expected.im))
+    };
+    let error = match *actual {
+        Value::None => return None,
+        Value::Char(actual) => match u8::decode(expected) {
+            Some(expected) if expected == actual => 0.0,
+            _ => f64::INFINITY,
+        },
+        Value::Integer(actual) => distance(actual as f64, f64::decode(expected)?),
+        Value::Real(actual) => distance(actual, f64::decode(expected)?),
+        Value::Complex(actual) => complex(actual, c64::decode(expected)?),
+        Value::Reals(ref actual) => {
+            let expected = Vec::<f64>::decode(expected)?;
+            if expected.len() != actual.len() {
+                f64::INFINITY
+            } else {
+                actual
+                    .iter()
+                    .zip(&expected)
+                    .fold(0.0, |error: f64, (&actual, &expected)| {
+                        error.max(distance(actual, expected))
+                    })
+            }
+        }
+        Value::Complexes(ref actual) => {
+            let expected = Vec::<c64>::decode(expected)?;
+            if expected.len() != actual.len() {
+                f64::INFINITY
+            } else {
+                actual
+                    .iter()
+                    .zip(&expected)
+                    .fold(0.0, |error: f64, (&actual, &expected)| {
+                        error.max(complex(actual, expected))
+                    })
+            }
+        }
+        Value::Length(_) => return None,
+    };
+    Some(error)
+}
+
+#[inline]
+fn distance(actual: f64, expected: f64) -> f64 {
+    if actual == expected || actual.is_nan() && expected.is_nan() {
+        0.0
+    } else {
+        let distance = (actual - expected).abs();
+        if distance.is_nan() {
+            f64::INFINITY
+        } else {
+            distance
+        }
+    }
+}
+
+/// Return the length of a vector or matrix given as an array or as an object
+/// giving its length.
+fn length(value: &Json) -> Option<usize> {
+    match *value {
+        Json::Array(ref values) => Some(values.len()),
+        Json::Object(_) => match value.field("length") {
+            Some(&Json::Number(length)) if length >= 0.0 && length.fract() == 0.0 => {
+                Some(length as usize)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn parse(line: &str, number: usize) -> io::Result<Json> {
+    Json::parse(line).ok_or_else(|| invalid(format!("line {} is not valid JSON", number + 1)))
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
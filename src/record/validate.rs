@@ -0,0 +1,458 @@
+//! Validation of recorded calls.
+//!
+//! The rules are those of the wrappers in [`checked`], applied to the
+//! recorded dimensions and increments and to the lengths of the recorded
+//! vectors and matrices, which are given either by their contents or by
+//! objects giving their lengths.
+//!
+//! [`checked`]: ../../checked/index.html
+
+use std::convert::TryFrom;
+use std::io;
+
+use super::{invalid, Arguments};
+use checked::{matrix_len, packed_len, Check};
+use {Diag, Error, Side, Transpose, Uplo};
+
+/// The reason a call is rejected.
+enum Rejection {
+    Decode(io::Error),
+    Check(Error),
+}
+
+type Result = ::std::result::Result<(), Rejection>;
+
+impl From<io::Error> for Rejection {
+    #[inline]
+    fn from(error: io::Error) -> Self {
+        Rejection::Decode(error)
+    }
+}
+
+impl From<Error> for Rejection {
+    #[inline]
+    fn from(error: Error) -> Self {
+        Rejection::Check(error)
+    }
+}
+
+const TRANSPOSES: &[Transpose] = &[Transpose::No, Transpose::Trans, Transpose::ConjTrans];
+const SYMMETRIC: &[Transpose] = &[Transpose::No, Transpose::Trans];
+const HERMITIAN: &[Transpose] = &[Transpose::No, Transpose::ConjTrans];
+
+const MAX_M: &str = "must be at least max(1, m)";
+const MAX_N: &str = "must be at least max(1, n)";
+const ROWS_A: &str = "must be at least max(1, rows of a)";
+const ROWS_B: &str = "must be at least max(1, rows of b)";
+
+/// Define a function validating a call given the name of the routine and the
+/// arguments, where each family of routines is validated by the function of
+/// the same name given the extra arguments, if any.
+macro_rules! validate {
+    ($($family:ident $extra:tt: $($routine:ident)*;)*) => {
+        /// Validate a call, where the calls of unknown routines are left to
+        /// be reported when executed.
+        pub fn validate(routine: &str, arguments: &Arguments) -> io::Result<()> {
+            let result = match routine {
+                $($(
+                    stringify!($routine) => {
+                        call!($family $extra, &Check::new(stringify!($routine)), arguments)
+                    }
+                )*)*
+                _ => Ok(()),
+            };
+            result.map_err(|rejection| match rejection {
+                Rejection::Decode(error) => error,
+                Rejection::Check(error) => {
+                    invalid(format!("call {} is invalid: {}", arguments.call, error))
+                }
+            })
+        }
+    };
+}
+
+/// Call the function validating a family of routines.
+macro_rules! call {
+    ($family:ident($($extra:expr),*), $check:expr, $arguments:expr) => {
+        $family($check, $arguments $(, $extra)*)
+    };
+}
+
+validate! {
+    rotmg(): srotmg drotmg;
+    pair(2): srot drot csrot zdrot sswap dswap cswap zswap scopy dcopy ccopy zcopy;
+    pair(2): sdot ddot dsdot cdotu cdotc zdotu zdotc;
+    pair(3): saxpy daxpy caxpy zaxpy;
+    rotm(): srotm drotm;
+    sdsdot(): sdsdot;
+    single(2): snrm2 dnrm2 scnrm2 dznrm2 sasum dasum scasum dzasum;
+    single(2): isamax idamax icamax izamax;
+    single(3): sscal dscal cscal zscal csscal zdscal;
+    gemv(): sgemv dgemv cgemv zgemv;
+    gbmv(): sgbmv dgbmv cgbmv zgbmv;
+    symv(): ssymv dsymv chemv zhemv;
+    sbmv(): ssbmv dsbmv chbmv zhbmv;
+    spmv(): sspmv dspmv chpmv zhpmv;
+    trmv("transa", "b"): strmv dtrmv ctrmv ztrmv;
+    trmv("trans", "x"): strsv dtrsv ctrsv ztrsv;
+    tbmv(): stbmv dtbmv ctbmv ztbmv stbsv dtbsv ctbsv ztbsv;
+    tpmv(): stpmv dtpmv ctpmv ztpmv stpsv dtpsv ctpsv ztpsv;
+    ger(): sger dger cgeru zgeru cgerc zgerc;
+    syr(): ssyr dsyr cher zher;
+    spr(): sspr dspr chpr zhpr;
+    syr2(): ssyr2 dsyr2 cher2 zher2;
+    spr2(): sspr2 dspr2 chpr2 zhpr2;
+    gemm(): sgemm dgemm cgemm zgemm;
+    symm(): ssymm dsymm csymm zsymm chemm zhemm;
+    syrk(TRANSPOSES, ""): ssyrk dsyrk;
+    syrk(SYMMETRIC, "must not be ConjTrans"): csyrk zsyrk;
+    syrk(HERMITIAN, "must not be Trans"): cherk zherk;
+    syr2k(TRANSPOSES, ""): ssyr2k dsyr2k;
+    syr2k(SYMMETRIC, "must not be ConjTrans"): csyr2k zsyr2k;
+    syr2k(HERMITIAN, "must not be Trans"): cher2k zher2k;
+    trmm(): strmm dtrmm ctrmm ztrmm strsm dtrsm ctrsm ztrsm;
+}
+
+/// Return a flag given its name.
+fn flag<T: TryFrom<u8>>(
+    check: &Check,
+    arguments: &Arguments,
+    position: usize,
+    name: &'static str,
+) -> ::std::result::Result<T, Rejection> {
+    let byte: u8 = arguments.get(name)?;
+    T::try_from(byte).map_err(|_| {
+        check
+            .argument(position, name, "must be a valid flag")
+            .into()
+    })
+}
+
+/// Validate a vector given the positions and the names of the vector and of
+/// its increment.
+fn vector(
+    check: &Check,
+    arguments: &Arguments,
+    position: usize,
+    name: &'static str,
+    increment: &'static str,
+    n: i64,
+) -> Result {
+    let inc: i64 = arguments.get(increment)?;
+    check.increment(position + 1, increment, inc)?;
+    check.vector(position, name, arguments.length(name)?, n, inc)?;
+    Ok(())
+}
+
+/// Validate a matrix given the positions and the names of the matrix and of
+/// its leading dimension and the numbers of its rows and columns.
+fn matrix(
+    check: &Check,
+    arguments: &Arguments,
+    position: usize,
+    name: &'static str,
+    leading: &'static str,
+    (rows, cols): (i64, i64),
+    reason: &'static str,
+) -> Result {
+    let ld: i64 = arguments.get(leading)?;
+    check.leading(position + 1, leading, ld, rows.max(1), reason)?;
+    check.length(
+        position,
+        name,
+        arguments.length(name)?,
+        matrix_len(rows, cols, ld),
+    )?;
+    Ok(())
+}
+
+/// Validate a dimension given its position and its name.
+fn dimension(
+    check: &Check,
+    arguments: &Arguments,
+    position: usize,
+    name: &'static str,
+) -> ::std::result::Result<i64, Rejection> {
+    let value: i64 = arguments.get(name)?;
+    check.dimension(position, name, value)?;
+    Ok(value)
+}
+
+fn rotmg(check: &Check, arguments: &Arguments) -> Result {
+    check.length(5, "param", arguments.length("param")?, 5)?;
+    Ok(())
+}
+
+/// Validate a Level 1 call taking two vectors, where `x` is at `position`.
+fn pair(check: &Check, arguments: &Arguments, position: usize) -> Result {
+    let n: i64 = arguments.get("n")?;
+    let (incx, incy): (i64, i64) = (arguments.get("incx")?, arguments.get("incy")?);
+    check.vector(position, "x", arguments.length("x")?, n, incx)?;
+    check.vector(position + 2, "y", arguments.length("y")?, n, incy)?;
+    Ok(())
+}
+
+fn rotm(check: &Check, arguments: &Arguments) -> Result {
+    pair(check, arguments, 2)?;
+    check.length(6, "param", arguments.length("param")?, 5)?;
+    Ok(())
+}
+
+fn sdsdot(check: &Check, arguments: &Arguments) -> Result {
+    check.length(2, "sb", arguments.length("sb")?, 1)?;
+    pair(check, arguments, 3)
+}
+
+/// Validate a Level 1 call taking one vector, where `x` is at `position`.
+fn single(check: &Check, arguments: &Arguments, position: usize) -> Result {
+    let (n, incx): (i64, i64) = (arguments.get("n")?, arguments.get("incx")?);
+    check.vector(position, "x", arguments.length("x")?, n, incx)?;
+    Ok(())
+}
+
+fn gemv(check: &Check, arguments: &Arguments) -> Result {
+    let trans: Transpose = flag(check, arguments, 1, "trans")?;
+    let m = dimension(check, arguments, 2, "m")?;
+    let n = dimension(check, arguments, 3, "n")?;
+    let (lenx, leny) = if trans == Transpose::No {
+        (n, m)
+    } else {
+        (m, n)
+    };
+    matrix(check, arguments, 5, "a", "lda", (m, n), MAX_M)?;
+    vector(check, arguments, 7, "x", "incx", lenx)?;
+    vector(check, arguments, 10, "y", "incy", leny)
+}
+
+fn gbmv(check: &Check, arguments: &Arguments) -> Result {
+    let trans: Transpose = flag(check, arguments, 1, "trans")?;
+    let m = dimension(check, arguments, 2, "m")?;
+    let n = dimension(check, arguments, 3, "n")?;
+    let kl = dimension(check, arguments, 4, "kl")?;
+    let ku = dimension(check, arguments, 5, "ku")?;
+    let (lenx, leny) = if trans == Transpose::No {
+        (n, m)
+    } else {
+        (m, n)
+    };
+    let band = kl.saturating_add(ku).saturating_add(1);
+    matrix(
+        check,
+        arguments,
+        7,
+        "a",
+        "lda",
+        (band, n),
+        "must be at least kl + ku + 1",
+    )?;
+    vector(check, arguments, 9, "x", "incx", lenx)?;
+    vector(check, arguments, 12, "y", "incy", leny)
+}
+
+fn symv(check: &Check, arguments: &Arguments) -> Result {
+    flag::<Uplo>(check, arguments, 1, "uplo")?;
+    let n = dimension(check, arguments, 2, "n")?;
+    matrix(check, arguments, 4, "a", "lda", (n, n), MAX_N)?;
+    vector(check, arguments, 6, "x", "incx", n)?;
+    vector(check, arguments, 9, "y", "incy", n)
+}
+
+fn sbmv(check: &Check, arguments: &Arguments) -> Result {
+    flag::<Uplo>(check, arguments, 1, "uplo")?;
+    let n = dimension(check, arguments, 2, "n")?;
+    let k = dimension(check, arguments, 3, "k")?;
+    let band = k.saturating_add(1);
+    matrix(
+        check,
+        arguments,
+        5,
+        "a",
+        "lda",
+        (band, n),
+        "must be at least k + 1",
+    )?;
+    vector(check, arguments, 7, "x", "incx", n)?;
+    vector(check, arguments, 10, "y", "incy", n)
+}
+
+fn spmv(check: &Check, arguments: &Arguments) -> Result {
+    flag::<Uplo>(check, arguments, 1, "uplo")?;
+    let n = dimension(check, arguments, 2, "n")?;
+    check.length(4, "ap", arguments.length("ap")?, packed_len(n))?;
+    vector(check, arguments, 5, "x", "incx", n)?;
+    vector(check, arguments, 8, "y", "incy", n)
+}
+
+/// Validate a call to `?trmv` or `?trsv`, whose operation and vector are
+/// recorded under the names `trans` and `x`.
+fn trmv(check: &Check, arguments: &Arguments, trans: &'static str, x: &'static str) -> Result {
+    flag::<Uplo>(check, arguments, 1, "uplo")?;
+    flag::<Transpose>(check, arguments, 2, trans)?;
+    flag::<Diag>(check, arguments, 3, "diag")?;
+    let n = dimension(check, arguments, 4, "n")?;
+    matrix(check, arguments, 5, "a", "lda", (n, n), MAX_N)?;
+    vector(check, arguments, 7, x, "incx", n)
+}
+
+fn tbmv(check: &Check, arguments: &Arguments) -> Result {
+    flag::<Uplo>(check, arguments, 1, "uplo")?;
+    flag::<Transpose>(check, arguments, 2, "trans")?;
+    flag::<Diag>(check, arguments, 3, "diag")?;
+    let n = dimension(check, arguments, 4, "n")?;
+    let k = dimension(check, arguments, 5, "k")?;
+    let band = k.saturating_add(1);
+    matrix(
+        check,
+        arguments,
+        6,
+        "a",
+        "lda",
+        (band, n),
+        "must be at least k + 1",
+    )?;
+    vector(check, arguments, 8, "x", "incx", n)
+}
+
+fn tpmv(check: &Check, arguments: &Arguments) -> Result {
+    flag::<Uplo>(check, arguments, 1, "uplo")?;
+    flag::<Transpose>(check, arguments, 2, "trans")?;
+    flag::<Diag>(check, arguments, 3, "diag")?;
+    let n = dimension(check, arguments, 4, "n")?;
+    check.length(5, "ap", arguments.length("ap")?, packed_len(n))?;
+    vector(check, arguments, 6, "x", "incx", n)
+}
+
+fn ger(check: &Check, arguments: &Arguments) -> Result {
+    let m = dimension(check, arguments, 1, "m")?;
+    let n = dimension(check, arguments, 2, "n")?;
+    vector(check, arguments, 4, "x", "incx", m)?;
+    vector(check, arguments, 6, "y", "incy", n)?;
+    matrix(check, arguments, 8, "a", "lda", (m, n), MAX_M)
+}
+
+fn syr(check: &Check, arguments: &Arguments) -> Result {
+    flag::<Uplo>(check, arguments, 1, "uplo")?;
+    let n = dimension(check, arguments, 2, "n")?;
+    vector(check, arguments, 4, "x", "incx", n)?;
+    matrix(check, arguments, 6, "a", "lda", (n, n), MAX_N)
+}
+
+fn spr(check: &Check, arguments: &Arguments) -> Result {
+    flag::<Uplo>(check, arguments, 1, "uplo")?;
+    let n = dimension(check, arguments, 2, "n")?;
+    vector(check, arguments, 4, "x", "incx", n)?;
+    check.length(6, "ap", arguments.length("ap")?, packed_len(n))?;
+    Ok(())
+}
+
+fn syr2(check: &Check, arguments: &Arguments) -> Result {
+    flag::<Uplo>(check, arguments, 1, "uplo")?;
+    let n = dimension(check, arguments, 2, "n")?;
+    vector(check, arguments, 4, "x", "incx", n)?;
+    vector(check, arguments, 6, "y", "incy", n)?;
+    matrix(check, arguments, 8, "a", "lda", (n, n), MAX_N)
+}
+
+fn spr2(check: &Check, arguments: &Arguments) -> Result {
+    flag::<Uplo>(check, arguments, 1, "uplo")?;
+    let n = dimension(check, arguments, 2, "n")?;
+    vector(check, arguments, 4, "x", "incx", n)?;
+    vector(check, arguments, 6, "y", "incy", n)?;
+    check.length(8, "ap", arguments.length("ap")?, packed_len(n))?;
+    Ok(())
+}
+
+fn gemm(check: &Check, arguments: &Arguments) -> Result {
+    let transa: Transpose = flag(check, arguments, 1, "transa")?;
+    let transb: Transpose = flag(check, arguments, 2, "transb")?;
+    let m = dimension(check, arguments, 3, "m")?;
+    let n = dimension(check, arguments, 4, "n")?;
+    let k = dimension(check, arguments, 5, "k")?;
+    let (rowsa, colsa) = if transa == Transpose::No {
+        (m, k)
+    } else {
+        (k, m)
+    };
+    let (rowsb, colsb) = if transb == Transpose::No {
+        (k, n)
+    } else {
+        (n, k)
+    };
+    matrix(check, arguments, 7, "a", "lda", (rowsa, colsa), ROWS_A)?;
+    matrix(check, arguments, 9, "b", "ldb", (rowsb, colsb), ROWS_B)?;
+    matrix(check, arguments, 12, "c", "ldc", (m, n), MAX_M)
+}
+
+fn symm(check: &Check, arguments: &Arguments) -> Result {
+    let side: Side = flag(check, arguments, 1, "side")?;
+    flag::<Uplo>(check, arguments, 2, "uplo")?;
+    let m = dimension(check, arguments, 3, "m")?;
+    let n = dimension(check, arguments, 4, "n")?;
+    let order = if side == Side::Left { m } else { n };
+    matrix(check, arguments, 6, "a", "lda", (order, order), ROWS_A)?;
+    matrix(check, arguments, 8, "b", "ldb", (m, n), MAX_M)?;
+    matrix(check, arguments, 11, "c", "ldc", (m, n), MAX_M)
+}
+
+/// Validate the operation of a rank-k update, which is one of `allowed`.
+fn operation(
+    check: &Check,
+    arguments: &Arguments,
+    allowed: &[Transpose],
+    reason: &'static str,
+) -> ::std::result::Result<Transpose, Rejection> {
+    let trans = flag(check, arguments, 2, "trans")?;
+    check.transpose(2, "trans", trans, allowed, reason)?;
+    Ok(trans)
+}
+
+fn syrk(
+    check: &Check,
+    arguments: &Arguments,
+    allowed: &[Transpose],
+    reason: &'static str,
+) -> Result {
+    flag::<Uplo>(check, arguments, 1, "uplo")?;
+    let trans = operation(check, arguments, allowed, reason)?;
+    let n = dimension(check, arguments, 3, "n")?;
+    let k = dimension(check, arguments, 4, "k")?;
+    let (rows, cols) = if trans == Transpose::No {
+        (n, k)
+    } else {
+        (k, n)
+    };
+    matrix(check, arguments, 6, "a", "lda", (rows, cols), ROWS_A)?;
+    matrix(check, arguments, 9, "c", "ldc", (n, n), MAX_N)
+}
+
+fn syr2k(
+    check: &Check,
+    arguments: &Arguments,
+    allowed: &[Transpose],
+    reason: &'static str,
+) -> Result {
+    flag::<Uplo>(check, arguments, 1, "uplo")?;
+    let trans = operation(check, arguments, allowed, reason)?;
+    let n = dimension(check, arguments, 3, "n")?;
+    let k = dimension(check, arguments, 4, "k")?;
+    let (rows, cols) = if trans == Transpose::No {
+        (n, k)
+    } else {
+        (k, n)
+    };
+    matrix(check, arguments, 6, "a", "lda", (rows, cols), ROWS_A)?;
+    matrix(check, arguments, 8, "b", "ldb", (rows, cols), ROWS_B)?;
+    matrix(check, arguments, 11, "c", "ldc", (n, n), MAX_N)
+}
+
+fn trmm(check: &Check, arguments: &Arguments) -> Result {
+    let side: Side = flag(check, arguments, 1, "side")?;
+    flag::<Uplo>(check, arguments, 2, "uplo")?;
+    flag::<Transpose>(check, arguments, 3, "transa")?;
+    flag::<Diag>(check, arguments, 4, "diag")?;
+    let m = dimension(check, arguments, 5, "m")?;
+    let n = dimension(check, arguments, 6, "n")?;
+    let order = if side == Side::Left { m } else { n };
+    matrix(check, arguments, 8, "a", "lda", (order, order), ROWS_A)?;
+    matrix(check, arguments, 10, "b", "ldb", (m, n), MAX_M)
+}
@@ -0,0 +1,258 @@
+//! Naive computations on dense matrices, against which the routines are
+//! tested.
+
+use {c64, Diag, Side, Transpose, Uplo};
+
+pub const UPLOS: [Uplo; 2] = [Uplo::Upper, Uplo::Lower];
+pub const TRANSPOSES: [Transpose; 3] = [Transpose::No, Transpose::Trans, Transpose::ConjTrans];
+pub const DIAGS: [Diag; 2] = [Diag::NonUnit, Diag::Unit];
+pub const SIDES: [Side; 2] = [Side::Left, Side::Right];
+
+/// The value of the elements that the routines must not touch.
+pub const GARBAGE: c64 = c64 {
+    re: 99.0,
+    im: -99.0,
+};
+
+/// A matrix stored in column-major order without padding.
+#[derive(Clone, Debug)]
+pub struct Dense {
+    pub rows: usize,
+    pub cols: usize,
+    pub data: Vec<c64>,
+}
+
+impl Dense {
+    /// Create a matrix with arbitrary but reproducible elements.
+    pub fn new(rows: usize, cols: usize, seed: f64) -> Self {
+        let data = (0..rows * cols)
+            .map(|index| {
+                let (i, j) = ((index % rows) as f64, (index / rows) as f64);
+                c64::new((seed + 0.7 * i - 0.3 * j).sin(), (seed * j + 0.5 * i).cos())
+            })
+            .collect();
+        Dense { rows, cols, data }
+    }
+
+    /// Create a matrix with `shift` added to the diagonal.
+    pub fn dominant(n: usize, seed: f64, shift: f64) -> Self {
+        let mut a = Dense::new(n, n, seed);
+        for i in 0..n {
+            a.data[i + i * n] += shift;
+        }
+        a
+    }
+
+    /// Create a column vector.
+    pub fn column(data: Vec<c64>) -> Self {
+        Dense {
+            rows: data.len(),
+            cols: 1,
+            data,
+        }
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> c64 {
+        self.data[i + j * self.rows]
+    }
+
+    fn map<F: Fn(usize, usize) -> c64>(rows: usize, cols: usize, f: F) -> Self {
+        let data = (0..rows * cols)
+            .map(|index| f(index % rows, index / rows))
+            .collect();
+        Dense { rows, cols, data }
+    }
+
+    /// Return `op(self)`.
+    pub fn op(&self, trans: Transpose) -> Self {
+        match trans {
+            Transpose::No => self.clone(),
+            Transpose::Trans => Dense::map(self.cols, self.rows, |i, j| self.get(j, i)),
+            Transpose::ConjTrans => Dense::map(self.cols, self.rows, |i, j| self.get(j, i).conj()),
+        }
+    }
+
+    /// Return `alpha * self * other + beta * c`.
+    pub fn mul(&self, alpha: c64, other: &Dense, beta: c64, c: &Dense) -> Self {
+        assert_eq!(
+            (self.cols, self.rows, other.cols),
+            (other.rows, c.rows, c.cols)
+        );
+        Dense::map(c.rows, c.cols, |i, j| {
+            let sum = (0..self.cols).fold(c64::new(0.0, 0.0), |sum, l| {
+                sum + self.get(i, l) * other.get(l, j)
+            });
+            alpha * sum + beta * c.get(i, j)
+        })
+    }
+
+    /// Return `alpha * x * y^T`.
+    pub fn outer(alpha: c64, x: &[c64], y: &[c64]) -> Self {
+        Dense::map(x.len(), y.len(), |i, j| alpha * x[i] * y[j])
+    }
+
+    /// Return `self + other`.
+    pub fn add(&self, other: &Dense) -> Self {
+        Dense::map(self.rows, self.cols, |i, j| {
+            self.get(i, j) + other.get(i, j)
+        })
+    }
+
+    /// Return the triangular matrix stored in a triangle.
+    pub fn triangular(&self, uplo: Uplo, diag: Diag) -> Self {
+        Dense::map(self.rows, self.cols, |i, j| {
+            if i == j && diag == Diag::Unit {
+                c64::new(1.0, 0.0)
+            } else if (uplo == Uplo::Upper && i <= j) || (uplo == Uplo::Lower && i >= j) {
+                self.get(i, j)
+            } else {
+                c64::new(0.0, 0.0)
+            }
+        })
+    }
+
+    /// Return the Hermitian matrix stored in a triangle, ignoring the
+    /// imaginary parts of the diagonal, or the symmetric one if `hermitian`
+    /// is not set.
+    pub fn hermitian(&self, uplo: Uplo, hermitian: bool) -> Self {
+        Dense::map(self.rows, self.cols, |i, j| {
+            let stored = (uplo == Uplo::Upper) == (i <= j);
+            if i == j && hermitian {
+                c64::new(self.get(i, i).re, 0.0)
+            } else if stored {
+                self.get(i, j)
+            } else if hermitian {
+                self.get(j, i).conj()
+            } else {
+                self.get(j, i)
+            }
+        })
+    }
+
+    /// Return the matrix with the elements outside of a band set to zero.
+    pub fn band(&self, kl: usize, ku: usize) -> Self {
+        Dense::map(self.rows, self.cols, |i, j| {
+            if i <= j + kl && j <= i + ku {
+                self.get(i, j)
+            } else {
+                c64::new(0.0, 0.0)
+            }
+        })
+    }
+
+    /// Return the matrix with the elements of a triangle replaced by those
+    /// of `other`.
+    pub fn merge(&self, uplo: Uplo, other: &Dense) -> Self {
+        Dense::map(self.rows, self.cols, |i, j| {
+            if (uplo == Uplo::Upper && i <= j) || (uplo == Uplo::Lower && i >= j) {
+                other.get(i, j)
+            } else {
+                self.get(i, j)
+            }
+        })
+    }
+
+    /// Store the matrix with a leading dimension, filling the padding with
+    /// garbage.
+    pub fn store(&self, ld: usize) -> Vec<c64> {
+        let mut a = vec![GARBAGE; ld * self.cols];
+        for j in 0..self.cols {
+            for i in 0..self.rows {
+                a[i + j * ld] = self.get(i, j);
+            }
+        }
+        a
+    }
+
+    /// Create a matrix from storage with a leading dimension.
+    pub fn load(rows: usize, cols: usize, a: &[c64], ld: usize) -> Self {
+        Dense::map(rows, cols, |i, j| a[i + j * ld])
+    }
+
+    /// Store a band of the matrix in band storage, filling the unused
+    /// elements with garbage.
+    pub fn store_band(&self, kl: usize, ku: usize, ld: usize) -> Vec<c64> {
+        let mut a = vec![GARBAGE; ld * self.cols];
+        for j in 0..self.cols {
+            for i in j.saturating_sub(ku)..self.rows.min(j + kl + 1) {
+                a[ku + i - j + j * ld] = self.get(i, j);
+            }
+        }
+        a
+    }
+
+    /// Store a triangle of the matrix in packed storage.
+    pub fn store_packed(&self, uplo: Uplo) -> Vec<c64> {
+        let n = self.rows;
+        let mut ap = vec![];
+        for j in 0..n {
+            let range = match uplo {
+                Uplo::Upper => 0..j + 1,
+                Uplo::Lower => j..n,
+            };
+            ap.extend(range.map(|i| self.get(i, j)));
+        }
+        ap
+    }
+}
+
+/// Create arbitrary but reproducible elements.
+pub fn elements(n: usize, seed: f64) -> Vec<c64> {
+    Dense::new(n, 1, seed).data
+}
+
+/// Store the elements of a vector with an increment, filling the gaps with
+/// garbage.
+pub fn store(x: &[c64], inc: i32) -> Vec<c64> {
+    let step = inc.unsigned_abs() as usize;
+    let mut buffer = vec![
+        GARBAGE;
+        if x.is_empty() {
+            0
+        } else {
+            1 + (x.len() - 1) * step
+        }
+    ];
+    for (i, &value) in x.iter().enumerate() {
+        buffer[offset(x.len(), i, inc)] = value;
+    }
+    buffer
+}
+
+/// Read the elements of a vector stored with an increment.
+pub fn load(buffer: &[c64], n: usize, inc: i32) -> Vec<c64> {
+    (0..n).map(|i| buffer[offset(n, i, inc)]).collect()
+}
+
+fn offset(n: usize, i: usize, inc: i32) -> usize {
+    let step = inc.unsigned_abs() as usize;
+    if inc > 0 {
+        i * step
+    } else {
+        (n - 1 - i) * step
+    }
+}
+
+/// Return the real parts.
+pub fn real(x: &[c64]) -> Vec<f64> {
+    x.iter().map(|x| x.re).collect()
+}
+
+/// Return numbers with the given real parts.
+pub fn complex(x: &[f64]) -> Vec<c64> {
+    x.iter().map(|&x| c64::new(x, 0.0)).collect()
+}
+
+/// Assert that the elements are equal up to rounding.
+pub fn assert_close(x: &[c64], y: &[c64]) {
+    assert_eq!(x.len(), y.len());
+    for (i, (x, y)) in x.iter().zip(y).enumerate() {
+        let tolerance = 1e-12 * (1.0 + x.norm_sqr().max(y.norm_sqr()));
+        assert!((x - y).norm_sqr() <= tolerance, "{}: {} != {}", i, x, y);
+    }
+}
+
+/// Assert that the real elements are equal up to rounding.
+pub fn assert_close_real(x: &[f64], y: &[f64]) {
+    assert_close(&complex(x), &complex(y));
+}
@@ -0,0 +1,810 @@
+use blas_sys::{c_double_complex, c_float_complex};
+use libc::{c_double, c_float, c_int};
+
+use super::{Number, Real, Vector};
+use {c32, c64};
+
+/// Construct a real plane rotation.
+unsafe fn rotg<T: Real>(a: &mut T, b: &mut T, c: &mut T, s: &mut T) {
+    let roe = if a.abs() > b.abs() { *a } else { *b };
+    let scale = a.abs() + b.abs();
+    let (r, z);
+    if scale == T::ZERO {
+        *c = T::ONE;
+        *s = T::ZERO;
+        r = T::ZERO;
+        z = T::ZERO;
+    } else {
+        let (p, q) = (*a / scale, *b / scale);
+        let mut norm = scale * (p * p + q * q).sqrt();
+        if roe < T::ZERO {
+            norm = -norm;
+        }
+        *c = *a / norm;
+        *s = *b / norm;
+        r = norm;
+        z = if a.abs() > b.abs() {
+            *s
+        } else if *c != T::ZERO {
+            T::ONE / *c
+        } else {
+            T::ONE
+        };
+    }
+    *a = r;
+    *b = z;
+}
+
+/// Construct a complex plane rotation.
+unsafe fn crotg<T: Number>(a: &mut T, b: T, c: &mut T::Real, s: &mut T) {
+    let zero = <T::Real as Number>::ZERO;
+    if a.abs() == zero {
+        *c = zero;
+        *s = T::ONE;
+        *a = b;
+    } else {
+        let scale = a.abs() + b.abs();
+        let (p, q) = (
+            (*a / T::from_real(scale)).abs(),
+            (b / T::from_real(scale)).abs(),
+        );
+        let norm = scale * (p * p + q * q).sqrt();
+        let alpha = *a / T::from_real(a.abs());
+        *c = a.abs() / norm;
+        *s = alpha * b.conj() / T::from_real(norm);
+        *a = alpha * T::from_real(norm);
+    }
+}
+
+/// Construct a modified plane rotation.
+unsafe fn rotmg<T: Real>(d1: &mut T, d2: &mut T, x1: &mut T, y1: T, param: *mut T) {
+    let (zero, one, two) = (T::ZERO, T::ONE, T::from_f64(2.0));
+    let gam = T::from_f64(4096.0);
+    let gamsq = gam * gam;
+    let rgamsq = one / gamsq;
+    let (mut h11, mut h12, mut h21, mut h22) = (zero, zero, zero, zero);
+    let mut flag;
+    if *d1 < zero {
+        flag = -one;
+        *d1 = zero;
+        *d2 = zero;
+        *x1 = zero;
+    } else {
+        let p2 = *d2 * y1;
+        if p2 == zero {
+            *param = -two;
+            return;
+        }
+        let p1 = *d1 * *x1;
+        let q2 = p2 * y1;
+        let q1 = p1 * *x1;
+        if q1.abs() > q2.abs() {
+            h21 = -y1 / *x1;
+            h12 = p2 / p1;
+            let u = one - h12 * h21;
+            if u > zero {
+                flag = zero;
+                *d1 = *d1 / u;
+                *d2 = *d2 / u;
+                *x1 = *x1 * u;
+            } else {
+                flag = -one;
+                h11 = zero;
+                h12 = zero;
+                h21 = zero;
+                h22 = zero;
+                *d1 = zero;
+                *d2 = zero;
+                *x1 = zero;
+            }
+        } else if q2 < zero {
+            flag = -one;
+            *d1 = zero;
+            *d2 = zero;
+            *x1 = zero;
+        } else {
+            flag = one;
+            h11 = p1 / p2;
+            h22 = *x1 / y1;
+            let u = one + h11 * h22;
+            let temp = *d2 / u;
+            *d2 = *d1 / u;
+            *d1 = temp;
+            *x1 = y1 * u;
+        }
+        if *d1 != zero {
+            while *d1 <= rgamsq || *d1 >= gamsq {
+                if flag == zero {
+                    h11 = one;
+                    h22 = one;
+                } else {
+                    h21 = -one;
+                    h12 = one;
+                }
+                flag = -one;
+                if *d1 <= rgamsq {
+                    *d1 = *d1 * gamsq;
+                    *x1 = *x1 / gam;
+                    h11 = h11 / gam;
+                    h12 = h12 / gam;
+                } else {
+                    *d1 = *d1 / gamsq;
+                    *x1 = *x1 * gam;
+                    h11 = h11 * gam;
+                    h12 = h12 * gam;
+                }
+            }
+        }
+        if *d2 != zero {
+            while d2.abs() <= rgamsq || d2.abs() >= gamsq {
+                if flag == zero {
+                    h11 = one;
+                    h22 = one;
+                } else {
+                    h21 = -one;
+                    h12 = one;
+                }
+                flag = -one;
+                if d2.abs() <= rgamsq {
+                    *d2 = *d2 * gamsq;
+                    h21 = h21 / gam;
+                    h22 = h22 / gam;
+                } else {
+                    *d2 = *d2 / gamsq;
+                    h21 = h21 * gam;
+                    h22 = h22 * gam;
+                }
+            }
+        }
+    }
+    if flag < zero {
+        *param.offset(1) = h11;
+        *param.offset(2) = h21;
+        *param.offset(3) = h12;
+        *param.offset(4) = h22;
+    } else if flag == zero {
+        *param.offset(2) = h21;
+        *param.offset(3) = h12;
+    } else {
+        *param.offset(1) = h11;
+        *param.offset(4) = h22;
+    }
+    *param = flag;
+}
+
+/// Apply a plane rotation with real cosine and sine.
+unsafe fn rot<T: Number>(n: i32, x: *mut T, incx: i32, y: *mut T, incy: i32, c: T, s: T) {
+    if n <= 0 {
+        return;
+    }
+    let (x, y) = (Vector::new(n, x, incx), Vector::new(n, y, incy));
+    for i in 0..n {
+        let (u, v) = (x.get(i), y.get(i));
+        x.set(i, c * u + s * v);
+        y.set(i, c * v - s * u);
+    }
+}
+
+/// Apply a modified plane rotation.
+unsafe fn rotm<T: Real>(n: i32, x: *mut T, incx: i32, y: *mut T, incy: i32, param: *const T) {
+    let flag = *param;
+    if n <= 0 || flag == -T::from_f64(2.0) {
+        return;
+    }
+    let (h11, h21, h12, h22) = if flag < T::ZERO {
+        (
+            *param.offset(1),
+            *param.offset(2),
+            *param.offset(3),
+            *param.offset(4),
+        )
+    } else if flag == T::ZERO {
+        (T::ONE, *param.offset(2), *param.offset(3), T::ONE)
+    } else {
+        (*param.offset(1), -T::ONE, T::ONE, *param.offset(4))
+    };
+    let (x, y) = (Vector::new(n, x, incx), Vector::new(n, y, incy));
+    for i in 0..n {
+        let (w, z) = (x.get(i), y.get(i));
+        x.set(i, w * h11 + z * h12);
+        y.set(i, w * h21 + z * h22);
+    }
+}
+
+/// Interchange two vectors.
+unsafe fn swap<T: Number>(n: i32, x: *mut T, incx: i32, y: *mut T, incy: i32) {
+    if n <= 0 {
+        return;
+    }
+    let (x, y) = (Vector::new(n, x, incx), Vector::new(n, y, incy));
+    for i in 0..n {
+        let temp = x.get(i);
+        x.set(i, y.get(i));
+        y.set(i, temp);
+    }
+}
+
+/// Scale a vector.
+unsafe fn scal<T: Number>(n: i32, a: T, x: *mut T, incx: i32) {
+    if n <= 0 || incx <= 0 {
+        return;
+    }
+    let x = Vector::new(n, x, incx);
+    for i in 0..n {
+        x.set(i, a * x.get(i));
+    }
+}
+
+/// Copy a vector.
+unsafe fn copy<T: Number>(n: i32, x: *const T, incx: i32, y: *mut T, incy: i32) {
+    if n <= 0 {
+        return;
+    }
+    let (x, y) = (Vector::new(n, x, incx), Vector::new(n, y, incy));
+    for i in 0..n {
+        y.set(i, x.get(i));
+    }
+}
+
+/// Add a scaled vector to another vector.
+unsafe fn axpy<T: Number>(n: i32, alpha: T, x: *const T, incx: i32, y: *mut T, incy: i32) {
+    if n <= 0 || alpha == T::ZERO {
+        return;
+    }
+    let (x, y) = (Vector::new(n, x, incx), Vector::new(n, y, incy));
+    for i in 0..n {
+        y.set(i, y.get(i) + alpha * x.get(i));
+    }
+}
+
+/// Compute a dot product, conjugating the first vector if requested.
+unsafe fn dot<T: Number>(
+    n: i32,
+    x: *const T,
+    incx: i32,
+    y: *const T,
+    incy: i32,
+    conjugate: bool,
+) -> T {
+    let mut sum = T::ZERO;
+    if n <= 0 {
+        return sum;
+    }
+    let (x, y) = (Vector::new(n, x, incx), Vector::new(n, y, incy));
+    for i in 0..n {
+        let value = if conjugate { x.get(i).conj() } else { x.get(i) };
+        sum = sum + value * y.get(i);
+    }
+    sum
+}
+
+/// Compute a dot product of single-precision vectors in double precision.
+unsafe fn dsdot(n: i32, x: *const f32, incx: i32, y: *const f32, incy: i32) -> f64 {
+    let mut sum = 0.0;
+    if n <= 0 {
+        return sum;
+    }
+    let (x, y) = (Vector::new(n, x, incx), Vector::new(n, y, incy));
+    for i in 0..n {
+        sum += x.get(i) as f64 * y.get(i) as f64;
+    }
+    sum
+}
+
+/// Compute the Euclidean norm.
+unsafe fn nrm2<T: Number>(n: i32, x: *const T, incx: i32) -> T::Real {
+    let zero = <T::Real as Number>::ZERO;
+    let one = <T::Real as Number>::ONE;
+    if n < 1 || incx < 1 {
+        return zero;
+    }
+    let (mut scale, mut ssq) = (zero, one);
+    for i in 0..n as isize {
+        let value = *x.offset(i * incx as isize);
+        for &part in &[value.re(), value.im()] {
+            if part != zero {
+                let absolute = part.abs();
+                if scale < absolute {
+                    let ratio = scale / absolute;
+                    ssq = one + ssq * ratio * ratio;
+                    scale = absolute;
+                } else {
+                    let ratio = absolute / scale;
+                    ssq = ssq + ratio * ratio;
+                }
+            }
+        }
+    }
+    scale * ssq.sqrt()
+}
+
+/// Compute the sum of the absolute values of the real and imaginary parts.
+unsafe fn asum<T: Number>(n: i32, x: *const T, incx: i32) -> T::Real {
+    let mut sum = <T::Real as Number>::ZERO;
+    if n <= 0 || incx <= 0 {
+        return sum;
+    }
+    for i in 0..n as isize {
+        sum = sum + (*x.offset(i * incx as isize)).abs1();
+    }
+    sum
+}
+
+/// Find the one-based index of the element with the largest absolute value.
+unsafe fn iamax<T: Number>(n: i32, x: *const T, incx: i32) -> c_int {
+    if n < 1 || incx <= 0 {
+        return 0;
+    }
+    let (mut index, mut maximum) = (1, (*x).abs1());
+    for i in 1..n {
+        let value = (*x.offset(i as isize * incx as isize)).abs1();
+        if value > maximum {
+            index = i + 1;
+            maximum = value;
+        }
+    }
+    index
+}
+
+macro_rules! rotg {
+    ($name:ident, $ty:ty) => {
+        pub unsafe fn $name(a: *mut $ty, b: *mut $ty, c: *mut $ty, s: *mut $ty) {
+            rotg(&mut *a, &mut *b, &mut *c, &mut *s)
+        }
+    };
+}
+
+rotg!(srotg_, c_float);
+rotg!(drotg_, c_double);
+
+macro_rules! crotg {
+    ($name:ident, $ty:ty, $real:ty, $raw:ty) => {
+        pub unsafe fn $name(a: *mut $raw, b: *const $raw, c: *mut $real, s: *mut $raw) {
+            crotg(
+                &mut *(a as *mut $ty),
+                *(b as *const $ty),
+                &mut *c,
+                &mut *(s as *mut $ty),
+            )
+        }
+    };
+}
+
+crotg!(crotg_, c32, c_float, c_float_complex);
+crotg!(zrotg_, c64, c_double, c_double_complex);
+
+macro_rules! rotmg {
+    ($name:ident, $ty:ty) => {
+        pub unsafe fn $name(
+            d1: *mut $ty,
+            d2: *mut $ty,
+            x1: *mut $ty,
+            y1: *const $ty,
+            param: *mut $ty,
+        ) {
+            rotmg(&mut *d1, &mut *d2, &mut *x1, *y1, param)
+        }
+    };
+}
+
+rotmg!(srotmg_, c_float);
+rotmg!(drotmg_, c_double);
+
+macro_rules! rot {
+    ($name:ident, $ty:ty, $real:ty, $raw:ty) => {
+        pub unsafe fn $name(
+            n: *const c_int,
+            x: *mut $raw,
+            incx: *const c_int,
+            y: *mut $raw,
+            incy: *const c_int,
+            c: *const $real,
+            s: *const $real,
+        ) {
+            rot::<$ty>(
+                *n,
+                x as *mut _,
+                *incx,
+                y as *mut _,
+                *incy,
+                Number::from_real(*c),
+                Number::from_real(*s),
+            )
+        }
+    };
+}
+
+rot!(srot_, f32, c_float, c_float);
+rot!(drot_, f64, c_double, c_double);
+rot!(csrot_, c32, c_float, c_float_complex);
+rot!(zdrot_, c64, c_double, c_double_complex);
+
+macro_rules! rotm {
+    ($name:ident, $ty:ty) => {
+        pub unsafe fn $name(
+            n: *const c_int,
+            x: *mut $ty,
+            incx: *const c_int,
+            y: *mut $ty,
+            incy: *const c_int,
+            param: *const $ty,
+        ) {
+            rotm(*n, x, *incx, y, *incy, param)
+        }
+    };
+}
+
+rotm!(srotm_, c_float);
+rotm!(drotm_, c_double);
+
+macro_rules! swap {
+    ($name:ident, $ty:ty, $raw:ty) => {
+        pub unsafe fn $name(
+            n: *const c_int,
+            x: *mut $raw,
+            incx: *const c_int,
+            y: *mut $raw,
+            incy: *const c_int,
+        ) {
+            swap::<$ty>(*n, x as *mut _, *incx, y as *mut _, *incy)
+        }
+    };
+}
+
+swap!(sswap_, f32, c_float);
+swap!(dswap_, f64, c_double);
+swap!(cswap_, c32, c_float_complex);
+swap!(zswap_, c64, c_double_complex);
+
+macro_rules! scal {
+    ($name:ident, $ty:ty, $raw:ty) => {
+        pub unsafe fn $name(n: *const c_int, a: *const $raw, x: *mut $raw, incx: *const c_int) {
+            scal::<$ty>(*n, *(a as *const $ty), x as *mut _, *incx)
+        }
+    };
+}
+
+scal!(sscal_, f32, c_float);
+scal!(dscal_, f64, c_double);
+scal!(cscal_, c32, c_float_complex);
+scal!(zscal_, c64, c_double_complex);
+
+macro_rules! rscal {
+    ($name:ident, $ty:ty, $real:ty, $raw:ty) => {
+        pub unsafe fn $name(n: *const c_int, a: *const $real, x: *mut $raw, incx: *const c_int) {
+            scal::<$ty>(*n, Number::from_real(*a), x as *mut _, *incx)
+        }
+    };
+}
+
+rscal!(csscal_, c32, c_float, c_float_complex);
+rscal!(zdscal_, c64, c_double, c_double_complex);
+
+macro_rules! copy {
+    ($name:ident, $ty:ty, $raw:ty) => {
+        pub unsafe fn $name(
+            n: *const c_int,
+            x: *const $raw,
+            incx: *const c_int,
+            y: *mut $raw,
+            incy: *const c_int,
+        ) {
+            copy::<$ty>(*n, x as *const _, *incx, y as *mut _, *incy)
+        }
+    };
+}
+
+copy!(scopy_, f32, c_float);
+copy!(dcopy_, f64, c_double);
+copy!(ccopy_, c32, c_float_complex);
+copy!(zcopy_, c64, c_double_complex);
+
+macro_rules! axpy {
+    ($name:ident, $ty:ty, $raw:ty) => {
+        pub unsafe fn $name(
+            n: *const c_int,
+            alpha: *const $raw,
+            x: *const $raw,
+            incx: *const c_int,
+            y: *mut $raw,
+            incy: *const c_int,
+        ) {
+            let alpha = *(alpha as *const $ty);
+            axpy::<$ty>(*n, alpha, x as *const _, *incx, y as *mut _, *incy)
+        }
+    };
+}
+
+axpy!(saxpy_, f32, c_float);
+axpy!(daxpy_, f64, c_double);
+axpy!(caxpy_, c32, c_float_complex);
+axpy!(zaxpy_, c64, c_double_complex);
+
+macro_rules! dot {
+    ($name:ident, $ty:ty) => {
+        pub unsafe fn $name(
+            n: *const c_int,
+            x: *const $ty,
+            incx: *const c_int,
+            y: *const $ty,
+            incy: *const c_int,
+        ) -> $ty {
+            dot(*n, x, *incx, y, *incy, false)
+        }
+    };
+}
+
+dot!(sdot_, c_float);
+dot!(ddot_, c_double);
+
+pub unsafe fn dsdot_(
+    n: *const c_int,
+    x: *const c_float,
+    incx: *const c_int,
+    y: *const c_float,
+    incy: *const c_int,
+) -> c_double {
+    dsdot(*n, x, *incx, y, *incy)
+}
+
+pub unsafe fn sdsdot_(
+    n: *const c_int,
+    sb: *const c_float,
+    x: *const c_float,
+    incx: *const c_int,
+    y: *const c_float,
+    incy: *const c_int,
+) -> c_float {
+    (*sb as f64 + dsdot(*n, x, *incx, y, *incy)) as f32
+}
+
+macro_rules! dotc {
+    ($name:ident, $ty:ty, $raw:ty, $conjugate:expr) => {
+        pub unsafe fn $name(
+            pres: *mut $raw,
+            n: *const c_int,
+            x: *const $raw,
+            incx: *const c_int,
+            y: *const $raw,
+            incy: *const c_int,
+        ) {
+            *(pres as *mut $ty) =
+                dot::<$ty>(*n, x as *const _, *incx, y as *const _, *incy, $conjugate)
+        }
+    };
+}
+
+dotc!(cdotu_, c32, c_float_complex, false);
+dotc!(cdotc_, c32, c_float_complex, true);
+dotc!(zdotu_, c64, c_double_complex, false);
+dotc!(zdotc_, c64, c_double_complex, true);
+
+macro_rules! reduce {
+    ($name:ident, $function:ident, $ty:ty, $raw:ty, $result:ty) => {
+        pub unsafe fn $name(n: *const c_int, x: *const $raw, incx: *const c_int) -> $result {
+            $function::<$ty>(*n, x as *const _, *incx)
+        }
+    };
+}
+
+reduce!(snrm2_, nrm2, f32, c_float, c_float);
+reduce!(dnrm2_, nrm2, f64, c_double, c_double);
+reduce!(scnrm2_, nrm2, c32, c_float_complex, c_float);
+reduce!(dznrm2_, nrm2, c64, c_double_complex, c_double);
+
+reduce!(sasum_, asum, f32, c_float, c_float);
+reduce!(dasum_, asum, f64, c_double, c_double);
+reduce!(scasum_, asum, c32, c_float_complex, c_float);
+reduce!(dzasum_, asum, c64, c_double_complex, c_double);
+
+reduce!(isamax_, iamax, f32, c_float, c_int);
+reduce!(idamax_, iamax, f64, c_double, c_int);
+reduce!(icamax_, iamax, c32, c_float_complex, c_int);
+reduce!(izamax_, iamax, c64, c_double_complex, c_int);
+
+#[cfg(test)]
+mod tests {
+    use super::super::dense::*;
+    use c64;
+
+    #[test]
+    fn rotg() {
+        let (mut a, mut b, mut c, mut s) = (3.0, -4.0, 0.0, 0.0);
+        unsafe { ::drotg(&mut a, &mut b, &mut c, &mut s) };
+        assert_close_real(&[a, b, c, s], &[-5.0, 1.0 / -0.6, -0.6, 0.8]);
+        let (mut a, mut b) = (0.0, 0.0);
+        unsafe { ::drotg(&mut a, &mut b, &mut c, &mut s) };
+        assert_eq!([a, b, c, s], [0.0, 0.0, 1.0, 0.0]);
+
+        for &(a, b) in &[
+            (c64::new(1.0, 2.0), c64::new(3.0, -1.0)),
+            (c64::new(0.0, 0.0), c64::new(3.0, -1.0)),
+        ] {
+            let (mut r, mut c, mut s) = (a, 0.0, c64::new(0.0, 0.0));
+            unsafe { ::zrotg(&mut r, b, &mut c, &mut s) };
+            assert_close(
+                &[c * a + s * b, c * b - s.conj() * a],
+                &[r, c64::new(0.0, 0.0)],
+            );
+            assert_close_real(&[c * c + s.norm_sqr()], &[1.0]);
+        }
+    }
+
+    #[test]
+    fn rot() {
+        let (n, c, s) = (4, 0.6, -0.8);
+        let (x, y) = (elements(n, 1.0), elements(n, 2.0));
+        let (mut u, mut v) = (store(&x, 2), store(&y, -1));
+        unsafe { ::zdrot(n as i32, &mut u, 2, &mut v, -1, c, s) };
+        let expected = (0..n).map(|i| x[i] * c + y[i] * s).collect::<Vec<_>>();
+        assert_close(&u, &store(&expected, 2));
+        let expected = (0..n).map(|i| y[i] * c - x[i] * s).collect::<Vec<_>>();
+        assert_close(&v, &store(&expected, -1));
+
+        let (mut u, mut v) = (real(&store(&x, -3)), real(&store(&y, 1)));
+        unsafe { ::drot(n as i32, &mut u, -3, &mut v, 1, c, s) };
+        let expected = (0..n)
+            .map(|i| x[i].re * c + y[i].re * s)
+            .collect::<Vec<_>>();
+        assert_close_real(&u, &real(&store(&complex(&expected), -3)));
+
+        let mut w = u.clone();
+        unsafe { ::drot(0, &mut w, -3, &mut v, 1, c, s) };
+        assert_eq!(w, u);
+    }
+
+    #[test]
+    fn rotm() {
+        let (n, x, y) = (3, real(&elements(3, 1.0)), real(&elements(3, 2.0)));
+        let (h11, h21, h12, h22) = (0.5, -2.0, 3.0, 1.5);
+        for &(flag, h) in &[
+            (-1.0, [h11, h21, h12, h22]),
+            (0.0, [1.0, h21, h12, 1.0]),
+            (1.0, [h11, -1.0, 1.0, h22]),
+            (-2.0, [1.0, 0.0, 0.0, 1.0]),
+        ] {
+            let param = [flag, h11, h21, h12, h22];
+            let (mut u, mut v) = (
+                real(&store(&complex(&x), -2)),
+                real(&store(&complex(&y), 1)),
+            );
+            unsafe { ::drotm(n as i32, &mut u, -2, &mut v, 1, &param) };
+            let expected = (0..n)
+                .map(|i| h[0] * x[i] + h[2] * y[i])
+                .collect::<Vec<_>>();
+            assert_close_real(&u, &real(&store(&complex(&expected), -2)));
+            let expected = (0..n)
+                .map(|i| h[1] * x[i] + h[3] * y[i])
+                .collect::<Vec<_>>();
+            assert_close_real(&v, &expected);
+        }
+    }
+
+    #[test]
+    fn rotmg() {
+        for &(d1, d2, x1, y1) in &[
+            (2.0, 3.0, 1.0, 2.0),
+            (1.0, 1.0, 4.0, 1.0),
+            (4.0, -1.0, 1.0, 1.0),
+            (1e-9, 2.0, 3.0, 1.0),
+            (1e9, 2.0, 3.0, 1e-4),
+            (2.0, 1e-9, 1.0, 3.0),
+            (1.0, 2.0, 3.0, 0.0),
+        ] {
+            let (mut e1, mut e2, mut z1, mut param) = (d1, d2, x1, [0.0; 5]);
+            unsafe { ::drotmg(&mut e1, &mut e2, &mut z1, y1, &mut param) };
+            let (mut u, mut v) = ([x1], [y1]);
+            unsafe { ::drotm(1, &mut u, 1, &mut v, 1, &param) };
+            assert_close_real(&[u[0], v[0]], &[z1, 0.0]);
+            assert_close_real(&[e1 * z1 * z1], &[d1 * x1 * x1 + d2 * y1 * y1]);
+        }
+
+        let (mut d1, mut d2, mut x1, mut param) = (-1.0, 2.0, 3.0, [0.0; 5]);
+        unsafe { ::drotmg(&mut d1, &mut d2, &mut x1, 1.0, &mut param) };
+        assert_eq!([d1, d2, x1], [0.0; 3]);
+        assert_eq!(param, [-1.0, 0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn swap() {
+        let (x, y) = (elements(3, 1.0), elements(3, 2.0));
+        let (mut u, mut v) = (store(&x, -2), store(&y, 3));
+        unsafe { ::zswap(3, &mut u, -2, &mut v, 3) };
+        assert_eq!((u, v), (store(&y, -2), store(&x, 3)));
+
+        let mut v = store(&y, -1);
+        unsafe { ::zcopy(3, &store(&x, 2), 2, &mut v, -1) };
+        assert_eq!(v, store(&x, -1));
+        unsafe { ::zcopy(0, &[], 1, &mut v, -1) };
+        assert_eq!(v, store(&x, -1));
+    }
+
+    #[test]
+    fn scal() {
+        let (x, a) = (elements(3, 1.0), c64::new(0.5, -2.0));
+        let mut u = store(&x, 2);
+        unsafe { ::zscal(3, a, &mut u, 2) };
+        let expected = x.iter().map(|&x| a * x).collect::<Vec<_>>();
+        assert_eq!(u, store(&expected, 2));
+        unsafe { ::zdscal(3, 2.0, &mut u, 2) };
+        let expected = x.iter().map(|&x| a * x * 2.0).collect::<Vec<_>>();
+        assert_close(&u, &store(&expected, 2));
+
+        // A nonpositive increment or dimension leaves the vector intact.
+        let mut v = u.clone();
+        unsafe { ::zscal(3, a, &mut v, -2) };
+        unsafe { ::zdscal(0, 2.0, &mut v, 2) };
+        assert_eq!(u, v);
+    }
+
+    #[test]
+    fn axpy() {
+        let (x, y, alpha) = (elements(4, 1.0), elements(4, 2.0), c64::new(-1.5, 0.5));
+        let mut v = store(&y, -2);
+        unsafe { ::zaxpy(4, alpha, &store(&x, 3), 3, &mut v, -2) };
+        let expected = (0..4).map(|i| y[i] + alpha * x[i]).collect::<Vec<_>>();
+        assert_close(&v, &store(&expected, -2));
+
+        let mut w = v.clone();
+        unsafe { ::zaxpy(4, c64::new(0.0, 0.0), &store(&x, 3), 3, &mut w, -2) };
+        unsafe { ::zaxpy(0, alpha, &[], 3, &mut w, -2) };
+        assert_eq!(v, w);
+    }
+
+    #[test]
+    fn dot() {
+        let (x, y) = (elements(4, 1.0), elements(4, 2.0));
+        let (u, v) = (store(&x, -2), store(&y, 3));
+        let expected = (0..4).fold(c64::new(0.0, 0.0), |sum, i| sum + x[i] * y[i]);
+        let conjugated = (0..4).fold(c64::new(0.0, 0.0), |sum, i| sum + x[i].conj() * y[i]);
+        unsafe {
+            assert_close(&[::zdotu(4, &u, -2, &v, 3)], &[expected]);
+            assert_close(&[::zdotc(4, &u, -2, &v, 3)], &[conjugated]);
+            assert_eq!(::zdotc(0, &u, -2, &v, 3), c64::new(0.0, 0.0));
+        }
+
+        let (u, v) = (real(&u), real(&v));
+        let expected = (0..4).fold(0.0, |sum, i| sum + x[i].re * y[i].re);
+        unsafe {
+            assert_close_real(&[::ddot(4, &u, -2, &v, 3)], &[expected]);
+            assert_eq!(::ddot(-1, &u, -2, &v, 3), 0.0);
+        }
+        let (u, v) = (
+            u.iter().map(|&u| u as f32).collect::<Vec<_>>(),
+            v.iter().map(|&v| v as f32).collect::<Vec<_>>(),
+        );
+        let expected = (0..4).fold(0.0, |sum, i| {
+            sum + (x[i].re as f32 as f64) * (y[i].re as f32 as f64)
+        });
+        unsafe {
+            assert_close_real(&[::dsdot(4, &u, -2, &v, 3)], &[expected]);
+            let sum = ::sdsdot(4, &[1.0], &u, -2, &v, 3) as f64;
+            assert!((sum - expected - 1.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn reduce() {
+        let x = elements(5, 1.0);
+        let u = store(&x, 2);
+        let norm = x.iter().map(|x| x.norm_sqr()).sum::<f64>().sqrt();
+        let sum = x.iter().map(|x| x.re.abs() + x.im.abs()).sum::<f64>();
+        let index = (0..5).fold(0, |best, i| {
+            if x[i].re.abs() + x[i].im.abs() > x[best].re.abs() + x[best].im.abs() {
+                i
+            } else {
+                best
+            }
+        });
+        unsafe {
+            assert_close_real(&[::dznrm2(5, &u, 2), ::dzasum(5, &u, 2)], &[norm, sum]);
+            assert_eq!(::izamax(5, &u, 2), index + 1);
+            assert_eq!([::dznrm2(0, &u, 2), ::dzasum(5, &u, -2)], [0.0, 0.0]);
+            assert_eq!([::izamax(0, &u, 2), ::izamax(5, &u, -2)], [0, 0]);
+
+            // The norm is computed without overflow.
+            assert_close_real(&[::dnrm2(2, &[3e200, 4e200], 1)], &[5e200]);
+            assert_eq!(::idamax(3, &[1.0, -3.0, 3.0], 1), 2);
+        }
+    }
+}
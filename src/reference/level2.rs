@@ -0,0 +1,1356 @@
+use std::cmp::{max, min};
+use std::ops::Range;
+
+use blas_sys::{c_double_complex, c_float_complex};
+use libc::{c_char, c_double, c_float, c_int};
+
+use super::{lsame, xerbla, Matrix, Number, Vector};
+use {c32, c64};
+
+/// Return the conjugate if `conjugate` is set.
+#[inline]
+fn cj<T: Number>(value: T, conjugate: bool) -> T {
+    if conjugate {
+        value.conj()
+    } else {
+        value
+    }
+}
+
+/// Return the real part if `hermitian` is set, as the diagonal of a
+/// Hermitian matrix is real.
+#[inline]
+fn diagonal<T: Number>(value: T, hermitian: bool) -> T {
+    if hermitian {
+        T::from_real(value.re())
+    } else {
+        value
+    }
+}
+
+/// Compute `y := beta * y`.
+#[inline]
+unsafe fn scale<T: Number>(n: i32, beta: T, y: Vector<T>) {
+    if beta == T::ONE {
+        return;
+    }
+    for i in 0..n {
+        y.set(
+            i,
+            if beta == T::ZERO {
+                T::ZERO
+            } else {
+                beta * y.get(i)
+            },
+        );
+    }
+}
+
+/// Return the position of the first element of column `j` of a packed
+/// triangular matrix of order `n`.
+#[inline]
+fn packed(upper: bool, n: i32, j: i32) -> isize {
+    let (n, j) = (n as isize, j as isize);
+    if upper {
+        j * (j + 1) / 2
+    } else {
+        j * n - j * (j - 1) / 2
+    }
+}
+
+/// A triangular matrix in full, band, or packed storage.
+#[derive(Clone, Copy)]
+enum Storage<T> {
+    Full(Matrix<T>),
+    Band(Matrix<T>, i32),
+    Packed(*mut T, i32),
+}
+
+impl<T: Number> Storage<T> {
+    /// Return a pointer to the element in row `i` and column `j`, which must
+    /// belong to the stored triangle and band.
+    #[inline]
+    unsafe fn at(&self, upper: bool, i: i32, j: i32) -> *mut T {
+        match *self {
+            Storage::Full(a) => a.pointer.offset(i as isize + j as isize * a.ld),
+            Storage::Band(a, k) => {
+                let row = if upper { k + i - j } else { i - j };
+                a.pointer.offset(row as isize + j as isize * a.ld)
+            }
+            Storage::Packed(a, n) => {
+                a.offset(packed(upper, n, j) + (i - if upper { 0 } else { j }) as isize)
+            }
+        }
+    }
+
+    #[inline]
+    unsafe fn get(&self, upper: bool, i: i32, j: i32) -> T {
+        *self.at(upper, i, j)
+    }
+
+    /// Return the rows of the stored off-diagonal elements of column `j`.
+    #[inline]
+    fn rows(&self, upper: bool, n: i32, j: i32) -> Range<i32> {
+        let k = match *self {
+            Storage::Band(_, k) => k,
+            _ => n,
+        };
+        if upper {
+            max(0, j - k)..j
+        } else {
+            (j + 1)..min(n, j + k + 1)
+        }
+    }
+}
+
+/// Compute `y := alpha * op(a) * x + beta * y` for a general matrix in full
+/// storage or, if the numbers of sub- and super-diagonals are given, in band
+/// storage.
+unsafe fn gemv<T: Number>(
+    routine: &str,
+    trans: u8,
+    m: i32,
+    n: i32,
+    band: Option<(i32, i32)>,
+    alpha: T,
+    a: *const T,
+    lda: i32,
+    x: *const T,
+    incx: i32,
+    beta: T,
+    y: *mut T,
+    incy: i32,
+) {
+    let shift = if band.is_some() { 2 } else { 0 };
+    let mut info = 0;
+    if !lsame(trans, b'N') && !lsame(trans, b'T') && !lsame(trans, b'C') {
+        info = 1;
+    } else if m < 0 {
+        info = 2;
+    } else if n < 0 {
+        info = 3;
+    } else if let Some((kl, ku)) = band {
+        if kl < 0 {
+            info = 4;
+        } else if ku < 0 {
+            info = 5;
+        } else if lda < kl + ku + 1 {
+            info = 8;
+        }
+    } else if lda < max(1, m) {
+        info = 6;
+    }
+    if info == 0 {
+        if incx == 0 {
+            info = 8 + shift;
+        } else if incy == 0 {
+            info = 11 + shift;
+        }
+    }
+    if info != 0 {
+        return xerbla(routine, info);
+    }
+    if m == 0 || n == 0 || (alpha == T::ZERO && beta == T::ONE) {
+        return;
+    }
+    let notrans = lsame(trans, b'N');
+    let conjugate = lsame(trans, b'C');
+    let (lenx, leny) = if notrans { (n, m) } else { (m, n) };
+    let (x, y) = (Vector::new(lenx, x, incx), Vector::new(leny, y, incy));
+    scale(leny, beta, y);
+    if alpha == T::ZERO {
+        return;
+    }
+    let a = Matrix::new(a, lda);
+    let (kl, ku) = band.unwrap_or((m, n));
+    let rows = |j: i32| max(0, j - ku)..min(m, j + kl + 1);
+    let get = |i: i32, j: i32| match band {
+        Some(_) => a.get(ku + i - j, j),
+        None => a.get(i, j),
+    };
+    for j in 0..n {
+        if notrans {
+            let temp = alpha * x.get(j);
+            for i in rows(j) {
+                y.set(i, y.get(i) + temp * get(i, j));
+            }
+        } else {
+            let mut temp = T::ZERO;
+            for i in rows(j) {
+                temp = temp + cj(get(i, j), conjugate) * x.get(i);
+            }
+            y.set(j, y.get(j) + alpha * temp);
+        }
+    }
+}
+
+/// Compute `y := alpha * a * x + beta * y` for a symmetric or Hermitian
+/// matrix.
+unsafe fn symv<T: Number>(
+    routine: &str,
+    uplo: u8,
+    n: i32,
+    alpha: T,
+    a: Storage<T>,
+    x: *const T,
+    incx: i32,
+    beta: T,
+    y: *mut T,
+    incy: i32,
+    hermitian: bool,
+) {
+    let upper = lsame(uplo, b'U');
+    let mut info = 0;
+    if !upper && !lsame(uplo, b'L') {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    }
+    let (shift, positions) = match a {
+        Storage::Full(a) => (0, Some((a.ld < max(1, n) as isize, 5))),
+        Storage::Band(a, k) => (1, Some((a.ld < k as isize + 1, 6))),
+        Storage::Packed(..) => (-1, None),
+    };
+    if info == 0 {
+        if let Storage::Band(_, k) = a {
+            if k < 0 {
+                info = 3;
+            }
+        }
+    }
+    if info == 0 {
+        if let Some((true, position)) = positions {
+            info = position;
+        } else if incx == 0 {
+            info = 7 + shift;
+        } else if incy == 0 {
+            info = 10 + shift;
+        }
+    }
+    if info != 0 {
+        return xerbla(routine, info);
+    }
+    if n == 0 || (alpha == T::ZERO && beta == T::ONE) {
+        return;
+    }
+    let (x, y) = (Vector::new(n, x, incx), Vector::new(n, y, incy));
+    scale(n, beta, y);
+    if alpha == T::ZERO {
+        return;
+    }
+    for j in 0..n {
+        let temp1 = alpha * x.get(j);
+        let mut temp2 = T::ZERO;
+        let value = diagonal(a.get(upper, j, j), hermitian);
+        if !upper {
+            y.set(j, y.get(j) + temp1 * value);
+        }
+        for i in a.rows(upper, n, j) {
+            let element = a.get(upper, i, j);
+            y.set(i, y.get(i) + temp1 * element);
+            temp2 = temp2 + cj(element, hermitian) * x.get(i);
+        }
+        if upper {
+            y.set(j, y.get(j) + temp1 * value + alpha * temp2);
+        } else {
+            y.set(j, y.get(j) + alpha * temp2);
+        }
+    }
+}
+
+/// Compute `x := op(a) * x` or solve `op(a) * x = b` for a triangular
+/// matrix.
+unsafe fn trmv<T: Number>(
+    routine: &str,
+    uplo: u8,
+    trans: u8,
+    diag: u8,
+    n: i32,
+    a: Storage<T>,
+    x: *mut T,
+    incx: i32,
+    solve: bool,
+) {
+    let upper = lsame(uplo, b'U');
+    let mut info = 0;
+    if !upper && !lsame(uplo, b'L') {
+        info = 1;
+    } else if !lsame(trans, b'N') && !lsame(trans, b'T') && !lsame(trans, b'C') {
+        info = 2;
+    } else if !lsame(diag, b'U') && !lsame(diag, b'N') {
+        info = 3;
+    } else if n < 0 {
+        info = 4;
+    } else {
+        match a {
+            Storage::Full(a) if a.ld < max(1, n) as isize => info = 6,
+            Storage::Full(_) if incx == 0 => info = 8,
+            Storage::Band(_, k) if k < 0 => info = 5,
+            Storage::Band(a, k) if a.ld < k as isize + 1 => info = 7,
+            Storage::Band(..) if incx == 0 => info = 9,
+            Storage::Packed(..) if incx == 0 => info = 7,
+            _ => {}
+        }
+    }
+    if info != 0 {
+        return xerbla(routine, info);
+    }
+    if n == 0 {
+        return;
+    }
+    let notrans = lsame(trans, b'N');
+    let conjugate = lsame(trans, b'C');
+    let nounit = lsame(diag, b'N');
+    let x = Vector::new(n, x, incx);
+    let element = |i: i32, j: i32| cj(a.get(upper, i, j), conjugate);
+    // The columns are ordered so that every element of x is read before it
+    // is overwritten or after its final value has been computed, as needed.
+    let ascending = if notrans {
+        upper != solve
+    } else {
+        upper == solve
+    };
+    let column = |step: i32| if ascending { step } else { n - 1 - step };
+    if notrans {
+        for j in (0..n).map(column) {
+            if x.get(j) == T::ZERO {
+                continue;
+            }
+            if solve {
+                if nounit {
+                    x.set(j, x.get(j) / element(j, j));
+                }
+                let temp = x.get(j);
+                for i in a.rows(upper, n, j) {
+                    x.set(i, x.get(i) - temp * element(i, j));
+                }
+            } else {
+                let temp = x.get(j);
+                for i in a.rows(upper, n, j) {
+                    x.set(i, x.get(i) + temp * element(i, j));
+                }
+                if nounit {
+                    x.set(j, x.get(j) * element(j, j));
+                }
+            }
+        }
+    } else {
+        for j in (0..n).map(column) {
+            let mut temp = x.get(j);
+            if !solve && nounit {
+                temp = temp * element(j, j);
+            }
+            for i in a.rows(upper, n, j) {
+                if solve {
+                    temp = temp - element(i, j) * x.get(i);
+                } else {
+                    temp = temp + element(i, j) * x.get(i);
+                }
+            }
+            if solve && nounit {
+                temp = temp / element(j, j);
+            }
+            x.set(j, temp);
+        }
+    }
+}
+
+/// Compute `a := alpha * x * op(y) + a`.
+unsafe fn ger<T: Number>(
+    routine: &str,
+    m: i32,
+    n: i32,
+    alpha: T,
+    x: *const T,
+    incx: i32,
+    y: *const T,
+    incy: i32,
+    a: *mut T,
+    lda: i32,
+    conjugate: bool,
+) {
+    let mut info = 0;
+    if m < 0 {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if incx == 0 {
+        info = 5;
+    } else if incy == 0 {
+        info = 7;
+    } else if lda < max(1, m) {
+        info = 9;
+    }
+    if info != 0 {
+        return xerbla(routine, info);
+    }
+    if m == 0 || n == 0 || alpha == T::ZERO {
+        return;
+    }
+    let (x, y) = (Vector::new(m, x, incx), Vector::new(n, y, incy));
+    let a = Matrix::new(a, lda);
+    for j in 0..n {
+        if y.get(j) != T::ZERO {
+            let temp = alpha * cj(y.get(j), conjugate);
+            for i in 0..m {
+                a.set(i, j, a.get(i, j) + x.get(i) * temp);
+            }
+        }
+    }
+}
+
+/// Compute `a := alpha * x * op(x) + a` or `a := alpha * x * op(y) +
+/// op(alpha) * y * op(x) + a` for a symmetric or Hermitian matrix.
+unsafe fn syr<T: Number>(
+    routine: &str,
+    uplo: u8,
+    n: i32,
+    alpha: T,
+    x: *const T,
+    incx: i32,
+    y: Option<(*const T, i32)>,
+    a: Storage<T>,
+    hermitian: bool,
+) {
+    let upper = lsame(uplo, b'U');
+    let shift = if y.is_some() { 2 } else { 0 };
+    let mut info = 0;
+    if !upper && !lsame(uplo, b'L') {
+        info = 1;
+    } else if n < 0 {
+        info = 2;
+    } else if incx == 0 {
+        info = 5;
+    } else if let Some((_, 0)) = y {
+        info = 7;
+    } else if let Storage::Full(a) = a {
+        if a.ld < max(1, n) as isize {
+            info = 7 + shift;
+        }
+    }
+    if info != 0 {
+        return xerbla(routine, info);
+    }
+    if n == 0 || alpha == T::ZERO {
+        return;
+    }
+    let x = Vector::new(n, x, incx);
+    let y = y.map(|(y, incy)| Vector::new(n, y, incy));
+    for j in 0..n {
+        let pointer = a.at(upper, j, j);
+        let (xj, yj) = (x.get(j), y.map_or(T::ZERO, |y| y.get(j)));
+        if xj == T::ZERO && yj == T::ZERO {
+            *pointer = diagonal(*pointer, hermitian);
+            continue;
+        }
+        let (temp1, temp2) = match y {
+            Some(_) => (alpha * cj(yj, hermitian), cj(alpha * xj, hermitian)),
+            None => (alpha * cj(xj, hermitian), T::ZERO),
+        };
+        let update = |i: i32| match y {
+            Some(y) => x.get(i) * temp1 + y.get(i) * temp2,
+            None => x.get(i) * temp1,
+        };
+        for i in a.rows(upper, n, j) {
+            let pointer = a.at(upper, i, j);
+            *pointer = *pointer + update(i);
+        }
+        *pointer = diagonal(*pointer + update(j), hermitian);
+    }
+}
+
+macro_rules! gemv {
+    ($name:ident, $ty:ty, $raw:ty) => {
+        pub unsafe fn $name(
+            trans: *const c_char,
+            m: *const c_int,
+            n: *const c_int,
+            alpha: *const $raw,
+            a: *const $raw,
+            lda: *const c_int,
+            x: *const $raw,
+            incx: *const c_int,
+            beta: *const $raw,
+            y: *mut $raw,
+            incy: *const c_int,
+        ) {
+            gemv::<$ty>(
+                stringify!($name),
+                *trans as u8,
+                *m,
+                *n,
+                None,
+                *(alpha as *const $ty),
+                a as *const _,
+                *lda,
+                x as *const _,
+                *incx,
+                *(beta as *const $ty),
+                y as *mut _,
+                *incy,
+            )
+        }
+    };
+}
+
+gemv!(sgemv_, f32, c_float);
+gemv!(dgemv_, f64, c_double);
+gemv!(cgemv_, c32, c_float_complex);
+gemv!(zgemv_, c64, c_double_complex);
+
+macro_rules! gbmv {
+    ($name:ident, $ty:ty, $raw:ty) => {
+        pub unsafe fn $name(
+            trans: *const c_char,
+            m: *const c_int,
+            n: *const c_int,
+            kl: *const c_int,
+            ku: *const c_int,
+            alpha: *const $raw,
+            a: *const $raw,
+            lda: *const c_int,
+            x: *const $raw,
+            incx: *const c_int,
+            beta: *const $raw,
+            y: *mut $raw,
+            incy: *const c_int,
+        ) {
+            gemv::<$ty>(
+                stringify!($name),
+                *trans as u8,
+                *m,
+                *n,
+                Some((*kl, *ku)),
+                *(alpha as *const $ty),
+                a as *const _,
+                *lda,
+                x as *const _,
+                *incx,
+                *(beta as *const $ty),
+                y as *mut _,
+                *incy,
+            )
+        }
+    };
+}
+
+gbmv!(sgbmv_, f32, c_float);
+gbmv!(dgbmv_, f64, c_double);
+gbmv!(cgbmv_, c32, c_float_complex);
+gbmv!(zgbmv_, c64, c_double_complex);
+
+macro_rules! symv {
+    ($name:ident, $ty:ty, $raw:ty, $hermitian:expr) => {
+        pub unsafe fn $name(
+            uplo: *const c_char,
+            n: *const c_int,
+            alpha: *const $raw,
+            a: *const $raw,
+            lda: *const c_int,
+            x: *const $raw,
+            incx: *const c_int,
+            beta: *const $raw,
+            y: *mut $raw,
+            incy: *const c_int,
+        ) {
+            symv::<$ty>(
+                stringify!($name),
+                *uplo as u8,
+                *n,
+                *(alpha as *const $ty),
+                Storage::Full(Matrix::new(a as *const _, *lda)),
+                x as *const _,
+                *incx,
+                *(beta as *const $ty),
+                y as *mut _,
+                *incy,
+                $hermitian,
+            )
+        }
+    };
+}
+
+symv!(ssymv_, f32, c_float, false);
+symv!(dsymv_, f64, c_double, false);
+symv!(chemv_, c32, c_float_complex, true);
+symv!(zhemv_, c64, c_double_complex, true);
+
+macro_rules! sbmv {
+    ($name:ident, $ty:ty, $raw:ty, $hermitian:expr) => {
+        pub unsafe fn $name(
+            uplo: *const c_char,
+            n: *const c_int,
+            k: *const c_int,
+            alpha: *const $raw,
+            a: *const $raw,
+            lda: *const c_int,
+            x: *const $raw,
+            incx: *const c_int,
+            beta: *const $raw,
+            y: *mut $raw,
+            incy: *const c_int,
+        ) {
+            symv::<$ty>(
+                stringify!($name),
+                *uplo as u8,
+                *n,
+                *(alpha as *const $ty),
+                Storage::Band(Matrix::new(a as *const _, *lda), *k),
+                x as *const _,
+                *incx,
+                *(beta as *const $ty),
+                y as *mut _,
+                *incy,
+                $hermitian,
+            )
+        }
+    };
+}
+
+sbmv!(ssbmv_, f32, c_float, false);
+sbmv!(dsbmv_, f64, c_double, false);
+sbmv!(chbmv_, c32, c_float_complex, true);
+sbmv!(zhbmv_, c64, c_double_complex, true);
+
+macro_rules! spmv {
+    ($name:ident, $ty:ty, $raw:ty, $hermitian:expr) => {
+        pub unsafe fn $name(
+            uplo: *const c_char,
+            n: *const c_int,
+            alpha: *const $raw,
+            ap: *const $raw,
+            x: *const $raw,
+            incx: *const c_int,
+            beta: *const $raw,
+            y: *mut $raw,
+            incy: *const c_int,
+        ) {
+            symv::<$ty>(
+                stringify!($name),
+                *uplo as u8,
+                *n,
+                *(alpha as *const $ty),
+                Storage::Packed(ap as *mut _, *n),
+                x as *const _,
+                *incx,
+                *(beta as *const $ty),
+                y as *mut _,
+                *incy,
+                $hermitian,
+            )
+        }
+    };
+}
+
+spmv!(sspmv_, f32, c_float, false);
+spmv!(dspmv_, f64, c_double, false);
+spmv!(chpmv_, c32, c_float_complex, true);
+spmv!(zhpmv_, c64, c_double_complex, true);
+
+macro_rules! trmv {
+    ($name:ident, $ty:ty, $raw:ty, $solve:expr) => {
+        pub unsafe fn $name(
+            uplo: *const c_char,
+            trans: *const c_char,
+            diag: *const c_char,
+            n: *const c_int,
+            a: *const $raw,
+            lda: *const c_int,
+            x: *mut $raw,
+            incx: *const c_int,
+        ) {
+            trmv::<$ty>(
+                stringify!($name),
+                *uplo as u8,
+                *trans as u8,
+                *diag as u8,
+                *n,
+                Storage::Full(Matrix::new(a as *const _, *lda)),
+                x as *mut _,
+                *incx,
+                $solve,
+            )
+        }
+    };
+}
+
+trmv!(strmv_, f32, c_float, false);
+trmv!(dtrmv_, f64, c_double, false);
+trmv!(ctrmv_, c32, c_float_complex, false);
+trmv!(ztrmv_, c64, c_double_complex, false);
+
+trmv!(strsv_, f32, c_float, true);
+trmv!(dtrsv_, f64, c_double, true);
+trmv!(ctrsv_, c32, c_float_complex, true);
+trmv!(ztrsv_, c64, c_double_complex, true);
+
+macro_rules! tbmv {
+    ($name:ident, $ty:ty, $raw:ty, $solve:expr) => {
+        pub unsafe fn $name(
+            uplo: *const c_char,
+            trans: *const c_char,
+            diag: *const c_char,
+            n: *const c_int,
+            k: *const c_int,
+            a: *const $raw,
+            lda: *const c_int,
+            x: *mut $raw,
+            incx: *const c_int,
+        ) {
+            trmv::<$ty>(
+                stringify!($name),
+                *uplo as u8,
+                *trans as u8,
+                *diag as u8,
+                *n,
+                Storage::Band(Matrix::new(a as *const _, *lda), *k),
+                x as *mut _,
+                *incx,
+                $solve,
+            )
+        }
+    };
+}
+
+tbmv!(stbmv_, f32, c_float, false);
+tbmv!(dtbmv_, f64, c_double, false);
+tbmv!(ctbmv_, c32, c_float_complex, false);
+tbmv!(ztbmv_, c64, c_double_complex, false);
+
+tbmv!(stbsv_, f32, c_float, true);
+tbmv!(dtbsv_, f64, c_double, true);
+tbmv!(ctbsv_, c32, c_float_complex, true);
+tbmv!(ztbsv_, c64, c_double_complex, true);
+
+macro_rules! tpmv {
+    ($name:ident, $ty:ty, $raw:ty, $solve:expr) => {
+        pub unsafe fn $name(
+            uplo: *const c_char,
+            trans: *const c_char,
+            diag: *const c_char,
+            n: *const c_int,
+            ap: *const $raw,
+            x: *mut $raw,
+            incx: *const c_int,
+        ) {
+            trmv::<$ty>(
+                stringify!($name),
+                *uplo as u8,
+                *trans as u8,
+                *diag as u8,
+                *n,
+                Storage::Packed(ap as *mut _, *n),
+                x as *mut _,
+                *incx,
+                $solve,
+            )
+        }
+    };
+}
+
+tpmv!(stpmv_, f32, c_float, false);
+tpmv!(dtpmv_, f64, c_double, false);
+tpmv!(ctpmv_, c32, c_float_complex, false);
+tpmv!(ztpmv_, c64, c_double_complex, false);
+
+tpmv!(stpsv_, f32, c_float, true);
+tpmv!(dtpsv_, f64, c_double, true);
+tpmv!(ctpsv_, c32, c_float_complex, true);
+tpmv!(ztpsv_, c64, c_double_complex, true);
+
+macro_rules! ger {
+    ($name:ident, $ty:ty, $raw:ty, $conjugate:expr) => {
+        pub unsafe fn $name(
+            m: *const c_int,
+            n: *const c_int,
+            alpha: *const $raw,
+            x: *const $raw,
+            incx: *const c_int,
+            y: *const $raw,
+            incy: *const c_int,
+            a: *mut $raw,
+            lda: *const c_int,
+        ) {
+            ger::<$ty>(
+                stringify!($name),
+                *m,
+                *n,
+                *(alpha as *const $ty),
+                x as *const _,
+                *incx,
+                y as *const _,
+                *incy,
+                a as *mut _,
+                *lda,
+                $conjugate,
+            )
+        }
+    };
+}
+
+ger!(sger_, f32, c_float, false);
+ger!(dger_, f64, c_double, false);
+ger!(cgeru_, c32, c_float_complex, false);
+ger!(cgerc_, c32, c_float_complex, true);
+ger!(zgeru_, c64, c_double_complex, false);
+ger!(zgerc_, c64, c_double_complex, true);
+
+macro_rules! syr {
+    ($name:ident, $ty:ty, $real:ty, $raw:ty, $hermitian:expr) => {
+        pub unsafe fn $name(
+            uplo: *const c_char,
+            n: *const c_int,
+            alpha: *const $real,
+            x: *const $raw,
+            incx: *const c_int,
+            a: *mut $raw,
+            lda: *const c_int,
+        ) {
+            syr::<$ty>(
+                stringify!($name),
+                *uplo as u8,
+                *n,
+                Number::from_real(*alpha),
+                x as *const _,
+                *incx,
+                None,
+                Storage::Full(Matrix::new(a as *const _, *lda)),
+                $hermitian,
+            )
+        }
+    };
+}
+
+syr!(ssyr_, f32, c_float, c_float, false);
+syr!(dsyr_, f64, c_double, c_double, false);
+syr!(cher_, c32, c_float, c_float_complex, true);
+syr!(zher_, c64, c_double, c_double_complex, true);
+
+macro_rules! spr {
+    ($name:ident, $ty:ty, $real:ty, $raw:ty, $hermitian:expr) => {
+        pub unsafe fn $name(
+            uplo: *const c_char,
+            n: *const c_int,
+            alpha: *const $real,
+            x: *const $raw,
+            incx: *const c_int,
+            ap: *mut $raw,
+        ) {
+            syr::<$ty>(
+                stringify!($name),
+                *uplo as u8,
+                *n,
+                Number::from_real(*alpha),
+                x as *const _,
+                *incx,
+                None,
+                Storage::Packed(ap as *mut _, *n),
+                $hermitian,
+            )
+        }
+    };
+}
+
+spr!(sspr_, f32, c_float, c_float, false);
+spr!(dspr_, f64, c_double, c_double, false);
+spr!(chpr_, c32, c_float, c_float_complex, true);
+spr!(zhpr_, c64, c_double, c_double_complex, true);
+
+macro_rules! syr2 {
+    ($name:ident, $ty:ty, $raw:ty, $hermitian:expr) => {
+        pub unsafe fn $name(
+            uplo: *const c_char,
+            n: *const c_int,
+            alpha: *const $raw,
+            x: *const $raw,
+            incx: *const c_int,
+            y: *const $raw,
+            incy: *const c_int,
+            a: *mut $raw,
+            lda: *const c_int,
+        ) {
+            syr::<$ty>(
+                stringify!($name),
+                *uplo as u8,
+                *n,
+                *(alpha as *const $ty),
+                x as *const _,
+                *incx,
+                Some((y as *const _, *incy)),
+                Storage::Full(Matrix::new(a as *const _, *lda)),
+                $hermitian,
+            )
+        }
+    };
+}
+
+syr2!(ssyr2_, f32, c_float, false);
+syr2!(dsyr2_, f64, c_double, false);
+syr2!(cher2_, c32, c_float_complex, true);
+syr2!(zher2_, c64, c_double_complex, true);
+
+macro_rules! spr2 {
+    ($name:ident, $ty:ty, $raw:ty, $hermitian:expr) => {
+        pub unsafe fn $name(
+            uplo: *const c_char,
+            n: *const c_int,
+            alpha: *const $raw,
+            x: *const $raw,
+            incx: *const c_int,
+            y: *const $raw,
+            incy: *const c_int,
+            ap: *mut $raw,
+        ) {
+            syr::<$ty>(
+                stringify!($name),
+                *uplo as u8,
+                *n,
+                *(alpha as *const $ty),
+                x as *const _,
+                *incx,
+                Some((y as *const _, *incy)),
+                Storage::Packed(ap as *mut _, *n),
+                $hermitian,
+            )
+        }
+    };
+}
+
+spr2!(sspr2_, f32, c_float, false);
+spr2!(dspr2_, f64, c_double, false);
+spr2!(chpr2_, c32, c_float_complex, true);
+spr2!(zhpr2_, c64, c_double_complex, true);
+
+#[cfg(test)]
+mod tests {
+    use super::super::dense::*;
+    use {c64, Diag, Transpose, Uplo};
+
+    fn column(x: &[c64]) -> Dense {
+        Dense::column(x.to_vec())
+    }
+
+    /// Return the dimensions of `op(a)` for an `m`-by-`n` `a`.
+    fn shape(trans: Transpose, m: usize, n: usize) -> (usize, usize) {
+        if trans == Transpose::No {
+            (m, n)
+        } else {
+            (n, m)
+        }
+    }
+
+    #[test]
+    fn gemv() {
+        let (m, n, kl, ku) = (4, 5, 1, 2);
+        let (alpha, beta) = (c64::new(0.5, -1.0), c64::new(-2.0, 0.5));
+        let a = Dense::new(m, n, 1.0);
+        for &trans in &TRANSPOSES {
+            let (rows, cols) = shape(trans, m, n);
+            let (x, y) = (elements(cols, 2.0), elements(rows, 3.0));
+
+            let mut v = store(&y, 3);
+            unsafe {
+                ::zgemv(
+                    trans,
+                    4,
+                    5,
+                    alpha,
+                    &a.store(6),
+                    6,
+                    &store(&x, -2),
+                    -2,
+                    beta,
+                    &mut v,
+                    3,
+                )
+            };
+            let expected = a.op(trans).mul(alpha, &column(&x), beta, &column(&y));
+            assert_close(&v, &store(&expected.data, 3));
+
+            let mut v = store(&y, -1);
+            let band = a.store_band(kl, ku, 5);
+            unsafe {
+                ::zgbmv(
+                    trans,
+                    4,
+                    5,
+                    1,
+                    2,
+                    alpha,
+                    &band,
+                    5,
+                    &store(&x, 2),
+                    2,
+                    beta,
+                    &mut v,
+                    -1,
+                )
+            };
+            let expected = a
+                .band(kl, ku)
+                .op(trans)
+                .mul(alpha, &column(&x), beta, &column(&y));
+            assert_close(&v, &store(&expected.data, -1));
+
+            let mut v = real(&store(&y, 1));
+            let (x, band) = (real(&store(&x, -1)), real(&band));
+            unsafe { ::dgbmv(trans, 4, 5, 1, 2, 2.0, &band, 5, &x, -1, 0.5, &mut v, 1) };
+            let a = Dense::load(m, n, &complex(&real(&a.data)), m).band(kl, ku);
+            let x = complex(&real(&load(&complex(&x), cols, -1)));
+            let one = c64::new(1.0, 0.0);
+            let expected = a.op(trans).mul(
+                one * 2.0,
+                &column(&x),
+                one * 0.5,
+                &column(&complex(&real(&y))),
+            );
+            assert_close_real(&v, &real(&expected.data));
+        }
+    }
+
+    #[test]
+    fn gemv_quick_return() {
+        let a = Dense::new(2, 3, 1.0).store(2);
+        let x = elements(3, 2.0);
+        let nan = c64::new(f64::NAN, 0.0);
+        let zero = c64::new(0.0, 0.0);
+        let mut y = vec![nan; 2];
+        unsafe {
+            ::zgemv(Transpose::No, 0, 3, zero, &a, 2, &x, 1, zero, &mut y, 1);
+            ::zgemv(Transpose::No, 2, 0, zero, &a, 2, &x, 1, zero, &mut y, 1);
+            ::zgbmv(
+                Transpose::No,
+                2,
+                3,
+                0,
+                0,
+                zero,
+                &a,
+                1,
+                &x,
+                1,
+                c64::new(1.0, 0.0),
+                &mut y,
+                1,
+            );
+        }
+        assert!(y.iter().all(|y| y.re.is_nan()));
+        // With beta = 0, y is overwritten rather than scaled.
+        unsafe { ::zgemv(Transpose::No, 2, 3, zero, &a, 2, &x, 1, zero, &mut y, 1) };
+        assert_eq!(y, [zero; 2]);
+    }
+
+    #[test]
+    fn hermitian() {
+        let (n, k) = (4, 1);
+        let (alpha, beta) = (c64::new(0.5, -1.0), c64::new(-2.0, 0.5));
+        let a = Dense::new(n, n, 1.0);
+        let (x, y) = (elements(n, 2.0), elements(n, 3.0));
+        for &uplo in &UPLOS {
+            let full = a.hermitian(uplo, true);
+            let expected = full.mul(alpha, &column(&x), beta, &column(&y));
+            let mut v = store(&y, -2);
+            unsafe {
+                ::zhemv(
+                    uplo,
+                    4,
+                    alpha,
+                    &a.store(5),
+                    5,
+                    &store(&x, 3),
+                    3,
+                    beta,
+                    &mut v,
+                    -2,
+                )
+            };
+            assert_close(&v, &store(&expected.data, -2));
+            let mut v = store(&y, 1);
+            unsafe {
+                ::zhpmv(
+                    uplo,
+                    4,
+                    alpha,
+                    &a.store_packed(uplo),
+                    &store(&x, -1),
+                    -1,
+                    beta,
+                    &mut v,
+                    1,
+                )
+            };
+            assert_close(&v, &store(&expected.data, 1));
+
+            let (kl, ku) = if uplo == Uplo::Upper { (0, k) } else { (k, 0) };
+            let band = a.band(kl, ku).hermitian(uplo, true);
+            let expected = band.mul(alpha, &column(&x), beta, &column(&y));
+            let mut v = store(&y, 2);
+            unsafe {
+                ::zhbmv(
+                    uplo,
+                    4,
+                    1,
+                    alpha,
+                    &a.store_band(kl, ku, 3),
+                    3,
+                    &x,
+                    1,
+                    beta,
+                    &mut v,
+                    2,
+                )
+            };
+            assert_close(&v, &store(&expected.data, 2));
+
+            // The real routines use the symmetric matrix.
+            let b = Dense::load(n, n, &complex(&real(&a.data)), n);
+            let (x, y) = (complex(&real(&x)), complex(&real(&y)));
+            let one = c64::new(1.0, 0.0);
+            let full = b.hermitian(uplo, false);
+            let expected = real(
+                &full
+                    .mul(one * 2.0, &column(&x), one * -1.0, &column(&y))
+                    .data,
+            );
+            let (u, w) = (real(&store(&x, -2)), real(&y));
+            let mut v = w.clone();
+            unsafe { ::dsymv(uplo, 4, 2.0, &real(&b.store(4)), 4, &u, -2, -1.0, &mut v, 1) };
+            assert_close_real(&v, &expected);
+            let mut v = w.clone();
+            unsafe {
+                ::dspmv(
+                    uplo,
+                    4,
+                    2.0,
+                    &real(&b.store_packed(uplo)),
+                    &u,
+                    -2,
+                    -1.0,
+                    &mut v,
+                    1,
+                )
+            };
+            assert_close_real(&v, &expected);
+            let band = b.band(kl, ku).hermitian(uplo, false);
+            let expected = real(
+                &band
+                    .mul(one * 2.0, &column(&x), one * -1.0, &column(&y))
+                    .data,
+            );
+            let mut v = w.clone();
+            unsafe {
+                ::dsbmv(
+                    uplo,
+                    4,
+                    1,
+                    2.0,
+                    &real(&b.store_band(kl, ku, 2)),
+                    2,
+                    &u,
+                    -2,
+                    -1.0,
+                    &mut v,
+                    1,
+                )
+            };
+            assert_close_real(&v, &expected);
+        }
+    }
+
+    #[test]
+    fn triangular() {
+        let (n, k) = (4, 2);
+        let a = Dense::dominant(n, 1.0, 4.0);
+        let x = elements(n, 2.0);
+        for &uplo in &UPLOS {
+            let (kl, ku) = if uplo == Uplo::Upper { (0, k) } else { (k, 0) };
+            for &trans in &TRANSPOSES {
+                for &diag in &DIAGS {
+                    let full = a.triangular(uplo, diag).op(trans);
+                    let band = a.band(kl, ku).triangular(uplo, diag).op(trans);
+                    let zero = column(&[c64::new(0.0, 0.0); 4]);
+                    let one = c64::new(1.0, 0.0);
+                    let product = |t: &Dense, x: &[c64]| t.mul(one, &column(x), one, &zero).data;
+
+                    let mut u = store(&x, -2);
+                    unsafe { ::ztrmv(uplo, trans, diag, 4, &a.store(5), 5, &mut u, -2) };
+                    assert_close(&u, &store(&product(&full, &x), -2));
+                    let mut u = store(&x, 3);
+                    unsafe { ::ztpmv(uplo, trans, diag, 4, &a.store_packed(uplo), &mut u, 3) };
+                    assert_close(&u, &store(&product(&full, &x), 3));
+                    let mut u = store(&x, -1);
+                    unsafe {
+                        ::ztbmv(
+                            uplo,
+                            trans,
+                            diag,
+                            4,
+                            2,
+                            &a.store_band(kl, ku, 3),
+                            3,
+                            &mut u,
+                            -1,
+                        )
+                    };
+                    assert_close(&u, &store(&product(&band, &x), -1));
+
+                    let mut u = store(&x, 2);
+                    unsafe { ::ztrsv(uplo, trans, diag, 4, &a.store(4), 4, &mut u, 2) };
+                    assert_close(&product(&full, &load(&u, n, 2)), &x);
+                    let mut u = store(&x, -3);
+                    unsafe { ::ztpsv(uplo, trans, diag, 4, &a.store_packed(uplo), &mut u, -3) };
+                    assert_close(&product(&full, &load(&u, n, -3)), &x);
+                    let mut u = store(&x, 1);
+                    unsafe {
+                        ::ztbsv(
+                            uplo,
+                            trans,
+                            diag,
+                            4,
+                            2,
+                            &a.store_band(kl, ku, 4),
+                            4,
+                            &mut u,
+                            1,
+                        )
+                    };
+                    assert_close(&product(&band, &u), &x);
+
+                    let mut u = real(&store(&x, -2));
+                    let (b, ap) = (real(&a.store_band(kl, ku, 3)), real(&a.store_packed(uplo)));
+                    unsafe {
+                        ::dtbmv(uplo, trans, diag, 4, 2, &b, 3, &mut u, -2);
+                        ::dtbsv(uplo, trans, diag, 4, 2, &b, 3, &mut u, -2);
+                        ::dtpsv(uplo, trans, diag, 4, &ap, &mut u, -2);
+                        ::dtpmv(uplo, trans, diag, 4, &ap, &mut u, -2);
+                    }
+                    assert_close_real(&u, &real(&store(&x, -2)));
+                }
+            }
+        }
+        let mut u = x.clone();
+        unsafe {
+            ::ztrsv(
+                Uplo::Upper,
+                Transpose::No,
+                Diag::NonUnit,
+                0,
+                &[],
+                1,
+                &mut u,
+                1,
+            )
+        };
+        assert_eq!(u, x);
+    }
+
+    #[test]
+    fn ger() {
+        let (m, n) = (3, 4);
+        let alpha = c64::new(0.5, -1.0);
+        let a = Dense::new(m, n, 1.0);
+        let (x, y) = (elements(m, 2.0), elements(n, 3.0));
+        let conjugated = y.iter().map(|y| y.conj()).collect::<Vec<_>>();
+
+        let mut b = a.store(4);
+        unsafe { ::zgeru(3, 4, alpha, &store(&x, -2), -2, &store(&y, 3), 3, &mut b, 4) };
+        assert_close(&b, &a.add(&Dense::outer(alpha, &x, &y)).store(4));
+        let mut b = a.store(3);
+        unsafe { ::zgerc(3, 4, alpha, &x, 1, &store(&y, -1), -1, &mut b, 3) };
+        assert_close(&b, &a.add(&Dense::outer(alpha, &x, &conjugated)).store(3));
+
+        let mut b = real(&a.store(3));
+        unsafe { ::dger(3, 4, 2.0, &real(&store(&x, 2)), 2, &real(&y), 1, &mut b, 3) };
+        let (x, y) = (complex(&real(&x)), complex(&real(&y)));
+        let outer = Dense::outer(c64::new(2.0, 0.0), &x, &y);
+        assert_close_real(&b, &real(&a.add(&outer).store(3)));
+
+        let mut c = b.clone();
+        unsafe { ::dger(3, 4, 0.0, &real(&x), 1, &real(&y), 1, &mut c, 3) };
+        assert_eq!(b, c);
+    }
+
+    #[test]
+    fn rank() {
+        let n = 4;
+        let alpha = c64::new(0.5, -1.0);
+        let a = Dense::new(n, n, 1.0);
+        let (x, y) = (elements(n, 2.0), elements(n, 3.0));
+        let conjugate = |x: &[c64]| x.iter().map(|x| x.conj()).collect::<Vec<_>>();
+        for &uplo in &UPLOS {
+            let full = a.hermitian(uplo, true);
+            let update = full.add(&Dense::outer(c64::new(2.0, 0.0), &x, &conjugate(&x)));
+            let expected = a.merge(uplo, &update);
+            let mut b = a.store(5);
+            unsafe { ::zher(uplo, 4, 2.0, &store(&x, -2), -2, &mut b, 5) };
+            assert_close(&b, &expected.store(5));
+            let mut ap = a.store_packed(uplo);
+            unsafe { ::zhpr(uplo, 4, 2.0, &store(&x, 3), 3, &mut ap) };
+            assert_close(&ap, &expected.store_packed(uplo));
+
+            let update = full
+                .add(&Dense::outer(alpha, &x, &conjugate(&y)))
+                .add(&Dense::outer(alpha.conj(), &y, &conjugate(&x)));
+            let expected = a.merge(uplo, &update);
+            let mut b = a.store(4);
+            unsafe {
+                ::zher2(
+                    uplo,
+                    4,
+                    alpha,
+                    &store(&x, 2),
+                    2,
+                    &store(&y, -1),
+                    -1,
+                    &mut b,
+                    4,
+                )
+            };
+            assert_close(&b, &expected.store(4));
+            let mut ap = a.store_packed(uplo);
+            unsafe { ::zhpr2(uplo, 4, alpha, &x, 1, &store(&y, -3), -3, &mut ap) };
+            assert_close(&ap, &expected.store_packed(uplo));
+
+            // The real routines update the symmetric matrix.
+            let b = Dense::load(n, n, &complex(&real(&a.data)), n);
+            let (u, v) = (complex(&real(&x)), complex(&real(&y)));
+            let (two, half) = (c64::new(2.0, 0.0), c64::new(0.5, 0.0));
+            let full = b.hermitian(uplo, false);
+            let expected = b.merge(uplo, &full.add(&Dense::outer(two, &u, &u)));
+            let mut c = real(&b.store(4));
+            unsafe { ::dsyr(uplo, 4, 2.0, &real(&store(&u, -2)), -2, &mut c, 4) };
+            assert_close_real(&c, &real(&expected.store(4)));
+            let mut ap = real(&b.store_packed(uplo));
+            unsafe { ::dspr(uplo, 4, 2.0, &real(&u), 1, &mut ap) };
+            assert_close_real(&ap, &real(&expected.store_packed(uplo)));
+            let update = full
+                .add(&Dense::outer(half, &u, &v))
+                .add(&Dense::outer(half, &v, &u));
+            let expected = b.merge(uplo, &update);
+            let mut c = real(&b.store(5));
+            unsafe {
+                ::dsyr2(
+                    uplo,
+                    4,
+                    0.5,
+                    &real(&u),
+                    1,
+                    &real(&store(&v, -2)),
+                    -2,
+                    &mut c,
+                    5,
+                )
+            };
+            assert_close_real(&c, &real(&expected.store(5)));
+            let mut ap = real(&b.store_packed(uplo));
+            unsafe { ::dspr2(uplo, 4, 0.5, &real(&store(&u, 2)), 2, &real(&v), 1, &mut ap) };
+            assert_close_real(&ap, &real(&expected.store_packed(uplo)));
+
+            // A zero alpha leaves even the imaginary parts of the diagonal.
+            let mut b = a.store(4);
+            unsafe { ::zher(uplo, 4, 0.0, &x, 1, &mut b, 4) };
+            unsafe { ::zher2(uplo, 0, alpha, &x, 1, &y, 1, &mut b, 4) };
+            assert_eq!(b, a.store(4));
+        }
+    }
+}
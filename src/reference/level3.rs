@@ -0,0 +1,1179 @@
+use std::cmp::max;
+
+use blas_sys::{c_double_complex, c_float_complex};
+use libc::{c_char, c_double, c_float, c_int};
+
+use super::{lsame, xerbla, Matrix, Number};
+use {c32, c64};
+
+/// Return the conjugate if `conjugate` is set.
+#[inline]
+fn cj<T: Number>(value: T, conjugate: bool) -> T {
+    if conjugate {
+        value.conj()
+    } else {
+        value
+    }
+}
+
+/// Return the real part if `hermitian` is set, as the diagonal of a
+/// Hermitian matrix is real.
+#[inline]
+fn diagonal<T: Number>(value: T, hermitian: bool) -> T {
+    if hermitian {
+        T::from_real(value.re())
+    } else {
+        value
+    }
+}
+
+/// Return `beta * value`, which is zero if `beta` is zero.
+#[inline]
+fn scaled<T: Number>(beta: T, value: T) -> T {
+    if beta == T::ZERO {
+        T::ZERO
+    } else {
+        beta * value
+    }
+}
+
+/// Compute `c := alpha * op(a) * op(b) + beta * c`.
+unsafe fn gemm<T: Number>(
+    routine: &str,
+    transa: u8,
+    transb: u8,
+    m: i32,
+    n: i32,
+    k: i32,
+    alpha: T,
+    a: *const T,
+    lda: i32,
+    b: *const T,
+    ldb: i32,
+    beta: T,
+    c: *mut T,
+    ldc: i32,
+) {
+    let nota = lsame(transa, b'N');
+    let notb = lsame(transb, b'N');
+    let conja = lsame(transa, b'C');
+    let conjb = lsame(transb, b'C');
+    let nrowa = if nota { m } else { k };
+    let nrowb = if notb { k } else { n };
+    let mut info = 0;
+    if !nota && !conja && !lsame(transa, b'T') {
+        info = 1;
+    } else if !notb && !conjb && !lsame(transb, b'T') {
+        info = 2;
+    } else if m < 0 {
+        info = 3;
+    } else if n < 0 {
+        info = 4;
+    } else if k < 0 {
+        info = 5;
+    } else if lda < max(1, nrowa) {
+        info = 8;
+    } else if ldb < max(1, nrowb) {
+        info = 10;
+    } else if ldc < max(1, m) {
+        info = 13;
+    }
+    if info != 0 {
+        return xerbla(routine, info);
+    }
+    if m == 0 || n == 0 || ((alpha == T::ZERO || k == 0) && beta == T::ONE) {
+        return;
+    }
+    let (a, b, c) = (
+        Matrix::new(a, lda),
+        Matrix::new(b, ldb),
+        Matrix::new(c, ldc),
+    );
+    let opb = |l: i32, j: i32| {
+        if notb {
+            b.get(l, j)
+        } else {
+            cj(b.get(j, l), conjb)
+        }
+    };
+    for j in 0..n {
+        if alpha == T::ZERO {
+            for i in 0..m {
+                c.set(i, j, scaled(beta, c.get(i, j)));
+            }
+        } else if nota {
+            if beta != T::ONE {
+                for i in 0..m {
+                    c.set(i, j, scaled(beta, c.get(i, j)));
+                }
+            }
+            for l in 0..k {
+                let temp = alpha * opb(l, j);
+                for i in 0..m {
+                    c.set(i, j, c.get(i, j) + temp * a.get(i, l));
+                }
+            }
+        } else {
+            for i in 0..m {
+                let mut temp = T::ZERO;
+                for l in 0..k {
+                    temp = temp + cj(a.get(l, i), conja) * opb(l, j);
+                }
+                c.set(i, j, alpha * temp + scaled(beta, c.get(i, j)));
+            }
+        }
+    }
+}
+
+/// Compute `c := alpha * a * b + beta * c` or `c := alpha * b * a + beta * c`
+/// for a symmetric or Hermitian matrix `a`.
+unsafe fn symm<T: Number>(
+    routine: &str,
+    side: u8,
+    uplo: u8,
+    m: i32,
+    n: i32,
+    alpha: T,
+    a: *const T,
+    lda: i32,
+    b: *const T,
+    ldb: i32,
+    beta: T,
+    c: *mut T,
+    ldc: i32,
+    hermitian: bool,
+) {
+    let left = lsame(side, b'L');
+    let upper = lsame(uplo, b'U');
+    let nrowa = if left { m } else { n };
+    let mut info = 0;
+    if !left && !lsame(side, b'R') {
+        info = 1;
+    } else if !upper && !lsame(uplo, b'L') {
+        info = 2;
+    } else if m < 0 {
+        info = 3;
+    } else if n < 0 {
+        info = 4;
+    } else if lda < max(1, nrowa) {
+        info = 7;
+    } else if ldb < max(1, m) {
+        info = 9;
+    } else if ldc < max(1, m) {
+        info = 12;
+    }
+    if info != 0 {
+        return xerbla(routine, info);
+    }
+    if m == 0 || n == 0 || (alpha == T::ZERO && beta == T::ONE) {
+        return;
+    }
+    let (a, b, c) = (
+        Matrix::new(a, lda),
+        Matrix::new(b, ldb),
+        Matrix::new(c, ldc),
+    );
+    if alpha == T::ZERO {
+        for j in 0..n {
+            for i in 0..m {
+                c.set(i, j, scaled(beta, c.get(i, j)));
+            }
+        }
+        return;
+    }
+    // Return the element of a in row i and column j using the stored
+    // triangle.
+    let element = |i: i32, j: i32| {
+        if (i <= j) == upper {
+            a.get(i, j)
+        } else {
+            cj(a.get(j, i), hermitian)
+        }
+    };
+    if left {
+        for j in 0..n {
+            for step in 0..m {
+                let i = if upper { step } else { m - 1 - step };
+                let temp1 = alpha * b.get(i, j);
+                let mut temp2 = T::ZERO;
+                let others = if upper { 0..i } else { (i + 1)..m };
+                for l in others {
+                    c.set(l, j, c.get(l, j) + temp1 * a.get(l, i));
+                    temp2 = temp2 + b.get(l, j) * cj(a.get(l, i), hermitian);
+                }
+                let value = diagonal(a.get(i, i), hermitian);
+                c.set(
+                    i,
+                    j,
+                    scaled(beta, c.get(i, j)) + temp1 * value + alpha * temp2,
+                );
+            }
+        }
+    } else {
+        for j in 0..n {
+            let temp = alpha * diagonal(a.get(j, j), hermitian);
+            for i in 0..m {
+                c.set(i, j, scaled(beta, c.get(i, j)) + temp * b.get(i, j));
+            }
+            for l in (0..n).filter(|&l| l != j) {
+                let temp = alpha * element(l, j);
+                for i in 0..m {
+                    c.set(i, j, c.get(i, j) + temp * b.get(i, l));
+                }
+            }
+        }
+    }
+}
+
+/// Compute `c := alpha * op(a) * op(a)^T + beta * c` or, if `b` is given,
+/// `c := alpha * op(a) * op(b)^T + op(alpha) * op(b) * op(a)^T + beta * c`
+/// for a symmetric or Hermitian matrix `c`, where the transposition is
+/// conjugate in the Hermitian case.
+unsafe fn syrk<T: Number>(
+    routine: &str,
+    uplo: u8,
+    trans: u8,
+    n: i32,
+    k: i32,
+    alpha: T,
+    a: *const T,
+    lda: i32,
+    b: Option<(*const T, i32)>,
+    beta: T,
+    c: *mut T,
+    ldc: i32,
+    allowed: &[u8],
+    hermitian: bool,
+) {
+    let upper = lsame(uplo, b'U');
+    let notrans = lsame(trans, b'N');
+    let nrowa = if notrans { n } else { k };
+    let shift = if b.is_some() { 2 } else { 0 };
+    let mut info = 0;
+    if !upper && !lsame(uplo, b'L') {
+        info = 1;
+    } else if !allowed.iter().any(|&letter| lsame(trans, letter)) {
+        info = 2;
+    } else if n < 0 {
+        info = 3;
+    } else if k < 0 {
+        info = 4;
+    } else if lda < max(1, nrowa) {
+        info = 7;
+    } else if b.is_some_and(|(_, ldb)| ldb < max(1, nrowa)) {
+        info = 9;
+    } else if ldc < max(1, n) {
+        info = 10 + shift;
+    }
+    if info != 0 {
+        return xerbla(routine, info);
+    }
+    if n == 0 || ((alpha == T::ZERO || k == 0) && beta == T::ONE) {
+        return;
+    }
+    let (a, c) = (Matrix::new(a, lda), Matrix::new(c, ldc));
+    let b = b.map(|(b, ldb)| Matrix::new(b, ldb));
+    for j in 0..n {
+        let rows = if upper { 0..(j + 1) } else { j..n };
+        if alpha == T::ZERO {
+            for i in rows {
+                c.set(i, j, scaled(beta, c.get(i, j)));
+            }
+        } else if notrans {
+            if beta != T::ONE {
+                for i in rows.clone() {
+                    c.set(i, j, scaled(beta, c.get(i, j)));
+                }
+            }
+            for l in 0..k {
+                let ajl = a.get(j, l);
+                match b {
+                    None if ajl != T::ZERO => {
+                        let temp = alpha * cj(ajl, hermitian);
+                        for i in rows.clone() {
+                            c.set(i, j, c.get(i, j) + temp * a.get(i, l));
+                        }
+                    }
+                    Some(b) if ajl != T::ZERO || b.get(j, l) != T::ZERO => {
+                        let temp1 = alpha * cj(b.get(j, l), hermitian);
+                        let temp2 = cj(alpha * ajl, hermitian);
+                        for i in rows.clone() {
+                            let update = a.get(i, l) * temp1 + b.get(i, l) * temp2;
+                            c.set(i, j, c.get(i, j) + update);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        } else {
+            for i in rows {
+                let mut temp1 = T::ZERO;
+                let mut temp2 = T::ZERO;
+                for l in 0..k {
+                    match b {
+                        None => temp1 = temp1 + cj(a.get(l, i), hermitian) * a.get(l, j),
+                        Some(b) => {
+                            temp1 = temp1 + cj(a.get(l, i), hermitian) * b.get(l, j);
+                            temp2 = temp2 + cj(b.get(l, i), hermitian) * a.get(l, j);
+                        }
+                    }
+                }
+                let value = match b {
+                    None => alpha * temp1,
+                    Some(_) => alpha * temp1 + cj(alpha, hermitian) * temp2,
+                };
+                c.set(i, j, value + scaled(beta, c.get(i, j)));
+            }
+        }
+        c.set(j, j, diagonal(c.get(j, j), hermitian));
+    }
+}
+
+/// Compute `b := alpha * op(a) * b` or `b := alpha * b * op(a)`, or solve
+/// `op(a) * x = alpha * b` or `x * op(a) = alpha * b` overwriting `b` with
+/// `x`, for a triangular matrix `a`.
+unsafe fn trmm<T: Number>(
+    routine: &str,
+    side: u8,
+    uplo: u8,
+    transa: u8,
+    diag: u8,
+    m: i32,
+    n: i32,
+    alpha: T,
+    a: *const T,
+    lda: i32,
+    b: *mut T,
+    ldb: i32,
+    solve: bool,
+) {
+    let left = lsame(side, b'L');
+    let upper = lsame(uplo, b'U');
+    let notrans = lsame(transa, b'N');
+    let conjugate = lsame(transa, b'C');
+    let nounit = lsame(diag, b'N');
+    let nrowa = if left { m } else { n };
+    let mut info = 0;
+    if !left && !lsame(side, b'R') {
+        info = 1;
+    } else if !upper && !lsame(uplo, b'L') {
+        info = 2;
+    } else if !notrans && !conjugate && !lsame(transa, b'T') {
+        info = 3;
+    } else if !nounit && !lsame(diag, b'U') {
+        info = 4;
+    } else if m < 0 {
+        info = 5;
+    } else if n < 0 {
+        info = 6;
+    } else if lda < max(1, nrowa) {
+        info = 9;
+    } else if ldb < max(1, m) {
+        info = 11;
+    }
+    if info != 0 {
+        return xerbla(routine, info);
+    }
+    if m == 0 || n == 0 {
+        return;
+    }
+    let (a, b) = (Matrix::new(a, lda), Matrix::new(b, ldb));
+    if alpha == T::ZERO {
+        for j in 0..n {
+            for i in 0..m {
+                b.set(i, j, T::ZERO);
+            }
+        }
+        return;
+    }
+    // Return the element of op(a) in row i and column j.
+    let element = |i: i32, j: i32| {
+        if notrans {
+            a.get(i, j)
+        } else {
+            cj(a.get(j, i), conjugate)
+        }
+    };
+    // Check if op(a) is upper triangular.
+    let upper = upper == notrans;
+    // Return the indices of the strictly upper or lower part of a column or
+    // row.
+    let part = |upper: bool, order: i32, index: i32| {
+        if upper {
+            0..index
+        } else {
+            (index + 1)..order
+        }
+    };
+    if left {
+        // Treat each column of b as a triangular system of order m.
+        let ascending = upper != solve;
+        for j in 0..n {
+            for step in 0..m {
+                let i = if ascending { step } else { m - 1 - step };
+                let mut temp = if solve {
+                    alpha * b.get(i, j)
+                } else {
+                    b.get(i, j)
+                };
+                if !solve && nounit {
+                    temp = temp * element(i, i);
+                }
+                // Row i of op(a) multiplied by column j of b.
+                for l in part(!upper, m, i) {
+                    if solve {
+                        temp = temp - element(i, l) * b.get(l, j);
+                    } else {
+                        temp = temp + element(i, l) * b.get(l, j);
+                    }
+                }
+                if solve && nounit {
+                    temp = temp / element(i, i);
+                }
+                b.set(i, j, if solve { temp } else { alpha * temp });
+            }
+        }
+    } else {
+        // Treat each row of b as a triangular system of order n.
+        let ascending = upper == solve;
+        for step in 0..n {
+            let j = if ascending { step } else { n - 1 - step };
+            let mut temp = alpha;
+            if nounit && !solve {
+                temp = temp * element(j, j);
+            }
+            for i in 0..m {
+                let mut value = b.get(i, j) * temp;
+                // Row i of b multiplied by column j of op(a).
+                for l in part(upper, n, j) {
+                    let factor = element(l, j);
+                    if factor != T::ZERO {
+                        if solve {
+                            value = value - b.get(i, l) * factor;
+                        } else {
+                            value = value + alpha * b.get(i, l) * factor;
+                        }
+                    }
+                }
+                if solve && nounit {
+                    value = value / element(j, j);
+                }
+                b.set(i, j, value);
+            }
+        }
+    }
+}
+
+macro_rules! gemm {
+    ($name:ident, $ty:ty, $raw:ty) => {
+        pub unsafe fn $name(
+            transa: *const c_char,
+            transb: *const c_char,
+            m: *const c_int,
+            n: *const c_int,
+            k: *const c_int,
+            alpha: *const $raw,
+            a: *const $raw,
+            lda: *const c_int,
+            b: *const $raw,
+            ldb: *const c_int,
+            beta: *const $raw,
+            c: *mut $raw,
+            ldc: *const c_int,
+        ) {
+            gemm::<$ty>(
+                stringify!($name),
+                *transa as u8,
+                *transb as u8,
+                *m,
+                *n,
+                *k,
+                *(alpha as *const $ty),
+                a as *const _,
+                *lda,
+                b as *const _,
+                *ldb,
+                *(beta as *const $ty),
+                c as *mut _,
+                *ldc,
+            )
+        }
+    };
+}
+
+gemm!(sgemm_, f32, c_float);
+gemm!(dgemm_, f64, c_double);
+gemm!(cgemm_, c32, c_float_complex);
+gemm!(zgemm_, c64, c_double_complex);
+
+macro_rules! symm {
+    ($name:ident, $ty:ty, $raw:ty, $hermitian:expr) => {
+        pub unsafe fn $name(
+            side: *const c_char,
+            uplo: *const c_char,
+            m: *const c_int,
+            n: *const c_int,
+            alpha: *const $raw,
+            a: *const $raw,
+            lda: *const c_int,
+            b: *const $raw,
+            ldb: *const c_int,
+            beta: *const $raw,
+            c: *mut $raw,
+            ldc: *const c_int,
+        ) {
+            symm::<$ty>(
+                stringify!($name),
+                *side as u8,
+                *uplo as u8,
+                *m,
+                *n,
+                *(alpha as *const $ty),
+                a as *const _,
+                *lda,
+                b as *const _,
+                *ldb,
+                *(beta as *const $ty),
+                c as *mut _,
+                *ldc,
+                $hermitian,
+            )
+        }
+    };
+}
+
+symm!(ssymm_, f32, c_float, false);
+symm!(dsymm_, f64, c_double, false);
+symm!(csymm_, c32, c_float_complex, false);
+symm!(zsymm_, c64, c_double_complex, false);
+symm!(chemm_, c32, c_float_complex, true);
+symm!(zhemm_, c64, c_double_complex, true);
+
+macro_rules! syrk {
+    ($name:ident, $ty:ty, $raw:ty, $allowed:expr) => {
+        pub unsafe fn $name(
+            uplo: *const c_char,
+            trans: *const c_char,
+            n: *const c_int,
+            k: *const c_int,
+            alpha: *const $raw,
+            a: *const $raw,
+            lda: *const c_int,
+            beta: *const $raw,
+            c: *mut $raw,
+            ldc: *const c_int,
+        ) {
+            syrk::<$ty>(
+                stringify!($name),
+                *uplo as u8,
+                *trans as u8,
+                *n,
+                *k,
+                *(alpha as *const $ty),
+                a as *const _,
+                *lda,
+                None,
+                *(beta as *const $ty),
+                c as *mut _,
+                *ldc,
+                $allowed,
+                false,
+            )
+        }
+    };
+}
+
+syrk!(ssyrk_, f32, c_float, b"NTC");
+syrk!(dsyrk_, f64, c_double, b"NTC");
+syrk!(csyrk_, c32, c_float_complex, b"NT");
+syrk!(zsyrk_, c64, c_double_complex, b"NT");
+
+macro_rules! herk {
+    ($name:ident, $ty:ty, $real:ty, $raw:ty) => {
+        pub unsafe fn $name(
+            uplo: *const c_char,
+            trans: *const c_char,
+            n: *const c_int,
+            k: *const c_int,
+            alpha: *const $real,
+            a: *const $raw,
+            lda: *const c_int,
+            beta: *const $real,
+            c: *mut $raw,
+            ldc: *const c_int,
+        ) {
+            syrk::<$ty>(
+                stringify!($name),
+                *uplo as u8,
+                *trans as u8,
+                *n,
+                *k,
+                Number::from_real(*alpha),
+                a as *const _,
+                *lda,
+                None,
+                Number::from_real(*beta),
+                c as *mut _,
+                *ldc,
+                b"NC",
+                true,
+            )
+        }
+    };
+}
+
+herk!(cherk_, c32, c_float, c_float_complex);
+herk!(zherk_, c64, c_double, c_double_complex);
+
+macro_rules! syr2k {
+    ($name:ident, $ty:ty, $raw:ty, $allowed:expr) => {
+        pub unsafe fn $name(
+            uplo: *const c_char,
+            trans: *const c_char,
+            n: *const c_int,
+            k: *const c_int,
+            alpha: *const $raw,
+            a: *const $raw,
+            lda: *const c_int,
+            b: *const $raw,
+            ldb: *const c_int,
+            beta: *const $raw,
+            c: *mut $raw,
+            ldc: *const c_int,
+        ) {
+            syrk::<$ty>(
+                stringify!($name),
+                *uplo as u8,
+                *trans as u8,
+                *n,
+                *k,
+                *(alpha as *const $ty),
+                a as *const _,
+                *lda,
+                Some((b as *const _, *ldb)),
+                *(beta as *const $ty),
+                c as *mut _,
+                *ldc,
+                $allowed,
+                false,
+            )
+        }
+    };
+}
+
+syr2k!(ssyr2k_, f32, c_float, b"NTC");
+syr2k!(dsyr2k_, f64, c_double, b"NTC");
+syr2k!(csyr2k_, c32, c_float_complex, b"NT");
+syr2k!(zsyr2k_, c64, c_double_complex, b"NT");
+
+macro_rules! her2k {
+    ($name:ident, $ty:ty, $real:ty, $raw:ty) => {
+        pub unsafe fn $name(
+            uplo: *const c_char,
+            trans: *const c_char,
+            n: *const c_int,
+            k: *const c_int,
+            alpha: *const $raw,
+            a: *const $raw,
+            lda: *const c_int,
+            b: *const $raw,
+            ldb: *const c_int,
+            beta: *const $real,
+            c: *mut $raw,
+            ldc: *const c_int,
+        ) {
+            syrk::<$ty>(
+                stringify!($name),
+                *uplo as u8,
+                *trans as u8,
+                *n,
+                *k,
+                *(alpha as *const $ty),
+                a as *const _,
+                *lda,
+                Some((b as *const _, *ldb)),
+                Number::from_real(*beta),
+                c as *mut _,
+                *ldc,
+                b"NC",
+                true,
+            )
+        }
+    };
+}
+
+her2k!(cher2k_, c32, c_float, c_float_complex);
+her2k!(zher2k_, c64, c_double, c_double_complex);
+
+macro_rules! trmm {
+    ($name:ident, $ty:ty, $raw:ty, $solve:expr) => {
+        pub unsafe fn $name(
+            side: *const c_char,
+            uplo: *const c_char,
+            transa: *const c_char,
+            diag: *const c_char,
+            m: *const c_int,
+            n: *const c_int,
+            alpha: *const $raw,
+            a: *const $raw,
+            lda: *const c_int,
+            b: *mut $raw,
+            ldb: *const c_int,
+        ) {
+            trmm::<$ty>(
+                stringify!($name),
+                *side as u8,
+                *uplo as u8,
+                *transa as u8,
+                *diag as u8,
+                *m,
+                *n,
+                *(alpha as *const $ty),
+                a as *const _,
+                *lda,
+                b as *mut _,
+                *ldb,
+                $solve,
+            )
+        }
+    };
+}
+
+trmm!(strmm_, f32, c_float, false);
+trmm!(dtrmm_, f64, c_double, false);
+trmm!(ctrmm_, c32, c_float_complex, false);
+trmm!(ztrmm_, c64, c_double_complex, false);
+
+trmm!(strsm_, f32, c_float, true);
+trmm!(dtrsm_, f64, c_double, true);
+trmm!(ctrsm_, c32, c_float_complex, true);
+trmm!(ztrsm_, c64, c_double_complex, true);
+
+#[cfg(test)]
+mod tests {
+    use super::super::dense::*;
+    use {c64, Side, Transpose};
+
+    /// Return the dimensions of `a` for an `m`-by-`n` `op(a)`.
+    fn shape(trans: Transpose, m: usize, n: usize) -> (usize, usize) {
+        if trans == Transpose::No {
+            (m, n)
+        } else {
+            (n, m)
+        }
+    }
+
+    fn zeros(rows: usize, cols: usize) -> Dense {
+        Dense {
+            rows,
+            cols,
+            data: vec![c64::new(0.0, 0.0); rows * cols],
+        }
+    }
+
+    #[test]
+    fn gemm() {
+        let (m, n, k) = (3, 2, 4);
+        let (alpha, beta) = (c64::new(0.5, -1.0), c64::new(-2.0, 0.5));
+        let c = Dense::new(m, n, 3.0);
+        for &transa in &TRANSPOSES {
+            for &transb in &TRANSPOSES {
+                let (rows, cols) = shape(transa, m, k);
+                let a = Dense::new(rows, cols, 1.0);
+                let (rows, cols) = shape(transb, k, n);
+                let b = Dense::new(rows, cols, 2.0);
+                let mut d = c.store(4);
+                unsafe {
+                    ::zgemm(
+                        transa,
+                        transb,
+                        3,
+                        2,
+                        4,
+                        alpha,
+                        &a.store(a.rows + 1),
+                        a.rows as i32 + 1,
+                        &b.store(b.rows),
+                        b.rows as i32,
+                        beta,
+                        &mut d,
+                        4,
+                    )
+                };
+                let expected = a.op(transa).mul(alpha, &b.op(transb), beta, &c);
+                assert_close(&d, &expected.store(4));
+            }
+        }
+    }
+
+    #[test]
+    fn gemm_quick_return() {
+        let (a, b) = (
+            Dense::new(3, 2, 1.0).store(3),
+            Dense::new(2, 2, 2.0).store(2),
+        );
+        let (zero, one) = (c64::new(0.0, 0.0), c64::new(1.0, 0.0));
+        let c = Dense::new(3, 2, 3.0);
+
+        // With k = 0 or alpha = 0, only c is scaled.
+        let beta = c64::new(0.0, 2.0);
+        let expected = c.data.iter().map(|&c| beta * c).collect::<Vec<_>>();
+        let mut d = c.store(3);
+        unsafe { ::zgemm(b'N', b'N', 3, 2, 0, one, &a, 3, &b, 1, beta, &mut d, 3) };
+        assert_close(&d, &expected);
+        let mut d = c.store(3);
+        unsafe { ::zgemm(b'N', b'N', 3, 2, 2, zero, &a, 3, &b, 2, beta, &mut d, 3) };
+        assert_close(&d, &expected);
+
+        // With beta = 0, c is overwritten rather than scaled.
+        let mut d = vec![c64::new(f64::NAN, f64::INFINITY); 6];
+        unsafe { ::zgemm(b'N', b'N', 3, 2, 0, one, &a, 3, &b, 1, zero, &mut d, 3) };
+        assert_eq!(d, [zero; 6]);
+
+        let mut d = c.store(3);
+        unsafe {
+            ::zgemm(b'N', b'N', 0, 2, 2, one, &a, 1, &b, 2, zero, &mut d, 1);
+            ::zgemm(b'N', b'N', 3, 2, 0, one, &a, 3, &b, 1, one, &mut d, 3);
+        }
+        assert_eq!(d, c.data);
+    }
+
+    #[test]
+    fn symm() {
+        let (m, n) = (3, 4);
+        let (alpha, beta) = (c64::new(0.5, -1.0), c64::new(-2.0, 0.5));
+        let (b, c) = (Dense::new(m, n, 2.0), Dense::new(m, n, 3.0));
+        for &side in &SIDES {
+            let order = if side == Side::Left { m } else { n };
+            let a = Dense::new(order, order, 1.0);
+            for &uplo in &UPLOS {
+                for &hermitian in &[false, true] {
+                    let full = a.hermitian(uplo, hermitian);
+                    let expected = if side == Side::Left {
+                        full.mul(alpha, &b, beta, &c)
+                    } else {
+                        b.mul(alpha, &full, beta, &c)
+                    };
+                    let (lda, mut d) = (order as i32 + 1, c.store(5));
+                    let a = a.store(order + 1);
+                    unsafe {
+                        if hermitian {
+                            ::zhemm(
+                                side,
+                                uplo,
+                                3,
+                                4,
+                                alpha,
+                                &a,
+                                lda,
+                                &b.store(3),
+                                3,
+                                beta,
+                                &mut d,
+                                5,
+                            )
+                        } else {
+                            ::zsymm(
+                                side,
+                                uplo,
+                                3,
+                                4,
+                                alpha,
+                                &a,
+                                lda,
+                                &b.store(3),
+                                3,
+                                beta,
+                                &mut d,
+                                5,
+                            )
+                        }
+                    };
+                    assert_close(&d, &expected.store(5));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn syrk() {
+        let (n, k) = (4, 3);
+        let (alpha, beta) = (c64::new(0.5, -1.0), c64::new(-2.0, 0.5));
+        let c = Dense::new(n, n, 3.0);
+        for &uplo in &UPLOS {
+            for &trans in &[Transpose::No, Transpose::Trans] {
+                let (rows, cols) = shape(trans, n, k);
+                let a = Dense::new(rows, cols, 1.0);
+                let product = a
+                    .op(trans)
+                    .mul(alpha, &a.op(trans).op(Transpose::Trans), beta, &c);
+                let mut d = c.store(n);
+                unsafe {
+                    ::zsyrk(
+                        uplo,
+                        trans,
+                        4,
+                        3,
+                        alpha,
+                        &a.store(rows),
+                        rows as i32,
+                        beta,
+                        &mut d,
+                        4,
+                    )
+                };
+                assert_close(&d, &c.merge(uplo, &product).store(n));
+
+                let b = Dense::new(rows, cols, 2.0);
+                let product = a
+                    .op(trans)
+                    .mul(alpha, &b.op(trans).op(Transpose::Trans), beta, &c)
+                    .add(&b.op(trans).mul(
+                        alpha,
+                        &a.op(trans).op(Transpose::Trans),
+                        beta * 0.0,
+                        &c,
+                    ));
+                let mut d = c.store(n + 1);
+                unsafe {
+                    ::zsyr2k(
+                        uplo,
+                        trans,
+                        4,
+                        3,
+                        alpha,
+                        &a.store(rows),
+                        rows as i32,
+                        &b.store(rows + 2),
+                        rows as i32 + 2,
+                        beta,
+                        &mut d,
+                        5,
+                    )
+                };
+                assert_close(&d, &c.merge(uplo, &product).store(n + 1));
+            }
+        }
+    }
+
+    #[test]
+    fn herk() {
+        let (n, k) = (4, 3);
+        let alpha = c64::new(0.5, -1.0);
+        let c = Dense::new(n, n, 3.0);
+        for &uplo in &UPLOS {
+            // The imaginary parts of the diagonal of c are ignored.
+            let hermitian = c.hermitian(uplo, true);
+            for &trans in &[Transpose::No, Transpose::ConjTrans] {
+                let (rows, cols) = shape(trans, n, k);
+                let a = Dense::new(rows, cols, 1.0);
+                let beta = c64::new(-2.0, 0.0);
+                let product = a.op(trans).mul(
+                    c64::new(0.5, 0.0),
+                    &a.op(trans).op(Transpose::ConjTrans),
+                    beta,
+                    &hermitian,
+                );
+                let mut d = c.store(n);
+                unsafe {
+                    ::zherk(
+                        uplo,
+                        trans,
+                        4,
+                        3,
+                        0.5,
+                        &a.store(rows),
+                        rows as i32,
+                        -2.0,
+                        &mut d,
+                        4,
+                    )
+                };
+                assert_close(&d, &c.merge(uplo, &product).store(n));
+
+                let b = Dense::new(rows, cols, 2.0);
+                let product = a
+                    .op(trans)
+                    .mul(
+                        alpha,
+                        &b.op(trans).op(Transpose::ConjTrans),
+                        beta,
+                        &hermitian,
+                    )
+                    .add(&b.op(trans).mul(
+                        alpha.conj(),
+                        &a.op(trans).op(Transpose::ConjTrans),
+                        beta * 0.0,
+                        &c,
+                    ));
+                let mut d = c.store(n);
+                unsafe {
+                    ::zher2k(
+                        uplo,
+                        trans,
+                        4,
+                        3,
+                        alpha,
+                        &a.store(rows),
+                        rows as i32,
+                        &b.store(rows),
+                        rows as i32,
+                        -2.0,
+                        &mut d,
+                        4,
+                    )
+                };
+                assert_close(&d, &c.merge(uplo, &product).store(n));
+            }
+
+            // With k = 0 and beta = 1, even the diagonal is left intact,
+            // whereas alpha = 0 makes it real.
+            let mut d = c.store(n);
+            unsafe { ::zherk(uplo, b'N', 4, 0, 0.5, &[], 4, 1.0, &mut d, 4) };
+            assert_eq!(d, c.data);
+            unsafe { ::zherk(uplo, b'N', 4, 3, 0.0, &c.data, 4, 2.0, &mut d, 4) };
+            let scaled = Dense::load(
+                n,
+                n,
+                &hermitian.data.iter().map(|&c| c * 2.0).collect::<Vec<_>>(),
+                n,
+            );
+            assert_close(&d, &c.merge(uplo, &scaled).data);
+        }
+    }
+
+    #[test]
+    fn syrk_real() {
+        let (n, k) = (3, 2);
+        let c = Dense::load(3, 3, &complex(&real(&Dense::new(n, n, 3.0).data)), 3);
+        for &uplo in &UPLOS {
+            // The transpose and the conjugate transpose are the same.
+            for &trans in &TRANSPOSES {
+                let (rows, cols) = shape(trans, n, k);
+                let a = Dense::load(
+                    rows,
+                    cols,
+                    &complex(&real(&Dense::new(rows, cols, 1.0).data)),
+                    rows,
+                );
+                let (alpha, beta) = (c64::new(2.0, 0.0), c64::new(-0.5, 0.0));
+                let product = a
+                    .op(trans)
+                    .mul(alpha, &a.op(trans).op(Transpose::Trans), beta, &c);
+                let mut d = real(&c.data);
+                unsafe {
+                    ::dsyrk(
+                        uplo,
+                        trans,
+                        3,
+                        2,
+                        2.0,
+                        &real(&a.data),
+                        rows as i32,
+                        -0.5,
+                        &mut d,
+                        3,
+                    )
+                };
+                assert_close_real(&d, &real(&c.merge(uplo, &product).data));
+
+                let mut e = real(&c.data);
+                unsafe {
+                    ::dsyr2k(
+                        uplo,
+                        trans,
+                        3,
+                        2,
+                        1.0,
+                        &real(&a.data),
+                        rows as i32,
+                        &real(&a.data),
+                        rows as i32,
+                        -0.5,
+                        &mut e,
+                        3,
+                    )
+                };
+                assert_close_real(&d, &e);
+            }
+        }
+    }
+
+    #[test]
+    fn trmm() {
+        let (m, n) = (3, 4);
+        let alpha = c64::new(0.5, -1.0);
+        let b = Dense::new(m, n, 2.0);
+        for &side in &SIDES {
+            let order = if side == Side::Left { m } else { n };
+            let a = Dense::dominant(order, 1.0, 4.0);
+            for &uplo in &UPLOS {
+                for &trans in &TRANSPOSES {
+                    for &diag in &DIAGS {
+                        let t = a.triangular(uplo, diag).op(trans);
+                        let zero = zeros(m, n);
+                        let product = |x: &Dense, alpha: c64| {
+                            if side == Side::Left {
+                                t.mul(alpha, x, alpha, &zero)
+                            } else {
+                                x.mul(alpha, &t, alpha, &zero)
+                            }
+                        };
+                        let lda = order as i32 + 1;
+                        let mut d = b.store(4);
+                        unsafe {
+                            ::ztrmm(
+                                side,
+                                uplo,
+                                trans,
+                                diag,
+                                3,
+                                4,
+                                alpha,
+                                &a.store(order + 1),
+                                lda,
+                                &mut d,
+                                4,
+                            )
+                        };
+                        assert_close(&d, &product(&b, alpha).store(4));
+
+                        let mut d = b.store(3);
+                        unsafe {
+                            ::ztrsm(
+                                side,
+                                uplo,
+                                trans,
+                                diag,
+                                3,
+                                4,
+                                alpha,
+                                &a.store(order + 1),
+                                lda,
+                                &mut d,
+                                3,
+                            )
+                        };
+                        let one = c64::new(1.0, 0.0);
+                        let scaled = b.data.iter().map(|&b| alpha * b).collect::<Vec<_>>();
+                        assert_close(&product(&Dense::load(m, n, &d, 3), one).data, &scaled);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn trmm_quick_return() {
+        let a = Dense::new(2, 2, 1.0).store(2);
+        let zero = c64::new(0.0, 0.0);
+        // With alpha = 0, b is overwritten with zeros.
+        let mut b = vec![c64::new(f64::NAN, 0.0); 6];
+        unsafe { ::ztrsm(b'L', b'U', b'N', b'N', 2, 3, zero, &a, 2, &mut b, 2) };
+        assert_eq!(b, [zero; 6]);
+        let mut b = elements(6, 2.0);
+        let c = b.clone();
+        unsafe { ::ztrmm(b'R', b'L', b'C', b'U', 0, 2, zero, &a, 2, &mut b, 1) };
+        assert_eq!(b, c);
+    }
+}
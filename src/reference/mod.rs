@@ -0,0 +1,238 @@
+//! Pure-Rust implementation.
+//!
+//! With the `reference` feature enabled, the wrappers at the root of the
+//! crate call the functions of this module instead of a native library, and
+//! no library needs to be linked. The functions have the same signatures as
+//! those declared by `blas-sys` and follow the reference implementation from
+//! Netlib, including the validation of the arguments. An illegal argument is
+//! reported via `xerbla` if the `xerbla` feature is enabled and causes a
+//! panic otherwise.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use error::xerbla;
+use {c32, c64};
+
+#[cfg(test)]
+mod dense;
+mod level1;
+mod level2;
+mod level3;
+
+pub use self::level1::*;
+pub use self::level2::*;
+pub use self::level3::*;
+
+/// A number supported by the implementation.
+pub trait Number:
+    Copy
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// The type of the real part.
+    type Real: Real;
+
+    /// The additive identity.
+    const ZERO: Self;
+
+    /// The multiplicative identity.
+    const ONE: Self;
+
+    /// Create a number from its real part.
+    fn from_real(value: Self::Real) -> Self;
+
+    /// Return the real part.
+    fn re(self) -> Self::Real;
+
+    /// Return the imaginary part.
+    fn im(self) -> Self::Real;
+
+    /// Return the complex conjugate.
+    fn conj(self) -> Self;
+
+    /// Return the modulus.
+    fn abs(self) -> Self::Real;
+
+    /// Return the sum of the absolute values of the real and imaginary
+    /// parts.
+    #[inline]
+    fn abs1(self) -> Self::Real {
+        self.re().abs() + self.im().abs()
+    }
+}
+
+/// A real number supported by the implementation.
+pub trait Real: Number<Real = Self> + PartialOrd {
+    /// Convert a double-precision number.
+    fn from_f64(value: f64) -> Self;
+
+    /// Return the square root.
+    fn sqrt(self) -> Self;
+}
+
+macro_rules! real {
+    ($ty:ty) => {
+        impl Number for $ty {
+            type Real = $ty;
+
+            const ZERO: $ty = 0.0;
+            const ONE: $ty = 1.0;
+
+            #[inline]
+            fn from_real(value: $ty) -> $ty {
+                value
+            }
+
+            #[inline]
+            fn re(self) -> $ty {
+                self
+            }
+
+            #[inline]
+            fn im(self) -> $ty {
+                0.0
+            }
+
+            #[inline]
+            fn conj(self) -> $ty {
+                self
+            }
+
+            #[inline]
+            fn abs(self) -> $ty {
+                <$ty>::abs(self)
+            }
+        }
+
+        impl Real for $ty {
+            #[inline]
+            fn from_f64(value: f64) -> $ty {
+                value as $ty
+            }
+
+            #[inline]
+            fn sqrt(self) -> $ty {
+                <$ty>::sqrt(self)
+            }
+        }
+    };
+}
+
+real!(f32);
+real!(f64);
+
+macro_rules! complex {
+    ($ty:ty, $real:ty) => {
+        impl Number for $ty {
+            type Real = $real;
+
+            const ZERO: $ty = <$ty>::new(0.0, 0.0);
+            const ONE: $ty = <$ty>::new(1.0, 0.0);
+
+            #[inline]
+            fn from_real(value: $real) -> $ty {
+                <$ty>::new(value, 0.0)
+            }
+
+            #[inline]
+            fn re(self) -> $real {
+                self.re
+            }
+
+            #[inline]
+            fn im(self) -> $real {
+                self.im
+            }
+
+            #[inline]
+            fn conj(self) -> $ty {
+                <$ty>::conj(&self)
+            }
+
+            #[inline]
+            fn abs(self) -> $real {
+                self.re.hypot(self.im)
+            }
+        }
+    };
+}
+
+complex!(c32, f32);
+complex!(c64, f64);
+
+/// Check if a flag matches a letter regardless of the case.
+#[inline]
+fn lsame(flag: u8, letter: u8) -> bool {
+    flag.eq_ignore_ascii_case(&letter)
+}
+
+/// Return the position of the first element of a vector relative to the
+/// pointer passed to BLAS.
+#[inline]
+fn first(n: i32, inc: i32) -> isize {
+    if inc < 0 {
+        (1 - n as isize) * inc as isize
+    } else {
+        0
+    }
+}
+
+/// A vector as seen by BLAS.
+#[derive(Clone, Copy)]
+struct Vector<T> {
+    pointer: *mut T,
+    start: isize,
+    inc: isize,
+}
+
+impl<T: Copy> Vector<T> {
+    #[inline]
+    fn new(n: i32, pointer: *const T, inc: i32) -> Self {
+        Vector {
+            pointer: pointer as *mut T,
+            start: first(n, inc),
+            inc: inc as isize,
+        }
+    }
+
+    #[inline]
+    unsafe fn get(&self, i: i32) -> T {
+        *self.pointer.offset(self.start + i as isize * self.inc)
+    }
+
+    #[inline]
+    unsafe fn set(&self, i: i32, value: T) {
+        *self.pointer.offset(self.start + i as isize * self.inc) = value;
+    }
+}
+
+/// A matrix stored in column-major order.
+#[derive(Clone, Copy)]
+struct Matrix<T> {
+    pointer: *mut T,
+    ld: isize,
+}
+
+impl<T: Copy> Matrix<T> {
+    #[inline]
+    fn new(pointer: *const T, ld: i32) -> Self {
+        Matrix {
+            pointer: pointer as *mut T,
+            ld: ld as isize,
+        }
+    }
+
+    #[inline]
+    unsafe fn get(&self, i: i32, j: i32) -> T {
+        *self.pointer.offset(i as isize + j as isize * self.ld)
+    }
+
+    #[inline]
+    unsafe fn set(&self, i: i32, j: i32, value: T) {
+        *self.pointer.offset(i as isize + j as isize * self.ld) = value;
+    }
+}
@@ -0,0 +1,989 @@
+use super::{conjugate, conjugated, flip_uplo, invert, row_major_len};
+use checked::{packed_len, Check};
+use {c32, c64, Blas, Diag, Result, Transpose, Uplo};
+
+macro_rules! gemv {
+    ($name:ident, $ty:ty, $complex:expr) => {
+        #[doc = concat!("Row-major [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            trans: Transpose,
+            m: i32,
+            n: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            x: &[$ty],
+            incx: i32,
+            beta: $ty,
+            y: &mut [$ty],
+            incy: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(2, "m", m)?;
+            check.dimension(3, "n", n)?;
+            check.leading(6, "lda", lda, n.max(1), "must be at least max(1, n)")?;
+            check.increment(8, "incx", incx)?;
+            check.increment(11, "incy", incy)?;
+            let (lenx, leny) = if trans == Transpose::No {
+                (n, m)
+            } else {
+                (m, n)
+            };
+            check.length(5, "a", a.len(), row_major_len(m, n, lda))?;
+            check.vector(7, "x", x.len(), lenx, incx)?;
+            check.vector(10, "y", y.len(), leny, incy)?;
+            unsafe {
+                if $complex && trans == Transpose::ConjTrans {
+                    let x = conjugated(lenx, x, incx);
+                    conjugate(leny, y, incy);
+                    ::$name(
+                        Transpose::No,
+                        n,
+                        m,
+                        Blas::conj(alpha),
+                        a,
+                        lda,
+                        &x,
+                        1,
+                        Blas::conj(beta),
+                        y,
+                        incy,
+                    );
+                    conjugate(leny, y, incy);
+                } else {
+                    let trans = invert(trans, false);
+                    ::$name(trans, n, m, alpha, a, lda, x, incx, beta, y, incy);
+                }
+            }
+            check.finish()
+        }
+    };
+}
+
+gemv!(sgemv, f32, false);
+gemv!(dgemv, f64, false);
+gemv!(cgemv, c32, true);
+gemv!(zgemv, c64, true);
+
+macro_rules! gbmv {
+    ($name:ident, $ty:ty, $complex:expr) => {
+        #[doc = concat!("Row-major [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            trans: Transpose,
+            m: i32,
+            n: i32,
+            kl: i32,
+            ku: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            x: &[$ty],
+            incx: i32,
+            beta: $ty,
+            y: &mut [$ty],
+            incy: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(2, "m", m)?;
+            check.dimension(3, "n", n)?;
+            check.dimension(4, "kl", kl)?;
+            check.dimension(5, "ku", ku)?;
+            let band = i64::from(kl) + i64::from(ku) + 1;
+            let band = check.band(8, "lda", lda, band, "must be at least kl + ku + 1")?;
+            check.increment(10, "incx", incx)?;
+            check.increment(13, "incy", incy)?;
+            let (lenx, leny) = if trans == Transpose::No {
+                (n, m)
+            } else {
+                (m, n)
+            };
+            check.length(7, "a", a.len(), row_major_len(m, band, lda))?;
+            check.vector(9, "x", x.len(), lenx, incx)?;
+            check.vector(12, "y", y.len(), leny, incy)?;
+            // The storage is the band storage of the transpose, whose
+            // subdiagonals are the superdiagonals of the matrix.
+            unsafe {
+                if $complex && trans == Transpose::ConjTrans {
+                    let x = conjugated(lenx, x, incx);
+                    conjugate(leny, y, incy);
+                    ::$name(
+                        Transpose::No,
+                        n,
+                        m,
+                        ku,
+                        kl,
+                        Blas::conj(alpha),
+                        a,
+                        lda,
+                        &x,
+                        1,
+                        Blas::conj(beta),
+                        y,
+                        incy,
+                    );
+                    conjugate(leny, y, incy);
+                } else {
+                    let trans = invert(trans, false);
+                    ::$name(trans, n, m, ku, kl, alpha, a, lda, x, incx, beta, y, incy);
+                }
+            }
+            check.finish()
+        }
+    };
+}
+
+gbmv!(sgbmv, f32, false);
+gbmv!(dgbmv, f64, false);
+gbmv!(cgbmv, c32, true);
+gbmv!(zgbmv, c64, true);
+
+macro_rules! symv {
+    ($name:ident, $ty:ty, $hermitian:expr) => {
+        #[doc = concat!("Row-major [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            n: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            x: &[$ty],
+            incx: i32,
+            beta: $ty,
+            y: &mut [$ty],
+            incy: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(2, "n", n)?;
+            check.leading(5, "lda", lda, n.max(1), "must be at least max(1, n)")?;
+            check.increment(7, "incx", incx)?;
+            check.increment(10, "incy", incy)?;
+            check.length(4, "a", a.len(), row_major_len(n, n, lda))?;
+            check.vector(6, "x", x.len(), n, incx)?;
+            check.vector(9, "y", y.len(), n, incy)?;
+            let uplo = flip_uplo(uplo);
+            unsafe {
+                if $hermitian {
+                    // The storage holds the conjugate of the matrix.
+                    let x = conjugated(n, x, incx);
+                    conjugate(n, y, incy);
+                    ::$name(
+                        uplo,
+                        n,
+                        Blas::conj(alpha),
+                        a,
+                        lda,
+                        &x,
+                        1,
+                        Blas::conj(beta),
+                        y,
+                        incy,
+                    );
+                    conjugate(n, y, incy);
+                } else {
+                    ::$name(uplo, n, alpha, a, lda, x, incx, beta, y, incy);
+                }
+            }
+            check.finish()
+        }
+    };
+}
+
+symv!(ssymv, f32, false);
+symv!(dsymv, f64, false);
+symv!(chemv, c32, true);
+symv!(zhemv, c64, true);
+
+macro_rules! sbmv {
+    ($name:ident, $ty:ty, $hermitian:expr) => {
+        #[doc = concat!("Row-major [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            n: i32,
+            k: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            x: &[$ty],
+            incx: i32,
+            beta: $ty,
+            y: &mut [$ty],
+            incy: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(2, "n", n)?;
+            check.dimension(3, "k", k)?;
+            let band = check.band(6, "lda", lda, i64::from(k) + 1, "must be at least k + 1")?;
+            check.increment(8, "incx", incx)?;
+            check.increment(11, "incy", incy)?;
+            check.length(5, "a", a.len(), row_major_len(n, band, lda))?;
+            check.vector(7, "x", x.len(), n, incx)?;
+            check.vector(10, "y", y.len(), n, incy)?;
+            let uplo = flip_uplo(uplo);
+            unsafe {
+                if $hermitian {
+                    // The storage holds the conjugate of the matrix.
+                    let x = conjugated(n, x, incx);
+                    conjugate(n, y, incy);
+                    ::$name(
+                        uplo,
+                        n,
+                        k,
+                        Blas::conj(alpha),
+                        a,
+                        lda,
+                        &x,
+                        1,
+                        Blas::conj(beta),
+                        y,
+                        incy,
+                    );
+                    conjugate(n, y, incy);
+                } else {
+                    ::$name(uplo, n, k, alpha, a, lda, x, incx, beta, y, incy);
+                }
+            }
+            check.finish()
+        }
+    };
+}
+
+sbmv!(ssbmv, f32, false);
+sbmv!(dsbmv, f64, false);
+sbmv!(chbmv, c32, true);
+sbmv!(zhbmv, c64, true);
+
+macro_rules! spmv {
+    ($name:ident, $ty:ty, $hermitian:expr) => {
+        #[doc = concat!("Row-major [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            n: i32,
+            alpha: $ty,
+            ap: &[$ty],
+            x: &[$ty],
+            incx: i32,
+            beta: $ty,
+            y: &mut [$ty],
+            incy: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(2, "n", n)?;
+            check.increment(6, "incx", incx)?;
+            check.increment(9, "incy", incy)?;
+            check.length(4, "ap", ap.len(), packed_len(n))?;
+            check.vector(5, "x", x.len(), n, incx)?;
+            check.vector(8, "y", y.len(), n, incy)?;
+            let uplo = flip_uplo(uplo);
+            unsafe {
+                if $hermitian {
+                    // The storage holds the conjugate of the matrix.
+                    let x = conjugated(n, x, incx);
+                    conjugate(n, y, incy);
+                    ::$name(
+                        uplo,
+                        n,
+                        Blas::conj(alpha),
+                        ap,
+                        &x,
+                        1,
+                        Blas::conj(beta),
+                        y,
+                        incy,
+                    );
+                    conjugate(n, y, incy);
+                } else {
+                    ::$name(uplo, n, alpha, ap, x, incx, beta, y, incy);
+                }
+            }
+            check.finish()
+        }
+    };
+}
+
+spmv!(sspmv, f32, false);
+spmv!(dspmv, f64, false);
+spmv!(chpmv, c32, true);
+spmv!(zhpmv, c64, true);
+
+macro_rules! trmv {
+    ($name:ident, $ty:ty, $complex:expr) => {
+        #[doc = concat!("Row-major [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            trans: Transpose,
+            diag: Diag,
+            n: i32,
+            a: &[$ty],
+            lda: i32,
+            x: &mut [$ty],
+            incx: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(4, "n", n)?;
+            check.leading(6, "lda", lda, n.max(1), "must be at least max(1, n)")?;
+            check.increment(8, "incx", incx)?;
+            check.length(5, "a", a.len(), row_major_len(n, n, lda))?;
+            check.vector(7, "x", x.len(), n, incx)?;
+            let uplo = flip_uplo(uplo);
+            unsafe {
+                if $complex && trans == Transpose::ConjTrans {
+                    conjugate(n, x, incx);
+                    let trans = Transpose::No;
+                    ::$name(uplo, trans, diag, n, a, lda, x, incx);
+                    conjugate(n, x, incx);
+                } else {
+                    let trans = invert(trans, false);
+                    ::$name(uplo, trans, diag, n, a, lda, x, incx);
+                }
+            }
+            check.finish()
+        }
+    };
+}
+
+trmv!(strmv, f32, false);
+trmv!(dtrmv, f64, false);
+trmv!(ctrmv, c32, true);
+trmv!(ztrmv, c64, true);
+
+trmv!(strsv, f32, false);
+trmv!(dtrsv, f64, false);
+trmv!(ctrsv, c32, true);
+trmv!(ztrsv, c64, true);
+
+macro_rules! tbmv {
+    ($name:ident, $ty:ty, $complex:expr) => {
+        #[doc = concat!("Row-major [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            trans: Transpose,
+            diag: Diag,
+            n: i32,
+            k: i32,
+            a: &[$ty],
+            lda: i32,
+            x: &mut [$ty],
+            incx: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(4, "n", n)?;
+            check.dimension(5, "k", k)?;
+            let band = check.band(7, "lda", lda, i64::from(k) + 1, "must be at least k + 1")?;
+            check.increment(9, "incx", incx)?;
+            check.length(6, "a", a.len(), row_major_len(n, band, lda))?;
+            check.vector(8, "x", x.len(), n, incx)?;
+            let uplo = flip_uplo(uplo);
+            unsafe {
+                if $complex && trans == Transpose::ConjTrans {
+                    conjugate(n, x, incx);
+                    ::$name(uplo, Transpose::No, diag, n, k, a, lda, x, incx);
+                    conjugate(n, x, incx);
+                } else {
+                    let trans = invert(trans, false);
+                    ::$name(uplo, trans, diag, n, k, a, lda, x, incx);
+                }
+            }
+            check.finish()
+        }
+    };
+}
+
+tbmv!(stbmv, f32, false);
+tbmv!(dtbmv, f64, false);
+tbmv!(ctbmv, c32, true);
+tbmv!(ztbmv, c64, true);
+
+tbmv!(stbsv, f32, false);
+tbmv!(dtbsv, f64, false);
+tbmv!(ctbsv, c32, true);
+tbmv!(ztbsv, c64, true);
+
+macro_rules! tpmv {
+    ($name:ident, $ty:ty, $complex:expr) => {
+        #[doc = concat!("Row-major [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            trans: Transpose,
+            diag: Diag,
+            n: i32,
+            ap: &[$ty],
+            x: &mut [$ty],
+            incx: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(4, "n", n)?;
+            check.increment(7, "incx", incx)?;
+            check.length(5, "ap", ap.len(), packed_len(n))?;
+            check.vector(6, "x", x.len(), n, incx)?;
+            let uplo = flip_uplo(uplo);
+            unsafe {
+                if $complex && trans == Transpose::ConjTrans {
+                    conjugate(n, x, incx);
+                    ::$name(uplo, Transpose::No, diag, n, ap, x, incx);
+                    conjugate(n, x, incx);
+                } else {
+                    let trans = invert(trans, false);
+                    ::$name(uplo, trans, diag, n, ap, x, incx);
+                }
+            }
+            check.finish()
+        }
+    };
+}
+
+tpmv!(stpmv, f32, false);
+tpmv!(dtpmv, f64, false);
+tpmv!(ctpmv, c32, true);
+tpmv!(ztpmv, c64, true);
+
+tpmv!(stpsv, f32, false);
+tpmv!(dtpsv, f64, false);
+tpmv!(ctpsv, c32, true);
+tpmv!(ztpsv, c64, true);
+
+macro_rules! ger {
+    ($name:ident, $ty:ty, $routine:ident, $conjugate:expr) => {
+        #[doc = concat!("Row-major [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            m: i32,
+            n: i32,
+            alpha: $ty,
+            x: &[$ty],
+            incx: i32,
+            y: &[$ty],
+            incy: i32,
+            a: &mut [$ty],
+            lda: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(1, "m", m)?;
+            check.dimension(2, "n", n)?;
+            check.increment(5, "incx", incx)?;
+            check.increment(7, "incy", incy)?;
+            check.leading(9, "lda", lda, n.max(1), "must be at least max(1, n)")?;
+            check.vector(4, "x", x.len(), m, incx)?;
+            check.vector(6, "y", y.len(), n, incy)?;
+            check.length(8, "a", a.len(), row_major_len(m, n, lda))?;
+            unsafe {
+                if $conjugate {
+                    let y = conjugated(n, y, incy);
+                    ::$routine(n, m, alpha, &y, 1, x, incx, a, lda);
+                } else {
+                    ::$routine(n, m, alpha, y, incy, x, incx, a, lda);
+                }
+            }
+            check.finish()
+        }
+    };
+}
+
+ger!(sger, f32, sger, false);
+ger!(dger, f64, dger, false);
+ger!(cgeru, c32, cgeru, false);
+ger!(cgerc, c32, cgeru, true);
+ger!(zgeru, c64, zgeru, false);
+ger!(zgerc, c64, zgeru, true);
+
+macro_rules! syr {
+    ($name:ident, $ty:ty, $alpha:ty, $hermitian:expr) => {
+        #[doc = concat!("Row-major [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            n: i32,
+            alpha: $alpha,
+            x: &[$ty],
+            incx: i32,
+            a: &mut [$ty],
+            lda: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(2, "n", n)?;
+            check.increment(5, "incx", incx)?;
+            check.leading(7, "lda", lda, n.max(1), "must be at least max(1, n)")?;
+            check.vector(4, "x", x.len(), n, incx)?;
+            check.length(6, "a", a.len(), row_major_len(n, n, lda))?;
+            let uplo = flip_uplo(uplo);
+            unsafe {
+                if $hermitian {
+                    // The storage receives the conjugate of the update.
+                    let x = conjugated(n, x, incx);
+                    ::$name(uplo, n, alpha, &x, 1, a, lda);
+                } else {
+                    ::$name(uplo, n, alpha, x, incx, a, lda);
+                }
+            }
+            check.finish()
+        }
+    };
+}
+
+syr!(ssyr, f32, f32, false);
+syr!(dsyr, f64, f64, false);
+syr!(cher, c32, f32, true);
+syr!(zher, c64, f64, true);
+
+macro_rules! spr {
+    ($name:ident, $ty:ty, $alpha:ty, $hermitian:expr) => {
+        #[doc = concat!("Row-major [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            n: i32,
+            alpha: $alpha,
+            x: &[$ty],
+            incx: i32,
+            ap: &mut [$ty],
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(2, "n", n)?;
+            check.increment(5, "incx", incx)?;
+            check.vector(4, "x", x.len(), n, incx)?;
+            check.length(6, "ap", ap.len(), packed_len(n))?;
+            let uplo = flip_uplo(uplo);
+            unsafe {
+                if $hermitian {
+                    // The storage receives the conjugate of the update.
+                    let x = conjugated(n, x, incx);
+                    ::$name(uplo, n, alpha, &x, 1, ap);
+                } else {
+                    ::$name(uplo, n, alpha, x, incx, ap);
+                }
+            }
+            check.finish()
+        }
+    };
+}
+
+spr!(sspr, f32, f32, false);
+spr!(dspr, f64, f64, false);
+spr!(chpr, c32, f32, true);
+spr!(zhpr, c64, f64, true);
+
+macro_rules! syr2 {
+    ($name:ident, $ty:ty, $hermitian:expr) => {
+        #[doc = concat!("Row-major [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            n: i32,
+            alpha: $ty,
+            x: &[$ty],
+            incx: i32,
+            y: &[$ty],
+            incy: i32,
+            a: &mut [$ty],
+            lda: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(2, "n", n)?;
+            check.increment(5, "incx", incx)?;
+            check.increment(7, "incy", incy)?;
+            check.leading(9, "lda", lda, n.max(1), "must be at least max(1, n)")?;
+            check.vector(4, "x", x.len(), n, incx)?;
+            check.vector(6, "y", y.len(), n, incy)?;
+            check.length(8, "a", a.len(), row_major_len(n, n, lda))?;
+            let uplo = flip_uplo(uplo);
+            unsafe {
+                if $hermitian {
+                    // The storage receives the conjugate of the update.
+                    let x = conjugated(n, x, incx);
+                    let y = conjugated(n, y, incy);
+                    ::$name(uplo, n, Blas::conj(alpha), &x, 1, &y, 1, a, lda);
+                } else {
+                    ::$name(uplo, n, alpha, x, incx, y, incy, a, lda);
+                }
+            }
+            check.finish()
+        }
+    };
+}
+
+syr2!(ssyr2, f32, false);
+syr2!(dsyr2, f64, false);
+syr2!(cher2, c32, true);
+syr2!(zher2, c64, true);
+
+macro_rules! spr2 {
+    ($name:ident, $ty:ty, $hermitian:expr) => {
+        #[doc = concat!("Row-major [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            n: i32,
+            alpha: $ty,
+            x: &[$ty],
+            incx: i32,
+            y: &[$ty],
+            incy: i32,
+            ap: &mut [$ty],
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(2, "n", n)?;
+            check.increment(5, "incx", incx)?;
+            check.increment(7, "incy", incy)?;
+            check.vector(4, "x", x.len(), n, incx)?;
+            check.vector(6, "y", y.len(), n, incy)?;
+            check.length(8, "ap", ap.len(), packed_len(n))?;
+            let uplo = flip_uplo(uplo);
+            unsafe {
+                if $hermitian {
+                    // The storage receives the conjugate of the update.
+                    let x = conjugated(n, x, incx);
+                    let y = conjugated(n, y, incy);
+                    ::$name(uplo, n, Blas::conj(alpha), &x, 1, &y, 1, ap);
+                } else {
+                    ::$name(uplo, n, alpha, x, incx, y, incy, ap);
+                }
+            }
+            check.finish()
+        }
+    };
+}
+
+spr2!(sspr2, f32, false);
+spr2!(dspr2, f64, false);
+spr2!(chpr2, c32, true);
+spr2!(zhpr2, c64, true);
+
+#[cfg(all(test, feature = "reference", not(feature = "dynamic")))]
+mod tests {
+    use checked;
+    use {c64, Diag, Transpose, Uplo};
+
+    const UPLOS: [Uplo; 2] = [Uplo::Upper, Uplo::Lower];
+    const TRANSPOSES: [Transpose; 3] = [Transpose::No, Transpose::Trans, Transpose::ConjTrans];
+    const DIAGS: [Diag; 2] = [Diag::NonUnit, Diag::Unit];
+
+    /// A dense matrix stored row by row.
+    #[derive(Clone)]
+    struct Matrix {
+        rows: usize,
+        cols: usize,
+        data: Vec<c64>,
+    }
+
+    impl Matrix {
+        fn new(rows: usize, cols: usize, seed: f64) -> Self {
+            let data = (0..rows * cols)
+                .map(|index| {
+                    let (i, j) = ((index / cols) as f64, (index % cols) as f64);
+                    c64::new(seed + i + 0.5 * j, 1.0 - 0.25 * i + seed * j)
+                })
+                .collect();
+            Matrix { rows, cols, data }
+        }
+
+        fn get(&self, i: usize, j: usize) -> c64 {
+            self.data[i * self.cols + j]
+        }
+
+        fn t(&self) -> Self {
+            let data = (0..self.rows * self.cols)
+                .map(|index| self.get(index % self.rows, index / self.rows))
+                .collect();
+            Matrix {
+                rows: self.cols,
+                cols: self.rows,
+                data,
+            }
+        }
+
+        /// Return the column-major storage.
+        fn dense(&self, ld: usize) -> Vec<c64> {
+            let mut a = vec![c64::new(-7.0, 7.0); ld * self.cols];
+            for j in 0..self.cols {
+                for i in 0..self.rows {
+                    a[i + j * ld] = self.get(i, j);
+                }
+            }
+            a
+        }
+
+        /// Return the column-major band storage.
+        fn band(&self, kl: usize, ku: usize, ld: usize) -> Vec<c64> {
+            let mut a = vec![c64::new(-7.0, 7.0); ld * self.cols];
+            for j in 0..self.cols {
+                for i in j.saturating_sub(ku)..self.rows.min(j + kl + 1) {
+                    a[ku + i - j + j * ld] = self.get(i, j);
+                }
+            }
+            a
+        }
+
+        /// Return the column-major band storage of a triangle.
+        fn triangular_band(&self, uplo: Uplo, k: usize, ld: usize) -> Vec<c64> {
+            match uplo {
+                Uplo::Upper => self.band(0, k, ld),
+                Uplo::Lower => self.band(k, 0, ld),
+            }
+        }
+
+        /// Return the column-major packed storage of a triangle.
+        fn packed(&self, uplo: Uplo) -> Vec<c64> {
+            let n = self.rows;
+            let mut ap = vec![];
+            for j in 0..n {
+                let range = match uplo {
+                    Uplo::Upper => 0..j + 1,
+                    Uplo::Lower => j..n,
+                };
+                ap.extend(range.map(|i| self.get(i, j)));
+            }
+            ap
+        }
+
+        /// Create a matrix from column-major packed storage, with zeros in
+        /// the other triangle.
+        fn unpack(n: usize, uplo: Uplo, ap: &[c64]) -> Self {
+            let mut matrix = Matrix {
+                rows: n,
+                cols: n,
+                data: vec![c64::new(0.0, 0.0); n * n],
+            };
+            let mut index = 0;
+            for j in 0..n {
+                let range = match uplo {
+                    Uplo::Upper => 0..j + 1,
+                    Uplo::Lower => j..n,
+                };
+                for i in range {
+                    matrix.data[i * n + j] = ap[index];
+                    index += 1;
+                }
+            }
+            matrix
+        }
+    }
+
+    fn flip(uplo: Uplo) -> Uplo {
+        match uplo {
+            Uplo::Upper => Uplo::Lower,
+            Uplo::Lower => Uplo::Upper,
+        }
+    }
+
+    fn vector(len: usize, seed: f64) -> Vec<c64> {
+        (0..len)
+            .map(|i| c64::new(seed - i as f64, 0.5 + seed * i as f64))
+            .collect()
+    }
+
+    fn assert_close(x: &[c64], y: &[c64]) {
+        assert_eq!(x.len(), y.len());
+        for (x, y) in x.iter().zip(y) {
+            assert!((x - y).norm_sqr() < 1e-18, "{} != {}", x, y);
+        }
+    }
+
+    fn real(x: &[c64]) -> Vec<f64> {
+        x.iter().map(|x| x.re).collect()
+    }
+
+    fn assert_close_real(x: &[f64], y: &[f64]) {
+        assert_eq!(x.len(), y.len());
+        for (x, y) in x.iter().zip(y) {
+            assert!((x - y).abs() < 1e-9, "{} != {}", x, y);
+        }
+    }
+
+    #[test]
+    fn gbmv() {
+        let (m, n, kl, ku) = (4, 5, 1, 2);
+        let (alpha, beta) = (c64::new(0.5, 1.0), c64::new(2.0, -0.5));
+        let matrix = Matrix::new(m, n, 1.0);
+        let a = matrix.band(kl, ku, 5);
+        // The row-major band storage is the band storage of the transpose.
+        let b = matrix.t().band(ku, kl, 5);
+        for &trans in &TRANSPOSES {
+            let (lenx, leny) = if trans == Transpose::No {
+                (n, m)
+            } else {
+                (m, n)
+            };
+            let x = vector(2 * lenx, 1.0);
+            let mut y = vector(leny, -2.0);
+            let mut z = y.clone();
+            let (m, n, kl, ku) = (m as i32, n as i32, kl as i32, ku as i32);
+            checked::zgbmv(trans, m, n, kl, ku, alpha, &a, 5, &x, -2, beta, &mut y, 1).unwrap();
+            super::zgbmv(trans, m, n, kl, ku, alpha, &b, 5, &x, -2, beta, &mut z, 1).unwrap();
+            assert_close(&y, &z);
+
+            let (x, mut y) = (real(&x), real(&y));
+            let mut z = y.clone();
+            let (a, b) = (real(&a), real(&b));
+            checked::dgbmv(trans, m, n, kl, ku, 0.5, &a, 5, &x, 2, 2.0, &mut y, -1).unwrap();
+            super::dgbmv(trans, m, n, kl, ku, 0.5, &b, 5, &x, 2, 2.0, &mut z, -1).unwrap();
+            assert_close_real(&y, &z);
+        }
+        let x = vector(n, 1.0);
+        let mut y = vector(m, 1.0);
+        let error = super::zgbmv(
+            Transpose::No,
+            4,
+            5,
+            1,
+            2,
+            alpha,
+            &b[..18],
+            5,
+            &x,
+            1,
+            beta,
+            &mut y,
+            1,
+        );
+        assert_eq!(error.unwrap_err().position(), 7);
+    }
+
+    #[test]
+    fn hermitian_products() {
+        let (n, k) = (4, 1);
+        let (alpha, beta) = (c64::new(0.5, 1.0), c64::new(2.0, -0.5));
+        let matrix = Matrix::new(n, n, 2.0);
+        let x = vector(2 * n, 1.0);
+        for &uplo in &UPLOS {
+            let (a, b) = (
+                matrix.triangular_band(uplo, k, 3),
+                matrix.t().triangular_band(flip(uplo), k, 3),
+            );
+            let (ap, bp) = (matrix.packed(uplo), matrix.t().packed(flip(uplo)));
+            let (n, k) = (n as i32, k as i32);
+
+            let (mut y, mut z) = (vector(n as usize, -2.0), vector(n as usize, -2.0));
+            checked::zhbmv(uplo, n, k, alpha, &a, 3, &x, 2, beta, &mut y, -1).unwrap();
+            super::zhbmv(uplo, n, k, alpha, &b, 3, &x, 2, beta, &mut z, -1).unwrap();
+            assert_close(&y, &z);
+            let (mut y, mut z) = (real(&y), real(&z));
+            checked::dsbmv(uplo, n, k, 0.5, &real(&a), 3, &real(&x), -2, 2.0, &mut y, 1).unwrap();
+            super::dsbmv(uplo, n, k, 0.5, &real(&b), 3, &real(&x), -2, 2.0, &mut z, 1).unwrap();
+            assert_close_real(&y, &z);
+
+            let (mut y, mut z) = (vector(n as usize, -2.0), vector(n as usize, -2.0));
+            checked::zhpmv(uplo, n, alpha, &ap, &x, -2, beta, &mut y, 1).unwrap();
+            super::zhpmv(uplo, n, alpha, &bp, &x, -2, beta, &mut z, 1).unwrap();
+            assert_close(&y, &z);
+            let (mut y, mut z) = (real(&y), real(&z));
+            checked::dspmv(uplo, n, 0.5, &real(&ap), &real(&x), 2, 2.0, &mut y, -1).unwrap();
+            super::dspmv(uplo, n, 0.5, &real(&bp), &real(&x), 2, 2.0, &mut z, -1).unwrap();
+            assert_close_real(&y, &z);
+        }
+    }
+
+    #[test]
+    fn triangular() {
+        let (n, k) = (4, 2);
+        let matrix = Matrix::new(n, n, 3.0);
+        for &uplo in &UPLOS {
+            let (a, b) = (
+                matrix.triangular_band(uplo, k, 3),
+                matrix.t().triangular_band(flip(uplo), k, 3),
+            );
+            let (ap, bp) = (matrix.packed(uplo), matrix.t().packed(flip(uplo)));
+            for &trans in &TRANSPOSES {
+                for &diag in &DIAGS {
+                    let (n, k) = (n as i32, k as i32);
+                    let (mut x, mut y) = (vector(8, 1.0), vector(8, 1.0));
+                    checked::ztbmv(uplo, trans, diag, n, k, &a, 3, &mut x, -2).unwrap();
+                    super::ztbmv(uplo, trans, diag, n, k, &b, 3, &mut y, -2).unwrap();
+                    assert_close(&x, &y);
+                    checked::ztbsv(uplo, trans, diag, n, k, &a, 3, &mut x, 2).unwrap();
+                    super::ztbsv(uplo, trans, diag, n, k, &b, 3, &mut y, 2).unwrap();
+                    assert_close(&x, &y);
+                    checked::ztpmv(uplo, trans, diag, n, &ap, &mut x, 1).unwrap();
+                    super::ztpmv(uplo, trans, diag, n, &bp, &mut y, 1).unwrap();
+                    assert_close(&x, &y);
+                    checked::ztpsv(uplo, trans, diag, n, &ap, &mut x, -1).unwrap();
+                    super::ztpsv(uplo, trans, diag, n, &bp, &mut y, -1).unwrap();
+                    assert_close(&x, &y);
+
+                    let (mut x, mut y) = (real(&x), real(&y));
+                    let (a, b, ap, bp) = (real(&a), real(&b), real(&ap), real(&bp));
+                    checked::dtbmv(uplo, trans, diag, n, k, &a, 3, &mut x, 2).unwrap();
+                    super::dtbmv(uplo, trans, diag, n, k, &b, 3, &mut y, 2).unwrap();
+                    checked::dtpsv(uplo, trans, diag, n, &ap, &mut x, -1).unwrap();
+                    super::dtpsv(uplo, trans, diag, n, &bp, &mut y, -1).unwrap();
+                    assert_close_real(&x, &y);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rank() {
+        let n = 3;
+        let alpha = c64::new(0.5, 1.0);
+        let matrix = Matrix::new(n, n, 2.0);
+        let (x, y) = (vector(2 * n, 1.0), vector(n, -2.0));
+        let ni = n as i32;
+        for &uplo in &UPLOS {
+            // The result of the row-major call, transposed, must equal the
+            // result of the column-major call.
+            let (mut a, mut b) = (matrix.dense(n), matrix.t().dense(n));
+            checked::zher(uplo, ni, 2.0, &x, -2, &mut a, ni).unwrap();
+            super::zher(uplo, ni, 2.0, &x, -2, &mut b, ni).unwrap();
+            checked::zher2(uplo, ni, alpha, &x, 2, &y, 1, &mut a, ni).unwrap();
+            super::zher2(uplo, ni, alpha, &x, 2, &y, 1, &mut b, ni).unwrap();
+            let transposed = Matrix {
+                rows: n,
+                cols: n,
+                data: b,
+            }
+            .t()
+            .data;
+            assert_close(&a, &transposed);
+
+            let (mut a, mut b) = (real(&matrix.dense(n)), real(&matrix.t().dense(n)));
+            checked::dsyr(uplo, ni, 2.0, &real(&x), 2, &mut a, ni).unwrap();
+            super::dsyr(uplo, ni, 2.0, &real(&x), 2, &mut b, ni).unwrap();
+            checked::dsyr2(uplo, ni, 0.5, &real(&x), -2, &real(&y), 1, &mut a, ni).unwrap();
+            super::dsyr2(uplo, ni, 0.5, &real(&x), -2, &real(&y), 1, &mut b, ni).unwrap();
+            let b = b.iter().map(|&value| c64::new(value, 0.0)).collect();
+            let transposed = real(
+                &Matrix {
+                    rows: n,
+                    cols: n,
+                    data: b,
+                }
+                .t()
+                .data,
+            );
+            assert_close_real(&a, &transposed);
+
+            let (mut ap, mut bp) = (matrix.packed(uplo), matrix.t().packed(flip(uplo)));
+            checked::zhpr(uplo, ni, 2.0, &x, 2, &mut ap).unwrap();
+            super::zhpr(uplo, ni, 2.0, &x, 2, &mut bp).unwrap();
+            checked::zhpr2(uplo, ni, alpha, &x, -2, &y, -1, &mut ap).unwrap();
+            super::zhpr2(uplo, ni, alpha, &x, -2, &y, -1, &mut bp).unwrap();
+            let unpacked = Matrix::unpack(n, flip(uplo), &bp).t();
+            assert_close(&ap, &unpacked.packed(uplo));
+
+            let (mut ap, mut bp) = (
+                real(&matrix.packed(uplo)),
+                real(&matrix.t().packed(flip(uplo))),
+            );
+            checked::dspr(uplo, ni, 2.0, &real(&x), -2, &mut ap).unwrap();
+            super::dspr(uplo, ni, 2.0, &real(&x), -2, &mut bp).unwrap();
+            checked::dspr2(uplo, ni, 0.5, &real(&x), 2, &real(&y), 1, &mut ap).unwrap();
+            super::dspr2(uplo, ni, 0.5, &real(&x), 2, &real(&y), 1, &mut bp).unwrap();
+            let bp = bp
+                .iter()
+                .map(|&value| c64::new(value, 0.0))
+                .collect::<Vec<_>>();
+            let unpacked = Matrix::unpack(n, flip(uplo), &bp).t();
+            assert_close_real(&ap, &real(&unpacked.packed(uplo)));
+
+            let error = super::zher(uplo, ni, 2.0, &x, 2, &mut [alpha; 8], ni);
+            assert_eq!(error.unwrap_err().position(), 6);
+        }
+    }
+}
@@ -0,0 +1,701 @@
+use super::{flip_side, flip_uplo, invert, rank2k_alpha, row_major_len};
+use checked::Check;
+use {c32, c64, Diag, Result, Side, Transpose, Uplo};
+
+macro_rules! gemm {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Row-major [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            transa: Transpose,
+            transb: Transpose,
+            m: i32,
+            n: i32,
+            k: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            b: &[$ty],
+            ldb: i32,
+            beta: $ty,
+            c: &mut [$ty],
+            ldc: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(3, "m", m)?;
+            check.dimension(4, "n", n)?;
+            check.dimension(5, "k", k)?;
+            let (rowsa, colsa) = if transa == Transpose::No {
+                (m, k)
+            } else {
+                (k, m)
+            };
+            let (rowsb, colsb) = if transb == Transpose::No {
+                (k, n)
+            } else {
+                (n, k)
+            };
+            check.leading(
+                8,
+                "lda",
+                lda,
+                colsa.max(1),
+                "must be at least max(1, columns of a)",
+            )?;
+            check.leading(
+                10,
+                "ldb",
+                ldb,
+                colsb.max(1),
+                "must be at least max(1, columns of b)",
+            )?;
+            check.leading(13, "ldc", ldc, n.max(1), "must be at least max(1, n)")?;
+            check.length(7, "a", a.len(), row_major_len(rowsa, colsa, lda))?;
+            check.length(9, "b", b.len(), row_major_len(rowsb, colsb, ldb))?;
+            check.length(12, "c", c.len(), row_major_len(m, n, ldc))?;
+            unsafe { ::$name(transb, transa, n, m, k, alpha, b, ldb, a, lda, beta, c, ldc) };
+            check.finish()
+        }
+    };
+}
+
+gemm!(sgemm, f32);
+gemm!(dgemm, f64);
+gemm!(cgemm, c32);
+gemm!(zgemm, c64);
+
+macro_rules! symm {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Row-major [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            side: Side,
+            uplo: Uplo,
+            m: i32,
+            n: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            b: &[$ty],
+            ldb: i32,
+            beta: $ty,
+            c: &mut [$ty],
+            ldc: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(3, "m", m)?;
+            check.dimension(4, "n", n)?;
+            let order = if side == Side::Left { m } else { n };
+            check.leading(
+                7,
+                "lda",
+                lda,
+                order.max(1),
+                "must be at least max(1, columns of a)",
+            )?;
+            check.leading(9, "ldb", ldb, n.max(1), "must be at least max(1, n)")?;
+            check.leading(12, "ldc", ldc, n.max(1), "must be at least max(1, n)")?;
+            check.length(6, "a", a.len(), row_major_len(order, order, lda))?;
+            check.length(8, "b", b.len(), row_major_len(m, n, ldb))?;
+            check.length(11, "c", c.len(), row_major_len(m, n, ldc))?;
+            unsafe {
+                ::$name(
+                    flip_side(side),
+                    flip_uplo(uplo),
+                    n,
+                    m,
+                    alpha,
+                    a,
+                    lda,
+                    b,
+                    ldb,
+                    beta,
+                    c,
+                    ldc,
+                )
+            };
+            check.finish()
+        }
+    };
+}
+
+symm!(ssymm, f32);
+symm!(dsymm, f64);
+symm!(csymm, c32);
+symm!(zsymm, c64);
+symm!(chemm, c32);
+symm!(zhemm, c64);
+
+macro_rules! syrk {
+    ($name:ident, $ty:ty, $scalar:ty, $conjugate:expr $(, $allowed:expr, $reason:expr)*) => {
+        #[doc = concat!("Row-major [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            trans: Transpose,
+            n: i32,
+            k: i32,
+            alpha: $scalar,
+            a: &[$ty],
+            lda: i32,
+            beta: $scalar,
+            c: &mut [$ty],
+            ldc: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            $(check.transpose(2, "trans", trans, $allowed, $reason)?;)*
+            check.dimension(3, "n", n)?;
+            check.dimension(4, "k", k)?;
+            let (rowsa, colsa) = if trans == Transpose::No { (n, k) } else { (k, n) };
+            check.leading(
+                7,
+                "lda",
+                lda,
+                colsa.max(1),
+                "must be at least max(1, columns of a)",
+            )?;
+            check.leading(10, "ldc", ldc, n.max(1), "must be at least max(1, n)")?;
+            check.length(6, "a", a.len(), row_major_len(rowsa, colsa, lda))?;
+            check.length(9, "c", c.len(), row_major_len(n, n, ldc))?;
+            let uplo = flip_uplo(uplo);
+            let trans = invert(trans, $conjugate);
+            unsafe { ::$name(uplo, trans, n, k, alpha, a, lda, beta, c, ldc) };
+            check.finish()
+        }
+    };
+}
+
+syrk!(ssyrk, f32, f32, false);
+syrk!(dsyrk, f64, f64, false);
+syrk!(
+    csyrk,
+    c32,
+    c32,
+    false,
+    &[Transpose::No, Transpose::Trans],
+    "must not be ConjTrans"
+);
+syrk!(
+    zsyrk,
+    c64,
+    c64,
+    false,
+    &[Transpose::No, Transpose::Trans],
+    "must not be ConjTrans"
+);
+syrk!(
+    cherk,
+    c32,
+    f32,
+    true,
+    &[Transpose::No, Transpose::ConjTrans],
+    "must not be Trans"
+);
+syrk!(
+    zherk,
+    c64,
+    f64,
+    true,
+    &[Transpose::No, Transpose::ConjTrans],
+    "must not be Trans"
+);
+
+macro_rules! syr2k {
+    ($name:ident, $ty:ty, $beta:ty, $conjugate:expr $(, $allowed:expr, $reason:expr)*) => {
+        #[doc = concat!("Row-major [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            uplo: Uplo,
+            trans: Transpose,
+            n: i32,
+            k: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            b: &[$ty],
+            ldb: i32,
+            beta: $beta,
+            c: &mut [$ty],
+            ldc: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            $(check.transpose(2, "trans", trans, $allowed, $reason)?;)*
+            check.dimension(3, "n", n)?;
+            check.dimension(4, "k", k)?;
+            let (rows, cols) = if trans == Transpose::No { (n, k) } else { (k, n) };
+            check.leading(
+                7,
+                "lda",
+                lda,
+                cols.max(1),
+                "must be at least max(1, columns of a)",
+            )?;
+            check.leading(
+                9,
+                "ldb",
+                ldb,
+                cols.max(1),
+                "must be at least max(1, columns of b)",
+            )?;
+            check.leading(12, "ldc", ldc, n.max(1), "must be at least max(1, n)")?;
+            check.length(6, "a", a.len(), row_major_len(rows, cols, lda))?;
+            check.length(8, "b", b.len(), row_major_len(rows, cols, ldb))?;
+            check.length(11, "c", c.len(), row_major_len(n, n, ldc))?;
+            let uplo = flip_uplo(uplo);
+            let trans = invert(trans, $conjugate);
+            let alpha = rank2k_alpha(alpha, $conjugate);
+            unsafe { ::$name(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc) };
+            check.finish()
+        }
+    };
+}
+
+syr2k!(ssyr2k, f32, f32, false);
+syr2k!(dsyr2k, f64, f64, false);
+syr2k!(
+    csyr2k,
+    c32,
+    c32,
+    false,
+    &[Transpose::No, Transpose::Trans],
+    "must not be ConjTrans"
+);
+syr2k!(
+    zsyr2k,
+    c64,
+    c64,
+    false,
+    &[Transpose::No, Transpose::Trans],
+    "must not be ConjTrans"
+);
+syr2k!(
+    cher2k,
+    c32,
+    f32,
+    true,
+    &[Transpose::No, Transpose::ConjTrans],
+    "must not be Trans"
+);
+syr2k!(
+    zher2k,
+    c64,
+    f64,
+    true,
+    &[Transpose::No, Transpose::ConjTrans],
+    "must not be Trans"
+);
+
+macro_rules! trmm {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Row-major [`", stringify!($name), "`](../fn.", stringify!($name), ".html).")]
+        #[inline]
+        pub fn $name(
+            side: Side,
+            uplo: Uplo,
+            transa: Transpose,
+            diag: Diag,
+            m: i32,
+            n: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            b: &mut [$ty],
+            ldb: i32,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(5, "m", m)?;
+            check.dimension(6, "n", n)?;
+            let order = if side == Side::Left { m } else { n };
+            check.leading(
+                9,
+                "lda",
+                lda,
+                order.max(1),
+                "must be at least max(1, columns of a)",
+            )?;
+            check.leading(11, "ldb", ldb, n.max(1), "must be at least max(1, n)")?;
+            check.length(8, "a", a.len(), row_major_len(order, order, lda))?;
+            check.length(10, "b", b.len(), row_major_len(m, n, ldb))?;
+            unsafe {
+                ::$name(
+                    flip_side(side),
+                    flip_uplo(uplo),
+                    transa,
+                    diag,
+                    n,
+                    m,
+                    alpha,
+                    a,
+                    lda,
+                    b,
+                    ldb,
+                )
+            };
+            check.finish()
+        }
+    };
+}
+
+trmm!(strmm, f32);
+trmm!(dtrmm, f64);
+trmm!(ctrmm, c32);
+trmm!(ztrmm, c64);
+
+trmm!(strsm, f32);
+trmm!(dtrsm, f64);
+trmm!(ctrsm, c32);
+trmm!(ztrsm, c64);
+
+#[cfg(all(test, feature = "reference", not(feature = "dynamic")))]
+mod tests {
+    use checked;
+    use {c64, Diag, Side, Transpose, Uplo};
+
+    const UPLOS: [Uplo; 2] = [Uplo::Upper, Uplo::Lower];
+    const SIDES: [Side; 2] = [Side::Left, Side::Right];
+    const TRANSPOSES: [Transpose; 3] = [Transpose::No, Transpose::Trans, Transpose::ConjTrans];
+    const DIAGS: [Diag; 2] = [Diag::NonUnit, Diag::Unit];
+
+    /// A dense matrix stored row by row.
+    struct Matrix {
+        rows: usize,
+        cols: usize,
+        data: Vec<c64>,
+    }
+
+    impl Matrix {
+        fn new(rows: usize, cols: usize, seed: f64) -> Self {
+            let data = (0..rows * cols)
+                .map(|index| {
+                    let (i, j) = ((index / cols) as f64, (index % cols) as f64);
+                    c64::new(seed + 0.5 * i - 0.25 * j, 1.0 - 0.25 * i + seed * j)
+                })
+                .collect();
+            Matrix { rows, cols, data }
+        }
+
+        /// Create a matrix with a dominant diagonal, so that its triangles
+        /// are well conditioned.
+        fn triangular(n: usize, seed: f64) -> Self {
+            let mut matrix = Matrix::new(n, n, seed);
+            for i in 0..n {
+                matrix.data[i * n + i] += c64::new(4.0 + n as f64, 0.0);
+            }
+            matrix
+        }
+
+        /// Read a matrix from column-major storage.
+        fn columns(rows: usize, cols: usize, a: &[c64], ld: usize) -> Self {
+            let data = (0..rows * cols)
+                .map(|index| a[index / cols + index % cols * ld])
+                .collect();
+            Matrix { rows, cols, data }
+        }
+
+        /// Read a matrix from row-major storage.
+        fn rows(rows: usize, cols: usize, a: &[c64], ld: usize) -> Self {
+            let data = (0..rows * cols)
+                .map(|index| a[index / cols * ld + index % cols])
+                .collect();
+            Matrix { rows, cols, data }
+        }
+
+        /// Return the column-major storage.
+        fn dense(&self, ld: usize) -> Vec<c64> {
+            let mut a = vec![c64::new(-7.0, 7.0); ld * self.cols];
+            for j in 0..self.cols {
+                for i in 0..self.rows {
+                    a[i + j * ld] = self.data[i * self.cols + j];
+                }
+            }
+            a
+        }
+
+        /// Return the row-major storage, which is the column-major storage
+        /// of the transpose.
+        fn transposed(&self, ld: usize) -> Vec<c64> {
+            let mut a = vec![c64::new(-7.0, 7.0); ld * self.rows];
+            for i in 0..self.rows {
+                for j in 0..self.cols {
+                    a[i * ld + j] = self.data[i * self.cols + j];
+                }
+            }
+            a
+        }
+    }
+
+    fn real(x: &[c64]) -> Vec<f64> {
+        x.iter().map(|x| x.re).collect()
+    }
+
+    fn complex(x: &[f64]) -> Vec<c64> {
+        x.iter().map(|&x| c64::new(x, 0.0)).collect()
+    }
+
+    fn assert_close(x: &Matrix, y: &Matrix) {
+        assert_eq!((x.rows, x.cols), (y.rows, y.cols));
+        for (x, y) in x.data.iter().zip(&y.data) {
+            assert!((x - y).norm_sqr() < 1e-18, "{} != {}", x, y);
+        }
+    }
+
+    /// Compare the result of a column-major call with `ld` rows of storage
+    /// with the one of a row-major call with `ld` columns of storage.
+    fn compare(rows: usize, cols: usize, a: &[c64], b: &[c64], ld: usize) {
+        assert_close(
+            &Matrix::columns(rows, cols, a, ld),
+            &Matrix::rows(rows, cols, b, ld),
+        );
+    }
+
+    #[test]
+    fn gemm() {
+        let (m, n, k) = (3, 4, 2);
+        let (alpha, beta) = (c64::new(0.5, 1.0), c64::new(2.0, -0.5));
+        let c = Matrix::new(m, n, -1.0);
+        for &transa in &TRANSPOSES {
+            for &transb in &TRANSPOSES {
+                let a = if transa == Transpose::No {
+                    Matrix::new(m, k, 1.0)
+                } else {
+                    Matrix::new(k, m, 1.0)
+                };
+                let b = if transb == Transpose::No {
+                    Matrix::new(k, n, 2.0)
+                } else {
+                    Matrix::new(n, k, 2.0)
+                };
+                let (lda, ldb, ldc) = (a.rows.max(a.cols) + 1, b.rows.max(b.cols) + 1, 6);
+                let (mut x, mut y) = (c.dense(ldc), c.transposed(ldc));
+                let (mi, ni, ki) = (m as i32, n as i32, k as i32);
+                let (ai, bi, ci) = (lda as i32, ldb as i32, ldc as i32);
+                let (ar, br) = (a.transposed(lda), b.transposed(ldb));
+                let (ac, bc) = (a.dense(lda), b.dense(ldb));
+                checked::zgemm(
+                    transa, transb, mi, ni, ki, alpha, &ac, ai, &bc, bi, beta, &mut x, ci,
+                )
+                .unwrap();
+                super::zgemm(
+                    transa, transb, mi, ni, ki, alpha, &ar, ai, &br, bi, beta, &mut y, ci,
+                )
+                .unwrap();
+                compare(m, n, &x, &y, ldc);
+
+                let (mut x, mut y) = (real(&c.dense(ldc)), real(&c.transposed(ldc)));
+                let (ac, bc, ar, br) = (real(&ac), real(&bc), real(&ar), real(&br));
+                checked::dgemm(
+                    transa, transb, mi, ni, ki, 0.5, &ac, ai, &bc, bi, 2.0, &mut x, ci,
+                )
+                .unwrap();
+                super::dgemm(
+                    transa, transb, mi, ni, ki, 0.5, &ar, ai, &br, bi, 2.0, &mut y, ci,
+                )
+                .unwrap();
+                compare(m, n, &complex(&x), &complex(&y), ldc);
+            }
+        }
+        let (a, b) = (Matrix::new(m, k, 1.0), Matrix::new(k, n, 2.0));
+        let error = super::zgemm(
+            Transpose::No,
+            Transpose::No,
+            3,
+            4,
+            2,
+            alpha,
+            &a.transposed(2),
+            2,
+            &b.transposed(4)[..7],
+            4,
+            beta,
+            &mut c.transposed(4),
+            4,
+        );
+        assert_eq!(error.unwrap_err().position(), 9);
+    }
+
+    #[test]
+    fn symm() {
+        let (m, n) = (3, 4);
+        let (alpha, beta) = (c64::new(0.5, 1.0), c64::new(2.0, -0.5));
+        let (b, c) = (Matrix::new(m, n, 2.0), Matrix::new(m, n, -1.0));
+        let (ldb, ldc) = (5, 6);
+        let (bc, br) = (b.dense(ldb), b.transposed(ldb));
+        let (mi, ni, bi, ci) = (m as i32, n as i32, ldb as i32, ldc as i32);
+        for &side in &SIDES {
+            let order = if side == Side::Left { m } else { n };
+            let a = Matrix::new(order, order, 1.0);
+            let (ac, ar) = (a.dense(order + 1), a.transposed(order + 1));
+            let ai = order as i32 + 1;
+            for &uplo in &UPLOS {
+                let (mut x, mut y) = (c.dense(ldc), c.transposed(ldc));
+                checked::zhemm(
+                    side, uplo, mi, ni, alpha, &ac, ai, &bc, bi, beta, &mut x, ci,
+                )
+                .unwrap();
+                super::zhemm(
+                    side, uplo, mi, ni, alpha, &ar, ai, &br, bi, beta, &mut y, ci,
+                )
+                .unwrap();
+                compare(m, n, &x, &y, ldc);
+
+                let (mut x, mut y) = (c.dense(ldc), c.transposed(ldc));
+                checked::zsymm(
+                    side, uplo, mi, ni, alpha, &ac, ai, &bc, bi, beta, &mut x, ci,
+                )
+                .unwrap();
+                super::zsymm(
+                    side, uplo, mi, ni, alpha, &ar, ai, &br, bi, beta, &mut y, ci,
+                )
+                .unwrap();
+                compare(m, n, &x, &y, ldc);
+
+                let (mut x, mut y) = (real(&c.dense(ldc)), real(&c.transposed(ldc)));
+                let (ac, bc, ar, br) = (real(&ac), real(&bc), real(&ar), real(&br));
+                checked::dsymm(side, uplo, mi, ni, 0.5, &ac, ai, &bc, bi, 2.0, &mut x, ci).unwrap();
+                super::dsymm(side, uplo, mi, ni, 0.5, &ar, ai, &br, bi, 2.0, &mut y, ci).unwrap();
+                compare(m, n, &complex(&x), &complex(&y), ldc);
+            }
+        }
+    }
+
+    #[test]
+    fn rank() {
+        let (n, k) = (4, 2);
+        let alpha = c64::new(0.5, 1.0);
+        let c = Matrix::new(n, n, -1.0);
+        let ldc = 5;
+        let (ni, ki, ci) = (n as i32, k as i32, ldc as i32);
+        for &uplo in &UPLOS {
+            for &trans in &TRANSPOSES {
+                let (a, b) = if trans == Transpose::No {
+                    (Matrix::new(n, k, 1.0), Matrix::new(n, k, 2.0))
+                } else {
+                    (Matrix::new(k, n, 1.0), Matrix::new(k, n, 2.0))
+                };
+                let ld = a.rows.max(a.cols) + 1;
+                let (ac, ar) = (a.dense(ld), a.transposed(ld));
+                let (bc, br) = (b.dense(ld), b.transposed(ld));
+                let li = ld as i32;
+                if trans != Transpose::Trans {
+                    let (mut x, mut y) = (c.dense(ldc), c.transposed(ldc));
+                    checked::zherk(uplo, trans, ni, ki, 0.5, &ac, li, 2.0, &mut x, ci).unwrap();
+                    super::zherk(uplo, trans, ni, ki, 0.5, &ar, li, 2.0, &mut y, ci).unwrap();
+                    compare(n, n, &x, &y, ldc);
+
+                    // A complex alpha exercises the conjugation of alpha in
+                    // the row-major translation.
+                    let (mut x, mut y) = (c.dense(ldc), c.transposed(ldc));
+                    checked::zher2k(
+                        uplo, trans, ni, ki, alpha, &ac, li, &bc, li, 2.0, &mut x, ci,
+                    )
+                    .unwrap();
+                    super::zher2k(
+                        uplo, trans, ni, ki, alpha, &ar, li, &br, li, 2.0, &mut y, ci,
+                    )
+                    .unwrap();
+                    compare(n, n, &x, &y, ldc);
+                }
+                if trans != Transpose::ConjTrans {
+                    let (mut x, mut y) = (c.dense(ldc), c.transposed(ldc));
+                    checked::zsyrk(uplo, trans, ni, ki, alpha, &ac, li, alpha, &mut x, ci).unwrap();
+                    super::zsyrk(uplo, trans, ni, ki, alpha, &ar, li, alpha, &mut y, ci).unwrap();
+                    compare(n, n, &x, &y, ldc);
+
+                    let (mut x, mut y) = (c.dense(ldc), c.transposed(ldc));
+                    checked::zsyr2k(
+                        uplo, trans, ni, ki, alpha, &ac, li, &bc, li, alpha, &mut x, ci,
+                    )
+                    .unwrap();
+                    super::zsyr2k(
+                        uplo, trans, ni, ki, alpha, &ar, li, &br, li, alpha, &mut y, ci,
+                    )
+                    .unwrap();
+                    compare(n, n, &x, &y, ldc);
+
+                    let (ac, ar, bc, br) = (real(&ac), real(&ar), real(&bc), real(&br));
+                    let (mut x, mut y) = (real(&c.dense(ldc)), real(&c.transposed(ldc)));
+                    checked::dsyrk(uplo, trans, ni, ki, 0.5, &ac, li, 2.0, &mut x, ci).unwrap();
+                    super::dsyrk(uplo, trans, ni, ki, 0.5, &ar, li, 2.0, &mut y, ci).unwrap();
+                    checked::dsyr2k(uplo, trans, ni, ki, 0.5, &ac, li, &bc, li, 2.0, &mut x, ci)
+                        .unwrap();
+                    super::dsyr2k(uplo, trans, ni, ki, 0.5, &ar, li, &br, li, 2.0, &mut y, ci)
+                        .unwrap();
+                    compare(n, n, &complex(&x), &complex(&y), ldc);
+                }
+            }
+        }
+        let error = super::zher2k(
+            Uplo::Upper,
+            Transpose::Trans,
+            4,
+            2,
+            alpha,
+            &[alpha; 8],
+            2,
+            &[alpha; 8],
+            2,
+            2.0,
+            &mut [alpha; 16],
+            4,
+        );
+        assert_eq!(error.unwrap_err().position(), 2);
+    }
+
+    #[test]
+    fn triangular() {
+        let (m, n) = (3, 4);
+        let alpha = c64::new(0.5, 1.0);
+        let b = Matrix::new(m, n, 2.0);
+        let ldb = 5;
+        let (mi, ni, bi) = (m as i32, n as i32, ldb as i32);
+        for &side in &SIDES {
+            let order = if side == Side::Left { m } else { n };
+            let a = Matrix::triangular(order, 1.0);
+            let (ac, ar) = (a.dense(order + 1), a.transposed(order + 1));
+            let ai = order as i32 + 1;
+            for &uplo in &UPLOS {
+                for &trans in &TRANSPOSES {
+                    for &diag in &DIAGS {
+                        let (mut x, mut y) = (b.dense(ldb), b.transposed(ldb));
+                        checked::ztrmm(side, uplo, trans, diag, mi, ni, alpha, &ac, ai, &mut x, bi)
+                            .unwrap();
+                        super::ztrmm(side, uplo, trans, diag, mi, ni, alpha, &ar, ai, &mut y, bi)
+                            .unwrap();
+                        compare(m, n, &x, &y, ldb);
+                        checked::ztrsm(side, uplo, trans, diag, mi, ni, alpha, &ac, ai, &mut x, bi)
+                            .unwrap();
+                        super::ztrsm(side, uplo, trans, diag, mi, ni, alpha, &ar, ai, &mut y, bi)
+                            .unwrap();
+                        compare(m, n, &x, &y, ldb);
+
+                        let (ac, ar) = (real(&ac), real(&ar));
+                        let (mut x, mut y) = (real(&b.dense(ldb)), real(&b.transposed(ldb)));
+                        checked::dtrmm(side, uplo, trans, diag, mi, ni, 0.5, &ac, ai, &mut x, bi)
+                            .unwrap();
+                        super::dtrmm(side, uplo, trans, diag, mi, ni, 0.5, &ar, ai, &mut y, bi)
+                            .unwrap();
+                        checked::dtrsm(side, uplo, trans, diag, mi, ni, 2.0, &ac, ai, &mut x, bi)
+                            .unwrap();
+                        super::dtrsm(side, uplo, trans, diag, mi, ni, 2.0, &ar, ai, &mut y, bi)
+                            .unwrap();
+                        compare(m, n, &complex(&x), &complex(&y), ldb);
+                    }
+                }
+            }
+        }
+        let error = super::ztrsm(
+            Side::Left,
+            Uplo::Upper,
+            Transpose::No,
+            Diag::Unit,
+            3,
+            4,
+            alpha,
+            &[alpha; 9],
+            2,
+            &mut [alpha; 12],
+            4,
+        );
+        assert_eq!(error.unwrap_err().position(), 9);
+    }
+}
@@ -0,0 +1,115 @@
+//! Wrappers for matrices stored in row-major order.
+//!
+//! Each function takes the same arguments as its counterpart in
+//! [`checked`](../checked/index.html), but the matrices are stored row by
+//! row: element `(i, j)` of a matrix with leading dimension `ld` is found at
+//! `i * ld + j`, and the leading dimension is therefore bounded by the number
+//! of columns. The arguments are validated accordingly.
+//!
+//! A row-major matrix is the column-major storage of its transpose, and the
+//! calls are translated using the same identities as CBLAS: the operands and
+//! the dimensions of `?gemm` are swapped, `uplo` and `side` are flipped, and
+//! the transposition of the rank-k updates and of the Level 2 routines is
+//! inverted. Where the translation calls for conjugation without
+//! transposition, which BLAS cannot express, the vectors involved are
+//! conjugated before and after the call or copied. The views in
+//! [`matrix`](../matrix/index.html) are translated the same way.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use blas::row_major::dgemm;
+//! use blas::Transpose;
+//!
+//! let (m, n, k) = (2, 3, 2);
+//! let a = vec![1.0, 2.0, 3.0, 4.0];
+//! let b = vec![1.0, 0.0, 1.0, 0.0, 1.0, 1.0];
+//! let mut c = vec![0.0; 6];
+//! dgemm(Transpose::No, Transpose::No, m, n, k, 1.0, &a, k, &b, n, 0.0, &mut c, n).unwrap();
+//! assert_eq!(c, vec![1.0, 2.0, 3.0, 3.0, 4.0, 7.0]);
+//! ```
+
+use checked::matrix_len;
+use {Blas, Side, Transpose, Uplo};
+
+mod level2;
+mod level3;
+
+pub use self::level2::*;
+pub use self::level3::*;
+
+/// Return the number of elements spanned by a row-major matrix.
+#[inline]
+fn row_major_len(rows: i32, cols: i32, ld: i32) -> usize {
+    matrix_len(cols, rows, ld)
+}
+
+/// Return the opposite triangle.
+#[inline]
+pub(crate) fn flip_uplo(uplo: Uplo) -> Uplo {
+    match uplo {
+        Uplo::Upper => Uplo::Lower,
+        Uplo::Lower => Uplo::Upper,
+    }
+}
+
+/// Return the opposite side.
+#[inline]
+pub(crate) fn flip_side(side: Side) -> Side {
+    match side {
+        Side::Left => Side::Right,
+        Side::Right => Side::Left,
+    }
+}
+
+/// Return the operation on the column-major storage that corresponds to an
+/// operation on a row-major matrix, where `conjugate` selects the Hermitian
+/// variant of the transposition.
+///
+/// `ConjTrans` maps to `No`, in which case the caller is responsible for the
+/// conjugation.
+#[inline]
+pub(crate) fn invert(trans: Transpose, conjugate: bool) -> Transpose {
+    match trans {
+        Transpose::No if conjugate => Transpose::ConjTrans,
+        Transpose::No => Transpose::Trans,
+        Transpose::Trans | Transpose::ConjTrans => Transpose::No,
+    }
+}
+
+/// Return the scalar of a rank-2k update on the column-major storage of a
+/// row-major matrix, where `conjugate` selects the Hermitian variant.
+///
+/// The storage of a Hermitian matrix holds its conjugate, which swaps the
+/// roles of `alpha` and its conjugate.
+#[inline]
+pub(crate) fn rank2k_alpha<T: Blas>(alpha: T, conjugate: bool) -> T {
+    if conjugate {
+        alpha.conj()
+    } else {
+        alpha
+    }
+}
+
+/// Conjugate the elements of a vector in place.
+#[inline]
+pub(crate) fn conjugate<T: Blas>(n: i32, x: &mut [T], inc: i32) {
+    let step = inc.unsigned_abs() as usize;
+    for value in x.iter_mut().step_by(step).take(n.max(0) as usize) {
+        *value = value.conj();
+    }
+}
+
+/// Return the conjugated elements of a vector with unit increment.
+#[inline]
+pub(crate) fn conjugated<T: Blas>(n: i32, x: &[T], inc: i32) -> Vec<T> {
+    let step = inc.unsigned_abs() as usize;
+    let values = x.iter().step_by(step).take(n.max(0) as usize);
+    if inc > 0 {
+        values.map(|value| value.conj()).collect()
+    } else {
+        let mut values = values.map(|value| value.conj()).collect::<Vec<_>>();
+        values.reverse();
+        values
+    }
+}
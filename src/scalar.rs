@@ -0,0 +1,1358 @@
+//! Generic interface.
+//!
+//! The [`Blas`] trait is implemented for `f32`, `f64`, `c32`, and `c64` and
+//! dispatches to the wrapper with the corresponding prefix, which makes it
+//! possible to write numerical code once for all four types:
+//!
+//! ```no_run
+//! use blas::{Blas, Transpose};
+//!
+//! fn square<T: Blas>(n: i32, a: &[T], c: &mut [T]) {
+//!     unsafe {
+//!         T::gemm(Transpose::No, Transpose::No, n, n, n, T::ONE, a, n, a, n, T::ZERO, c, n);
+//!     }
+//! }
+//! ```
+//!
+//! The Hermitian routines fall back to the symmetric ones for real types, and
+//! routines that expect a real scalar take [`Blas::Real`].
+//!
+//! [`Blas`]: trait.Blas.html
+//! [`Blas::Real`]: trait.Blas.html#associatedtype.Real
+
+use {c32, c64, Diag, Side, Transpose, Uplo};
+
+/// A type supported by BLAS.
+pub trait Blas: Copy + Send + Sync + 'static {
+    /// The type of the real part, which is `Self` for real types.
+    type Real: Copy + PartialOrd + Send + Sync + 'static;
+
+    /// The additive identity.
+    const ZERO: Self;
+
+    /// The multiplicative identity.
+    const ONE: Self;
+
+    /// Return the complex conjugate, which is the value itself for real
+    /// types.
+    fn conj(self) -> Self;
+
+    /// Return the sum of the absolute values of the real and imaginary parts,
+    /// which is the magnitude compared by `i?amax`.
+    fn abs1(self) -> Self::Real;
+
+    /// Apply a plane rotation (`?rot`, `csrot`, or `zdrot`).
+    unsafe fn rot(
+        n: i32,
+        x: &mut [Self],
+        incx: i32,
+        y: &mut [Self],
+        incy: i32,
+        c: Self::Real,
+        s: Self::Real,
+    );
+
+    /// Interchange two vectors (`?swap`).
+    unsafe fn swap(n: i32, x: &mut [Self], incx: i32, y: &mut [Self], incy: i32);
+
+    /// Scale a vector (`?scal`).
+    unsafe fn scal(n: i32, a: Self, x: &mut [Self], incx: i32);
+
+    /// Copy a vector (`?copy`).
+    unsafe fn copy(n: i32, x: &[Self], incx: i32, y: &mut [Self], incy: i32);
+
+    /// Add a scaled vector to another vector (`?axpy`).
+    unsafe fn axpy(n: i32, alpha: Self, x: &[Self], incx: i32, y: &mut [Self], incy: i32);
+
+    /// Compute the dot product without conjugation (`?dot` or `?dotu`).
+    unsafe fn dot(n: i32, x: &[Self], incx: i32, y: &[Self], incy: i32) -> Self;
+
+    /// Compute the dot product conjugating the first vector (`?dot` or
+    /// `?dotc`).
+    unsafe fn dotc(n: i32, x: &[Self], incx: i32, y: &[Self], incy: i32) -> Self;
+
+    /// Compute the Euclidean norm (`?nrm2`).
+    unsafe fn nrm2(n: i32, x: &[Self], incx: i32) -> Self::Real;
+
+    /// Compute the sum of the absolute values of the real and imaginary parts
+    /// (`?asum`).
+    unsafe fn asum(n: i32, x: &[Self], incx: i32) -> Self::Real;
+
+    /// Find the one-based index of the element with the largest absolute value
+    /// (`i?amax`).
+    unsafe fn iamax(n: i32, x: &[Self], incx: i32) -> usize;
+
+    /// Compute a matrix-vector product (`?gemv`).
+    unsafe fn gemv(
+        trans: Transpose,
+        m: i32,
+        n: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        x: &[Self],
+        incx: i32,
+        beta: Self,
+        y: &mut [Self],
+        incy: i32,
+    );
+
+    /// Compute a band matrix-vector product (`?gbmv`).
+    unsafe fn gbmv(
+        trans: Transpose,
+        m: i32,
+        n: i32,
+        kl: i32,
+        ku: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        x: &[Self],
+        incx: i32,
+        beta: Self,
+        y: &mut [Self],
+        incy: i32,
+    );
+
+    /// Perform a rank-one update without conjugation (`?ger` or `?geru`).
+    unsafe fn ger(
+        m: i32,
+        n: i32,
+        alpha: Self,
+        x: &[Self],
+        incx: i32,
+        y: &[Self],
+        incy: i32,
+        a: &mut [Self],
+        lda: i32,
+    );
+
+    /// Perform a rank-one update conjugating the second vector (`?ger` or
+    /// `?gerc`).
+    unsafe fn gerc(
+        m: i32,
+        n: i32,
+        alpha: Self,
+        x: &[Self],
+        incx: i32,
+        y: &[Self],
+        incy: i32,
+        a: &mut [Self],
+        lda: i32,
+    );
+
+    /// Compute a Hermitian matrix-vector product (`?symv` or `?hemv`).
+    unsafe fn hemv(
+        uplo: Uplo,
+        n: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        x: &[Self],
+        incx: i32,
+        beta: Self,
+        y: &mut [Self],
+        incy: i32,
+    );
+
+    /// Compute a Hermitian band matrix-vector product (`?sbmv` or `?hbmv`).
+    unsafe fn hbmv(
+        uplo: Uplo,
+        n: i32,
+        k: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        x: &[Self],
+        incx: i32,
+        beta: Self,
+        y: &mut [Self],
+        incy: i32,
+    );
+
+    /// Compute a packed Hermitian matrix-vector product (`?spmv` or `?hpmv`).
+    unsafe fn hpmv(
+        uplo: Uplo,
+        n: i32,
+        alpha: Self,
+        ap: &[Self],
+        x: &[Self],
+        incx: i32,
+        beta: Self,
+        y: &mut [Self],
+        incy: i32,
+    );
+
+    /// Perform a Hermitian rank-one update (`?syr` or `?her`).
+    unsafe fn her(
+        uplo: Uplo,
+        n: i32,
+        alpha: Self::Real,
+        x: &[Self],
+        incx: i32,
+        a: &mut [Self],
+        lda: i32,
+    );
+
+    /// Perform a Hermitian rank-two update (`?syr2` or `?her2`).
+    unsafe fn her2(
+        uplo: Uplo,
+        n: i32,
+        alpha: Self,
+        x: &[Self],
+        incx: i32,
+        y: &[Self],
+        incy: i32,
+        a: &mut [Self],
+        lda: i32,
+    );
+
+    /// Perform a packed Hermitian rank-one update (`?spr` or `?hpr`).
+    unsafe fn hpr(uplo: Uplo, n: i32, alpha: Self::Real, x: &[Self], incx: i32, ap: &mut [Self]);
+
+    /// Perform a packed Hermitian rank-two update (`?spr2` or `?hpr2`).
+    unsafe fn hpr2(
+        uplo: Uplo,
+        n: i32,
+        alpha: Self,
+        x: &[Self],
+        incx: i32,
+        y: &[Self],
+        incy: i32,
+        ap: &mut [Self],
+    );
+
+    /// Compute a triangular matrix-vector product (`?trmv`).
+    unsafe fn trmv(
+        uplo: Uplo,
+        trans: Transpose,
+        diag: Diag,
+        n: i32,
+        a: &[Self],
+        lda: i32,
+        x: &mut [Self],
+        incx: i32,
+    );
+
+    /// Solve a triangular system (`?trsv`).
+    unsafe fn trsv(
+        uplo: Uplo,
+        trans: Transpose,
+        diag: Diag,
+        n: i32,
+        a: &[Self],
+        lda: i32,
+        x: &mut [Self],
+        incx: i32,
+    );
+
+    /// Compute a triangular band matrix-vector product (`?tbmv`).
+    unsafe fn tbmv(
+        uplo: Uplo,
+        trans: Transpose,
+        diag: Diag,
+        n: i32,
+        k: i32,
+        a: &[Self],
+        lda: i32,
+        x: &mut [Self],
+        incx: i32,
+    );
+
+    /// Solve a triangular band system (`?tbsv`).
+    unsafe fn tbsv(
+        uplo: Uplo,
+        trans: Transpose,
+        diag: Diag,
+        n: i32,
+        k: i32,
+        a: &[Self],
+        lda: i32,
+        x: &mut [Self],
+        incx: i32,
+    );
+
+    /// Compute a packed triangular matrix-vector product (`?tpmv`).
+    unsafe fn tpmv(
+        uplo: Uplo,
+        trans: Transpose,
+        diag: Diag,
+        n: i32,
+        ap: &[Self],
+        x: &mut [Self],
+        incx: i32,
+    );
+
+    /// Solve a packed triangular system (`?tpsv`).
+    unsafe fn tpsv(
+        uplo: Uplo,
+        trans: Transpose,
+        diag: Diag,
+        n: i32,
+        ap: &[Self],
+        x: &mut [Self],
+        incx: i32,
+    );
+
+    /// Compute a matrix-matrix product (`?gemm`).
+    unsafe fn gemm(
+        transa: Transpose,
+        transb: Transpose,
+        m: i32,
+        n: i32,
+        k: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        b: &[Self],
+        ldb: i32,
+        beta: Self,
+        c: &mut [Self],
+        ldc: i32,
+    );
+
+    /// Compute a symmetric matrix-matrix product (`?symm`).
+    unsafe fn symm(
+        side: Side,
+        uplo: Uplo,
+        m: i32,
+        n: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        b: &[Self],
+        ldb: i32,
+        beta: Self,
+        c: &mut [Self],
+        ldc: i32,
+    );
+
+    /// Compute a Hermitian matrix-matrix product (`?symm` or `?hemm`).
+    unsafe fn hemm(
+        side: Side,
+        uplo: Uplo,
+        m: i32,
+        n: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        b: &[Self],
+        ldb: i32,
+        beta: Self,
+        c: &mut [Self],
+        ldc: i32,
+    );
+
+    /// Perform a symmetric rank-k update (`?syrk`).
+    unsafe fn syrk(
+        uplo: Uplo,
+        trans: Transpose,
+        n: i32,
+        k: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        beta: Self,
+        c: &mut [Self],
+        ldc: i32,
+    );
+
+    /// Perform a Hermitian rank-k update (`?syrk` or `?herk`).
+    unsafe fn herk(
+        uplo: Uplo,
+        trans: Transpose,
+        n: i32,
+        k: i32,
+        alpha: Self::Real,
+        a: &[Self],
+        lda: i32,
+        beta: Self::Real,
+        c: &mut [Self],
+        ldc: i32,
+    );
+
+    /// Perform a symmetric rank-2k update (`?syr2k`).
+    unsafe fn syr2k(
+        uplo: Uplo,
+        trans: Transpose,
+        n: i32,
+        k: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        b: &[Self],
+        ldb: i32,
+        beta: Self,
+        c: &mut [Self],
+        ldc: i32,
+    );
+
+    /// Perform a Hermitian rank-2k update (`?syr2k` or `?her2k`).
+    unsafe fn her2k(
+        uplo: Uplo,
+        trans: Transpose,
+        n: i32,
+        k: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        b: &[Self],
+        ldb: i32,
+        beta: Self::Real,
+        c: &mut [Self],
+        ldc: i32,
+    );
+
+    /// Compute a triangular matrix-matrix product (`?trmm`).
+    unsafe fn trmm(
+        side: Side,
+        uplo: Uplo,
+        transa: Transpose,
+        diag: Diag,
+        m: i32,
+        n: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+    );
+
+    /// Solve a triangular system with multiple right-hand sides (`?trsm`).
+    unsafe fn trsm(
+        side: Side,
+        uplo: Uplo,
+        transa: Transpose,
+        diag: Diag,
+        m: i32,
+        n: i32,
+        alpha: Self,
+        a: &[Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+    );
+}
+
+macro_rules! common {
+    ($ty:ty {
+        rot: $rot:ident,
+        swap: $swap:ident,
+        scal: $scal:ident,
+        copy: $copy:ident,
+        axpy: $axpy:ident,
+        nrm2: $nrm2:ident,
+        asum: $asum:ident,
+        iamax: $iamax:ident,
+        gemv: $gemv:ident,
+        hemv: $hemv:ident,
+        her: $her:ident,
+        her2: $her2:ident,
+        gbmv: $gbmv:ident,
+        hbmv: $hbmv:ident,
+        hpmv: $hpmv:ident,
+        hpr: $hpr:ident,
+        hpr2: $hpr2:ident,
+        tbmv: $tbmv:ident,
+        tbsv: $tbsv:ident,
+        tpmv: $tpmv:ident,
+        tpsv: $tpsv:ident,
+        trmv: $trmv:ident,
+        trsv: $trsv:ident,
+        gemm: $gemm:ident,
+        symm: $symm:ident,
+        hemm: $hemm:ident,
+        syrk: $syrk:ident,
+        herk: $herk:ident,
+        syr2k: $syr2k:ident,
+        her2k: $her2k:ident,
+        trmm: $trmm:ident,
+        trsm: $trsm:ident,
+    }) => {
+        #[inline]
+        unsafe fn rot(
+            n: i32,
+            x: &mut [$ty],
+            incx: i32,
+            y: &mut [$ty],
+            incy: i32,
+            c: Self::Real,
+            s: Self::Real,
+        ) {
+            ::$rot(n, x, incx, y, incy, c, s)
+        }
+
+        #[inline]
+        unsafe fn swap(n: i32, x: &mut [$ty], incx: i32, y: &mut [$ty], incy: i32) {
+            ::$swap(n, x, incx, y, incy)
+        }
+
+        #[inline]
+        unsafe fn scal(n: i32, a: $ty, x: &mut [$ty], incx: i32) {
+            ::$scal(n, a, x, incx)
+        }
+
+        #[inline]
+        unsafe fn copy(n: i32, x: &[$ty], incx: i32, y: &mut [$ty], incy: i32) {
+            ::$copy(n, x, incx, y, incy)
+        }
+
+        #[inline]
+        unsafe fn axpy(n: i32, alpha: $ty, x: &[$ty], incx: i32, y: &mut [$ty], incy: i32) {
+            ::$axpy(n, alpha, x, incx, y, incy)
+        }
+
+        #[inline]
+        unsafe fn nrm2(n: i32, x: &[$ty], incx: i32) -> Self::Real {
+            ::$nrm2(n, x, incx)
+        }
+
+        #[inline]
+        unsafe fn asum(n: i32, x: &[$ty], incx: i32) -> Self::Real {
+            ::$asum(n, x, incx)
+        }
+
+        #[inline]
+        unsafe fn iamax(n: i32, x: &[$ty], incx: i32) -> usize {
+            ::$iamax(n, x, incx)
+        }
+
+        #[inline]
+        unsafe fn gemv(
+            trans: Transpose,
+            m: i32,
+            n: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            x: &[$ty],
+            incx: i32,
+            beta: $ty,
+            y: &mut [$ty],
+            incy: i32,
+        ) {
+            ::$gemv(trans, m, n, alpha, a, lda, x, incx, beta, y, incy)
+        }
+
+        #[inline]
+        unsafe fn gbmv(
+            trans: Transpose,
+            m: i32,
+            n: i32,
+            kl: i32,
+            ku: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            x: &[$ty],
+            incx: i32,
+            beta: $ty,
+            y: &mut [$ty],
+            incy: i32,
+        ) {
+            ::$gbmv(trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy)
+        }
+
+        #[inline]
+        unsafe fn hemv(
+            uplo: Uplo,
+            n: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            x: &[$ty],
+            incx: i32,
+            beta: $ty,
+            y: &mut [$ty],
+            incy: i32,
+        ) {
+            ::$hemv(uplo, n, alpha, a, lda, x, incx, beta, y, incy)
+        }
+
+        #[inline]
+        unsafe fn hbmv(
+            uplo: Uplo,
+            n: i32,
+            k: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            x: &[$ty],
+            incx: i32,
+            beta: $ty,
+            y: &mut [$ty],
+            incy: i32,
+        ) {
+            ::$hbmv(uplo, n, k, alpha, a, lda, x, incx, beta, y, incy)
+        }
+
+        #[inline]
+        unsafe fn hpmv(
+            uplo: Uplo,
+            n: i32,
+            alpha: $ty,
+            ap: &[$ty],
+            x: &[$ty],
+            incx: i32,
+            beta: $ty,
+            y: &mut [$ty],
+            incy: i32,
+        ) {
+            ::$hpmv(uplo, n, alpha, ap, x, incx, beta, y, incy)
+        }
+
+        #[inline]
+        unsafe fn her(
+            uplo: Uplo,
+            n: i32,
+            alpha: Self::Real,
+            x: &[$ty],
+            incx: i32,
+            a: &mut [$ty],
+            lda: i32,
+        ) {
+            ::$her(uplo, n, alpha, x, incx, a, lda)
+        }
+
+        #[inline]
+        unsafe fn her2(
+            uplo: Uplo,
+            n: i32,
+            alpha: $ty,
+            x: &[$ty],
+            incx: i32,
+            y: &[$ty],
+            incy: i32,
+            a: &mut [$ty],
+            lda: i32,
+        ) {
+            ::$her2(uplo, n, alpha, x, incx, y, incy, a, lda)
+        }
+
+        #[inline]
+        unsafe fn hpr(uplo: Uplo, n: i32, alpha: Self::Real, x: &[$ty], incx: i32, ap: &mut [$ty]) {
+            ::$hpr(uplo, n, alpha, x, incx, ap)
+        }
+
+        #[inline]
+        unsafe fn hpr2(
+            uplo: Uplo,
+            n: i32,
+            alpha: $ty,
+            x: &[$ty],
+            incx: i32,
+            y: &[$ty],
+            incy: i32,
+            ap: &mut [$ty],
+        ) {
+            ::$hpr2(uplo, n, alpha, x, incx, y, incy, ap)
+        }
+
+        #[inline]
+        unsafe fn trmv(
+            uplo: Uplo,
+            trans: Transpose,
+            diag: Diag,
+            n: i32,
+            a: &[$ty],
+            lda: i32,
+            x: &mut [$ty],
+            incx: i32,
+        ) {
+            ::$trmv(uplo, trans, diag, n, a, lda, x, incx)
+        }
+
+        #[inline]
+        unsafe fn trsv(
+            uplo: Uplo,
+            trans: Transpose,
+            diag: Diag,
+            n: i32,
+            a: &[$ty],
+            lda: i32,
+            x: &mut [$ty],
+            incx: i32,
+        ) {
+            ::$trsv(uplo, trans, diag, n, a, lda, x, incx)
+        }
+
+        #[inline]
+        unsafe fn tbmv(
+            uplo: Uplo,
+            trans: Transpose,
+            diag: Diag,
+            n: i32,
+            k: i32,
+            a: &[$ty],
+            lda: i32,
+            x: &mut [$ty],
+            incx: i32,
+        ) {
+            ::$tbmv(uplo, trans, diag, n, k, a, lda, x, incx)
+        }
+
+        #[inline]
+        unsafe fn tbsv(
+            uplo: Uplo,
+            trans: Transpose,
+            diag: Diag,
+            n: i32,
+            k: i32,
+            a: &[$ty],
+            lda: i32,
+            x: &mut [$ty],
+            incx: i32,
+        ) {
+            ::$tbsv(uplo, trans, diag, n, k, a, lda, x, incx)
+        }
+
+        #[inline]
+        unsafe fn tpmv(
+            uplo: Uplo,
+            trans: Transpose,
+            diag: Diag,
+            n: i32,
+            ap: &[$ty],
+            x: &mut [$ty],
+            incx: i32,
+        ) {
+            ::$tpmv(uplo, trans, diag, n, ap, x, incx)
+        }
+
+        #[inline]
+        unsafe fn tpsv(
+            uplo: Uplo,
+            trans: Transpose,
+            diag: Diag,
+            n: i32,
+            ap: &[$ty],
+            x: &mut [$ty],
+            incx: i32,
+        ) {
+            ::$tpsv(uplo, trans, diag, n, ap, x, incx)
+        }
+
+        #[inline]
+        unsafe fn gemm(
+            transa: Transpose,
+            transb: Transpose,
+            m: i32,
+            n: i32,
+            k: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            b: &[$ty],
+            ldb: i32,
+            beta: $ty,
+            c: &mut [$ty],
+            ldc: i32,
+        ) {
+            ::$gemm(transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+        }
+
+        #[inline]
+        unsafe fn symm(
+            side: Side,
+            uplo: Uplo,
+            m: i32,
+            n: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            b: &[$ty],
+            ldb: i32,
+            beta: $ty,
+            c: &mut [$ty],
+            ldc: i32,
+        ) {
+            ::$symm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc)
+        }
+
+        #[inline]
+        unsafe fn hemm(
+            side: Side,
+            uplo: Uplo,
+            m: i32,
+            n: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            b: &[$ty],
+            ldb: i32,
+            beta: $ty,
+            c: &mut [$ty],
+            ldc: i32,
+        ) {
+            ::$hemm(side, uplo, m, n, alpha, a, lda, b, ldb, beta, c, ldc)
+        }
+
+        #[inline]
+        unsafe fn syrk(
+            uplo: Uplo,
+            trans: Transpose,
+            n: i32,
+            k: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            beta: $ty,
+            c: &mut [$ty],
+            ldc: i32,
+        ) {
+            ::$syrk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+        }
+
+        #[inline]
+        unsafe fn herk(
+            uplo: Uplo,
+            trans: Transpose,
+            n: i32,
+            k: i32,
+            alpha: Self::Real,
+            a: &[$ty],
+            lda: i32,
+            beta: Self::Real,
+            c: &mut [$ty],
+            ldc: i32,
+        ) {
+            ::$herk(uplo, trans, n, k, alpha, a, lda, beta, c, ldc)
+        }
+
+        #[inline]
+        unsafe fn syr2k(
+            uplo: Uplo,
+            trans: Transpose,
+            n: i32,
+            k: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            b: &[$ty],
+            ldb: i32,
+            beta: $ty,
+            c: &mut [$ty],
+            ldc: i32,
+        ) {
+            ::$syr2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+        }
+
+        #[inline]
+        unsafe fn her2k(
+            uplo: Uplo,
+            trans: Transpose,
+            n: i32,
+            k: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            b: &[$ty],
+            ldb: i32,
+            beta: Self::Real,
+            c: &mut [$ty],
+            ldc: i32,
+        ) {
+            ::$her2k(uplo, trans, n, k, alpha, a, lda, b, ldb, beta, c, ldc)
+        }
+
+        #[inline]
+        unsafe fn trmm(
+            side: Side,
+            uplo: Uplo,
+            transa: Transpose,
+            diag: Diag,
+            m: i32,
+            n: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            b: &mut [$ty],
+            ldb: i32,
+        ) {
+            ::$trmm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+        }
+
+        #[inline]
+        unsafe fn trsm(
+            side: Side,
+            uplo: Uplo,
+            transa: Transpose,
+            diag: Diag,
+            m: i32,
+            n: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            b: &mut [$ty],
+            ldb: i32,
+        ) {
+            ::$trsm(side, uplo, transa, diag, m, n, alpha, a, lda, b, ldb)
+        }
+    };
+}
+
+macro_rules! real {
+    ($ty:ty {
+        dot: $dot:ident,
+        ger: $ger:ident,
+        $($routine:ident: $name:ident,)*
+    }) => {
+        impl Blas for $ty {
+            type Real = $ty;
+
+            const ZERO: $ty = 0.0;
+            const ONE: $ty = 1.0;
+
+            #[inline]
+            fn conj(self) -> $ty {
+                self
+            }
+
+            #[inline]
+            fn abs1(self) -> $ty {
+                self.abs()
+            }
+
+            #[inline]
+            unsafe fn dot(n: i32, x: &[$ty], incx: i32, y: &[$ty], incy: i32) -> $ty {
+                ::$dot(n, x, incx, y, incy)
+            }
+
+            #[inline]
+            unsafe fn dotc(n: i32, x: &[$ty], incx: i32, y: &[$ty], incy: i32) -> $ty {
+                ::$dot(n, x, incx, y, incy)
+            }
+
+            #[inline]
+            unsafe fn ger(
+                m: i32,
+                n: i32,
+                alpha: $ty,
+                x: &[$ty],
+                incx: i32,
+                y: &[$ty],
+                incy: i32,
+                a: &mut [$ty],
+                lda: i32,
+            ) {
+                ::$ger(m, n, alpha, x, incx, y, incy, a, lda)
+            }
+
+            #[inline]
+            unsafe fn gerc(
+                m: i32,
+                n: i32,
+                alpha: $ty,
+                x: &[$ty],
+                incx: i32,
+                y: &[$ty],
+                incy: i32,
+                a: &mut [$ty],
+                lda: i32,
+            ) {
+                ::$ger(m, n, alpha, x, incx, y, incy, a, lda)
+            }
+
+            common!($ty { $($routine: $name,)* });
+        }
+    };
+}
+
+macro_rules! complex {
+    ($ty:ty, $real:ty {
+        dotu: $dotu:ident,
+        dotc: $dotc:ident,
+        geru: $geru:ident,
+        gerc: $gerc:ident,
+        $($routine:ident: $name:ident,)*
+    }) => {
+        impl Blas for $ty {
+            type Real = $real;
+
+            const ZERO: $ty = <$ty>::new(0.0, 0.0);
+            const ONE: $ty = <$ty>::new(1.0, 0.0);
+
+            #[inline]
+            fn conj(self) -> $ty {
+                <$ty>::conj(&self)
+            }
+
+            #[inline]
+            fn abs1(self) -> $real {
+                self.re.abs() + self.im.abs()
+            }
+
+            #[inline]
+            unsafe fn dot(n: i32, x: &[$ty], incx: i32, y: &[$ty], incy: i32) -> $ty {
+                ::$dotu(n, x, incx, y, incy)
+            }
+
+            #[inline]
+            unsafe fn dotc(n: i32, x: &[$ty], incx: i32, y: &[$ty], incy: i32) -> $ty {
+                ::$dotc(n, x, incx, y, incy)
+            }
+
+            #[inline]
+            unsafe fn ger(
+                m: i32,
+                n: i32,
+                alpha: $ty,
+                x: &[$ty],
+                incx: i32,
+                y: &[$ty],
+                incy: i32,
+                a: &mut [$ty],
+                lda: i32,
+            ) {
+                ::$geru(m, n, alpha, x, incx, y, incy, a, lda)
+            }
+
+            #[inline]
+            unsafe fn gerc(
+                m: i32,
+                n: i32,
+                alpha: $ty,
+                x: &[$ty],
+                incx: i32,
+                y: &[$ty],
+                incy: i32,
+                a: &mut [$ty],
+                lda: i32,
+            ) {
+                ::$gerc(m, n, alpha, x, incx, y, incy, a, lda)
+            }
+
+            common!($ty { $($routine: $name,)* });
+        }
+    };
+}
+
+real!(f32 {
+    dot: sdot,
+    ger: sger,
+    rot: srot,
+    swap: sswap,
+    scal: sscal,
+    copy: scopy,
+    axpy: saxpy,
+    nrm2: snrm2,
+    asum: sasum,
+    iamax: isamax,
+    gemv: sgemv,
+    hemv: ssymv,
+    her: ssyr,
+    her2: ssyr2,
+    gbmv: sgbmv,
+    hbmv: ssbmv,
+    hpmv: sspmv,
+    hpr: sspr,
+    hpr2: sspr2,
+    tbmv: stbmv,
+    tbsv: stbsv,
+    tpmv: stpmv,
+    tpsv: stpsv,
+    trmv: strmv,
+    trsv: strsv,
+    gemm: sgemm,
+    symm: ssymm,
+    hemm: ssymm,
+    syrk: ssyrk,
+    herk: ssyrk,
+    syr2k: ssyr2k,
+    her2k: ssyr2k,
+    trmm: strmm,
+    trsm: strsm,
+});
+
+real!(f64 {
+    dot: ddot,
+    ger: dger,
+    rot: drot,
+    swap: dswap,
+    scal: dscal,
+    copy: dcopy,
+    axpy: daxpy,
+    nrm2: dnrm2,
+    asum: dasum,
+    iamax: idamax,
+    gemv: dgemv,
+    hemv: dsymv,
+    her: dsyr,
+    her2: dsyr2,
+    gbmv: dgbmv,
+    hbmv: dsbmv,
+    hpmv: dspmv,
+    hpr: dspr,
+    hpr2: dspr2,
+    tbmv: dtbmv,
+    tbsv: dtbsv,
+    tpmv: dtpmv,
+    tpsv: dtpsv,
+    trmv: dtrmv,
+    trsv: dtrsv,
+    gemm: dgemm,
+    symm: dsymm,
+    hemm: dsymm,
+    syrk: dsyrk,
+    herk: dsyrk,
+    syr2k: dsyr2k,
+    her2k: dsyr2k,
+    trmm: dtrmm,
+    trsm: dtrsm,
+});
+
+complex!(
+    c32,
+    f32 {
+        dotu: cdotu,
+        dotc: cdotc,
+        geru: cgeru,
+        gerc: cgerc,
+        rot: csrot,
+        swap: cswap,
+        scal: cscal,
+        copy: ccopy,
+        axpy: caxpy,
+        nrm2: scnrm2,
+        asum: scasum,
+        iamax: icamax,
+        gemv: cgemv,
+        hemv: chemv,
+        her: cher,
+        her2: cher2,
+        gbmv: cgbmv,
+        hbmv: chbmv,
+        hpmv: chpmv,
+        hpr: chpr,
+        hpr2: chpr2,
+        tbmv: ctbmv,
+        tbsv: ctbsv,
+        tpmv: ctpmv,
+        tpsv: ctpsv,
+        trmv: ctrmv,
+        trsv: ctrsv,
+        gemm: cgemm,
+        symm: csymm,
+        hemm: chemm,
+        syrk: csyrk,
+        herk: cherk,
+        syr2k: csyr2k,
+        her2k: cher2k,
+        trmm: ctrmm,
+        trsm: ctrsm,
+    }
+);
+
+complex!(
+    c64,
+    f64 {
+        dotu: zdotu,
+        dotc: zdotc,
+        geru: zgeru,
+        gerc: zgerc,
+        rot: zdrot,
+        swap: zswap,
+        scal: zscal,
+        copy: zcopy,
+        axpy: zaxpy,
+        nrm2: dznrm2,
+        asum: dzasum,
+        iamax: izamax,
+        gemv: zgemv,
+        hemv: zhemv,
+        her: zher,
+        her2: zher2,
+        gbmv: zgbmv,
+        hbmv: zhbmv,
+        hpmv: zhpmv,
+        hpr: zhpr,
+        hpr2: zhpr2,
+        tbmv: ztbmv,
+        tbsv: ztbsv,
+        tpmv: ztpmv,
+        tpsv: ztpsv,
+        trmv: ztrmv,
+        trsv: ztrsv,
+        gemm: zgemm,
+        symm: zsymm,
+        hemm: zhemm,
+        syrk: zsyrk,
+        herk: zherk,
+        syr2k: zsyr2k,
+        her2k: zher2k,
+        trmm: ztrmm,
+        trsm: ztrsm,
+    }
+);
+
+#[cfg(all(test, feature = "reference", not(feature = "dynamic")))]
+mod tests {
+    use super::*;
+
+    macro_rules! dispatch {
+        ($name:ident, $ty:ty, $real:ty, $new:expr, {
+            nrm2: $nrm2:ident,
+            asum: $asum:ident,
+            dotc: $dotc:ident,
+            iamax: $iamax:ident,
+            hemv: $hemv:ident,
+            her: $her:ident,
+            gemm: $gemm:ident,
+            herk: $herk:ident,
+            her2k: $her2k:ident,
+        }) => {
+            #[test]
+            fn $name() {
+                let new = $new;
+                let elements = |len: usize, seed: f64| -> Vec<$ty> {
+                    (0..len)
+                        .map(|i| new((seed + 0.7 * i as f64).sin(), (seed * i as f64).cos()))
+                        .collect()
+                };
+                let (alpha, beta) = (new(0.5, -1.5), new(2.0, 0.25));
+                let (x, y, a, b) = (
+                    elements(6, 1.0),
+                    elements(6, 2.0),
+                    elements(9, 3.0),
+                    elements(6, 4.0),
+                );
+                unsafe {
+                    let norm: $real = <$ty as Blas>::nrm2(3, &x, 2);
+                    assert_eq!(norm, ::$nrm2(3, &x, 2));
+                    let sum: $real = <$ty as Blas>::asum(6, &x, 1);
+                    assert_eq!(sum, ::$asum(6, &x, 1));
+                    assert_eq!(
+                        <$ty as Blas>::dotc(6, &x, 1, &y, 1),
+                        ::$dotc(6, &x, 1, &y, 1)
+                    );
+                    assert_eq!(<$ty as Blas>::iamax(6, &x, 1), ::$iamax(6, &x, 1));
+
+                    let (mut expected, mut actual) = (y.clone(), y.clone());
+                    ::$hemv(b'L', 3, alpha, &a, 3, &x, 2, beta, &mut expected, 2);
+                    <$ty>::hemv(Uplo::Lower, 3, alpha, &a, 3, &x, 2, beta, &mut actual, 2);
+                    assert_eq!(actual, expected);
+
+                    let (mut expected, mut actual) = (a.clone(), a.clone());
+                    ::$her(b'U', 3, 1.5, &x, 1, &mut expected, 3);
+                    <$ty>::her(Uplo::Upper, 3, 1.5, &x, 1, &mut actual, 3);
+                    assert_eq!(actual, expected);
+
+                    let (mut expected, mut actual) = (a.clone(), a.clone());
+                    ::$gemm(
+                        b'C',
+                        b'N',
+                        3,
+                        3,
+                        2,
+                        alpha,
+                        &b,
+                        2,
+                        &y,
+                        2,
+                        beta,
+                        &mut expected,
+                        3,
+                    );
+                    <$ty>::gemm(
+                        Transpose::ConjTrans,
+                        Transpose::No,
+                        3,
+                        3,
+                        2,
+                        alpha,
+                        &b,
+                        2,
+                        &y,
+                        2,
+                        beta,
+                        &mut actual,
+                        3,
+                    );
+                    assert_eq!(actual, expected);
+
+                    let (mut expected, mut actual) = (a.clone(), a.clone());
+                    ::$herk(b'U', b'C', 3, 2, 0.5, &b, 2, -1.0, &mut expected, 3);
+                    <$ty>::herk(
+                        Uplo::Upper,
+                        Transpose::ConjTrans,
+                        3,
+                        2,
+                        0.5,
+                        &b,
+                        2,
+                        -1.0,
+                        &mut actual,
+                        3,
+                    );
+                    assert_eq!(actual, expected);
+
+                    let (mut expected, mut actual) = (a.clone(), a.clone());
+                    ::$her2k(b'L', b'N', 3, 2, alpha, &b, 3, &y, 3, 2.0, &mut expected, 3);
+                    <$ty>::her2k(
+                        Uplo::Lower,
+                        Transpose::No,
+                        3,
+                        2,
+                        alpha,
+                        &b,
+                        3,
+                        &y,
+                        3,
+                        2.0,
+                        &mut actual,
+                        3,
+                    );
+                    assert_eq!(actual, expected);
+                }
+            }
+        };
+    }
+
+    dispatch!(single, f32, f32, |re: f64, _: f64| re as f32, {
+        nrm2: snrm2,
+        asum: sasum,
+        dotc: sdot,
+        iamax: isamax,
+        hemv: ssymv,
+        her: ssyr,
+        gemm: sgemm,
+        herk: ssyrk,
+        her2k: ssyr2k,
+    });
+
+    dispatch!(double, f64, f64, |re: f64, _: f64| re, {
+        nrm2: dnrm2,
+        asum: dasum,
+        dotc: ddot,
+        iamax: idamax,
+        hemv: dsymv,
+        her: dsyr,
+        gemm: dgemm,
+        herk: dsyrk,
+        her2k: dsyr2k,
+    });
+
+    dispatch!(complex, c32, f32, |re: f64, im: f64| c32::new(re as f32, im as f32), {
+        nrm2: scnrm2,
+        asum: scasum,
+        dotc: cdotc,
+        iamax: icamax,
+        hemv: chemv,
+        her: cher,
+        gemm: cgemm,
+        herk: cherk,
+        her2k: cher2k,
+    });
+
+    dispatch!(double_complex, c64, f64, c64::new, {
+        nrm2: dznrm2,
+        asum: dzasum,
+        dotc: zdotc,
+        iamax: izamax,
+        hemv: zhemv,
+        her: zher,
+        gemm: zgemm,
+        herk: zherk,
+        her2k: zher2k,
+    });
+}
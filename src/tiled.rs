@@ -0,0 +1,335 @@
+//! Parallel matrix multiplication.
+//!
+//! A [`Pool`] partitions the output of `?gemm` into tiles and computes them
+//! on several threads, calling the wrapper once per tile, which provides
+//! multi-core throughput even with a single-threaded library such as the
+//! reference implementation. Products below a configurable number of
+//! floating-point operations are passed on as a whole. Each call of the
+//! wrapper is traced and counted as a call of its own.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use blas::tiled::Pool;
+//!
+//! let n = 1000;
+//! let a = vec![1.0; 1000 * 1000];
+//! let b = vec![1.0; 1000 * 1000];
+//! let mut c = vec![0.0; 1000 * 1000];
+//!
+//! let pool = Pool::new(4).with_threshold(1e8);
+//! unsafe {
+//!     pool.dgemm(b'N', b'N', n, n, n, 1.0, &a, n, &b, n, 0.0, &mut c, n);
+//! }
+//! ```
+//!
+//! [`Pool`]: struct.Pool.html
+
+use std::convert::TryFrom;
+
+use checked::matrix_len;
+use parallel;
+use {c32, c64, Transpose};
+
+/// The default number of floating-point operations below which a product is
+/// computed as a whole.
+const THRESHOLD: f64 = 1e7;
+
+/// The default number of rows and columns of a tile.
+const TILE: usize = 256;
+
+/// A configuration of the threads computing tiles.
+///
+/// The threads are kept alive between calls and shared by all
+/// configurations, which only limit how many of them a call uses.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Pool {
+    threads: usize,
+    threshold: f64,
+    tile: usize,
+}
+
+impl Pool {
+    /// Create a configuration with the given number of threads and the
+    /// default threshold and tile size.
+    pub fn new(threads: usize) -> Self {
+        Pool {
+            threads: threads.max(1),
+            threshold: THRESHOLD,
+            tile: TILE,
+        }
+    }
+
+    /// Set the number of floating-point operations below which a product is
+    /// computed as a whole on the calling thread.
+    pub fn with_threshold(mut self, flops: f64) -> Self {
+        self.threshold = flops;
+        self
+    }
+
+    /// Set the number of rows and columns of a tile.
+    pub fn with_tile(mut self, size: usize) -> Self {
+        self.tile = size.max(1);
+        self
+    }
+
+    /// Return the number of threads.
+    #[inline]
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+
+    /// Return the threshold.
+    #[inline]
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Return the tile size.
+    #[inline]
+    pub fn tile(&self) -> usize {
+        self.tile
+    }
+
+    /// Return the number of columns of the panels of the output computed in
+    /// parallel if the product is to be computed in parallel.
+    ///
+    /// A panel is computed tile by tile by one thread, so that the threads
+    /// write disjoint parts of the output.
+    fn panel(&self, transa: u8, transb: u8, shape: [i32; 3], lds: [i32; 3]) -> Option<usize> {
+        let [m, n, k] = shape;
+        let [lda, ldb, ldc] = lds;
+        let transa = Transpose::try_from(transa).ok()?;
+        let transb = Transpose::try_from(transb).ok()?;
+        if m < 0 || n < 0 || k < 0 {
+            return None;
+        }
+        if lda < (if transa == Transpose::No { m } else { k }).max(1)
+            || ldb < (if transb == Transpose::No { k } else { n }).max(1)
+            || ldc < m.max(1)
+        {
+            return None;
+        }
+        let flops = 2.0 * m as f64 * n as f64 * k as f64;
+        if self.threads <= 1 || flops == 0.0 || flops < self.threshold {
+            return None;
+        }
+        let (m, n) = (m as usize, n as usize);
+        let panel = self.tile.min(n.div_ceil(self.threads));
+        if m.div_ceil(self.tile) * n.div_ceil(panel) <= 1 {
+            return None;
+        }
+        Some(panel)
+    }
+}
+
+impl Default for Pool {
+    /// Create a configuration with as many threads as are available.
+    fn default() -> Self {
+        Pool::new(parallel::available())
+    }
+}
+
+macro_rules! gemm {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!(
+            "Compute `c := alpha * op(a) * op(b) + beta * c` tile by tile using [`",
+            stringify!($name), "`](../fn.", stringify!($name), ".html).\n\n",
+            "The arguments are as for the wrapper, which is called directly if the product ",
+            "is below the threshold or the arguments are illegal.\n\n",
+            "## Panics\n\n",
+            "The function panics if a matrix has fewer elements than its dimensions and ",
+            "leading dimension require.",
+        )]
+        pub unsafe fn $name(
+            &self,
+            transa: impl Into<u8>,
+            transb: impl Into<u8>,
+            m: i32,
+            n: i32,
+            k: i32,
+            alpha: $ty,
+            a: &[$ty],
+            lda: i32,
+            b: &[$ty],
+            ldb: i32,
+            beta: $ty,
+            c: &mut [$ty],
+            ldc: i32,
+        ) {
+            let transa: u8 = transa.into();
+            let transb: u8 = transb.into();
+            let panel = match self.panel(transa, transb, [m, n, k], [lda, ldb, ldc]) {
+                Some(panel) => panel,
+                _ => {
+                    return ::$name(
+                        transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+                    )
+                }
+            };
+            let (transposea, transposeb) = (
+                !transa.eq_ignore_ascii_case(&b'N'),
+                !transb.eq_ignore_ascii_case(&b'N'),
+            );
+            let (rowsa, colsa) = if transposea { (k, m) } else { (m, k) };
+            let (rowsb, colsb) = if transposeb { (n, k) } else { (k, n) };
+            assert!(a.len() >= matrix_len(rowsa, colsa, lda), "a is too short");
+            assert!(b.len() >= matrix_len(rowsb, colsb, ldb), "b is too short");
+            assert!(c.len() >= matrix_len(m, n, ldc), "c is too short");
+            let (m, n) = (m as usize, n as usize);
+            let (lda, ldb, ldc) = (lda as usize, ldb as usize, ldc as usize);
+            let panels = n.div_ceil(panel);
+            let threads = self.threads.min(panels);
+            parallel::chunks(c, panel * ldc, panels, threads, |i, c| {
+                let col = i * panel;
+                let cols = panel.min(n - col);
+                let b = &b[if transposeb { col } else { col * ldb }..];
+                for row in (0..m).step_by(self.tile) {
+                    let rows = self.tile.min(m - row);
+                    ::$name(
+                        transa,
+                        transb,
+                        rows as i32,
+                        cols as i32,
+                        k,
+                        alpha,
+                        &a[if transposea { row * lda } else { row }..],
+                        lda as i32,
+                        b,
+                        ldb as i32,
+                        beta,
+                        &mut c[row..],
+                        ldc as i32,
+                    );
+                }
+            });
+        }
+    };
+}
+
+impl Pool {
+    gemm!(sgemm, f32);
+    gemm!(dgemm, f64);
+    gemm!(cgemm, c32);
+    gemm!(zgemm, c64);
+}
+
+#[cfg(all(test, feature = "reference", not(feature = "dynamic")))]
+mod tests {
+    use super::*;
+
+    /// Create arbitrary but reproducible elements.
+    fn elements(len: usize, seed: f64) -> Vec<c64> {
+        (0..len)
+            .map(|i| c64::new((seed + 0.7 * i as f64).sin(), (seed * i as f64).cos()))
+            .collect()
+    }
+
+    #[test]
+    fn real() {
+        let pool = Pool::new(4).with_threshold(0.0).with_tile(16);
+        let (m, n, k) = (37, 29, 11);
+        for &transa in b"NT" {
+            for &transb in b"NT" {
+                let lda = if transa == b'N' { m + 1 } else { k + 2 };
+                let ldb = if transb == b'N' { k + 3 } else { n };
+                let a: Vec<_> = elements(lda * if transa == b'N' { k } else { m }, 1.0)
+                    .iter()
+                    .map(|x| x.re)
+                    .collect();
+                let b: Vec<_> = elements(ldb * if transb == b'N' { n } else { k }, 2.0)
+                    .iter()
+                    .map(|x| x.im)
+                    .collect();
+                let ldc = m + 4;
+                let mut expected: Vec<_> = elements(ldc * n, 3.0).iter().map(|x| x.re).collect();
+                let mut c = expected.clone();
+                let (m, n, k) = (m as i32, n as i32, k as i32);
+                let (lda, ldb, ldc) = (lda as i32, ldb as i32, ldc as i32);
+                unsafe {
+                    ::dgemm(
+                        transa,
+                        transb,
+                        m,
+                        n,
+                        k,
+                        1.5,
+                        &a,
+                        lda,
+                        &b,
+                        ldb,
+                        -0.5,
+                        &mut expected,
+                        ldc,
+                    );
+                    pool.dgemm(
+                        transa, transb, m, n, k, 1.5, &a, lda, &b, ldb, -0.5, &mut c, ldc,
+                    );
+                }
+                assert_eq!(c, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn complex() {
+        let pool = Pool::new(3).with_threshold(0.0).with_tile(8);
+        let (m, n, k) = (21, 50, 9);
+        let (alpha, beta) = (c64::new(0.5, 1.0), c64::new(0.0, 0.0));
+        for &(transa, transb) in &[(b'N', b'C'), (b'C', b'N'), (b'T', b'C')] {
+            let lda = if transa == b'N' { m } else { k };
+            let ldb = if transb == b'N' { k } else { n + 1 };
+            let a = elements(lda * if transa == b'N' { k } else { m }, 4.0);
+            let b = elements(ldb * if transb == b'N' { n } else { k }, 5.0);
+            let mut expected = vec![c64::new(f64::NAN, 0.0); m * n];
+            let mut c = expected.clone();
+            let (m, n, k, lda, ldb) = (m as i32, n as i32, k as i32, lda as i32, ldb as i32);
+            unsafe {
+                ::zgemm(
+                    transa,
+                    transb,
+                    m,
+                    n,
+                    k,
+                    alpha,
+                    &a,
+                    lda,
+                    &b,
+                    ldb,
+                    beta,
+                    &mut expected,
+                    m,
+                );
+                pool.zgemm(
+                    transa, transb, m, n, k, alpha, &a, lda, &b, ldb, beta, &mut c, m,
+                );
+            }
+            assert_eq!(c, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "c is too short")]
+    fn short() {
+        let pool = Pool::new(2).with_threshold(0.0).with_tile(2);
+        let (a, b) = (vec![1.0; 16], vec![1.0; 16]);
+        let mut c = vec![0.0; 15];
+        unsafe {
+            pool.dgemm(
+                Transpose::No,
+                Transpose::No,
+                4,
+                4,
+                4,
+                1.0,
+                &a,
+                4,
+                &b,
+                4,
+                0.0,
+                &mut c,
+                4,
+            )
+        };
+    }
+}
@@ -0,0 +1,365 @@
+//! Tracing of calls.
+//!
+//! With the `trace` feature enabled, each wrapper at the root of the crate and
+//! in [`ilp64`] reports the name of the routine, the flags, the dimensions,
+//! the leading dimensions, the elapsed time, and an estimate of the number of
+//! floating-point operations to the [`Sink`] installed via [`install`], if
+//! any. With the feature disabled, the wrappers call BLAS directly. With the
+//! `counter` feature enabled, the same calls can also be accumulated by
+//! [`counter`].
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//!
+//! use blas::trace::{self, Ring};
+//!
+//! let ring = Arc::new(Ring::new(1000));
+//! trace::install(ring.clone());
+//!
+//! let x = vec![1.0, 2.0, 3.0];
+//! let norm = unsafe { blas::dnrm2(3, &x, 1) };
+//!
+//! for call in ring.calls() {
+//!     println!("{}", call);
+//! }
+//! ```
+//!
+//! [`Sink`]: trait.Sink.html
+//! [`counter`]: ../counter/index.html
+//! [`ilp64`]: ../ilp64/index.html
+//! [`install`]: fn.install.html
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static SINK: RwLock<Option<Arc<dyn Sink>>> = RwLock::new(None);
+
+/// A call to a routine.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Call {
+    /// The name of the routine.
+    pub routine: &'static str,
+    /// The names and values of the character arguments.
+    pub flags: Vec<(&'static str, char)>,
+    /// The names and values of the dimensions, such as `m`, `n`, and `k`.
+    pub dimensions: Vec<(&'static str, i64)>,
+    /// The names and values of the leading dimensions.
+    pub leading: Vec<(&'static str, i64)>,
+    /// The time spent in the routine.
+    pub elapsed: Duration,
+    /// The estimated number of floating-point operations.
+    pub flops: f64,
+    /// The estimated number of bytes read and written.
+    pub bytes: f64,
+}
+
+/// A recipient of calls.
+///
+/// Closures taking a call can be used as sinks.
+pub trait Sink: Send + Sync {
+    /// Record a call.
+    fn record(&self, call: &Call);
+}
+
+/// A sink keeping the most recent calls in memory.
+#[derive(Debug)]
+pub struct Ring {
+    capacity: usize,
+    calls: Mutex<VecDeque<Call>>,
+}
+
+/// A sink writing each call as a line to the standard error.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Log;
+
+impl Call {
+    /// Return the value of a dimension or leading dimension given its name.
+    pub fn dimension(&self, name: &str) -> Option<i64> {
+        self.dimensions
+            .iter()
+            .chain(&self.leading)
+            .find(|&&(other, _)| other == name)
+            .map(|&(_, value)| value)
+    }
+
+    /// Return the value of a flag given its name.
+    pub fn flag(&self, name: &str) -> Option<char> {
+        self.flags
+            .iter()
+            .find(|&&(other, _)| other == name)
+            .map(|&(_, value)| value)
+    }
+}
+
+impl fmt::Display for Call {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{}", self.routine)?;
+        for &(name, value) in &self.flags {
+            write!(formatter, " {}={}", name, value)?;
+        }
+        for &(name, value) in self.dimensions.iter().chain(&self.leading) {
+            write!(formatter, " {}={}", name, value)?;
+        }
+        write!(
+            formatter,
+            " elapsed={:?} flops={} bytes={}",
+            self.elapsed, self.flops, self.bytes
+        )
+    }
+}
+
+impl<F> Sink for F
+where
+    F: Fn(&Call) + Send + Sync,
+{
+    #[inline]
+    fn record(&self, call: &Call) {
+        self(call)
+    }
+}
+
+impl Ring {
+    /// Create a sink keeping at most `capacity` calls.
+    pub fn new(capacity: usize) -> Self {
+        Ring {
+            capacity,
+            calls: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Return the calls kept from the oldest to the most recent one.
+    pub fn calls(&self) -> Vec<Call> {
+        self.calls.lock().unwrap().iter().cloned().collect()
+    }
+
+    /// Remove all calls.
+    pub fn clear(&self) {
+        self.calls.lock().unwrap().clear();
+    }
+}
+
+impl Sink for Ring {
+    fn record(&self, call: &Call) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut calls = self.calls.lock().unwrap();
+        if calls.len() == self.capacity {
+            calls.pop_front();
+        }
+        calls.push_back(call.clone());
+    }
+}
+
+impl Sink for Log {
+    fn record(&self, call: &Call) {
+        eprintln!("{}", call);
+    }
+}
+
+/// A sink shared with the caller of `install`, which may be a trait object.
+struct Shared<S: ?Sized>(Arc<S>);
+
+impl<S: Sink + ?Sized> Sink for Shared<S> {
+    #[inline]
+    fn record(&self, call: &Call) {
+        self.0.record(call)
+    }
+}
+
+/// Use a sink for subsequent calls.
+pub fn install<S: Sink + ?Sized + 'static>(sink: Arc<S>) {
+    *SINK.write().unwrap() = Some(Arc::new(Shared(sink)));
+    ENABLED.store(true, Ordering::Release);
+}
+
+/// Stop recording calls.
+pub fn uninstall() {
+    ENABLED.store(false, Ordering::Release);
+    SINK.write().unwrap().take();
+}
+
+/// Return the current time if calls are being recorded or counted.
+#[inline]
+pub(crate) fn start() -> Option<Instant> {
+    if ENABLED.load(Ordering::Acquire) || counting() {
+        Some(Instant::now())
+    } else {
+        None
+    }
+}
+
+/// Check if calls are being counted.
+#[cfg(feature = "counter")]
+#[inline]
+fn counting() -> bool {
+    ::counter::enabled()
+}
+
+#[cfg(not(feature = "counter"))]
+#[inline]
+fn counting() -> bool {
+    false
+}
+
+/// Pass a call on to the sink and the counters.
+#[cold]
+pub(crate) fn record(
+    routine: &'static str,
+    flags: Vec<(&'static str, char)>,
+    dimensions: Vec<(&'static str, i64)>,
+    leading: Vec<(&'static str, i64)>,
+    elapsed: Duration,
+) {
+    let mut call = Call {
+        routine,
+        flags,
+        dimensions,
+        leading,
+        elapsed,
+        flops: 0.0,
+        bytes: 0.0,
+    };
+    call.flops = flops(&call);
+    call.bytes = bytes(&call);
+    #[cfg(feature = "counter")]
+    {
+        if ::counter::enabled() {
+            ::counter::add(&call);
+        }
+    }
+    let sink = SINK.read().unwrap().clone();
+    if let Some(sink) = sink {
+        sink.record(&call);
+    }
+}
+
+/// The operations whose floating-point operations and bytes are counted,
+/// which are recognized by the ends of the names of the routines.
+const OPERATIONS: &[&str] = &[
+    "gemm", "symm", "hemm", "syrk", "herk", "syr2k", "her2k", "trmm", "trsm", "gemv", "gbmv",
+    "symv", "hemv", "sbmv", "hbmv", "spmv", "hpmv", "trmv", "trsv", "tbmv", "tbsv", "tpmv", "tpsv",
+    "ger", "geru", "gerc", "syr", "her", "spr", "hpr", "syr2", "her2", "spr2", "hpr2", "dot",
+    "dotu", "dotc", "axpy", "nrm2", "asum", "scal", "amax", "rot", "rotm", "copy", "swap",
+];
+
+/// The routines applying real scalars to complex vectors.
+const REAL_SCALARS: &[&str] = &["csscal", "zdscal", "csrot", "zdrot"];
+
+/// Estimate the number of floating-point operations of a call, counting a
+/// complex multiplication and addition as eight operations and the
+/// multiplication of a complex number by a real one as two.
+fn flops(call: &Call) -> f64 {
+    let get = |name| call.dimension(name).unwrap_or(0) as f64;
+    let (m, n, k) = (get("m"), get("n"), get("k"));
+    let left = call
+        .flag("side")
+        .is_some_and(|side| side.eq_ignore_ascii_case(&'L'));
+    let routine = call.routine;
+    let count = match operation(routine) {
+        Some("gemm") => 2.0 * m * n * k,
+        Some("symm") | Some("hemm") => 2.0 * m * n * if left { m } else { n },
+        Some("syrk") | Some("herk") => n * n * k,
+        Some("syr2k") | Some("her2k") => 2.0 * n * n * k,
+        Some("trmm") | Some("trsm") => m * n * if left { m } else { n },
+        Some("gemv") | Some("ger") | Some("geru") | Some("gerc") => 2.0 * m * n,
+        Some("gbmv") => 2.0 * n * (get("kl") + get("ku") + 1.0),
+        Some("sbmv") | Some("hbmv") => 2.0 * n * (2.0 * k + 1.0),
+        Some("tbmv") | Some("tbsv") => n * (2.0 * k + 1.0),
+        Some("symv") | Some("hemv") | Some("spmv") | Some("hpmv") => 2.0 * n * n,
+        Some("syr2") | Some("her2") | Some("spr2") | Some("hpr2") => 2.0 * n * n,
+        Some("trmv") | Some("trsv") | Some("tpmv") | Some("tpsv") => n * n,
+        Some("syr") | Some("her") | Some("spr") | Some("hpr") => n * n,
+        Some("rot") | Some("rotm") => 6.0 * n,
+        Some("dot") | Some("dotu") | Some("dotc") | Some("axpy") | Some("nrm2") => 2.0 * n,
+        Some("asum") | Some("scal") | Some("amax") => n,
+        _ => 0.0,
+    };
+    if REAL_SCALARS.contains(&routine) {
+        2.0 * count
+    } else if complex(routine) {
+        4.0 * count
+    } else {
+        count
+    }
+}
+
+/// Estimate the number of bytes read and written by a call, counting each
+/// element of the output as read and written once, and only the referenced
+/// triangle or band of a structured matrix.
+fn bytes(call: &Call) -> f64 {
+    let get = |name| call.dimension(name).unwrap_or(0) as f64;
+    let (m, n, k) = (get("m"), get("n"), get("k"));
+    let left = call
+        .flag("side")
+        .is_some_and(|side| side.eq_ignore_ascii_case(&'L'));
+    let transposed = call
+        .flag("trans")
+        .is_some_and(|trans| !trans.eq_ignore_ascii_case(&'N'));
+    let (x, y) = if transposed { (m, n) } else { (n, m) };
+    let triangle = |n: f64| n * (n + 1.0) / 2.0;
+    let elements = match operation(call.routine) {
+        Some("gemm") => m * k + k * n + 2.0 * m * n,
+        Some("symm") | Some("hemm") => triangle(if left { m } else { n }) + 3.0 * m * n,
+        Some("syrk") | Some("herk") => n * k + 2.0 * triangle(n),
+        Some("syr2k") | Some("her2k") => 2.0 * n * k + 2.0 * triangle(n),
+        Some("trmm") | Some("trsm") => triangle(if left { m } else { n }) + 2.0 * m * n,
+        Some("gemv") => m * n + x + 2.0 * y,
+        Some("gbmv") => n * (get("kl") + get("ku") + 1.0) + x + 2.0 * y,
+        Some("ger") | Some("geru") | Some("gerc") => 2.0 * m * n + m + n,
+        Some("sbmv") | Some("hbmv") => n * (k + 1.0) + 3.0 * n,
+        Some("tbmv") | Some("tbsv") => n * (k + 1.0) + 2.0 * n,
+        Some("symv") | Some("hemv") | Some("spmv") | Some("hpmv") => triangle(n) + 3.0 * n,
+        Some("trmv") | Some("trsv") | Some("tpmv") | Some("tpsv") => triangle(n) + 2.0 * n,
+        Some("syr") | Some("her") | Some("spr") | Some("hpr") => 2.0 * triangle(n) + n,
+        Some("syr2") | Some("her2") | Some("spr2") | Some("hpr2") => 2.0 * triangle(n) + 2.0 * n,
+        Some("rot") | Some("rotm") | Some("swap") => 4.0 * n,
+        Some("axpy") => 3.0 * n,
+        Some("scal") | Some("copy") | Some("dot") | Some("dotu") | Some("dotc") => 2.0 * n,
+        Some("nrm2") | Some("asum") | Some("amax") => n,
+        _ => 0.0,
+    };
+    elements * size(call.routine)
+}
+
+/// Return the operation performed by a routine if it is counted.
+#[inline]
+fn operation(routine: &str) -> Option<&'static str> {
+    OPERATIONS
+        .iter()
+        .find(|operation| routine.ends_with(*operation))
+        .cloned()
+}
+
+/// Return the size in bytes of the elements of the vectors and matrices
+/// taken by a routine.
+fn size(routine: &str) -> f64 {
+    let routine = routine.trim_start_matches('i');
+    if complex(routine) {
+        if routine.starts_with('z') || routine.starts_with("dz") {
+            16.0
+        } else {
+            8.0
+        }
+    } else if routine.starts_with('d') && !routine.starts_with("dsdot") {
+        8.0
+    } else {
+        4.0
+    }
+}
+
+/// Check if a routine operates on complex numbers.
+#[inline]
+fn complex(routine: &str) -> bool {
+    let routine = routine.trim_start_matches('i');
+    ["c", "z", "sc", "dz"]
+        .iter()
+        .any(|prefix| routine.starts_with(prefix))
+        && routine != "scopy"
+}
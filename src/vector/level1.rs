@@ -0,0 +1,278 @@
+use super::{same, StridedVec, StridedVecMut};
+use checked::Check;
+use {c32, c64, Blas, Result};
+
+/// Apply a plane rotation to `x` and `y`.
+pub fn rot<T: Blas>(
+    x: &mut StridedVecMut<T>,
+    y: &mut StridedVecMut<T>,
+    c: T::Real,
+    s: T::Real,
+) -> Result<()> {
+    let check = Check::new("rot");
+    same(&check, 2, "y", y.len, x.len)?;
+    unsafe { T::rot(x.len as i32, x.data, x.inc, y.data, y.inc, c, s) };
+    Ok(())
+}
+
+/// Interchange `x` and `y`.
+pub fn swap<T: Blas>(x: &mut StridedVecMut<T>, y: &mut StridedVecMut<T>) -> Result<()> {
+    let check = Check::new("swap");
+    same(&check, 2, "y", y.len, x.len)?;
+    unsafe { T::swap(x.len as i32, x.data, x.inc, y.data, y.inc) };
+    Ok(())
+}
+
+/// Compute `x := a * x`.
+pub fn scal<T: Blas>(a: T, x: &mut StridedVecMut<T>) {
+    // The order of the elements does not matter.
+    unsafe { T::scal(x.len as i32, a, x.data, x.inc.abs()) };
+}
+
+/// Copy `x` into `y`.
+pub fn copy<T: Blas>(x: StridedVec<T>, y: &mut StridedVecMut<T>) -> Result<()> {
+    let check = Check::new("copy");
+    same(&check, 2, "y", y.len, x.len)?;
+    unsafe { T::copy(x.len as i32, x.data, x.inc, y.data, y.inc) };
+    Ok(())
+}
+
+/// Compute `y := alpha * x + y`.
+pub fn axpy<T: Blas>(alpha: T, x: StridedVec<T>, y: &mut StridedVecMut<T>) -> Result<()> {
+    let check = Check::new("axpy");
+    same(&check, 3, "y", y.len, x.len)?;
+    unsafe { T::axpy(x.len as i32, alpha, x.data, x.inc, y.data, y.inc) };
+    Ok(())
+}
+
+/// Compute `x^T * y`.
+pub fn dot<T: Blas>(x: StridedVec<T>, y: StridedVec<T>) -> Result<T> {
+    let check = Check::new("dot");
+    same(&check, 2, "y", y.len, x.len)?;
+    Ok(unsafe { T::dot(x.len as i32, x.data, x.inc, y.data, y.inc) })
+}
+
+/// Compute `x^H * y`.
+pub fn dotc<T: Blas>(x: StridedVec<T>, y: StridedVec<T>) -> Result<T> {
+    let check = Check::new("dotc");
+    same(&check, 2, "y", y.len, x.len)?;
+    Ok(unsafe { T::dotc(x.len as i32, x.data, x.inc, y.data, y.inc) })
+}
+
+/// Compute the Euclidean norm of `x`.
+pub fn nrm2<T: Blas>(x: StridedVec<T>) -> T::Real {
+    // The order of the elements does not matter.
+    unsafe { T::nrm2(x.len as i32, x.data, x.inc.abs()) }
+}
+
+/// Compute the sum of the absolute values of the real and imaginary parts of
+/// `x`.
+pub fn asum<T: Blas>(x: StridedVec<T>) -> T::Real {
+    // The order of the elements does not matter.
+    unsafe { T::asum(x.len as i32, x.data, x.inc.abs()) }
+}
+
+/// Find the index of the element with the largest absolute value.
+///
+/// The index is zero-based and refers to the order of the elements in the
+/// view, and the result is `None` if the vector is empty. For complex types,
+/// the absolute value is the sum of the absolute values of the real and
+/// imaginary parts. Ties are resolved in favor of the element with the
+/// smallest index, as in `i?amax`, regardless of the sign of the increment.
+/// The handling of NaN is that of the backend; the reference implementation
+/// ignores NaN unless it is the first element stored.
+pub fn iamax<T: Blas>(x: StridedVec<T>) -> Option<usize> {
+    if x.len == 0 {
+        return None;
+    }
+    let step = x.step();
+    let index = unsafe { T::iamax(x.len as i32, x.data, step as i32) };
+    if index == 0 {
+        return None;
+    }
+    let mut stored = index - 1;
+    if x.inc < 0 {
+        // The backend prefers the element stored first, which comes last in
+        // the view, so look for ties stored after it.
+        let maximum = x.data[stored * step].abs1();
+        if let Some(last) = (stored + 1..x.len)
+            .rev()
+            .find(|&i| x.data[i * step].abs1() == maximum)
+        {
+            stored = last;
+        }
+    }
+    Some(position(&x, stored))
+}
+
+macro_rules! index {
+    ($name:ident, $function:ident, $ty:ty, $extreme:expr, $using:expr) => {
+        #[doc = concat!(
+            "Find the zero-based index of the element with the ", $extreme,
+            " absolute value", $using, ".\n\n",
+            "The result is `None` if `n` is not positive. Otherwise, `x` holds `n` elements ",
+            "`incx` elements apart as in [`StridedVec::new`](struct.StridedVec.html#method.new), ",
+            "and the conventions of [`", stringify!($function), "`](fn.",
+            stringify!($function), ".html) apply. An error is returned if `incx` is zero or ",
+            "`x` is too short.",
+        )]
+        pub fn $name(n: i32, x: &[$ty], incx: i32) -> Result<Option<usize>> {
+            let check = Check::new(stringify!($name));
+            check.increment(3, "incx", incx)?;
+            if n <= 0 {
+                return Ok(None);
+            }
+            check.vector(2, "x", x.len(), n, incx)?;
+            Ok($function(StridedVec::new(x, n as usize, incx)?))
+        }
+    };
+}
+
+macro_rules! iamax {
+    ($name:ident, $ty:ty) => {
+        index!(
+            $name,
+            iamax,
+            $ty,
+            "largest",
+            concat!(
+                " using [`",
+                stringify!($name),
+                "`](../fn.",
+                stringify!($name),
+                ".html)"
+            )
+        );
+    };
+}
+
+iamax!(isamax, f32);
+iamax!(idamax, f64);
+iamax!(icamax, c32);
+iamax!(izamax, c64);
+
+/// Find the index of the element with the smallest absolute value.
+///
+/// The function is implemented in Rust, as many backends lack `i?amin`, and
+/// follows the conventions of [`iamax`](fn.iamax.html), except that NaN is
+/// never ignored: if the vector contains NaN, the index of the first NaN
+/// element is returned.
+///
+/// ```
+/// use blas::vector::{iamin, StridedVec};
+///
+/// let x = [3.0, -1.0, 2.0, f64::NAN];
+/// assert_eq!(iamin(StridedVec::new(&x, 3, 1).unwrap()), Some(1));
+/// assert_eq!(iamin(StridedVec::new(&x, 2, -2).unwrap()), Some(0));
+/// assert_eq!(iamin(StridedVec::from(&x[..])), Some(3));
+/// assert_eq!(iamin::<f64>(StridedVec::from(&[][..])), None);
+/// ```
+pub fn iamin<T: Blas>(x: StridedVec<T>) -> Option<usize> {
+    let mut best: Option<(usize, T::Real)> = None;
+    for (index, value) in x.iter().enumerate() {
+        let value = value.abs1();
+        if value.partial_cmp(&value).is_none() {
+            return Some(index);
+        }
+        match best {
+            Some((_, minimum)) if minimum <= value => {}
+            _ => best = Some((index, value)),
+        }
+    }
+    best.map(|(index, _)| index)
+}
+
+macro_rules! iamin {
+    ($name:ident, $ty:ty) => {
+        index!($name, iamin, $ty, "smallest", "");
+    };
+}
+
+iamin!(isamin, f32);
+iamin!(idamin, f64);
+iamin!(icamin, c32);
+iamin!(izamin, c64);
+
+/// Convert the position of an element in the buffer into an index.
+#[inline]
+fn position<T>(x: &StridedVec<T>, stored: usize) -> usize {
+    if x.inc > 0 {
+        stored
+    } else {
+        x.len - 1 - stored
+    }
+}
+
+#[cfg(all(test, feature = "reference", not(feature = "dynamic")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maximum() {
+        let x = [1.0, -4.0, 2.0, 4.0, 3.0];
+        assert_eq!(iamax(StridedVec::from(&x[..])), Some(1));
+        assert_eq!(iamax(StridedVec::new(&x, 5, -1).unwrap()), Some(1));
+        assert_eq!(iamax(StridedVec::new(&x, 3, -2).unwrap()), Some(0));
+        assert_eq!(iamax(StridedVec::new(&x, 2, -3).unwrap()), Some(0));
+        assert_eq!(iamax::<f64>(StridedVec::from(&[][..])), None);
+
+        let x = [c64::new(1.0, -2.0), c64::new(-3.0, 0.0), c64::new(0.5, 2.5)];
+        assert_eq!(izamax(3, &x, 1).unwrap(), Some(0));
+        assert_eq!(izamax(3, &x, -1).unwrap(), Some(0));
+        assert_eq!(izamax(2, &x, -2).unwrap(), Some(0));
+        assert_eq!(izamax(2, &x[1..], -1).unwrap(), Some(0));
+    }
+
+    #[test]
+    fn precisions() {
+        assert_eq!(isamax(3, &[1.0, -2.0, 2.0], 1).unwrap(), Some(1));
+        assert_eq!(isamax(3, &[1.0, -2.0, 2.0], -1).unwrap(), Some(0));
+        assert_eq!(idamax(2, &[1.0, 9.0, -3.0], 2).unwrap(), Some(1));
+        assert_eq!(idamax(0, &[], 1).unwrap(), None);
+        assert_eq!(idamax(-1, &[1.0], 1).unwrap(), None);
+        assert_eq!(
+            icamax(2, &[c32::new(0.0, 1.0), c32::new(-1.0, 0.0)], 1).unwrap(),
+            Some(0)
+        );
+        assert_eq!(izamax(1, &[c64::new(0.0, 0.0)], 1).unwrap(), Some(0));
+    }
+
+    #[test]
+    fn invalid() {
+        let error = idamax(2, &[1.0, 2.0], 0).unwrap_err();
+        assert_eq!((error.routine(), error.position()), ("idamax", 3));
+        assert_eq!(idamin(0, &[], 0).unwrap_err().position(), 3);
+        assert_eq!(
+            izamax(3, &[c64::new(1.0, 0.0); 4], -2)
+                .unwrap_err()
+                .position(),
+            2
+        );
+        assert_eq!(isamin(2, &[1.0], 1).unwrap_err().position(), 2);
+    }
+
+    #[test]
+    fn minimum() {
+        let x = [3.0, 1.0, -2.0, -1.0];
+        assert_eq!(iamin(StridedVec::from(&x[..])), Some(1));
+        assert_eq!(iamin(StridedVec::new(&x, 4, -1).unwrap()), Some(0));
+        assert_eq!(iamin(StridedVec::new(&x, 2, -2).unwrap()), Some(0));
+
+        let x = [f64::NAN, 1.0, f64::NAN, 0.0];
+        assert_eq!(iamin(StridedVec::new(&x, 4, -1).unwrap()), Some(1));
+        assert_eq!(iamin(StridedVec::from(&x[..])), Some(0));
+
+        assert_eq!(isamin(3, &[1.0, -0.5, 0.5], 1).unwrap(), Some(1));
+        assert_eq!(isamin(3, &[1.0, -0.5, 0.5], -1).unwrap(), Some(0));
+        assert_eq!(idamin(2, &[3.0, 9.0, -3.0], 2).unwrap(), Some(0));
+        assert_eq!(idamin(-1, &[1.0], 1).unwrap(), None);
+        assert_eq!(
+            icamin(2, &[c32::new(0.0, 1.0), c32::new(-0.5, 0.0)], 1).unwrap(),
+            Some(1)
+        );
+        assert_eq!(
+            izamin(2, &[c64::new(1.0, 1.0), c64::new(2.0, 0.0)], -1).unwrap(),
+            Some(0)
+        );
+    }
+}
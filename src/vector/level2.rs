@@ -0,0 +1,506 @@
+use super::{same, StridedVec, StridedVecMut};
+use checked::{matrix_len, packed_len, Check};
+use {Blas, Diag, Result, Transpose, Uplo};
+
+/// Compute `y := alpha * op(a) * x + beta * y` for a band matrix `a` with
+/// `kl` subdiagonals and `ku` superdiagonals.
+///
+/// The numbers of rows and columns of `a` are the numbers of elements of `y`
+/// and `x`, respectively, for `Transpose::No` and the other way around
+/// otherwise.
+pub fn gbmv<T: Blas>(
+    trans: Transpose,
+    kl: i32,
+    ku: i32,
+    alpha: T,
+    a: &[T],
+    lda: i32,
+    x: StridedVec<T>,
+    beta: T,
+    y: &mut StridedVecMut<T>,
+) -> Result<()> {
+    let check = Check::new("gbmv");
+    check.dimension(2, "kl", kl)?;
+    check.dimension(3, "ku", ku)?;
+    let band = i64::from(kl) + i64::from(ku) + 1;
+    let band = check.band(6, "lda", lda, band, "must be at least kl + ku + 1")?;
+    let (m, n) = if trans == Transpose::No {
+        (y.len as i32, x.len as i32)
+    } else {
+        (x.len as i32, y.len as i32)
+    };
+    check.length(5, "a", a.len(), matrix_len(band, n, lda))?;
+    unsafe {
+        T::gbmv(
+            trans, m, n, kl, ku, alpha, a, lda, x.data, x.inc, beta, y.data, y.inc,
+        );
+    }
+    check.finish()
+}
+
+/// Compute `y := alpha * a * x + beta * y` for a Hermitian band matrix `a`
+/// with `k` superdiagonals, which is `?sbmv` for real types.
+pub fn hbmv<T: Blas>(
+    uplo: Uplo,
+    k: i32,
+    alpha: T,
+    a: &[T],
+    lda: i32,
+    x: StridedVec<T>,
+    beta: T,
+    y: &mut StridedVecMut<T>,
+) -> Result<()> {
+    let check = Check::new("hbmv");
+    check.dimension(2, "k", k)?;
+    let band = check.band(5, "lda", lda, i64::from(k) + 1, "must be at least k + 1")?;
+    same(&check, 8, "y", y.len, x.len)?;
+    let n = x.len as i32;
+    check.length(4, "a", a.len(), matrix_len(band, n, lda))?;
+    unsafe {
+        T::hbmv(
+            uplo, n, k, alpha, a, lda, x.data, x.inc, beta, y.data, y.inc,
+        );
+    }
+    check.finish()
+}
+
+/// Compute `y := alpha * a * x + beta * y` for a packed Hermitian matrix `a`,
+/// which is `?spmv` for real types.
+pub fn hpmv<T: Blas>(
+    uplo: Uplo,
+    alpha: T,
+    ap: &[T],
+    x: StridedVec<T>,
+    beta: T,
+    y: &mut StridedVecMut<T>,
+) -> Result<()> {
+    let check = Check::new("hpmv");
+    same(&check, 6, "y", y.len, x.len)?;
+    let n = x.len as i32;
+    check.length(3, "ap", ap.len(), packed_len(n))?;
+    unsafe { T::hpmv(uplo, n, alpha, ap, x.data, x.inc, beta, y.data, y.inc) };
+    check.finish()
+}
+
+/// Compute `a := alpha * x * x^H + a` for a packed Hermitian matrix `a`, which
+/// is `?spr` for real types.
+pub fn hpr<T: Blas>(uplo: Uplo, alpha: T::Real, x: StridedVec<T>, ap: &mut [T]) -> Result<()> {
+    let check = Check::new("hpr");
+    let n = x.len as i32;
+    check.length(4, "ap", ap.len(), packed_len(n))?;
+    unsafe { T::hpr(uplo, n, alpha, x.data, x.inc, ap) };
+    check.finish()
+}
+
+/// Compute `a := alpha * x * y^H + conj(alpha) * y * x^H + a` for a packed
+/// Hermitian matrix `a`, which is `?spr2` for real types.
+pub fn hpr2<T: Blas>(
+    uplo: Uplo,
+    alpha: T,
+    x: StridedVec<T>,
+    y: StridedVec<T>,
+    ap: &mut [T],
+) -> Result<()> {
+    let check = Check::new("hpr2");
+    same(&check, 4, "y", y.len, x.len)?;
+    let n = x.len as i32;
+    check.length(5, "ap", ap.len(), packed_len(n))?;
+    unsafe { T::hpr2(uplo, n, alpha, x.data, x.inc, y.data, y.inc, ap) };
+    check.finish()
+}
+
+macro_rules! band {
+    ($(#[$attribute:meta])* fn $name:ident) => {
+        $(#[$attribute])*
+        pub fn $name<T: Blas>(
+            uplo: Uplo,
+            trans: Transpose,
+            diag: Diag,
+            k: i32,
+            a: &[T],
+            lda: i32,
+            x: &mut StridedVecMut<T>,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            check.dimension(4, "k", k)?;
+            let band = check.band(6, "lda", lda, i64::from(k) + 1, "must be at least k + 1")?;
+            let n = x.len as i32;
+            check.length(5, "a", a.len(), matrix_len(band, n, lda))?;
+            unsafe { T::$name(uplo, trans, diag, n, k, a, lda, x.data, x.inc) };
+            check.finish()
+        }
+    };
+}
+
+band! {
+    /// Compute `x := op(a) * x` for a triangular band matrix `a` with `k`
+    /// diagonals besides the main one.
+    fn tbmv
+}
+
+band! {
+    /// Solve `op(a) * x = b` for a triangular band matrix `a` with `k`
+    /// diagonals besides the main one, overwriting `b` stored in `x`.
+    fn tbsv
+}
+
+macro_rules! packed {
+    ($(#[$attribute:meta])* fn $name:ident) => {
+        $(#[$attribute])*
+        pub fn $name<T: Blas>(
+            uplo: Uplo,
+            trans: Transpose,
+            diag: Diag,
+            ap: &[T],
+            x: &mut StridedVecMut<T>,
+        ) -> Result<()> {
+            let check = Check::new(stringify!($name));
+            let n = x.len as i32;
+            check.length(4, "ap", ap.len(), packed_len(n))?;
+            unsafe { T::$name(uplo, trans, diag, n, ap, x.data, x.inc) };
+            check.finish()
+        }
+    };
+}
+
+packed! {
+    /// Compute `x := op(a) * x` for a packed triangular matrix `a`.
+    fn tpmv
+}
+
+packed! {
+    /// Solve `op(a) * x = b` for a packed triangular matrix `a`, overwriting
+    /// `b` stored in `x`.
+    fn tpsv
+}
+
+#[cfg(all(test, feature = "reference", not(feature = "dynamic")))]
+mod tests {
+    use super::*;
+    use c64;
+
+    const UPLOS: [Uplo; 2] = [Uplo::Upper, Uplo::Lower];
+    const TRANSPOSES: [Transpose; 3] = [Transpose::No, Transpose::Trans, Transpose::ConjTrans];
+
+    fn element(i: usize, j: usize) -> c64 {
+        c64::new(
+            1.0 + i as f64 + 0.5 * j as f64,
+            1.0 - 0.25 * i as f64 + j as f64,
+        )
+    }
+
+    fn hermitian(i: usize, j: usize) -> c64 {
+        if i < j {
+            element(i, j)
+        } else if i > j {
+            element(j, i).conj()
+        } else {
+            c64::new(element(i, i).re, 0.0)
+        }
+    }
+
+    fn vector(n: usize, seed: f64) -> Vec<c64> {
+        (0..n)
+            .map(|i| c64::new(seed - i as f64, 0.5 + seed * i as f64))
+            .collect()
+    }
+
+    fn op(trans: Transpose, a: &dyn Fn(usize, usize) -> c64, i: usize, j: usize) -> c64 {
+        match trans {
+            Transpose::No => a(i, j),
+            Transpose::Trans => a(j, i),
+            Transpose::ConjTrans => a(j, i).conj(),
+        }
+    }
+
+    fn referenced(uplo: Uplo, i: usize, j: usize) -> bool {
+        match uplo {
+            Uplo::Upper => i <= j,
+            Uplo::Lower => i >= j,
+        }
+    }
+
+    /// Return the position of an element in packed storage.
+    fn packed(uplo: Uplo, n: usize, i: usize, j: usize) -> usize {
+        match uplo {
+            Uplo::Upper => i + j * (j + 1) / 2,
+            Uplo::Lower => i + j * (2 * n - j - 1) / 2,
+        }
+    }
+
+    fn assert_close(x: &[c64], y: &[c64]) {
+        assert_eq!(x.len(), y.len());
+        for (x, y) in x.iter().zip(y) {
+            assert!((x - y).norm_sqr() < 1e-20, "{} != {}", x, y);
+        }
+    }
+
+    #[test]
+    fn gbmv() {
+        let (m, n, kl, ku, lda) = (4, 5, 1, 2, 5);
+        let (alpha, beta) = (c64::new(0.5, 1.0), c64::new(2.0, -0.5));
+        let dense = |i: usize, j: usize| {
+            if i + ku >= j && j + kl >= i {
+                element(i, j)
+            } else {
+                c64::new(0.0, 0.0)
+            }
+        };
+        let mut a = vec![c64::new(1e3, 1e3); lda * n];
+        for j in 0..n {
+            for i in 0..m {
+                if i + ku >= j && j + kl >= i {
+                    a[ku + i - j + j * lda] = element(i, j);
+                }
+            }
+        }
+        for &trans in &TRANSPOSES {
+            let (rows, cols) = if trans == Transpose::No {
+                (m, n)
+            } else {
+                (n, m)
+            };
+            let x = vector(2 * cols, 1.0);
+            let mut y = vector(rows, -2.0);
+            let expected = (0..rows)
+                .map(|i| {
+                    let sum = (0..cols)
+                        .map(|j| op(trans, &dense, i, j) * x[2 * (cols - 1 - j)])
+                        .sum::<c64>();
+                    alpha * sum + beta * y[rows - 1 - i]
+                })
+                .rev()
+                .collect::<Vec<_>>();
+            let x = StridedVec::new(&x, cols, -2).unwrap();
+            let mut view = StridedVecMut::new(&mut y, rows, -1).unwrap();
+            super::gbmv(
+                trans, kl as i32, ku as i32, alpha, &a, lda as i32, x, beta, &mut view,
+            )
+            .unwrap();
+            assert_close(&y, &expected);
+        }
+        let mut y = vector(m, 1.0);
+        let error = super::gbmv(
+            Transpose::No,
+            kl as i32,
+            ku as i32,
+            alpha,
+            &a[..lda * (n - 1) + 3],
+            lda as i32,
+            vector(n, 1.0)[..].into(),
+            beta,
+            &mut (&mut y[..]).into(),
+        );
+        assert_eq!(error.unwrap_err().position(), 5);
+        let error = super::gbmv(
+            Transpose::No,
+            kl as i32,
+            ku as i32,
+            alpha,
+            &a,
+            3,
+            vector(n, 1.0)[..].into(),
+            beta,
+            &mut (&mut y[..]).into(),
+        );
+        assert_eq!(error.unwrap_err().position(), 6);
+    }
+
+    #[test]
+    fn hermitian_products() {
+        let (n, k, lda) = (4, 1, 3);
+        let (alpha, beta) = (c64::new(0.5, 1.0), c64::new(2.0, -0.5));
+        let band = |i: usize, j: usize| {
+            if i + k >= j && j + k >= i {
+                hermitian(i, j)
+            } else {
+                c64::new(0.0, 0.0)
+            }
+        };
+        let x = vector(n, 1.0);
+        let y = vector(n, -2.0);
+        let product = |a: &dyn Fn(usize, usize) -> c64| {
+            (0..n)
+                .map(|i| alpha * (0..n).map(|j| a(i, j) * x[j]).sum::<c64>() + beta * y[i])
+                .collect::<Vec<_>>()
+        };
+        for &uplo in &UPLOS {
+            let mut a = vec![c64::new(1e3, 1e3); lda * n];
+            let mut ap = vec![c64::new(0.0, 0.0); n * (n + 1) / 2];
+            for j in 0..n {
+                for i in 0..n {
+                    if !referenced(uplo, i, j) {
+                        continue;
+                    }
+                    ap[packed(uplo, n, i, j)] = hermitian(i, j);
+                    if i + k >= j && j + k >= i {
+                        let row = if uplo == Uplo::Upper {
+                            k + i - j
+                        } else {
+                            i - j
+                        };
+                        a[row + j * lda] = hermitian(i, j);
+                    }
+                }
+            }
+
+            let mut z = y.clone();
+            super::hbmv(
+                uplo,
+                k as i32,
+                alpha,
+                &a,
+                lda as i32,
+                x[..].into(),
+                beta,
+                &mut (&mut z[..]).into(),
+            )
+            .unwrap();
+            assert_close(&z, &product(&band));
+
+            let mut z = y.clone();
+            super::hpmv(
+                uplo,
+                alpha,
+                &ap,
+                x[..].into(),
+                beta,
+                &mut (&mut z[..]).into(),
+            )
+            .unwrap();
+            assert_close(&z, &product(&hermitian));
+
+            let mut bp = ap.clone();
+            super::hpr(uplo, 2.0, x[..].into(), &mut bp).unwrap();
+            let mut cp = ap.clone();
+            super::hpr2(uplo, alpha, x[..].into(), y[..].into(), &mut cp).unwrap();
+            for j in 0..n {
+                for i in 0..n {
+                    if referenced(uplo, i, j) {
+                        let index = packed(uplo, n, i, j);
+                        let one = hermitian(i, j) + x[i] * x[j].conj() * 2.0;
+                        let two = hermitian(i, j)
+                            + alpha * x[i] * y[j].conj()
+                            + alpha.conj() * y[i] * x[j].conj();
+                        assert_close(&[bp[index], cp[index]], &[one, two]);
+                    }
+                }
+            }
+
+            let error = super::hpmv(
+                uplo,
+                alpha,
+                &ap[1..],
+                x[..].into(),
+                beta,
+                &mut (&mut z[..]).into(),
+            );
+            assert_eq!(error.unwrap_err().position(), 3);
+            let error = super::hpr2(uplo, alpha, x[..].into(), y[1..].into(), &mut cp);
+            assert_eq!(error.unwrap_err().position(), 4);
+        }
+    }
+
+    #[test]
+    fn triangular() {
+        let (n, k, lda) = (4, 2, 3);
+        for &uplo in &UPLOS {
+            for &trans in &TRANSPOSES {
+                for &diag in &[Diag::NonUnit, Diag::Unit] {
+                    let dense = |i: usize, j: usize| {
+                        if i == j && diag == Diag::Unit {
+                            c64::new(1.0, 0.0)
+                        } else if referenced(uplo, i, j) && i + k >= j && j + k >= i {
+                            element(i, j) * 0.25
+                        } else {
+                            c64::new(0.0, 0.0)
+                        }
+                    };
+                    let full = |i: usize, j: usize| {
+                        if i == j && diag == Diag::Unit {
+                            c64::new(1.0, 0.0)
+                        } else if referenced(uplo, i, j) {
+                            element(i, j) * 0.25
+                        } else {
+                            c64::new(0.0, 0.0)
+                        }
+                    };
+                    let mut a = vec![c64::new(1e3, 1e3); lda * n];
+                    let mut ap = vec![c64::new(1e3, 1e3); n * (n + 1) / 2];
+                    for j in 0..n {
+                        for i in 0..n {
+                            if referenced(uplo, i, j) && !(i == j && diag == Diag::Unit) {
+                                ap[packed(uplo, n, i, j)] = full(i, j);
+                                if i + k >= j && j + k >= i {
+                                    let row = if uplo == Uplo::Upper {
+                                        k + i - j
+                                    } else {
+                                        i - j
+                                    };
+                                    a[row + j * lda] = full(i, j);
+                                }
+                            }
+                        }
+                    }
+                    let b = vector(2 * n - 1, 1.0);
+                    let product = |a: &dyn Fn(usize, usize) -> c64| {
+                        let mut x = b.clone();
+                        for i in 0..n {
+                            x[2 * (n - 1 - i)] = (0..n)
+                                .map(|j| op(trans, a, i, j) * b[2 * (n - 1 - j)])
+                                .sum();
+                        }
+                        x
+                    };
+
+                    let mut x = b.clone();
+                    {
+                        let mut view = StridedVecMut::new(&mut x, n, -2).unwrap();
+                        tbmv(uplo, trans, diag, k as i32, &a, lda as i32, &mut view).unwrap();
+                    }
+                    assert_close(&x, &product(&dense));
+                    {
+                        let mut view = StridedVecMut::new(&mut x, n, -2).unwrap();
+                        tbsv(uplo, trans, diag, k as i32, &a, lda as i32, &mut view).unwrap();
+                    }
+                    assert_close(&x, &b);
+
+                    let mut x = b.clone();
+                    {
+                        let mut view = StridedVecMut::new(&mut x, n, -2).unwrap();
+                        tpmv(uplo, trans, diag, &ap, &mut view).unwrap();
+                    }
+                    assert_close(&x, &product(&full));
+                    {
+                        let mut view = StridedVecMut::new(&mut x, n, -2).unwrap();
+                        tpsv(uplo, trans, diag, &ap, &mut view).unwrap();
+                        let error = tpsv(uplo, trans, diag, &ap[1..], &mut view);
+                        assert_eq!(error.unwrap_err().position(), 4);
+                    }
+                    assert_close(&x, &b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let mut y = [c64::new(1.0, 0.0); 2];
+        let mut empty = StridedVecMut::new(&mut y[..0], 0, 1).unwrap();
+        let x = StridedVec::new(&[], 0, -1).unwrap();
+        super::gbmv(
+            Transpose::No,
+            0,
+            0,
+            c64::new(1.0, 0.0),
+            &[],
+            1,
+            x,
+            c64::new(0.0, 0.0),
+            &mut empty,
+        )
+        .unwrap();
+        tpsv(Uplo::Upper, Transpose::No, Diag::NonUnit, &[], &mut empty).unwrap();
+        super::hpr(Uplo::Lower, 1.0, x, &mut []).unwrap();
+    }
+}
@@ -0,0 +1,484 @@
+//! Vector views.
+//!
+//! A [`StridedVec`] or [`StridedVecMut`] borrows a buffer together with the
+//! number of elements and the increment between consecutive elements. As in
+//! BLAS, a negative increment means that the first element is stored at the
+//! end of the buffer and the last element at the beginning. Indexing and
+//! iteration follow this order, so element `i` of a view is element `i` as
+//! seen by BLAS. The buffer is validated once when the view is created.
+//!
+//! ## Example
+//!
+//! ```
+//! use blas::vector::StridedVec;
+//!
+//! let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+//! let x = StridedVec::new(&data, 3, -2).unwrap();
+//! assert_eq!(x.iter().cloned().collect::<Vec<_>>(), vec![5.0, 3.0, 1.0]);
+//! assert_eq!(x[0], 5.0);
+//! ```
+//!
+//! Slices convert into views with unit increment.
+//!
+//! The functions in this module take views in place of the vector arguments of
+//! the Level 1 routines and of the Level 2 routines for band and packed
+//! matrices, and the number of elements in place of the dimension. The Level 2
+//! routines for dense matrices take views together with the matrix views of
+//! [`matrix`](../matrix/index.html).
+//!
+//! [`StridedVec`]: struct.StridedVec.html
+//! [`StridedVecMut`]: struct.StridedVecMut.html
+
+use std::iter::{Rev, StepBy};
+use std::ops::{Index, IndexMut};
+use std::slice;
+
+use checked::{vector_len, Check};
+use Result;
+
+mod level1;
+mod level2;
+
+pub use self::level1::*;
+pub use self::level2::*;
+
+/// An immutable vector view.
+#[derive(Debug)]
+pub struct StridedVec<'a, T: 'a> {
+    pub(crate) data: &'a [T],
+    pub(crate) len: usize,
+    pub(crate) inc: i32,
+}
+
+/// A mutable vector view.
+#[derive(Debug)]
+pub struct StridedVecMut<'a, T: 'a> {
+    pub(crate) data: &'a mut [T],
+    pub(crate) len: usize,
+    pub(crate) inc: i32,
+}
+
+/// An iterator over the elements of a vector view in BLAS order.
+#[derive(Clone, Debug)]
+pub struct Iter<'a, T: 'a>(Order<StepBy<slice::Iter<'a, T>>, StepBy<Rev<slice::Iter<'a, T>>>>);
+
+/// An iterator over the mutable elements of a vector view in BLAS order.
+#[derive(Debug)]
+pub struct IterMut<'a, T: 'a>(
+    Order<StepBy<slice::IterMut<'a, T>>, StepBy<Rev<slice::IterMut<'a, T>>>>,
+);
+
+#[derive(Clone, Debug)]
+enum Order<F, B> {
+    Forward(F),
+    Backward(B),
+}
+
+impl<'a, T> Clone for StridedVec<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for StridedVec<'a, T> {}
+
+macro_rules! accessors {
+    () => {
+        /// Return the number of elements.
+        #[inline]
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        /// Check if the vector has no elements.
+        #[inline]
+        pub fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        /// Return the increment.
+        #[inline]
+        pub fn inc(&self) -> i32 {
+            self.inc
+        }
+
+        /// Return the part of the underlying buffer spanned by the vector.
+        #[inline]
+        pub fn as_slice(&self) -> &[T] {
+            self.data
+        }
+
+        /// Return the number of elements, the buffer, and the increment in
+        /// the form expected by the wrappers at the root of the crate.
+        #[inline]
+        pub fn as_parts(&self) -> (i32, &[T], i32) {
+            (self.len as i32, self.data, self.inc)
+        }
+
+        /// Return an element.
+        #[inline]
+        pub fn get(&self, index: usize) -> Option<&T> {
+            if index < self.len {
+                Some(&self.data[self.offset(index)])
+            } else {
+                None
+            }
+        }
+
+        /// Return an iterator over the elements.
+        #[inline]
+        pub fn iter(&self) -> Iter<'_, T> {
+            let step = self.step();
+            Iter(if self.inc > 0 {
+                Order::Forward(self.data.iter().step_by(step))
+            } else {
+                Order::Backward(self.data.iter().rev().step_by(step))
+            })
+        }
+
+        /// Return the position of an element in the underlying buffer.
+        #[inline]
+        fn offset(&self, index: usize) -> usize {
+            if self.inc > 0 {
+                index * self.step()
+            } else {
+                (self.len - 1 - index) * self.step()
+            }
+        }
+
+        #[inline]
+        fn step(&self) -> usize {
+            self.inc.unsigned_abs() as usize
+        }
+    };
+}
+
+impl<'a, T> StridedVec<'a, T> {
+    /// Create a view.
+    ///
+    /// The increment must be nonzero, and the buffer must span at least
+    /// `1 + (len - 1) * |inc|` elements.
+    pub fn new(data: &'a [T], len: usize, inc: i32) -> Result<Self> {
+        let span = validate("StridedVec::new", data.len(), len, inc)?;
+        Ok(StridedVec {
+            data: &data[..span],
+            len,
+            inc,
+        })
+    }
+
+    accessors!();
+
+    /// Return the view with the order of the elements reversed.
+    #[inline]
+    pub fn rev(&self) -> StridedVec<'a, T> {
+        StridedVec {
+            data: self.data,
+            len: self.len,
+            inc: -self.inc,
+        }
+    }
+}
+
+impl<'a, T> StridedVecMut<'a, T> {
+    /// Create a view.
+    ///
+    /// The increment must be nonzero, and the buffer must span at least
+    /// `1 + (len - 1) * |inc|` elements.
+    pub fn new(data: &'a mut [T], len: usize, inc: i32) -> Result<Self> {
+        let span = validate("StridedVecMut::new", data.len(), len, inc)?;
+        Ok(StridedVecMut {
+            data: &mut data[..span],
+            len,
+            inc,
+        })
+    }
+
+    accessors!();
+
+    /// Return the part of the underlying buffer spanned by the vector.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.data
+    }
+
+    /// Return a mutable element.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.len {
+            let offset = self.offset(index);
+            Some(&mut self.data[offset])
+        } else {
+            None
+        }
+    }
+
+    /// Return an iterator over the mutable elements.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let step = self.step();
+        IterMut(if self.inc > 0 {
+            Order::Forward(self.data.iter_mut().step_by(step))
+        } else {
+            Order::Backward(self.data.iter_mut().rev().step_by(step))
+        })
+    }
+
+    /// Return an immutable view of the same vector.
+    #[inline]
+    pub fn as_ref(&self) -> StridedVec<'_, T> {
+        StridedVec {
+            data: self.data,
+            len: self.len,
+            inc: self.inc,
+        }
+    }
+
+    /// Return a mutable view of the same vector with a shorter lifetime.
+    #[inline]
+    pub fn as_mut(&mut self) -> StridedVecMut<'_, T> {
+        StridedVecMut {
+            data: self.data,
+            len: self.len,
+            inc: self.inc,
+        }
+    }
+
+    /// Return the view with the order of the elements reversed.
+    #[inline]
+    pub fn rev(self) -> StridedVecMut<'a, T> {
+        StridedVecMut {
+            data: self.data,
+            len: self.len,
+            inc: -self.inc,
+        }
+    }
+}
+
+impl<'a, T> From<&'a [T]> for StridedVec<'a, T> {
+    /// Create a view with unit increment.
+    ///
+    /// ## Panics
+    ///
+    /// The function panics if the slice has more than `i32::MAX` elements.
+    #[inline]
+    fn from(data: &'a [T]) -> Self {
+        StridedVec::new(data, data.len(), 1).expect("the slice is too long")
+    }
+}
+
+impl<'a, T> From<&'a mut [T]> for StridedVecMut<'a, T> {
+    /// Create a view with unit increment.
+    ///
+    /// ## Panics
+    ///
+    /// The function panics if the slice has more than `i32::MAX` elements.
+    #[inline]
+    fn from(data: &'a mut [T]) -> Self {
+        let len = data.len();
+        StridedVecMut::new(data, len, 1).expect("the slice is too long")
+    }
+}
+
+impl<'a, T> From<StridedVecMut<'a, T>> for StridedVec<'a, T> {
+    #[inline]
+    fn from(vector: StridedVecMut<'a, T>) -> Self {
+        StridedVec {
+            data: vector.data,
+            len: vector.len,
+            inc: vector.inc,
+        }
+    }
+}
+
+impl<'a, T> Index<usize> for StridedVec<'a, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("the index is out of bounds")
+    }
+}
+
+impl<'a, T> Index<usize> for StridedVecMut<'a, T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        self.get(index).expect("the index is out of bounds")
+    }
+}
+
+impl<'a, T> IndexMut<usize> for StridedVecMut<'a, T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        self.get_mut(index).expect("the index is out of bounds")
+    }
+}
+
+impl<'a, 'b, T> IntoIterator for &'b StridedVec<'a, T> {
+    type Item = &'b T;
+    type IntoIter = Iter<'b, T>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'b, T> {
+        self.iter()
+    }
+}
+
+impl<'a, 'b, T> IntoIterator for &'b mut StridedVecMut<'a, T> {
+    type Item = &'b mut T;
+    type IntoIter = IterMut<'b, T>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'b, T> {
+        self.iter_mut()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a T> {
+        match self.0 {
+            Order::Forward(ref mut iterator) => iterator.next(),
+            Order::Backward(ref mut iterator) => iterator.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.0 {
+            Order::Forward(ref iterator) => iterator.size_hint(),
+            Order::Backward(ref iterator) => iterator.size_hint(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut T> {
+        match self.0 {
+            Order::Forward(ref mut iterator) => iterator.next(),
+            Order::Backward(ref mut iterator) => iterator.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.0 {
+            Order::Forward(ref iterator) => iterator.size_hint(),
+            Order::Backward(ref iterator) => iterator.size_hint(),
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {}
+
+/// Ensure that two vectors have the same number of elements.
+#[inline]
+fn same(
+    check: &Check,
+    position: usize,
+    name: &'static str,
+    len: usize,
+    other: usize,
+) -> Result<()> {
+    if len != other {
+        return Err(check.argument(position, name, "must have as many elements as x"));
+    }
+    Ok(())
+}
+
+/// Validate a view and return the number of elements that it spans.
+fn validate(routine: &'static str, provided: usize, len: usize, inc: i32) -> Result<usize> {
+    let check = Check::new(routine);
+    if len > i32::MAX as usize {
+        return Err(check.argument(2, "len", "must not exceed i32::MAX"));
+    }
+    check.increment(3, "inc", inc)?;
+    let span = vector_len(len as i32, inc);
+    check.length(1, "data", provided, span)?;
+    Ok(span)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Error;
+
+    #[test]
+    fn order() {
+        let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let x = StridedVec::new(&data, 3, -2).unwrap();
+        assert_eq!(x.as_slice(), &data[..5]);
+        assert_eq!(x.iter().cloned().collect::<Vec<_>>(), [5.0, 3.0, 1.0]);
+        assert_eq!(x.iter().len(), 3);
+        assert_eq!((x[0], x[2]), (5.0, 1.0));
+        assert_eq!(x.get(3), None);
+        assert_eq!(x.rev().iter().cloned().collect::<Vec<_>>(), [1.0, 3.0, 5.0]);
+
+        let mut data = [1.0, 2.0, 3.0, 4.0];
+        {
+            let mut y = StridedVecMut::new(&mut data, 4, -1).unwrap();
+            for (i, value) in y.iter_mut().enumerate() {
+                *value += 10.0 * i as f64;
+            }
+            y[3] = 0.0;
+            *y.get_mut(0).unwrap() *= 2.0;
+            assert_eq!(y.get_mut(4), None);
+        }
+        assert_eq!(data, [0.0, 22.0, 13.0, 8.0]);
+
+        let x = StridedVec::new(&data, 0, -3).unwrap();
+        assert!(x.is_empty());
+        assert_eq!(x.iter().next(), None);
+        assert_eq!(x.as_parts(), (0, &[][..], -3));
+    }
+
+    #[test]
+    fn validate() {
+        let data = [0.0; 7];
+        assert_eq!(
+            StridedVec::new(&data, 3, 0).unwrap_err(),
+            Error::Argument {
+                routine: "StridedVec::new",
+                position: 3,
+                name: "inc",
+                reason: "must be nonzero",
+            },
+        );
+        assert_eq!(
+            StridedVec::new(&data, 3, -4).unwrap_err(),
+            Error::Length {
+                routine: "StridedVec::new",
+                position: 1,
+                name: "data",
+                required: 9,
+                provided: 7,
+            },
+        );
+        assert_eq!(
+            StridedVecMut::new(&mut [0.0; 2][..], 1 << 31, 1)
+                .unwrap_err()
+                .position(),
+            2,
+        );
+        assert_eq!(StridedVec::new(&data, 3, -3).unwrap().len(), 3);
+        assert_eq!(StridedVec::new(&[0.0; 0], 0, 5).unwrap().len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "the index is out of bounds")]
+    fn index() {
+        let data = [1.0; 5];
+        let x = StridedVec::new(&data, 2, -4).unwrap();
+        let _ = x[2];
+    }
+}
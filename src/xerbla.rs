@@ -0,0 +1,92 @@
+//! Error handler for BLAS.
+//!
+//! When a BLAS routine detects an illegal argument, it calls `xerbla_`, whose
+//! default implementation prints a message and might terminate the process.
+//! With the `xerbla` feature enabled, the crate exports its own `xerbla_`,
+//! which records the name of the routine and the position of the argument in
+//! a thread-local slot and returns control to the routine, which then returns
+//! without performing any computation.
+//!
+//! The wrappers in [`checked`](../checked/index.html) consult the slot after
+//! each call and report the error as [`Error::Xerbla`]. The `unsafe` wrappers
+//! return nothing on failure and leave the error in the slot, from which it
+//! can be retrieved using [`take`] after the call.
+//!
+//! Note that the exported symbol replaces the default handler only if the
+//! library resolves `xerbla_` dynamically or is linked statically without its
+//! own copy of the handler.
+//!
+//! [`Error::Xerbla`]: ../enum.Error.html#variant.Xerbla
+//! [`take`]: fn.take.html
+
+use libc::{c_char, c_int};
+use std::cell::RefCell;
+use std::slice;
+
+use Error;
+
+/// The maximal number of bytes read from the name of a routine.
+const NAME_CAPACITY: usize = 32;
+
+thread_local! {
+    static LAST: RefCell<Option<Error>> = const { RefCell::new(None) };
+}
+
+/// Take the error reported by the last failed call on the current thread.
+///
+/// The slot is cleared, so a subsequent call returns `None` unless another
+/// error has been reported in the meantime.
+pub fn take() -> Option<Error> {
+    LAST.with(|last| last.borrow_mut().take())
+}
+
+/// Clear the error reported on the current thread.
+#[inline]
+pub(crate) fn clear() {
+    LAST.with(|last| {
+        last.borrow_mut().take();
+    })
+}
+
+#[doc(hidden)]
+#[no_mangle]
+pub unsafe extern "C" fn xerbla_(srname: *const c_char, info: *const c_int, len: usize) {
+    let name = slice::from_raw_parts(srname as *const u8, len.min(NAME_CAPACITY));
+    let name = name
+        .split(|&byte| byte == b' ' || byte == 0)
+        .next()
+        .unwrap_or(&[]);
+    let error = Error::Xerbla {
+        routine: String::from_utf8_lossy(name).to_ascii_lowercase(),
+        position: (*info).max(0) as usize,
+    };
+    LAST.with(|last| *last.borrow_mut() = Some(error));
+}
+
+#[cfg(all(test, feature = "reference", not(feature = "dynamic")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference() {
+        let a = [1.0; 4];
+        let mut c = [1.0; 4];
+        clear();
+        unsafe { ::dgemm(b'X', b'N', 2, 2, 2, 1.0, &a, 2, &a, 2, 0.0, &mut c, 2) };
+        assert_eq!(
+            take(),
+            Some(Error::Xerbla {
+                routine: "dgemm".into(),
+                position: 1,
+            }),
+        );
+        assert_eq!(c, [1.0; 4]);
+        assert_eq!(take(), None);
+
+        let mut x = [1.0; 2];
+        unsafe { ::dtrsv(b'L', b'N', b'N', 2, &a, 1, &mut x, 1) };
+        assert_eq!(take().map(|error| error.position()), Some(6));
+        unsafe { ::daxpy(2, 1.0, &a, 1, &mut x, 1) };
+        assert_eq!(take(), None);
+    }
+}
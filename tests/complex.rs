@@ -0,0 +1,96 @@
+//! The routines called by the wrappers are provided by stand-ins defined
+//! below, which return their result in the way selected by the
+//! `complex-by-value` feature: by value or through a hidden first argument.
+
+#![cfg(not(any(feature = "dynamic", feature = "reference")))]
+#![allow(clippy::missing_safety_doc)]
+
+extern crate blas;
+extern crate libc;
+
+use blas::{c32, c64};
+use libc::c_int;
+
+macro_rules! stand_in {
+    ($name:ident, $ty:ident, $conjugate:expr) => {
+        #[cfg(not(feature = "complex-by-value"))]
+        #[no_mangle]
+        pub unsafe extern "C" fn $name(
+            pres: *mut $ty,
+            n: *const c_int,
+            x: *const $ty,
+            incx: *const c_int,
+            y: *const $ty,
+            incy: *const c_int,
+        ) {
+            *pres = dot(*n, x, *incx, y, *incy, $conjugate);
+        }
+
+        #[cfg(feature = "complex-by-value")]
+        #[no_mangle]
+        pub unsafe extern "C" fn $name(
+            n: *const c_int,
+            x: *const $ty,
+            incx: *const c_int,
+            y: *const $ty,
+            incy: *const c_int,
+        ) -> $ty {
+            dot(*n, x, *incx, y, *incy, $conjugate)
+        }
+    };
+}
+
+stand_in!(cdotu_, c32, false);
+stand_in!(cdotc_, c32, true);
+stand_in!(zdotu_, c64, false);
+stand_in!(zdotc_, c64, true);
+
+unsafe fn dot<T>(n: c_int, x: *const T, incx: c_int, y: *const T, incy: c_int, conjugate: bool) -> T
+where
+    T: Conj + Copy + Default + std::ops::Add<Output = T> + std::ops::Mul<Output = T>,
+{
+    assert_eq!((incx, incy), (1, 1));
+    let mut sum = T::default();
+    for i in 0..n as isize {
+        let x = *x.offset(i);
+        sum = sum + if conjugate { x.conj() } else { x } * *y.offset(i);
+    }
+    sum
+}
+
+trait Conj {
+    fn conj(&self) -> Self;
+}
+
+impl Conj for c32 {
+    fn conj(&self) -> Self {
+        c32::conj(self)
+    }
+}
+
+impl Conj for c64 {
+    fn conj(&self) -> Self {
+        c64::conj(self)
+    }
+}
+
+#[test]
+fn single() {
+    let x = vec![c32::new(1.0, 2.0), c32::new(3.0, -1.0)];
+    let y = vec![c32::new(2.0, 1.0), c32::new(-1.0, 4.0)];
+    unsafe {
+        assert_eq!(blas::cdotu(2, &x, 1, &y, 1), c32::new(1.0, 18.0));
+        assert_eq!(blas::cdotc(2, &x, 1, &y, 1), c32::new(-3.0, 8.0));
+    }
+}
+
+#[test]
+fn double() {
+    let x = vec![c64::new(1.0, 2.0), c64::new(3.0, -1.0)];
+    let y = vec![c64::new(2.0, 1.0), c64::new(-1.0, 4.0)];
+    unsafe {
+        assert_eq!(blas::zdotu(2, &x, 1, &y, 1), c64::new(1.0, 18.0));
+        assert_eq!(blas::zdotc(2, &x, 1, &y, 1), c64::new(-3.0, 8.0));
+        assert_eq!(blas::zdotc(0, &x, 1, &y, 1), c64::new(0.0, 0.0));
+    }
+}
@@ -0,0 +1,197 @@
+extern crate blas;
+
+use std::env::consts::{DLL_PREFIX, DLL_SUFFIX};
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::Once;
+
+use blas::dynamic::{self, Error, Library};
+use blas::Transpose;
+
+fn stand_in() -> PathBuf {
+    static BUILD: Once = Once::new();
+    let directory = PathBuf::from(env!("CARGO_TARGET_TMPDIR"));
+    BUILD.call_once(|| {
+        let manifest = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/stand-in/Cargo.toml");
+        let status = Command::new(env!("CARGO"))
+            .args(["build", "--quiet", "--manifest-path", manifest])
+            .arg("--target-dir")
+            .arg(&directory)
+            .status()
+            .unwrap();
+        assert!(status.success());
+    });
+    directory
+        .join("debug")
+        .join(format!("{}blas_stand_in{}", DLL_PREFIX, DLL_SUFFIX))
+}
+
+/// Install the stand-in once, as each installed library stays open.
+fn install() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| dynamic::install(Library::open(stand_in()).unwrap()));
+}
+
+#[test]
+fn call() {
+    install();
+    let x = vec![1.0, 2.0, 3.0];
+    let mut y = vec![4.0, 5.0, 6.0];
+    unsafe {
+        blas::daxpy(3, 2.0, &x, 1, &mut y, 1);
+        assert_eq!(y, vec![6.0, 9.0, 12.0]);
+        assert_eq!(blas::ddot(3, &x, 1, &y, 1), 60.0);
+    }
+    let (a, b) = (vec![1.0, 3.0, 2.0, 4.0], vec![5.0, 7.0, 6.0, 8.0]);
+    let mut c = vec![1.0; 4];
+    unsafe {
+        blas::dgemm(b'N', b'N', 2, 2, 2, 1.0, &a, 2, &b, 2, 1.0, &mut c, 2);
+    }
+    assert_eq!(c, vec![20.0, 44.0, 23.0, 51.0]);
+    assert_eq!(dynamic::current().unwrap().path(), stand_in());
+}
+
+#[test]
+fn call_extension() {
+    install();
+    let (a, b) = (vec![1.0, 2.0, 3.0, 4.0], vec![5.0, 6.0]);
+    let mut c = vec![1.0; 4];
+    blas::batch::sgemm_batch_strided(
+        Transpose::No,
+        Transpose::No,
+        1,
+        1,
+        1,
+        2.0,
+        &a,
+        1,
+        1,
+        &b,
+        1,
+        0,
+        1.0,
+        &mut c,
+        1,
+        1,
+        4,
+    )
+    .unwrap();
+    assert_eq!(c, vec![11.0, 21.0, 31.0, 41.0]);
+}
+
+#[test]
+fn call_fallback() {
+    install();
+    // The stand-in provides dgemm_ but not dgemm_batch_strided_.
+    let (a, b) = (vec![1.0, 2.0, 3.0, 4.0], vec![5.0, 6.0]);
+    let mut c = vec![1.0; 4];
+    blas::batch::dgemm_batch_strided(
+        Transpose::No,
+        Transpose::No,
+        1,
+        1,
+        1,
+        2.0,
+        &a,
+        1,
+        1,
+        &b,
+        1,
+        0,
+        1.0,
+        &mut c,
+        1,
+        1,
+        4,
+    )
+    .unwrap();
+    assert_eq!(c, vec![11.0, 21.0, 31.0, 41.0]);
+}
+
+#[test]
+fn call_axpby() {
+    install();
+    let x = vec![1.0, 2.0, 3.0];
+    let mut y = vec![1.0, 1.0, 1.0];
+    unsafe { blas::daxpby(3, 2.0, &x, 1, 3.0, &mut y, 1) };
+    assert_eq!(y, vec![5.0, 7.0, 9.0]);
+}
+
+#[cfg(feature = "ilp64")]
+#[test]
+fn call_ilp64() {
+    install();
+    let x = vec![1.0, 0.0, 2.0, 0.0, 3.0];
+    let mut y = vec![1.0, 1.0, 1.0];
+    unsafe { blas::ilp64::daxpy(3, 2.0, &x, 2, &mut y, 1) };
+    assert_eq!(y, vec![3.0, 5.0, 7.0]);
+}
+
+#[cfg(feature = "ilp64")]
+#[test]
+#[should_panic(expected = "does not provide ddot_64_")]
+fn call_missing_ilp64() {
+    install();
+    unsafe { blas::ilp64::ddot(1, &[1.0], 1, &[1.0], 1) };
+}
+
+#[test]
+fn backend() {
+    install();
+    let backend = blas::backend_info().unwrap();
+    assert_eq!(backend.name, "OpenBLAS");
+    assert_eq!(backend.config.unwrap(), "OpenBLAS stand-in USE64BITINT");
+    assert_eq!(backend.integer, Some(64));
+    assert_eq!(blas::set_num_threads(3), Some(()));
+    assert_eq!(blas::get_num_threads(), Some(3));
+}
+
+#[test]
+#[should_panic(expected = "does not provide sgemm_")]
+fn call_missing() {
+    install();
+    let mut c = vec![0.0; 1];
+    unsafe {
+        blas::sgemm(
+            b'N',
+            b'N',
+            1,
+            1,
+            1,
+            1.0,
+            &[1.0],
+            1,
+            &[1.0],
+            1,
+            0.0,
+            &mut c,
+            1,
+        );
+    }
+}
+
+#[test]
+fn load_missing() {
+    let library = Library::open(stand_in()).unwrap();
+    assert!(library.missing().contains(&"sgemm_"));
+    assert!(!library.missing().contains(&"dgemm_"));
+    assert!(!library.missing().iter().any(|name| name.ends_with("_64_")));
+    match dynamic::load(stand_in()) {
+        Err(Error::Missing { path, symbols }) => {
+            assert_eq!(path, stand_in());
+            assert_eq!(symbols, library.missing());
+        }
+        result => panic!("{:?}", result),
+    }
+    let message = dynamic::load(stand_in()).unwrap_err().to_string();
+    assert!(message.contains("sgemm_, "));
+    assert!(!message.contains("dgemm_"));
+}
+
+#[test]
+fn load_nonexistent() {
+    match dynamic::load("nonexistent") {
+        Err(Error::Open { path, .. }) => assert_eq!(path, PathBuf::from("nonexistent")),
+        result => panic!("{:?}", result),
+    }
+}
@@ -0,0 +1,87 @@
+//! The routines called by the wrappers are provided by stand-ins defined
+//! below, which record the hidden lengths that they receive.
+
+#![cfg(not(any(feature = "dynamic", feature = "reference")))]
+#![allow(clippy::missing_safety_doc, clippy::too_many_arguments)]
+
+extern crate blas;
+extern crate libc;
+
+use std::sync::Mutex;
+
+use libc::{c_char, c_double, c_int, size_t};
+
+static DGEMV: Mutex<Vec<(u8, size_t)>> = Mutex::new(Vec::new());
+static DTRSM: Mutex<Vec<([u8; 4], [size_t; 4])>> = Mutex::new(Vec::new());
+
+#[no_mangle]
+pub unsafe extern "C" fn dgemv_(
+    trans: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const c_int,
+    x: *const c_double,
+    incx: *const c_int,
+    beta: *const c_double,
+    y: *mut c_double,
+    incy: *const c_int,
+    trans_len: size_t,
+) {
+    DGEMV.lock().unwrap().push((*trans as u8, trans_len));
+    assert_eq!((*incx, *incy), (1, 1));
+    for i in 0..*m as isize {
+        let mut sum = 0.0;
+        for j in 0..*n as isize {
+            sum += *a.offset(i + j * *lda as isize) * *x.offset(j);
+        }
+        *y.offset(i) = *alpha * sum + *beta * *y.offset(i);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dtrsm_(
+    side: *const c_char,
+    uplo: *const c_char,
+    transa: *const c_char,
+    diag: *const c_char,
+    _m: *const c_int,
+    _n: *const c_int,
+    _alpha: *const c_double,
+    _a: *const c_double,
+    _lda: *const c_int,
+    _b: *mut c_double,
+    _ldb: *const c_int,
+    side_len: size_t,
+    uplo_len: size_t,
+    transa_len: size_t,
+    diag_len: size_t,
+) {
+    DTRSM.lock().unwrap().push((
+        [*side as u8, *uplo as u8, *transa as u8, *diag as u8],
+        [side_len, uplo_len, transa_len, diag_len],
+    ));
+}
+
+#[test]
+fn dgemv() {
+    let a = vec![1.0, 3.0, 2.0, 4.0];
+    let x = vec![1.0, 1.0];
+    let mut y = vec![1.0, 1.0];
+    unsafe {
+        blas::dgemv(b'N', 2, 2, 1.0, &a, 2, &x, 1, 2.0, &mut y, 1);
+    }
+    assert_eq!(y, vec![5.0, 9.0]);
+    assert_eq!(*DGEMV.lock().unwrap(), vec![(b'N', 1)]);
+}
+
+#[test]
+fn dtrsm() {
+    let a = vec![1.0; 4];
+    let mut b = vec![1.0; 4];
+    unsafe {
+        blas::dtrsm(b'R', b'L', b'T', b'U', 2, 2, 1.0, &a, 2, &mut b, 2);
+    }
+    assert_eq!(*DTRSM.lock().unwrap(), vec![(*b"RLTU", [1, 1, 1, 1])]);
+}
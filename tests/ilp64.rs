@@ -0,0 +1,134 @@
+//! The routines called by the wrappers are provided by stand-ins defined
+//! below under the names of the ILP64 interface, which take 64-bit integers.
+//! With the `dynamic` feature enabled, the routines are looked up in the
+//! library loaded at run time instead, which is tested in `dynamic`.
+
+#![cfg(not(feature = "dynamic"))]
+#![allow(clippy::missing_safety_doc)]
+
+extern crate blas;
+
+#[cfg(feature = "record")]
+use std::io::{self, Write};
+#[cfg(feature = "record")]
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use blas::c64;
+use blas::ilp64;
+#[cfg(feature = "record")]
+use blas::record;
+
+static DAXPY: Mutex<Vec<(i64, i64, i64)>> = Mutex::new(Vec::new());
+
+#[no_mangle]
+pub unsafe extern "C" fn daxpy_64_(
+    n: *const i64,
+    alpha: *const f64,
+    x: *const f64,
+    incx: *const i64,
+    y: *mut f64,
+    incy: *const i64,
+) {
+    DAXPY.lock().unwrap().push((*n, *incx, *incy));
+    for i in 0..*n as isize {
+        *y.offset(i * *incy as isize) += *alpha * *x.offset(i * *incx as isize);
+    }
+}
+
+/// Return `n` without reading `x`, so that the width of the result is
+/// observable.
+#[no_mangle]
+pub unsafe extern "C" fn idamax_64_(n: *const i64, _: *const f64, _: *const i64) -> i64 {
+    *n
+}
+
+#[cfg(not(feature = "complex-by-value"))]
+#[no_mangle]
+pub unsafe extern "C" fn zdotc_64_(
+    pres: *mut c64,
+    n: *const i64,
+    x: *const c64,
+    incx: *const i64,
+    y: *const c64,
+    incy: *const i64,
+) {
+    *pres = dot(*n, x, *incx, y, *incy);
+}
+
+#[cfg(feature = "complex-by-value")]
+#[no_mangle]
+pub unsafe extern "C" fn zdotc_64_(
+    n: *const i64,
+    x: *const c64,
+    incx: *const i64,
+    y: *const c64,
+    incy: *const i64,
+) -> c64 {
+    dot(*n, x, *incx, y, *incy)
+}
+
+unsafe fn dot(n: i64, x: *const c64, incx: i64, y: *const c64, incy: i64) -> c64 {
+    let mut sum = c64::new(0.0, 0.0);
+    for i in 0..n as isize {
+        sum += (*x.offset(i * incx as isize)).conj() * *y.offset(i * incy as isize);
+    }
+    sum
+}
+
+#[test]
+fn daxpy() {
+    let x = vec![1.0, 0.0, 2.0, 0.0, 3.0];
+    let mut y = vec![1.0, 1.0, 1.0];
+    unsafe { ilp64::daxpy(3, 2.0, &x, 2, &mut y, 1) };
+    assert_eq!(y, vec![3.0, 5.0, 7.0]);
+    assert_eq!(*DAXPY.lock().unwrap(), vec![(3, 2, 1)]);
+}
+
+#[test]
+fn idamax() {
+    let n = 3 << 32;
+    assert_eq!(unsafe { ilp64::idamax(n, &[], 1) }, n);
+}
+
+#[test]
+fn zdotc() {
+    let x = vec![c64::new(1.0, 2.0), c64::new(3.0, -1.0)];
+    let y = vec![c64::new(2.0, 1.0), c64::new(-1.0, 4.0)];
+    assert_eq!(
+        unsafe { ilp64::zdotc(2, &x, 1, &y, 1) },
+        c64::new(-3.0, 8.0)
+    );
+}
+
+#[cfg(feature = "record")]
+#[derive(Clone, Default)]
+struct Shared(Arc<Mutex<Vec<u8>>>);
+
+#[cfg(feature = "record")]
+impl Write for Shared {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(data)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The replay of the recording is not tested, as it refers to all the
+/// routines of the ILP64 interface, which the stand-ins do not provide.
+#[cfg(feature = "record")]
+#[test]
+fn record() {
+    let shared = Shared::default();
+    record::start(shared.clone(), true).unwrap();
+    let n = 3 << 32;
+    assert_eq!(unsafe { ilp64::idamax(n, &[], 1) }, n);
+    record::stop().unwrap();
+    let recording = String::from_utf8(shared.0.lock().unwrap().clone()).unwrap();
+    assert!(recording.contains(&format!(
+        "{{\"routine\":\"idamax\",\"integer\":64,\"arguments\":{{\"n\":{},",
+        n
+    )));
+}
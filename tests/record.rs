@@ -0,0 +1,151 @@
+//! The routines are provided by the reference implementation.
+
+#![cfg(not(feature = "dynamic"))]
+
+extern crate blas;
+
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+
+use blas::c64;
+use blas::record::{self, Difference};
+
+#[derive(Clone, Default)]
+struct Shared(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Shared {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(data)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+unsafe fn calls() {
+    let a = vec![1.0, 2.0, 3.0, 4.0];
+    let b = vec![0.5, -1.0, 2.0, f64::INFINITY];
+    let mut c = vec![1.0; 4];
+    blas::dgemm(b'N', b'T', 2, 2, 2, 1.0, &a, 2, &b, 2, 0.5, &mut c, 2);
+    let x = vec![c64::new(1.0, 2.0), c64::new(-3.0, 0.5)];
+    blas::zdotc(2, &x, 1, &x, 1);
+    let (mut p, mut q, mut cosine, mut sine) = (3.0, 4.0, 0.0, 0.0);
+    blas::drotg(&mut p, &mut q, &mut cosine, &mut sine);
+}
+
+fn recording(buffers: bool) -> String {
+    let shared = Shared::default();
+    record::start(shared.clone(), buffers).unwrap();
+    unsafe { calls() };
+    record::stop().unwrap();
+    let data = shared.0.lock().unwrap().clone();
+    String::from_utf8(data).unwrap()
+}
+
+#[test]
+fn replay() {
+    let _lock = LOCK.lock().unwrap();
+    let recording = recording(true);
+    assert_eq!(recording.lines().count(), 4);
+    assert!(recording.starts_with("{\"format\":\"blas\",\"version\":2,\"buffers\":true}\n"));
+    assert!(recording.contains("{\"routine\":\"dgemm\",\"integer\":32,"));
+    assert!(recording.contains("{\"routine\":\"drotg\",\"arguments\""));
+    assert!(recording.contains("\"Infinity\""));
+    let report = record::replay(recording.as_bytes(), 0.0).unwrap();
+    assert_eq!(report.calls, 3);
+    assert_eq!(report.differences, vec![]);
+}
+
+#[test]
+fn replay_without_buffers() {
+    let _lock = LOCK.lock().unwrap();
+    let recording = recording(false);
+    assert!(recording.contains("\"a\":{\"length\":4}"));
+    let report = record::replay(recording.as_bytes(), 0.0).unwrap();
+    assert_eq!(report.calls, 3);
+    assert_eq!(report.differences, vec![]);
+}
+
+#[test]
+fn report() {
+    let _lock = LOCK.lock().unwrap();
+    let recording = recording(true).replace("\"result\":[14.25,0.0]", "\"result\":[14.5,0.0]");
+    let report = record::replay(recording.as_bytes(), 0.1).unwrap();
+    assert_eq!(
+        report.differences,
+        vec![Difference {
+            call: 1,
+            routine: "zdotc".into(),
+            argument: "result".into(),
+            error: 0.25,
+        }]
+    );
+    let report = record::replay(recording.as_bytes(), 0.5).unwrap();
+    assert_eq!(report.differences, vec![]);
+}
+
+#[test]
+fn invalid() {
+    let recording =
+        "{\"format\":\"blas\",\"version\":1,\"buffers\":true}\n{\"routine\":\"dfoo\"}\n";
+    assert!(record::replay(recording.as_bytes(), 0.0).is_err());
+    assert!(record::replay(&b""[..], 0.0).is_err());
+}
+
+#[test]
+fn rejected() {
+    let _lock = LOCK.lock().unwrap();
+    let unbuffered = recording(false);
+    let short = unbuffered.replace("\"a\":{\"length\":4}", "\"a\":{\"length\":3}");
+    let error = record::replay(short.as_bytes(), 0.0).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(
+        error.to_string(),
+        "call 0 is invalid: dgemm: parameter 7 (a) has 3 elements but 4 are required"
+    );
+
+    let recording = recording(true);
+    let short = recording.replace("\"x\":[[1.0,2.0],[-3.0,0.5]]", "\"x\":[[1.0,2.0]]");
+    let error = record::replay(short.as_bytes(), 0.0).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    assert!(error.to_string().contains("zdotc: parameter 2 (x)"));
+
+    let error =
+        record::replay(recording.replace("\"ldc\":2", "\"ldc\":1").as_bytes(), 0.0).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    assert!(error.to_string().contains("dgemm: parameter 13 (ldc)"));
+
+    let error = record::replay(
+        recording
+            .replace("\"transa\":\"N\"", "\"transa\":\"X\"")
+            .as_bytes(),
+        0.0,
+    )
+    .unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    assert!(error.to_string().contains("dgemm: parameter 1 (transa)"));
+}
+
+#[test]
+fn width() {
+    let _lock = LOCK.lock().unwrap();
+    let recording = recording(true);
+    let version = recording
+        .replacen("\"version\":2", "\"version\":1", 1)
+        .replace("\"integer\":32,", "");
+    let report = record::replay(version.as_bytes(), 0.0).unwrap();
+    assert_eq!(report.calls, 3);
+    assert_eq!(report.differences, vec![]);
+
+    let error = record::replay(recording.replace(":32,", ":16,").as_bytes(), 0.0);
+    assert!(error
+        .unwrap_err()
+        .to_string()
+        .contains("unknown integer width"));
+
+    let error = record::replay(recording.replace(":32,", ":64,").as_bytes(), 0.0);
+    assert!(error.unwrap_err().to_string().contains("`ilp64` feature"));
+}
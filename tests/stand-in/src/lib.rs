@@ -0,0 +1,153 @@
+//! A library exporting a few BLAS routines, which stands in for a complete
+//! implementation in the tests of the `dynamic` feature. Only positive
+//! increments and the non-transposed case are supported. The extensions are
+//! exported without the routines that they could be implemented with so that
+//! their use is evident.
+
+use std::os::raw::{c_char, c_double, c_float, c_int};
+use std::sync::atomic::{AtomicI32, Ordering};
+
+static THREADS: AtomicI32 = AtomicI32::new(1);
+
+#[no_mangle]
+pub unsafe extern "C" fn daxpy_(
+    n: *const c_int,
+    alpha: *const c_double,
+    x: *const c_double,
+    incx: *const c_int,
+    y: *mut c_double,
+    incy: *const c_int,
+) {
+    for i in 0..*n as isize {
+        *y.offset(i * *incy as isize) += *alpha * *x.offset(i * *incx as isize);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn daxpy_64_(
+    n: *const i64,
+    alpha: *const c_double,
+    x: *const c_double,
+    incx: *const i64,
+    y: *mut c_double,
+    incy: *const i64,
+) {
+    for i in 0..*n as isize {
+        *y.offset(i * *incy as isize) += *alpha * *x.offset(i * *incx as isize);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn daxpby_(
+    n: *const c_int,
+    alpha: *const c_double,
+    x: *const c_double,
+    incx: *const c_int,
+    beta: *const c_double,
+    y: *mut c_double,
+    incy: *const c_int,
+) {
+    for i in 0..*n as isize {
+        let y = y.offset(i * *incy as isize);
+        *y = *alpha * *x.offset(i * *incx as isize) + *beta * *y;
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ddot_(
+    n: *const c_int,
+    x: *const c_double,
+    incx: *const c_int,
+    y: *const c_double,
+    incy: *const c_int,
+) -> c_double {
+    let mut sum = 0.0;
+    for i in 0..*n as isize {
+        sum += *x.offset(i * *incx as isize) * *y.offset(i * *incy as isize);
+    }
+    sum
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dgemm_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_double,
+    a: *const c_double,
+    lda: *const c_int,
+    b: *const c_double,
+    ldb: *const c_int,
+    beta: *const c_double,
+    c: *mut c_double,
+    ldc: *const c_int,
+) {
+    assert!(*transa as u8 == b'N' && *transb as u8 == b'N');
+    let (lda, ldb, ldc) = (*lda as isize, *ldb as isize, *ldc as isize);
+    for j in 0..*n as isize {
+        for i in 0..*m as isize {
+            let mut sum = 0.0;
+            for l in 0..*k as isize {
+                sum += *a.offset(i + l * lda) * *b.offset(l + j * ldb);
+            }
+            let c = c.offset(i + j * ldc);
+            *c = *alpha * sum + *beta * *c;
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sgemm_batch_strided_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const c_float,
+    a: *const c_float,
+    lda: *const c_int,
+    stridea: *const c_int,
+    b: *const c_float,
+    ldb: *const c_int,
+    strideb: *const c_int,
+    beta: *const c_float,
+    c: *mut c_float,
+    ldc: *const c_int,
+    stridec: *const c_int,
+    batch_size: *const c_int,
+) {
+    assert!(*transa as u8 == b'N' && *transb as u8 == b'N');
+    let (lda, ldb, ldc) = (*lda as isize, *ldb as isize, *ldc as isize);
+    for p in 0..*batch_size as isize {
+        let a = a.offset(p * *stridea as isize);
+        let b = b.offset(p * *strideb as isize);
+        let c = c.offset(p * *stridec as isize);
+        for j in 0..*n as isize {
+            for i in 0..*m as isize {
+                let mut sum = 0.0;
+                for l in 0..*k as isize {
+                    sum += *a.offset(i + l * lda) * *b.offset(l + j * ldb);
+                }
+                let c = c.offset(i + j * ldc);
+                *c = *alpha * sum + *beta * *c;
+            }
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn openblas_get_config() -> *const c_char {
+    "OpenBLAS stand-in USE64BITINT\0".as_ptr() as *const c_char
+}
+
+#[no_mangle]
+pub extern "C" fn openblas_get_num_threads() -> c_int {
+    THREADS.load(Ordering::Relaxed)
+}
+
+#[no_mangle]
+pub extern "C" fn openblas_set_num_threads(threads: c_int) {
+    THREADS.store(threads, Ordering::Relaxed);
+}
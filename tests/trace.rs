@@ -0,0 +1,170 @@
+//! The routines called by the wrappers are provided by the reference
+//! implementation if enabled or else by stand-ins defined below, which leave
+//! the outputs untouched.
+
+#![cfg(not(any(
+    feature = "dynamic",
+    all(feature = "hidden-length", not(feature = "reference")),
+)))]
+#![allow(clippy::missing_safety_doc, clippy::too_many_arguments)]
+
+extern crate blas;
+extern crate libc;
+
+use std::sync::{Arc, Mutex};
+#[cfg(feature = "counter")]
+use std::thread;
+
+#[cfg(feature = "counter")]
+use blas::c64;
+#[cfg(feature = "counter")]
+use blas::counter;
+use blas::trace::{self, Call, Ring, Sink};
+#[cfg(not(feature = "reference"))]
+use libc::{c_char, c_double, c_int};
+
+#[cfg(not(feature = "reference"))]
+#[no_mangle]
+pub unsafe extern "C" fn dgemm_(
+    _transa: *const c_char,
+    _transb: *const c_char,
+    _m: *const c_int,
+    _n: *const c_int,
+    _k: *const c_int,
+    _alpha: *const c_double,
+    _a: *const c_double,
+    _lda: *const c_int,
+    _b: *const c_double,
+    _ldb: *const c_int,
+    _beta: *const c_double,
+    _c: *mut c_double,
+    _ldc: *const c_int,
+) {
+}
+
+#[cfg(all(feature = "counter", not(feature = "reference")))]
+#[no_mangle]
+pub unsafe extern "C" fn zscal_(
+    _n: *const c_int,
+    _a: *const c64,
+    _x: *mut c64,
+    _incx: *const c_int,
+) {
+}
+
+#[cfg(all(feature = "counter", not(feature = "reference")))]
+#[no_mangle]
+pub unsafe extern "C" fn zdscal_(
+    _n: *const c_int,
+    _a: *const c_double,
+    _x: *mut c64,
+    _incx: *const c_int,
+) {
+}
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+unsafe fn dgemm(m: i32, n: i32, k: i32) {
+    let a = vec![1.0; (m * k) as usize];
+    let b = vec![1.0; (k * n) as usize];
+    let mut c = vec![0.0; (m * n) as usize];
+    blas::dgemm(b'N', b'T', m, n, k, 1.0, &a, m, &b, n, 0.0, &mut c, m);
+}
+
+#[test]
+fn ring() {
+    let _lock = LOCK.lock().unwrap();
+    let ring = Arc::new(Ring::new(2));
+    trace::install(ring.clone());
+    unsafe {
+        dgemm(1, 1, 1);
+        dgemm(2, 3, 4);
+        dgemm(5, 6, 7);
+    }
+    trace::uninstall();
+    unsafe {
+        dgemm(1, 1, 1);
+    }
+    let calls = ring.calls();
+    assert_eq!(calls.len(), 2);
+    let call = &calls[0];
+    assert_eq!(call.routine, "dgemm");
+    assert_eq!(call.flags, vec![("transa", 'N'), ("transb", 'T')]);
+    assert_eq!(call.dimensions, vec![("m", 2), ("n", 3), ("k", 4)]);
+    assert_eq!(call.leading, vec![("lda", 2), ("ldb", 3), ("ldc", 2)]);
+    assert_eq!(call.flops, 48.0);
+    assert_eq!(call.bytes, 8.0 * (8.0 + 12.0 + 12.0));
+    assert_eq!(calls[1].dimension("k"), Some(7));
+}
+
+#[test]
+fn closure() {
+    let _lock = LOCK.lock().unwrap();
+    let routines = Arc::new(Mutex::new(vec![]));
+    let sink = {
+        let routines = routines.clone();
+        move |call: &Call| routines.lock().unwrap().push(call.routine)
+    };
+    trace::install(Arc::new(sink));
+    unsafe {
+        dgemm(1, 1, 1);
+    }
+    trace::uninstall();
+    assert_eq!(*routines.lock().unwrap(), vec!["dgemm"]);
+}
+
+#[test]
+fn object() {
+    let _lock = LOCK.lock().unwrap();
+    let ring = Arc::new(Ring::new(1));
+    let sink: Arc<dyn Sink> = ring.clone();
+    trace::install(sink);
+    unsafe {
+        dgemm(2, 2, 2);
+    }
+    trace::uninstall();
+    assert_eq!(ring.calls()[0].dimension("n"), Some(2));
+}
+
+#[cfg(feature = "counter")]
+#[test]
+fn counter() {
+    let _lock = LOCK.lock().unwrap();
+    counter::reset();
+    counter::enable();
+    unsafe {
+        dgemm(2, 3, 4);
+    }
+    thread::spawn(|| unsafe { dgemm(1, 1, 1) }).join().unwrap();
+    counter::disable();
+    unsafe {
+        dgemm(1, 1, 1);
+    }
+    let count = counter::thread().get("dgemm");
+    assert_eq!(count.calls, 1);
+    assert_eq!(count.flops, 48.0);
+    let total = counter::total();
+    assert_eq!(total.get("dgemm").calls, 2);
+    assert_eq!(total.sum().flops, 50.0);
+    counter::reset();
+    assert_eq!(counter::thread().sum().calls, 0);
+    assert_eq!(counter::total().sum().calls, 0);
+}
+
+#[cfg(feature = "counter")]
+#[test]
+fn real_scalars() {
+    let _lock = LOCK.lock().unwrap();
+    counter::reset();
+    counter::enable();
+    let mut x = vec![c64::new(1.0, 2.0); 3];
+    unsafe {
+        blas::zdscal(3, 2.0, &mut x, 1);
+        blas::zscal(3, c64::new(0.0, 1.0), &mut x, 1);
+    }
+    counter::disable();
+    let count = counter::thread();
+    assert_eq!(count.get("zdscal").flops, 6.0);
+    assert_eq!(count.get("zscal").flops, 12.0);
+    counter::reset();
+}